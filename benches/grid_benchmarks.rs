@@ -0,0 +1,46 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use proj_exercise_simple::coordinates::Coordinate;
+use proj_exercise_simple::grid::{project_regular_grid, project_regular_grid_into};
+use proj_exercise_simple::transformer::TransformerConfig;
+
+fn benchmark_regular_grids(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Regular Grid Projection");
+    let config = TransformerConfig::default();
+    let origin = Coordinate::new(-180.0, -80.0);
+
+    // Grids of increasing resolution over the Web Mercator range
+    for (cols, rows) in [(360, 160), (720, 320), (1440, 640)].iter() {
+        let (dx, dy) = (360.0 / *cols as f64, 160.0 / *rows as f64);
+
+        group.bench_function(format!("Grid of {}x{} points", cols, rows), |b| {
+            b.iter(|| {
+                let grid = project_regular_grid(black_box(origin), dx, dy, *cols, *rows, &config);
+                assert!(grid.is_ok());
+            })
+        });
+
+        let mut output = Vec::new();
+        group.bench_function(
+            format!("Grid of {}x{} points into a reused buffer", cols, rows),
+            |b| {
+                b.iter(|| {
+                    let result = project_regular_grid_into(
+                        black_box(origin),
+                        dx,
+                        dy,
+                        *cols,
+                        *rows,
+                        &config,
+                        &mut output,
+                    );
+                    assert!(result.is_ok());
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, benchmark_regular_grids);
+criterion_main!(benches);
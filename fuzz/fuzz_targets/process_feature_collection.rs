@@ -0,0 +1,17 @@
+//! Feed arbitrary JSON documents through the full processing pipeline
+//!
+//! Run with `cargo fuzz run process_feature_collection` from the repository
+//! root. The seed corpus in `fuzz/corpus/process_feature_collection` is also
+//! replayed by the regular test suite.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use proj_exercise_simple::testing::assert_pipeline_invariants;
+use proj_exercise_simple::transformer::TransformerConfig;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(json_value) = serde_json::from_slice(data) {
+        assert_pipeline_invariants(json_value, &mut TransformerConfig::default());
+    }
+});
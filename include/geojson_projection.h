@@ -0,0 +1,73 @@
+/* Generated with cbindgen from src/ffi.rs, do not edit by hand */
+
+#ifndef GEOJSON_PROJECTION_H
+#define GEOJSON_PROJECTION_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * The call succeeded and `output` holds the processed GeoJSON
+ */
+#define GP_OK 0
+
+/**
+ * A required pointer was null or a string was not valid UTF-8
+ */
+#define GP_ERROR_INVALID_ARGUMENT 1
+
+/**
+ * The input could not be parsed or projected
+ */
+#define GP_ERROR_PROCESSING 2
+
+/**
+ * The pipeline panicked, the panic was caught at the boundary
+ */
+#define GP_ERROR_PANIC 3
+
+/**
+ * A processing session, reusing one transformer configuration across calls
+ */
+typedef struct GpSession GpSession;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Project a GeoJSON document from one CRS to another
+ */
+int32_t gp_process_json(const char *input,
+                        const char *from_crs,
+                        const char *to_crs,
+                        char **output,
+                        char **error);
+
+/**
+ * Create a processing session for a pair of CRS, null if a CRS is invalid
+ */
+GpSession *gp_session_create(const char *from_crs, const char *to_crs);
+
+/**
+ * Project a GeoJSON document with the CRS of a session
+ */
+int32_t gp_session_process(GpSession *session, const char *input, char **output, char **error);
+
+/**
+ * Destroy a session, null is ignored
+ */
+void gp_session_destroy(GpSession *session);
+
+/**
+ * Free a string returned by this library, null is ignored
+ */
+void gp_free_string(char *value);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* GEOJSON_PROJECTION_H */
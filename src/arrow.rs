@@ -0,0 +1,471 @@
+//! Arrow export of projected feature collections
+//!
+//! Enabled by the `arrow` feature. A collection is projected through the
+//! processing pipeline and turned into one `RecordBatch`: the geometry column
+//! comes first, followed by one column per property key. Property columns
+//! are typed by `infer_schema`, after conflicting values were coerced to the
+//! majority type with `coerce_properties`. Values that still do not match
+//! their column type are written as nulls.
+
+use crate::error::ProjectionError;
+use crate::helpers::process_feature_collection_with_options;
+use crate::options::ProcessingOptions;
+use crate::schema::{coerce_properties, infer_schema, PropertyType};
+use crate::transformer::TransformerConfig;
+use arrow_array::builder::{BinaryBuilder, BooleanBuilder, Float64Builder, StringBuilder};
+use arrow_array::{
+    new_null_array, Array, ArrayRef, FixedSizeListArray, Float64Array, ListArray, RecordBatch,
+};
+use arrow_buffer::{NullBuffer, OffsetBuffer};
+use arrow_ipc::writer::StreamWriter;
+use arrow_schema::{DataType, Field, Schema};
+use geo::{Coord, Geometry, LineString, Polygon};
+use geojson::{Feature, FeatureCollection, GeoJson, JsonValue};
+use std::collections::HashMap;
+use std::io::Write;
+use std::sync::Arc;
+
+/// The name of the geometry column
+pub const GEOMETRY_COLUMN: &str = "geometry";
+
+/// How the geometry column of an exported batch is encoded
+///
+/// # Variants
+///
+/// * `Wkb` - ISO WKB in a binary column, geometry types may be mixed
+/// * `GeoArrowNative` - GeoArrow nested lists of interleaved coordinates, one geometry type per batch
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GeometryEncoding {
+    #[default]
+    Wkb,
+    GeoArrowNative,
+}
+
+/// Project a feature collection into an Arrow record batch
+///
+/// The geometry column carries the GeoArrow extension name and the target
+/// CRS in its field metadata. `GeoArrowNative` needs every geometry to have
+/// the same type: mixed single and multi geometries can be unified with
+/// `ProcessingOptions::with_coerce`, anything else is rejected with
+/// `ProjectionError::UnexpectedGeometry`. Features without a geometry get a
+/// null geometry cell.
+///
+/// # Arguments
+///
+/// * `collection` - The features to project
+/// * `encoding` - The encoding of the geometry column
+/// * `config` - A transformer config
+/// * `options` - Processing options, applied before the export
+///
+/// # Returns
+///
+/// * `Result<RecordBatch, ProjectionError>` - One row per output feature
+///
+/// # Example
+///
+/// ```rust
+/// use geojson::FeatureCollection;
+/// use proj_exercise_simple::arrow::{to_arrow, GeometryEncoding};
+/// use proj_exercise_simple::options::ProcessingOptions;
+/// use proj_exercise_simple::transformer::TransformerConfig;
+///
+/// let collection: FeatureCollection = r#"{"type": "FeatureCollection", "features": [
+///     {"type": "Feature", "properties": {"name": "a"},
+///      "geometry": {"type": "Point", "coordinates": [1.0, 2.0]}}
+/// ]}"#.parse().unwrap();
+/// let mut config = TransformerConfig::default();
+/// let batch = to_arrow(&collection, GeometryEncoding::Wkb, &mut config, &ProcessingOptions::new()).unwrap();
+/// assert_eq!(batch.num_rows(), 1);
+/// assert!(batch.column_by_name("name").is_some());
+/// ```
+pub fn to_arrow(
+    collection: &FeatureCollection,
+    encoding: GeometryEncoding,
+    config: &mut TransformerConfig,
+    options: &ProcessingOptions,
+) -> Result<RecordBatch, ProjectionError> {
+    let json_value = serde_json::to_value(collection).map_err(geojson::Error::from)?;
+    let mut geojson = process_feature_collection_with_options(json_value, config, options)?.geojson;
+    let schema = infer_schema(&geojson);
+    coerce_properties(&mut geojson, &schema);
+    let features = match geojson {
+        GeoJson::FeatureCollection(collection) => collection.features,
+        GeoJson::Feature(feature) => vec![feature],
+        GeoJson::Geometry(geometry) => vec![Feature::from(geometry)],
+    };
+
+    let geometries = features
+        .iter()
+        .map(|feature| {
+            feature
+                .geometry
+                .as_ref()
+                .map(|geometry| {
+                    Geometry::<f64>::try_from(geometry.value.clone()).map_err(ProjectionError::from)
+                })
+                .transpose()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let (extension, column) = match encoding {
+        GeometryEncoding::Wkb => ("geoarrow.wkb", wkb_column(&geometries)),
+        GeometryEncoding::GeoArrowNative => native_column(&geometries)?,
+    };
+    let metadata = HashMap::from([
+        ("ARROW:extension:name".to_string(), extension.to_string()),
+        (
+            "ARROW:extension:metadata".to_string(),
+            serde_json::json!({ "crs": config.to() }).to_string(),
+        ),
+    ]);
+    let mut fields =
+        vec![Field::new(GEOMETRY_COLUMN, column.data_type().clone(), true).with_metadata(metadata)];
+    let mut columns = vec![column];
+
+    for (key, property) in &schema.properties {
+        let column = property_column(&features, key, property.majority_type());
+        fields.push(Field::new(key, column.data_type().clone(), true));
+        columns.push(column);
+    }
+    Ok(RecordBatch::try_new(
+        Arc::new(Schema::new(fields)),
+        columns,
+    )?)
+}
+
+/// Write a record batch in the Arrow IPC streaming format
+///
+/// # Arguments
+///
+/// * `writer` - The destination of the stream
+/// * `batch` - The batch to write, usually from `to_arrow`
+///
+/// # Returns
+///
+/// * `Result<(), ProjectionError>` - An error if the batch cannot be encoded or written
+///
+/// # Example
+///
+/// ```rust
+/// use geojson::FeatureCollection;
+/// use proj_exercise_simple::arrow::{to_arrow, write_arrow_ipc, GeometryEncoding};
+/// use proj_exercise_simple::options::ProcessingOptions;
+/// use proj_exercise_simple::transformer::TransformerConfig;
+///
+/// let collection: FeatureCollection = r#"{"type": "FeatureCollection", "features": []}"#.parse().unwrap();
+/// let mut config = TransformerConfig::default();
+/// let batch = to_arrow(&collection, GeometryEncoding::Wkb, &mut config, &ProcessingOptions::new()).unwrap();
+/// let mut bytes = Vec::new();
+/// write_arrow_ipc(&mut bytes, &batch).unwrap();
+/// assert!(!bytes.is_empty());
+/// ```
+pub fn write_arrow_ipc<W: Write>(writer: W, batch: &RecordBatch) -> Result<(), ProjectionError> {
+    let mut writer = StreamWriter::try_new(writer, &batch.schema())?;
+    writer.write(batch)?;
+    writer.finish()?;
+    Ok(())
+}
+
+/// A column of the most frequent property type, with nulls for other values
+fn property_column(
+    features: &[Feature],
+    key: &str,
+    property_type: Option<PropertyType>,
+) -> ArrayRef {
+    let values = features.iter().map(|feature| feature.property(key));
+    match property_type {
+        Some(PropertyType::Bool) => {
+            let mut builder = BooleanBuilder::new();
+            values.for_each(|value| builder.append_option(value.and_then(JsonValue::as_bool)));
+            Arc::new(builder.finish())
+        }
+        Some(PropertyType::Number) => {
+            let mut builder = Float64Builder::new();
+            values.for_each(|value| builder.append_option(value.and_then(JsonValue::as_f64)));
+            Arc::new(builder.finish())
+        }
+        Some(PropertyType::String) => {
+            let mut builder = StringBuilder::new();
+            values.for_each(|value| builder.append_option(value.and_then(JsonValue::as_str)));
+            Arc::new(builder.finish())
+        }
+        // Nested values are kept as JSON text
+        Some(nested @ (PropertyType::Array | PropertyType::Object)) => {
+            let mut builder = StringBuilder::new();
+            values.for_each(|value| {
+                builder.append_option(
+                    value
+                        .filter(|value| PropertyType::of(value) == nested)
+                        .map(JsonValue::to_string),
+                )
+            });
+            Arc::new(builder.finish())
+        }
+        Some(PropertyType::Null) | None => new_null_array(&DataType::Null, features.len()),
+    }
+}
+
+/// ISO WKB geometry type codes
+const WKB_POINT: u32 = 1;
+const WKB_LINE_STRING: u32 = 2;
+const WKB_POLYGON: u32 = 3;
+const WKB_MULTI_POINT: u32 = 4;
+const WKB_MULTI_LINE_STRING: u32 = 5;
+const WKB_MULTI_POLYGON: u32 = 6;
+const WKB_GEOMETRY_COLLECTION: u32 = 7;
+
+fn wkb_column(geometries: &[Option<Geometry<f64>>]) -> ArrayRef {
+    let mut builder = BinaryBuilder::new();
+    let mut wkb = Vec::new();
+    for geometry in geometries {
+        match geometry {
+            Some(geometry) => {
+                wkb.clear();
+                write_wkb(geometry, &mut wkb);
+                builder.append_value(&wkb);
+            }
+            None => builder.append_null(),
+        }
+    }
+    Arc::new(builder.finish())
+}
+
+/// Append the little endian, two-dimensional WKB of a geometry
+fn write_wkb(geometry: &Geometry<f64>, out: &mut Vec<u8>) {
+    let header = |out: &mut Vec<u8>, code: u32| {
+        out.push(1);
+        out.extend_from_slice(&code.to_le_bytes());
+    };
+    let count = |out: &mut Vec<u8>, count: usize| {
+        out.extend_from_slice(&(count as u32).to_le_bytes());
+    };
+    match geometry {
+        Geometry::Point(point) => {
+            header(out, WKB_POINT);
+            write_coord(point.0, out);
+        }
+        Geometry::Line(line) => {
+            write_wkb(&Geometry::LineString(LineString::from(*line)), out);
+        }
+        Geometry::LineString(line) => {
+            header(out, WKB_LINE_STRING);
+            write_line(line, out);
+        }
+        Geometry::Polygon(polygon) => {
+            header(out, WKB_POLYGON);
+            write_polygon(polygon, out);
+        }
+        Geometry::MultiPoint(points) => {
+            header(out, WKB_MULTI_POINT);
+            count(out, points.0.len());
+            for point in points {
+                write_wkb(&Geometry::Point(*point), out);
+            }
+        }
+        Geometry::MultiLineString(lines) => {
+            header(out, WKB_MULTI_LINE_STRING);
+            count(out, lines.0.len());
+            for line in lines {
+                header(out, WKB_LINE_STRING);
+                write_line(line, out);
+            }
+        }
+        Geometry::MultiPolygon(polygons) => {
+            header(out, WKB_MULTI_POLYGON);
+            count(out, polygons.0.len());
+            for polygon in polygons {
+                header(out, WKB_POLYGON);
+                write_polygon(polygon, out);
+            }
+        }
+        Geometry::GeometryCollection(collection) => {
+            header(out, WKB_GEOMETRY_COLLECTION);
+            count(out, collection.0.len());
+            for member in collection {
+                write_wkb(member, out);
+            }
+        }
+        Geometry::Rect(rect) => write_wkb(&Geometry::Polygon(rect.to_polygon()), out),
+        Geometry::Triangle(triangle) => write_wkb(&Geometry::Polygon(triangle.to_polygon()), out),
+    }
+}
+
+fn write_coord(coord: Coord<f64>, out: &mut Vec<u8>) {
+    out.extend_from_slice(&coord.x.to_le_bytes());
+    out.extend_from_slice(&coord.y.to_le_bytes());
+}
+
+fn write_line(line: &LineString<f64>, out: &mut Vec<u8>) {
+    out.extend_from_slice(&(line.0.len() as u32).to_le_bytes());
+    for coord in line {
+        write_coord(*coord, out);
+    }
+}
+
+fn write_polygon(polygon: &Polygon<f64>, out: &mut Vec<u8>) {
+    out.extend_from_slice(&(polygon.interiors().len() as u32 + 1).to_le_bytes());
+    write_line(polygon.exterior(), out);
+    for hole in polygon.interiors() {
+        write_line(hole, out);
+    }
+}
+
+/// The GeoArrow extension name and the child field names of each list level, outermost first
+fn native_layout(geometry: &Geometry<f64>) -> Option<(&'static str, &'static [&'static str])> {
+    match geometry {
+        Geometry::Point(_) => Some(("geoarrow.point", &[])),
+        Geometry::LineString(_) => Some(("geoarrow.linestring", &["vertices"])),
+        Geometry::MultiPoint(_) => Some(("geoarrow.multipoint", &["points"])),
+        Geometry::Polygon(_) => Some(("geoarrow.polygon", &["rings", "vertices"])),
+        Geometry::MultiLineString(_) => {
+            Some(("geoarrow.multilinestring", &["linestrings", "vertices"]))
+        }
+        Geometry::MultiPolygon(_) => {
+            Some(("geoarrow.multipolygon", &["polygons", "rings", "vertices"]))
+        }
+        _ => None,
+    }
+}
+
+/// Interleaved coordinates and the list lengths of every nesting level
+struct NativeColumn {
+    coords: Vec<f64>,
+    lengths: Vec<Vec<usize>>,
+    validity: Vec<bool>,
+}
+
+impl NativeColumn {
+    fn push_line(&mut self, line: &LineString<f64>, level: usize) {
+        for coord in line {
+            self.coords.extend([coord.x, coord.y]);
+        }
+        self.lengths[level].push(line.0.len());
+    }
+
+    fn push_polygon(&mut self, polygon: &Polygon<f64>, level: usize) {
+        self.lengths[level].push(polygon.interiors().len() + 1);
+        self.push_line(polygon.exterior(), level + 1);
+        for hole in polygon.interiors() {
+            self.push_line(hole, level + 1);
+        }
+    }
+
+    fn push(&mut self, geometry: Option<&Geometry<f64>>) {
+        self.validity.push(geometry.is_some());
+        match geometry {
+            Some(Geometry::Point(point)) => self.coords.extend([point.x(), point.y()]),
+            Some(Geometry::LineString(line)) => self.push_line(line, 0),
+            Some(Geometry::MultiPoint(points)) => {
+                self.lengths[0].push(points.0.len());
+                for point in points {
+                    self.coords.extend([point.x(), point.y()]);
+                }
+            }
+            Some(Geometry::Polygon(polygon)) => self.push_polygon(polygon, 0),
+            Some(Geometry::MultiLineString(lines)) => {
+                self.lengths[0].push(lines.0.len());
+                for line in lines {
+                    self.push_line(line, 1);
+                }
+            }
+            Some(Geometry::MultiPolygon(polygons)) => {
+                self.lengths[0].push(polygons.0.len());
+                for polygon in polygons {
+                    self.push_polygon(polygon, 1);
+                }
+            }
+            // A null point still takes a coordinate slot, a null list is empty
+            _ => match self.lengths.first_mut() {
+                Some(lengths) => lengths.push(0),
+                None => self.coords.extend([0.0, 0.0]),
+            },
+        }
+    }
+
+    fn finish(self, names: &[&str]) -> ArrayRef {
+        let nulls = Some(NullBuffer::from(self.validity)).filter(|nulls| nulls.null_count() > 0);
+        let coord_field = Arc::new(Field::new("xy", DataType::Float64, false));
+        let values = Arc::new(Float64Array::from(self.coords));
+        let mut array: ArrayRef = Arc::new(FixedSizeListArray::new(
+            coord_field,
+            2,
+            values,
+            if names.is_empty() {
+                nulls.clone()
+            } else {
+                None
+            },
+        ));
+        for (level, lengths) in self.lengths.into_iter().enumerate().rev() {
+            let field = Arc::new(Field::new(names[level], array.data_type().clone(), false));
+            let level_nulls = if level == 0 { nulls.clone() } else { None };
+            array = Arc::new(ListArray::new(
+                field,
+                OffsetBuffer::from_lengths(lengths),
+                array,
+                level_nulls,
+            ));
+        }
+        array
+    }
+}
+
+fn native_column(
+    geometries: &[Option<Geometry<f64>>],
+) -> Result<(&'static str, ArrayRef), ProjectionError> {
+    let type_name = |geometry: &Geometry<f64>| -> &'static str {
+        match geometry {
+            Geometry::Point(_) => "Point",
+            Geometry::Line(_) => "Line",
+            Geometry::LineString(_) => "LineString",
+            Geometry::Polygon(_) => "Polygon",
+            Geometry::MultiPoint(_) => "MultiPoint",
+            Geometry::MultiLineString(_) => "MultiLineString",
+            Geometry::MultiPolygon(_) => "MultiPolygon",
+            Geometry::GeometryCollection(_) => "GeometryCollection",
+            Geometry::Rect(_) => "Rect",
+            Geometry::Triangle(_) => "Triangle",
+        }
+    };
+    let mut first: Option<&Geometry<f64>> = None;
+    for (feature_index, geometry) in geometries.iter().enumerate() {
+        let Some(geometry) = geometry else {
+            continue;
+        };
+        let unexpected = |reason: String| ProjectionError::UnexpectedGeometry {
+            feature_index,
+            reason,
+        };
+        if native_layout(geometry).is_none() {
+            return Err(unexpected(format!(
+                "a {} has no GeoArrow native encoding, use WKB",
+                type_name(geometry)
+            )));
+        }
+        match first {
+            None => first = Some(geometry),
+            Some(first) if type_name(first) != type_name(geometry) => {
+                return Err(unexpected(format!(
+                    "a {} after a {}, a GeoArrow native column holds one geometry type; \
+                     coerce the geometries or use WKB",
+                    type_name(geometry),
+                    type_name(first)
+                )));
+            }
+            Some(_) => {}
+        }
+    }
+
+    // A column without any geometry is written as null points
+    let (extension, names) = first
+        .and_then(native_layout)
+        .unwrap_or(("geoarrow.point", &[]));
+    let mut column = NativeColumn {
+        coords: Vec::new(),
+        lengths: vec![Vec::with_capacity(geometries.len()); names.len()],
+        validity: Vec::with_capacity(geometries.len()),
+    };
+    for geometry in geometries {
+        column.push(geometry.as_ref());
+    }
+    Ok((extension, column.finish(names)))
+}
@@ -0,0 +1,118 @@
+use crate::helpers::ProcessingWarning;
+use crate::vertices::{for_each_position, for_each_position_mut};
+use geojson::{Bbox, GeoJson, Geometry};
+
+/// The axis order of input positions
+///
+/// GeoJSON positions are longitude first, but plenty of real-world data has
+/// latitude first.
+///
+/// # Variants
+///
+/// * `LonLat` - Positions are `[longitude, latitude]`, as RFC 7946 requires
+/// * `LatLon` - Positions are `[latitude, longitude]` and are swapped before processing
+/// * `Auto` - Swap a feature when every `|x| <= 90` and some `|y| > 90`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AxisOrder {
+    #[default]
+    LonLat,
+    LatLon,
+    Auto,
+}
+
+/// Bring the positions of a GeoJSON object into longitude-latitude order
+///
+/// With `Auto`, each feature is checked on its own. A swap is recorded as a
+/// warning, and so is a feature whose axis order cannot be told apart because
+/// every coordinate is within `[-90, 90]`.
+///
+/// # Arguments
+///
+/// * `geojson` - The GeoJSON object to correct in place
+/// * `order` - The axis order of the input
+/// * `warnings` - Receives a warning for every feature handled by `Auto`
+pub(crate) fn correct_axis_order(
+    geojson: &mut GeoJson,
+    order: AxisOrder,
+    warnings: &mut Vec<ProcessingWarning>,
+) {
+    if order == AxisOrder::LonLat {
+        return;
+    }
+    match geojson {
+        GeoJson::FeatureCollection(collection) => {
+            let mut swapped_any = false;
+            for (index, feature) in collection.features.iter_mut().enumerate() {
+                if let Some(geometry) = feature.geometry.as_mut() {
+                    if correct_geometry(geometry, order, index, warnings) {
+                        feature.bbox.iter_mut().for_each(swap_bbox);
+                        swapped_any = true;
+                    }
+                }
+            }
+            if swapped_any {
+                collection.bbox.iter_mut().for_each(swap_bbox);
+            }
+        }
+        GeoJson::Feature(feature) => {
+            if let Some(geometry) = feature.geometry.as_mut() {
+                if correct_geometry(geometry, order, 0, warnings) {
+                    feature.bbox.iter_mut().for_each(swap_bbox);
+                }
+            }
+        }
+        GeoJson::Geometry(geometry) => {
+            correct_geometry(geometry, order, 0, warnings);
+        }
+    }
+}
+
+/// Swap the positions of one geometry if needed, returns whether it was swapped
+fn correct_geometry(
+    geometry: &mut Geometry,
+    order: AxisOrder,
+    feature_index: usize,
+    warnings: &mut Vec<ProcessingWarning>,
+) -> bool {
+    if order == AxisOrder::Auto {
+        let mut x_within_latitude = true;
+        let mut y_beyond_latitude = false;
+        for_each_position(&geometry.value, &mut |p| {
+            if let [x, y, ..] = p {
+                x_within_latitude &= x.abs() <= 90.0;
+                y_beyond_latitude |= y.abs() > 90.0;
+            }
+        });
+        if !x_within_latitude {
+            return false;
+        }
+        if !y_beyond_latitude {
+            warnings.push(ProcessingWarning::new(
+                feature_index,
+                "axis order is ambiguous, assuming longitude first",
+            ));
+            return false;
+        }
+        warnings.push(ProcessingWarning::new(
+            feature_index,
+            "positions look latitude first, swapped to longitude first",
+        ));
+    }
+
+    for_each_position_mut(&mut geometry.value, &mut |p| {
+        if p.len() >= 2 {
+            p.swap(0, 1);
+        }
+    });
+    geometry.bbox.iter_mut().for_each(swap_bbox);
+    true
+}
+
+/// Swap the x and y bounds of a 2D or 3D bounding box
+fn swap_bbox(bbox: &mut Bbox) {
+    let dimensions = bbox.len() / 2;
+    if dimensions >= 2 {
+        bbox.swap(0, 1);
+        bbox.swap(dimensions, dimensions + 1);
+    }
+}
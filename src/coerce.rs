@@ -0,0 +1,84 @@
+use crate::error::ProjectionError;
+use crate::helpers::ProcessedGeometry;
+use geo::{MultiLineString, MultiPoint, MultiPolygon};
+
+/// The geometry type family output geometries are coerced to
+///
+/// # Variants
+///
+/// * `Multi` - Wrap single geometries into their multi counterpart
+/// * `SingleOrError` - Unwrap single-member multi geometries, error on more members
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoerceTo {
+    Multi,
+    SingleOrError,
+}
+
+/// Coerce a processed geometry to the requested geometry type family
+///
+/// Geometry collections cannot be coerced and are rejected, they have to be
+/// exploded into their members first.
+///
+/// # Arguments
+///
+/// * `geometry` - The processed geometry
+/// * `coerce` - The target geometry type family
+/// * `feature_index` - The index of the feature, used in errors
+///
+/// # Returns
+///
+/// * `Result<ProcessedGeometry, ProjectionError>` - The coerced geometry
+pub(crate) fn coerce_geometry(
+    geometry: ProcessedGeometry,
+    coerce: CoerceTo,
+    feature_index: usize,
+) -> Result<ProcessedGeometry, ProjectionError> {
+    let unexpected = |reason: String| ProjectionError::UnexpectedGeometry {
+        feature_index,
+        reason,
+    };
+    match (coerce, geometry) {
+        (_, ProcessedGeometry::GeometryCollection(_)) => Err(unexpected(
+            "a GeometryCollection cannot be coerced, explode it instead".to_string(),
+        )),
+        (CoerceTo::Multi, ProcessedGeometry::Point(point)) => {
+            Ok(ProcessedGeometry::MultiPoint(MultiPoint::new(vec![point])))
+        }
+        (CoerceTo::Multi, ProcessedGeometry::LineString(line)) => Ok(
+            ProcessedGeometry::MultiLineString(MultiLineString::new(vec![line])),
+        ),
+        (CoerceTo::Multi, ProcessedGeometry::Polygon(polygon)) => {
+            Ok(ProcessedGeometry::MultiPolygon(MultiPolygon::new(vec![
+                polygon,
+            ])))
+        }
+        (CoerceTo::SingleOrError, ProcessedGeometry::MultiPoint(multi)) => {
+            let count = multi.0.len();
+            single_member(multi.0)
+                .map(ProcessedGeometry::Point)
+                .ok_or_else(|| unexpected(format!("MultiPoint with {} members", count)))
+        }
+        (CoerceTo::SingleOrError, ProcessedGeometry::MultiLineString(multi)) => {
+            let count = multi.0.len();
+            single_member(multi.0)
+                .map(ProcessedGeometry::LineString)
+                .ok_or_else(|| unexpected(format!("MultiLineString with {} members", count)))
+        }
+        (CoerceTo::SingleOrError, ProcessedGeometry::MultiPolygon(multi)) => {
+            let count = multi.0.len();
+            single_member(multi.0)
+                .map(ProcessedGeometry::Polygon)
+                .ok_or_else(|| unexpected(format!("MultiPolygon with {} members", count)))
+        }
+        (_, geometry) => Ok(geometry),
+    }
+}
+
+/// Take the only member of a multi geometry
+fn single_member<T>(mut members: Vec<T>) -> Option<T> {
+    if members.len() == 1 {
+        members.pop()
+    } else {
+        None
+    }
+}
@@ -1,6 +1,6 @@
 use crate::coordinates::{Coordinate, Line, Polygon};
 use geo::{CoordsIter, LineString, Point, Polygon as GeoPolygon};
-use geojson::Value;
+use geojson::{Geometry, Value};
 
 pub trait ToGeo {
     type Output;
@@ -12,7 +12,16 @@ pub trait FromGeo<T> {
 }
 
 pub trait ToGeoJson {
-    fn to_geojson(&self) -> Value;
+    fn to_geojson_value(&self) -> Value;
+
+    fn to_geojson_geometry(&self) -> Geometry {
+        Geometry::new(self.to_geojson_value())
+    }
+
+    #[deprecated(since = "0.2.0", note = "use to_geojson_value()")]
+    fn to_geojson(&self) -> Value {
+        self.to_geojson_value()
+    }
 }
 
 impl ToGeo for Coordinate {
@@ -65,19 +74,19 @@ impl ToGeo for Polygon {
 }
 
 impl ToGeoJson for Coordinate {
-    fn to_geojson(&self) -> Value {
+    fn to_geojson_value(&self) -> Value {
         Value::Point(vec![self.x, self.y])
     }
 }
 
 impl ToGeoJson for Line {
-    fn to_geojson(&self) -> Value {
+    fn to_geojson_value(&self) -> Value {
         Value::LineString(self.to_vecs())
     }
 }
 
 impl ToGeoJson for Polygon {
-    fn to_geojson(&self) -> Value {
+    fn to_geojson_value(&self) -> Value {
         let mut rings = vec![self.exterior.to_vecs()];
         rings.extend(self.interiors.iter().map(|l| l.to_vecs()));
         Value::Polygon(rings)
@@ -1,13 +1,31 @@
+use crate::densify::{densify_positions, Densify, DensifyMethod};
+use crate::error::ProjectionError;
+use crate::simplification::perpendicular_distance;
+use crate::tiles::clip_segment;
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
 use std::fmt;
+use std::io::{BufRead, BufReader, Read};
 use std::iter::FromIterator;
+use std::str::FromStr;
 
 /// A 2D coordinate with x and y values
-#[derive(Debug, Clone, Copy, PartialEq)]
+///
+/// Coordinates compare by value like `f64`, except that every NaN equals
+/// every other NaN, so equality and ordering agree.
+#[derive(Debug, Clone, Copy)]
 pub struct Coordinate {
     pub x: f64,
     pub y: f64,
 }
 
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Coordinate>();
+    assert_send_sync::<Line>();
+    assert_send_sync::<Polygon>();
+};
+
 impl Coordinate {
     /// Create a new coordinate
     ///
@@ -78,270 +96,1889 @@ impl Coordinate {
     pub fn to_vec(&self) -> Vec<f64> {
         vec![self.x, self.y]
     }
-}
 
-impl From<geo::Point<f64>> for Coordinate {
-    /// Convert a geo point to a coordinate
+    /// Convert a coordinate to a GeoJSON point
+    ///
+    /// # Returns
+    ///
+    /// * `geojson::Value` - A GeoJSON point
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::coordinates::Coordinate;
+    ///
+    /// let value = Coordinate::new(13.377, 52.518).to_geojson_value();
+    /// assert_eq!(value, geojson::Value::Point(vec![13.377, 52.518]));
+    /// ```
+    pub fn to_geojson_value(&self) -> geojson::Value {
+        geojson::Value::Point(self.to_vec())
+    }
+
+    /// Convert a coordinate to a GeoJSON point geometry
+    ///
+    /// # Returns
+    ///
+    /// * `geojson::Geometry` - A GeoJSON geometry wrapping `to_geojson_value`
+    pub fn to_geojson_geometry(&self) -> geojson::Geometry {
+        geojson::Geometry::new(self.to_geojson_value())
+    }
+
+    /// Extract a coordinate from a GeoJSON point
+    ///
+    /// Elements beyond the first two, such as an altitude, are ignored.
     ///
     /// # Arguments
     ///
-    /// * `point` - A geo point
+    /// * `value` - A GeoJSON geometry value
     ///
     /// # Returns
     ///
-    /// * `Coordinate` - A coordinate
+    /// * `Result<Coordinate, ProjectionError>` - An error if the value is not a point or has fewer than 2 elements
     ///
     /// # Example
     ///
     /// ```rust
+    /// use geojson::Value;
     /// use proj_exercise_simple::coordinates::Coordinate;
     ///
-    /// let point = geo::Point::new(13.377, 52.518);
-    /// let coord = Coordinate::from(point);
+    /// let coord = Coordinate::from_geojson_point(&Value::Point(vec![13.377, 52.518])).unwrap();
+    /// assert_eq!(coord, Coordinate::new(13.377, 52.518));
     /// ```
-    fn from(point: geo::Point<f64>) -> Self {
-        Self {
-            x: point.x(),
-            y: point.y(),
+    pub fn from_geojson_point(value: &geojson::Value) -> Result<Self, ProjectionError> {
+        match value {
+            geojson::Value::Point(position) if position.len() >= 2 => {
+                Ok(Self::new(position[0], position[1]))
+            }
+            geojson::Value::Point(_) => Err(ProjectionError::InvalidCoordinates(
+                "Point coordinate array has fewer than 2 elements".to_string(),
+            )),
+            other => Err(ProjectionError::InvalidCoordinates(format!(
+                "Expected Point geometry, got {}",
+                other.type_name()
+            ))),
         }
     }
-}
 
-impl From<Coordinate> for geo::Point<f64> {
-    /// Convert a coordinate to a geo point
+    /// Create a coordinate from a GeoJSON position
+    ///
+    /// Ordinates beyond x and y, such as Z and M values, are ignored.
     ///
     /// # Arguments
     ///
-    /// * `coord` - A coordinate
+    /// * `position` - A GeoJSON position
     ///
     /// # Returns
     ///
-    /// * `geo::Point<f64>` - A geo point
+    /// * `Result<Coordinate, ProjectionError>` - An error if the position has fewer than 2 elements
     ///
     /// # Example
     ///
     /// ```rust
     /// use proj_exercise_simple::coordinates::Coordinate;
     ///
-    /// let coord = Coordinate::new(13.377, 52.518);
-    /// let point = geo::Point::from(coord);
+    /// let coord = Coordinate::try_from_slice(&[13.377, 52.518, 35.0]).unwrap();
+    /// assert_eq!(coord, Coordinate::new(13.377, 52.518));
+    /// assert!(Coordinate::try_from_slice(&[13.377]).is_err());
     /// ```
-    fn from(coord: Coordinate) -> Self {
-        Self::new(coord.x, coord.y)
-    }
-}
-
-impl fmt::Display for Coordinate {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "({}, {})", self.x, self.y)
+    pub fn try_from_slice(position: &[f64]) -> Result<Self, ProjectionError> {
+        match position {
+            [x, y, ..] => Ok(Self::new(*x, *y)),
+            _ => Err(ProjectionError::InvalidCoordinates(
+                "Position has fewer than 2 elements".to_string(),
+            )),
+        }
     }
-}
-
-/// A collection of coordinates that form a line
-#[derive(Debug, Clone)]
-pub struct Line {
-    pub coordinates: Vec<Coordinate>,
-}
 
-impl Line {
-    /// Create a new line from a vector of coordinates
+    /// Get the index of the Web Mercator map tile containing a WGS84 coordinate
+    ///
+    /// Uses the Slippy Map tile numbering, with tile (0, 0) in the north-west
+    /// corner. Latitudes beyond the Web Mercator limit of about ±85.0511° and
+    /// longitudes outside ±180° are clamped to the outermost tiles.
     ///
     /// # Arguments
     ///
-    /// * `coordinates` - A vector of coordinates
+    /// * `zoom` - The zoom level, `2^zoom` tiles per axis
     ///
     /// # Returns
     ///
-    /// * `Line` - A new line
+    /// * `(u32, u32)` - The x and y tile index
     ///
     /// # Example
     ///
     /// ```rust
     /// use proj_exercise_simple::coordinates::Coordinate;
-    /// use proj_exercise_simple::coordinates::Line;
     ///
-    /// let coords = vec![Coordinate::new(13.377, 52.518), Coordinate::new(13.377, 52.518)];
-    /// let line = Line::new(coords);
+    /// let reichstag = Coordinate::new(13.376, 52.518);
+    /// assert_eq!(reichstag.to_tile_index(10), (550, 335));
     /// ```
-    pub fn new(coordinates: Vec<Coordinate>) -> Self {
-        Self { coordinates }
+    pub fn to_tile_index(&self, zoom: u8) -> (u32, u32) {
+        let tiles = 2f64.powi(zoom as i32);
+        let latitude = self
+            .y
+            .clamp(-MAX_MERCATOR_LATITUDE, MAX_MERCATOR_LATITUDE)
+            .to_radians();
+        let x = (self.x + 180.0) / 360.0 * tiles;
+        let y = (1.0 - latitude.tan().asinh() / std::f64::consts::PI) / 2.0 * tiles;
+        let clamp = |index: f64| index.floor().clamp(0.0, tiles - 1.0) as u32;
+        (clamp(x), clamp(y))
     }
 
-    /// Convert a line to a GeoJSON line string
+    /// Get the WGS84 bounds of a Web Mercator map tile
+    ///
+    /// # Arguments
+    ///
+    /// * `tile_x` - The x tile index, counted from the antimeridian eastwards
+    /// * `tile_y` - The y tile index, counted from the north
+    /// * `zoom` - The zoom level
     ///
     /// # Returns
     ///
-    /// * `geojson::Value` - A GeoJSON line string
+    /// * `BoundingBox` - The longitude and latitude range covered by the tile
     ///
     /// # Example
     ///
     /// ```rust
     /// use proj_exercise_simple::coordinates::Coordinate;
-    /// use proj_exercise_simple::coordinates::Line;
     ///
-    /// let coords = vec![Coordinate::new(13.377, 52.518), Coordinate::new(13.377, 52.518)];
-    /// let line = Line::new(coords);
-    /// let geojson = line.to_geojson();
+    /// let world = Coordinate::to_tile_bounds(0, 0, 0);
+    /// assert_eq!((world.min.x, world.max.x), (-180.0, 180.0));
+    /// assert!((world.max.y - 85.0511).abs() < 1e-4);
     /// ```
-    pub fn to_geojson(&self) -> geojson::Value {
-        geojson::Value::LineString(Coordinate::to_vecs(&self.coordinates))
+    pub fn to_tile_bounds(tile_x: u32, tile_y: u32, zoom: u8) -> BoundingBox {
+        let tiles = 2f64.powi(zoom as i32);
+        let longitude = |x: f64| x / tiles * 360.0 - 180.0;
+        let latitude = |y: f64| {
+            (std::f64::consts::PI * (1.0 - 2.0 * y / tiles))
+                .sinh()
+                .atan()
+                .to_degrees()
+        };
+        BoundingBox::new(
+            longitude(tile_x as f64),
+            longitude(tile_x as f64 + 1.0),
+            latitude(tile_y as f64 + 1.0),
+            latitude(tile_y as f64),
+        )
     }
 
-    /// Convert a line to a geo line string
+    /// Get the midpoint on the great circle between two WGS84 coordinates
+    ///
+    /// The earth is treated as a sphere. Over long distances this differs a
+    /// lot from the arithmetic mean of the coordinates. For antipodal points
+    /// the great circle is not unique and the result is arbitrary.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The other longitude and latitude, in degrees
     ///
     /// # Returns
     ///
-    /// * `geo::LineString<f64>` - A geo line string
+    /// * `Coordinate` - The midpoint, with the longitude normalized to ±180°
     ///
     /// # Example
     ///
     /// ```rust
     /// use proj_exercise_simple::coordinates::Coordinate;
-    /// use proj_exercise_simple::coordinates::Line;
     ///
-    /// let coords = vec![Coordinate::new(13.377, 52.518), Coordinate::new(13.377, 52.518)];
-    /// let line = Line::new(coords);
-    /// let geo = line.to_geo();
+    /// let midpoint = Coordinate::new(0.0, 0.0).geodesic_midpoint(&Coordinate::new(90.0, 0.0));
+    /// assert!((midpoint.x - 45.0).abs() < 1e-9);
+    /// assert!(midpoint.y.abs() < 1e-9);
     /// ```
-    pub fn to_geo(&self) -> geo::LineString<f64> {
-        geo::LineString::from(
-            self.coordinates
-                .iter()
-                .map(|c| geo::Coord::from((c.x, c.y)))
-                .collect::<Vec<_>>(),
-        )
-    }
-
-    pub fn from_geo(ls: &geo::LineString<f64>) -> Self {
-        Self {
-            coordinates: ls.coords().map(|c| Coordinate::new(c.x, c.y)).collect(),
-        }
-    }
-
-    pub fn to_vecs(&self) -> Vec<Vec<f64>> {
-        self.coordinates.iter().map(|c| c.to_vec()).collect()
+    pub fn geodesic_midpoint(&self, other: &Coordinate) -> Coordinate {
+        let (lon1, lat1) = (self.x.to_radians(), self.y.to_radians());
+        let (lat2, delta_lon) = (other.y.to_radians(), (other.x - self.x).to_radians());
+        let bx = lat2.cos() * delta_lon.cos();
+        let by = lat2.cos() * delta_lon.sin();
+        let lat = (lat1.sin() + lat2.sin()).atan2(((lat1.cos() + bx).powi(2) + by.powi(2)).sqrt());
+        let lon = lon1 + by.atan2(lat1.cos() + bx);
+        let lon = (lon.to_degrees() + 540.0).rem_euclid(360.0) - 180.0;
+        Coordinate::new(lon, lat.to_degrees())
     }
 }
 
-impl FromIterator<Coordinate> for Line {
-    /// Create a new line from an iterator of coordinates
+/// The largest latitude representable in Web Mercator, in degrees
+pub(crate) const MAX_MERCATOR_LATITUDE: f64 = 85.051_128_779_806_59;
+
+impl From<geo::Point<f64>> for Coordinate {
+    /// Convert a geo point to a coordinate
     ///
     /// # Arguments
     ///
-    /// * `iter` - An iterator of coordinates
+    /// * `point` - A geo point
     ///
     /// # Returns
     ///
-    /// * `Line` - A new line
+    /// * `Coordinate` - A coordinate
     ///
     /// # Example
     ///
     /// ```rust
     /// use proj_exercise_simple::coordinates::Coordinate;
-    /// use proj_exercise_simple::coordinates::Line;
     ///
-    /// let coords = vec![Coordinate::new(13.377, 52.518), Coordinate::new(13.377, 52.518)];
-    /// let line = Line::from_iter(coords);
+    /// let point = geo::Point::new(13.377, 52.518);
+    /// let coord = Coordinate::from(point);
     /// ```
-    fn from_iter<T: IntoIterator<Item = Coordinate>>(iter: T) -> Self {
+    fn from(point: geo::Point<f64>) -> Self {
         Self {
-            coordinates: iter.into_iter().collect(),
+            x: point.x(),
+            y: point.y(),
         }
     }
 }
 
-/// A collection of lines that form a polygon
-#[derive(Debug, Clone)]
-pub struct Polygon {
-    pub exterior: Line,
-    pub interiors: Vec<Line>,
-}
-
-impl Polygon {
-    /// Create a new polygon from an exterior line and interior lines
+impl From<Coordinate> for geo::Point<f64> {
+    /// Convert a coordinate to a geo point
     ///
     /// # Arguments
     ///
-    /// * `exterior` - The exterior line
-    /// * `interiors` - The interior lines
+    /// * `coord` - A coordinate
     ///
     /// # Returns
     ///
-    /// * `Polygon` - A new polygon
+    /// * `geo::Point<f64>` - A geo point
     ///
     /// # Example
     ///
     /// ```rust
     /// use proj_exercise_simple::coordinates::Coordinate;
-    /// use proj_exercise_simple::coordinates::Line;
-    /// use proj_exercise_simple::coordinates::Polygon;
     ///
-    /// let coords = vec![Coordinate::new(13.377, 52.518), Coordinate::new(13.377, 52.518)];
-    /// let line = Line::new(coords);
-    /// let polygon = Polygon::new(line, vec![]);
+    /// let coord = Coordinate::new(13.377, 52.518);
+    /// let point = geo::Point::from(coord);
     /// ```
-    pub fn new(exterior: Line, interiors: Vec<Line>) -> Self {
-        Self {
-            exterior,
-            interiors,
-        }
+    fn from(coord: Coordinate) -> Self {
+        Self::new(coord.x, coord.y)
     }
+}
 
-    /// Convert a polygon to a GeoJSON polygon
+impl From<[f64; 2]> for Coordinate {
+    /// Convert an `[x, y]` array to a coordinate
     ///
-    /// # Returns
+    /// # Example
     ///
-    /// * `geojson::Value` - A GeoJSON polygon
+    /// ```rust
+    /// use proj_exercise_simple::coordinates::Coordinate;
+    ///
+    /// let coord = Coordinate::from([13.377, 52.518]);
+    /// assert_eq!(coord, Coordinate::new(13.377, 52.518));
+    /// ```
+    fn from([x, y]: [f64; 2]) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<Coordinate> for [f64; 2] {
+    /// Convert a coordinate to an `[x, y]` array
     ///
     /// # Example
     ///
     /// ```rust
     /// use proj_exercise_simple::coordinates::Coordinate;
-    /// use proj_exercise_simple::coordinates::Line;
-    /// use proj_exercise_simple::coordinates::Polygon;
     ///
-    /// let coords = vec![Coordinate::new(13.377, 52.518), Coordinate::new(13.377, 52.518)];
-    /// let line = Line::new(coords);
-    /// let polygon = Polygon::new(line, vec![]);
-    /// let geojson = polygon.to_geojson();
+    /// let array: [f64; 2] = Coordinate::new(13.377, 52.518).into();
+    /// assert_eq!(array, [13.377, 52.518]);
     /// ```
-    pub fn to_geojson(&self) -> geojson::Value {
-        let mut rings = vec![Coordinate::to_vecs(&self.exterior.coordinates)];
-        rings.extend(
-            self.interiors
-                .iter()
-                .map(|line| Coordinate::to_vecs(&line.coordinates)),
-        );
-        geojson::Value::Polygon(rings)
+    fn from(coord: Coordinate) -> Self {
+        [coord.x, coord.y]
     }
+}
 
-    /// Convert a polygon to a geo polygon
+impl From<(f64, f64)> for Coordinate {
+    /// Convert an `(x, y)` tuple to a coordinate
     ///
-    /// # Returns
+    /// # Example
     ///
-    /// * `geo::Polygon<f64>` - A geo polygon
+    /// ```rust
+    /// use proj_exercise_simple::coordinates::Coordinate;
+    ///
+    /// let coord = Coordinate::from((13.377, 52.518));
+    /// assert_eq!(coord, Coordinate::new(13.377, 52.518));
+    /// ```
+    fn from((x, y): (f64, f64)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<Coordinate> for (f64, f64) {
+    /// Convert a coordinate to an `(x, y)` tuple
     ///
     /// # Example
     ///
     /// ```rust
     /// use proj_exercise_simple::coordinates::Coordinate;
-    /// use proj_exercise_simple::coordinates::Line;
-    /// use proj_exercise_simple::coordinates::Polygon;
     ///
-    /// let coords = vec![Coordinate::new(13.377, 52.518), Coordinate::new(13.377, 52.518)];
-    /// let line = Line::new(coords);
-    /// let polygon = Polygon::new(line, vec![]);
-    /// let geo = polygon.to_geo();
+    /// let (x, y) = Coordinate::new(13.377, 52.518).into();
+    /// assert_eq!((x, y), (13.377, 52.518));
     /// ```
-    pub fn to_geo(&self) -> geo::Polygon<f64> {
-        let exterior = self.exterior.to_geo();
-        let interiors = self
-            .interiors
-            .iter()
-            .map(|line| line.to_geo())
-            .collect::<Vec<_>>();
-        geo::Polygon::new(exterior, interiors)
+    fn from(coord: Coordinate) -> Self {
+        (coord.x, coord.y)
+    }
+}
+
+impl fmt::Display for Coordinate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl FromStr for Coordinate {
+    type Err = ProjectionError;
+
+    /// Parse a coordinate from `x,y`, as in a CSV row
+    ///
+    /// Whitespace around the values is ignored, and so are the parentheses
+    /// written by `Display`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::coordinates::Coordinate;
+    ///
+    /// let coord: Coordinate = "13.377, 52.518".parse().unwrap();
+    /// assert_eq!(coord, Coordinate::new(13.377, 52.518));
+    /// assert_eq!(coord.to_string().parse::<Coordinate>().unwrap(), coord);
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let inner = trimmed
+            .strip_prefix('(')
+            .and_then(|rest| rest.strip_suffix(')'))
+            .unwrap_or(trimmed);
+        let invalid = |reason: &str| {
+            ProjectionError::InvalidCoordinates(format!("{reason} in {:?}", s.trim()))
+        };
+        let mut values = inner.split(',').map(|value| value.trim().parse::<f64>());
+        match (values.next(), values.next(), values.next()) {
+            (Some(Ok(x)), Some(Ok(y)), None) => Ok(Coordinate::new(x, y)),
+            (Some(_), Some(_), None) => Err(invalid("Invalid number")),
+            _ => Err(invalid("Expected two comma-separated values")),
+        }
+    }
+}
+
+/// Fold `-0.0` into `0.0` and every NaN into a single NaN, so `total_cmp` agrees with `==`
+fn canonical(value: f64) -> f64 {
+    if value == 0.0 {
+        0.0
+    } else if value.is_nan() {
+        f64::NAN
+    } else {
+        value
+    }
+}
+
+impl PartialEq for Coordinate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Coordinate {}
+
+impl PartialOrd for Coordinate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Coordinate {
+    /// Compare coordinates lexicographically, first by `x` then by `y`
+    ///
+    /// The comparison uses `f64::total_cmp` after folding `-0.0` into `0.0`
+    /// and all NaNs into one, so the order is total, agrees with `==` and is
+    /// safe to use in `BTreeMap`/`BTreeSet` or with `sort` and `dedup`. NaN
+    /// sorts after every number.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::coordinates::Coordinate;
+    /// use std::collections::BTreeSet;
+    ///
+    /// let set: BTreeSet<Coordinate> = vec![
+    ///     Coordinate::new(2.0, 1.0),
+    ///     Coordinate::new(1.0, 5.0),
+    ///     Coordinate::new(1.0, 2.0),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    /// let first = set.iter().next().unwrap();
+    /// assert_eq!(*first, Coordinate::new(1.0, 2.0));
+    /// ```
+    fn cmp(&self, other: &Self) -> Ordering {
+        canonical(self.x)
+            .total_cmp(&canonical(other.x))
+            .then_with(|| canonical(self.y).total_cmp(&canonical(other.y)))
+    }
+}
+
+/// The largest coordinate magnitude accepted by default, far beyond any real CRS
+pub const DEFAULT_MAX_MAGNITUDE: f64 = 1e15;
+
+/// Check that a position has finite x and y values no larger than a magnitude
+///
+/// Every input path checks positions with this before doing arithmetic on
+/// them, so huge values cannot overflow to infinity in areas, lengths or
+/// bounding boxes. Extra ordinates are not checked.
+///
+/// # Arguments
+///
+/// * `position` - The position, x and y followed by optional extra ordinates
+/// * `max_magnitude` - The largest absolute x and y value accepted
+///
+/// # Returns
+///
+/// * `Result<(f64, f64), ProjectionError>` - The x and y values, or an error for short positions, non-finite or too large values
+///
+/// # Example
+///
+/// ```rust
+/// use proj_exercise_simple::coordinates::{sanitize_position, DEFAULT_MAX_MAGNITUDE};
+///
+/// assert_eq!(sanitize_position(&[1.0, 2.0, 3.0], DEFAULT_MAX_MAGNITUDE).unwrap(), (1.0, 2.0));
+/// assert!(sanitize_position(&[1e308, 2.0], DEFAULT_MAX_MAGNITUDE).is_err());
+/// assert!(sanitize_position(&[f64::INFINITY, 2.0], DEFAULT_MAX_MAGNITUDE).is_err());
+/// ```
+pub fn sanitize_position(
+    position: &[f64],
+    max_magnitude: f64,
+) -> Result<(f64, f64), ProjectionError> {
+    let [x, y, ..] = *position else {
+        return Err(ProjectionError::InvalidCoordinates(
+            "Position has fewer than 2 elements".to_string(),
+        ));
+    };
+    if !x.is_finite() || !y.is_finite() {
+        return Err(ProjectionError::InvalidCoordinates(
+            "NaN or infinite coordinates are not allowed".to_string(),
+        ));
+    }
+    if x.abs() > max_magnitude || y.abs() > max_magnitude {
+        return Err(ProjectionError::InvalidCoordinates(format!(
+            "Coordinate magnitude exceeds {max_magnitude:e}"
+        )));
+    }
+    Ok((x, y))
+}
+
+/// Sum values with Kahan-Babuška compensation
+///
+/// The rounding error of every addition is carried along, so the sum of many
+/// segment lengths does not drift with the number of segments.
+pub(crate) fn compensated_sum(values: impl IntoIterator<Item = f64>) -> f64 {
+    let (mut sum, mut compensation) = (0.0_f64, 0.0_f64);
+    for value in values {
+        let next = sum + value;
+        compensation += if sum.abs() >= value.abs() {
+            (sum - next) + value
+        } else {
+            (value - next) + sum
+        };
+        sum = next;
+    }
+    sum + compensation
+}
+
+/// The signed shoelace area of a ring, positive for counter-clockwise rings
+///
+/// Points are translated towards their centroid first, so rings far from the
+/// origin do not lose their area to the rounding of large cross products.
+pub(crate) fn shoelace_signed_area(points: &[(f64, f64)]) -> f64 {
+    // A closing point would weigh the first point twice in the centroid
+    let open = match points {
+        [first, .., last] if first == last => &points[..points.len() - 1],
+        _ => points,
+    };
+    if open.len() < 3 {
+        return 0.0;
+    }
+    let count = open.len() as f64;
+    let (cx, cy) = open.iter().fold((0.0, 0.0), |(cx, cy), (x, y)| {
+        (cx + x / count, cy + y / count)
+    });
+    let twice_area = compensated_sum(
+        open.iter()
+            .zip(open.iter().cycle().skip(1))
+            .map(|(a, b)| (a.0 - cx) * (b.1 - cy) - (b.0 - cx) * (a.1 - cy)),
+    );
+    twice_area / 2.0
+}
+
+/// The range of valid input coordinates
+///
+/// Besides the range, coordinates must be finite and no larger than
+/// `max_magnitude`, which matters for ranges without finite limits.
+///
+/// # Fields
+///
+/// * `min_x` - The smallest valid x value
+/// * `max_x` - The largest valid x value
+/// * `min_y` - The smallest valid y value
+/// * `max_y` - The largest valid y value
+/// * `max_magnitude` - The largest absolute x and y value, `DEFAULT_MAX_MAGNITUDE` by default
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CoordinateBounds {
+    pub min_x: f64,
+    pub max_x: f64,
+    pub min_y: f64,
+    pub max_y: f64,
+    pub max_magnitude: f64,
+}
+
+impl Default for CoordinateBounds {
+    fn default() -> Self {
+        Self::wgs84()
+    }
+}
+
+impl CoordinateBounds {
+    /// Create a new coordinate range
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::coordinates::CoordinateBounds;
+    ///
+    /// let bounds = CoordinateBounds::new(0.0, 1_000_000.0, 0.0, 10_000_000.0);
+    /// assert!(bounds.validate(500_000.0, 5_800_000.0).is_ok());
+    /// ```
+    pub fn new(min_x: f64, max_x: f64, min_y: f64, max_y: f64) -> Self {
+        Self {
+            min_x,
+            max_x,
+            min_y,
+            max_y,
+            max_magnitude: DEFAULT_MAX_MAGNITUDE,
+        }
+    }
+
+    /// Set the largest absolute x and y value accepted
+    ///
+    /// # Arguments
+    ///
+    /// * `max_magnitude` - The largest absolute value
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::coordinates::CoordinateBounds;
+    ///
+    /// let bounds = CoordinateBounds::unbounded().with_max_magnitude(1e20);
+    /// assert!(bounds.validate(1e18, 0.0).is_ok());
+    /// assert!(CoordinateBounds::unbounded().validate(1e18, 0.0).is_err());
+    /// ```
+    pub fn with_max_magnitude(mut self, max_magnitude: f64) -> Self {
+        self.max_magnitude = max_magnitude;
+        self
+    }
+
+    /// The WGS84 range of longitudes and latitudes
+    pub fn wgs84() -> Self {
+        Self::new(-180.0, 180.0, -90.0, 90.0)
+    }
+
+    /// A range accepting every finite coordinate up to the default magnitude
+    pub fn unbounded() -> Self {
+        Self::new(
+            f64::NEG_INFINITY,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::INFINITY,
+        )
+    }
+
+    /// Check a coordinate against the range
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The x value
+    /// * `y` - The y value
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), ProjectionError>` - An error for non-finite, too large or out of range values
+    pub fn validate(&self, x: f64, y: f64) -> Result<(), ProjectionError> {
+        sanitize_position(&[x, y], self.max_magnitude)?;
+        if !(self.min_x..=self.max_x).contains(&x) || !(self.min_y..=self.max_y).contains(&y) {
+            return Err(ProjectionError::InvalidCoordinates(
+                "Coordinates out of valid range".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Check a GeoJSON position against the range
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The position, x and y followed by optional extra ordinates
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), ProjectionError>` - An error for short positions, non-finite, too large or out of range values
+    pub fn validate_position(&self, position: &[f64]) -> Result<(), ProjectionError> {
+        match position {
+            [x, y, ..] => self.validate(*x, *y),
+            _ => Err(ProjectionError::InvalidCoordinates(
+                "Position has fewer than 2 elements".to_string(),
+            )),
+        }
+    }
+}
+
+/// An axis-aligned bounding box
+///
+/// # Fields
+///
+/// * `min` - The corner with the smallest x and y values
+/// * `max` - The corner with the largest x and y values
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    pub min: Coordinate,
+    pub max: Coordinate,
+}
+
+impl BoundingBox {
+    /// Create a bounding box from its extent
+    ///
+    /// # Arguments
+    ///
+    /// * `min_x` - The smallest x value
+    /// * `max_x` - The largest x value
+    /// * `min_y` - The smallest y value
+    /// * `max_y` - The largest y value
+    ///
+    /// # Returns
+    ///
+    /// * `BoundingBox` - A new bounding box
+    pub fn new(min_x: f64, max_x: f64, min_y: f64, max_y: f64) -> Self {
+        Self {
+            min: Coordinate::new(min_x, min_y),
+            max: Coordinate::new(max_x, max_y),
+        }
+    }
+
+    /// Check whether a coordinate lies inside the box or on its edge
+    ///
+    /// # Arguments
+    ///
+    /// * `coord` - The coordinate
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the box contains the coordinate
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::coordinates::{BoundingBox, Coordinate};
+    ///
+    /// let bbox = BoundingBox::new(0.0, 10.0, 0.0, 10.0);
+    /// assert!(bbox.contains(Coordinate::new(10.0, 5.0)));
+    /// assert!(!bbox.contains(Coordinate::new(10.5, 5.0)));
+    /// ```
+    pub fn contains(&self, coord: Coordinate) -> bool {
+        (self.min.x..=self.max.x).contains(&coord.x) && (self.min.y..=self.max.y).contains(&coord.y)
+    }
+
+    /// Check whether two boxes overlap or touch
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The other box
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the boxes share at least one point
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::coordinates::BoundingBox;
+    ///
+    /// let bbox = BoundingBox::new(0.0, 10.0, 0.0, 10.0);
+    /// assert!(bbox.intersects(&BoundingBox::new(10.0, 20.0, 5.0, 6.0)));
+    /// assert!(!bbox.intersects(&BoundingBox::new(11.0, 20.0, 5.0, 6.0)));
+    /// ```
+    pub fn intersects(&self, other: &BoundingBox) -> bool {
+        self.min.x <= other.max.x
+            && other.min.x <= self.max.x
+            && self.min.y <= other.max.y
+            && other.min.y <= self.max.y
+    }
+
+    /// Convert the box to a closed, counter-clockwise polygon
+    ///
+    /// # Returns
+    ///
+    /// * `Polygon` - A polygon with the four corners of the box
+    pub fn to_polygon(&self) -> Polygon {
+        Polygon::new(
+            Line::new(vec![
+                Coordinate::new(self.min.x, self.min.y),
+                Coordinate::new(self.max.x, self.min.y),
+                Coordinate::new(self.max.x, self.max.y),
+                Coordinate::new(self.min.x, self.max.y),
+                Coordinate::new(self.min.x, self.min.y),
+            ]),
+            vec![],
+        )
+    }
+
+    /// Convert the box to a GeoJSON polygon feature for visualization
+    ///
+    /// The feature's properties hold the extent as `minX`, `minY`, `maxX`
+    /// and `maxY`.
+    ///
+    /// # Returns
+    ///
+    /// * `geojson::Feature` - A polygon feature covering the box
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::coordinates::BoundingBox;
+    ///
+    /// let bbox = BoundingBox::new(13.0, 14.0, 52.0, 53.0);
+    /// let feature = bbox.to_geojson_feature();
+    /// assert_eq!(feature.property("maxY").and_then(|v| v.as_f64()), Some(53.0));
+    /// ```
+    pub fn to_geojson_feature(&self) -> geojson::Feature {
+        let mut feature = self.to_polygon().to_geojson_feature();
+        for (key, value) in [
+            ("minX", self.min.x),
+            ("minY", self.min.y),
+            ("maxX", self.max.x),
+            ("maxY", self.max.y),
+        ] {
+            feature.set_property(key, value);
+        }
+        feature
+    }
+
+    /// Serialize the box as a compact GeoJSON feature, ready to paste into a viewer
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The JSON text of `to_geojson_feature`
+    pub fn to_geojson_string(&self) -> String {
+        self.to_geojson_feature().to_string()
+    }
+}
+
+/// A collection of coordinates that form a line
+#[derive(Debug, Clone)]
+pub struct Line {
+    pub coordinates: Vec<Coordinate>,
+}
+
+/// Parse one CSV row, `None` for blank and comment rows
+fn parse_csv_row(index: usize, row: &str) -> Result<Option<Coordinate>, ProjectionError> {
+    let row = row.trim();
+    if row.is_empty() || row.starts_with('#') {
+        return Ok(None);
+    }
+    row.parse().map(Some).map_err(|e| match e {
+        ProjectionError::InvalidCoordinates(reason) => {
+            ProjectionError::InvalidCoordinates(format!("line {}: {reason}", index + 1))
+        }
+        other => other,
+    })
+}
+
+impl Line {
+    /// Create a new line from a vector of coordinates
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinates` - A vector of coordinates
+    ///
+    /// # Returns
+    ///
+    /// * `Line` - A new line
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::coordinates::Coordinate;
+    /// use proj_exercise_simple::coordinates::Line;
+    ///
+    /// let coords = vec![Coordinate::new(13.377, 52.518), Coordinate::new(13.377, 52.518)];
+    /// let line = Line::new(coords);
+    /// ```
+    pub fn new(coordinates: Vec<Coordinate>) -> Self {
+        Self { coordinates }
+    }
+
+    /// Parse a line from CSV text with one `x,y` row per vertex
+    ///
+    /// Blank lines and lines starting with `#` are skipped. A header row has
+    /// to be commented out.
+    ///
+    /// # Arguments
+    ///
+    /// * `csv` - The CSV text
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Line, ProjectionError>` - An error naming the line number of the first invalid row
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::coordinates::Line;
+    ///
+    /// let line = Line::from_csv_str("# lon,lat\n13.377,52.518\n\n13.376,52.516\n").unwrap();
+    /// assert_eq!(line.coordinates.len(), 2);
+    /// ```
+    pub fn from_csv_str(csv: &str) -> Result<Self, ProjectionError> {
+        csv.lines()
+            .enumerate()
+            .filter_map(|(index, row)| parse_csv_row(index, row).transpose())
+            .collect()
+    }
+
+    /// Parse a line from a CSV reader with one `x,y` row per vertex
+    ///
+    /// Works like `from_csv_str`, reading one row at a time so the input does
+    /// not have to fit in memory as text.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - A reader over the CSV text
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Line, ProjectionError>` - An error naming the line number of the first invalid row
+    pub fn from_csv_reader<R: Read>(reader: R) -> Result<Self, ProjectionError> {
+        let mut coordinates = Vec::new();
+        for (index, row) in BufReader::new(reader).lines().enumerate() {
+            if let Some(coordinate) = parse_csv_row(index, &row?)? {
+                coordinates.push(coordinate);
+            }
+        }
+        Ok(Line::new(coordinates))
+    }
+
+    /// Create a line from GeoJSON positions
+    ///
+    /// # Arguments
+    ///
+    /// * `coords` - The positions of a GeoJSON line string
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Line, ProjectionError>` - An error if a position has fewer than 2 elements
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::coordinates::Line;
+    ///
+    /// let line = Line::from_geojson_coords(&[vec![13.377, 52.518], vec![13.376, 52.516]]).unwrap();
+    /// assert_eq!(line.coordinates.len(), 2);
+    /// ```
+    pub fn from_geojson_coords(coords: &[Vec<f64>]) -> Result<Self, ProjectionError> {
+        coords
+            .iter()
+            .map(|position| Coordinate::try_from_slice(position))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self::new)
+    }
+
+    /// Create a line from the positions of a GeoJSON linear ring
+    ///
+    /// A linear ring needs at least 4 positions, the last equal to the first.
+    ///
+    /// # Arguments
+    ///
+    /// * `ring` - The positions of a GeoJSON polygon ring
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Line, ProjectionError>` - An error if a position is invalid or the ring is too short or not closed
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::coordinates::Line;
+    ///
+    /// let ring = vec![vec![0.0, 0.0], vec![1.0, 0.0], vec![1.0, 1.0], vec![0.0, 0.0]];
+    /// assert!(Line::from_geojson_ring(&ring).is_ok());
+    /// assert!(Line::from_geojson_ring(&ring[..3]).is_err());
+    /// ```
+    pub fn from_geojson_ring(ring: &[Vec<f64>]) -> Result<Self, ProjectionError> {
+        Self::from_geojson_coords(ring)?.into_checked_ring()
+    }
+
+    /// Check that the line is a valid linear ring: at least 4 positions, closed
+    pub(crate) fn into_checked_ring(self) -> Result<Self, ProjectionError> {
+        if self.coordinates.len() < 4 {
+            return Err(ProjectionError::InvalidCoordinates(format!(
+                "Linear ring has {} positions, at least 4 are required",
+                self.coordinates.len()
+            )));
+        }
+        if self.coordinates.first() != self.coordinates.last() {
+            return Err(ProjectionError::InvalidCoordinates(
+                "Linear ring is not closed".to_string(),
+            ));
+        }
+        Ok(self)
+    }
+
+    /// Convert a line to a GeoJSON line string
+    ///
+    /// # Returns
+    ///
+    /// * `geojson::Value` - A GeoJSON line string
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::coordinates::Coordinate;
+    /// use proj_exercise_simple::coordinates::Line;
+    ///
+    /// let coords = vec![Coordinate::new(13.377, 52.518), Coordinate::new(13.377, 52.518)];
+    /// let line = Line::new(coords);
+    /// let geojson = line.to_geojson_value();
+    /// ```
+    pub fn to_geojson_value(&self) -> geojson::Value {
+        geojson::Value::LineString(Coordinate::to_vecs(&self.coordinates))
+    }
+
+    /// Convert a line to a GeoJSON line string geometry
+    ///
+    /// # Returns
+    ///
+    /// * `geojson::Geometry` - A GeoJSON geometry wrapping `to_geojson_value`
+    pub fn to_geojson_geometry(&self) -> geojson::Geometry {
+        geojson::Geometry::new(self.to_geojson_value())
+    }
+
+    /// Convert a line to a GeoJSON line string
+    #[deprecated(since = "0.2.0", note = "use to_geojson_value()")]
+    pub fn to_geojson(&self) -> geojson::Value {
+        self.to_geojson_value()
+    }
+
+    /// Convert a line to a geo line string
+    ///
+    /// # Returns
+    ///
+    /// * `geo::LineString<f64>` - A geo line string
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::coordinates::Coordinate;
+    /// use proj_exercise_simple::coordinates::Line;
+    ///
+    /// let coords = vec![Coordinate::new(13.377, 52.518), Coordinate::new(13.377, 52.518)];
+    /// let line = Line::new(coords);
+    /// let geo = line.to_geo();
+    /// ```
+    pub fn to_geo(&self) -> geo::LineString<f64> {
+        geo::LineString::from(
+            self.coordinates
+                .iter()
+                .map(|c| geo::Coord::from((c.x, c.y)))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// Convert the vertices of a line to a geo multi point
+    ///
+    /// # Returns
+    ///
+    /// * `geo::MultiPoint<f64>` - One point per vertex, in line order
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::coordinates::{Coordinate, Line};
+    ///
+    /// let line = Line::new(vec![Coordinate::new(0.0, 0.0), Coordinate::new(1.0, 1.0)]);
+    /// assert_eq!(line.to_multipoint().0.len(), 2);
+    /// ```
+    pub fn to_multipoint(&self) -> geo::MultiPoint<f64> {
+        geo::MultiPoint::new(Coordinate::to_points(&self.coordinates))
+    }
+
+    pub fn from_geo(ls: &geo::LineString<f64>) -> Self {
+        Self {
+            coordinates: ls.coords().map(|c| Coordinate::new(c.x, c.y)).collect(),
+        }
+    }
+
+    pub fn to_vecs(&self) -> Vec<Vec<f64>> {
+        self.coordinates.iter().map(|c| c.to_vec()).collect()
+    }
+
+    /// Check whether a point is within a distance of the line
+    ///
+    /// Segments are tested in order and the search stops at the first segment
+    /// closer than `max_distance`, so no full minimum distance is computed.
+    /// The distance to a segment is the perpendicular distance when the foot
+    /// lies on the segment, otherwise the distance to the nearer endpoint.
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - The point to test
+    /// * `max_distance` - The maximum distance, in the units of the coordinates
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether some part of the line is at most `max_distance` away
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::coordinates::{Coordinate, Line};
+    ///
+    /// let line = Line::new(vec![Coordinate::new(0.0, 0.0), Coordinate::new(10.0, 0.0)]);
+    /// assert!(line.is_within_distance(Coordinate::new(5.0, 1.0), 1.5));
+    /// assert!(!line.is_within_distance(Coordinate::new(12.0, 0.0), 1.5));
+    /// ```
+    pub fn is_within_distance(&self, point: Coordinate, max_distance: f64) -> bool {
+        let p = geo::Coord::from((point.x, point.y));
+        if let [only] = self.coordinates.as_slice() {
+            return (only.x - p.x).hypot(only.y - p.y) <= max_distance;
+        }
+        self.coordinates.windows(2).any(|segment| {
+            let start = geo::Coord::from((segment[0].x, segment[0].y));
+            let end = geo::Coord::from((segment[1].x, segment[1].y));
+            let direction = end - start;
+            let length_squared = direction.x * direction.x + direction.y * direction.y;
+            let t = if length_squared == 0.0 {
+                0.0
+            } else {
+                ((p - start).x * direction.x + (p - start).y * direction.y) / length_squared
+            };
+            let distance = if (0.0..=1.0).contains(&t) {
+                perpendicular_distance(&p, &start, &end)
+            } else {
+                let nearest = if t < 0.0 { start } else { end };
+                (nearest.x - p.x).hypot(nearest.y - p.y)
+            };
+            distance <= max_distance
+        })
+    }
+
+    /// Merge consecutive segments that are nearly collinear
+    ///
+    /// Interior vertices are removed when the direction change between the
+    /// incoming and outgoing segment is below `tolerance` degrees. The incoming
+    /// direction is measured from the last kept vertex, so a gentle curve made
+    /// of many tiny segments is not flattened into a single straight line.
+    /// Repeated vertices are dropped, the first and last vertex are always kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `tolerance` - The maximum deflection angle in degrees for a vertex to be removed
+    ///
+    /// # Returns
+    ///
+    /// * `Line` - A new line without the nearly straight interior vertices
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::coordinates::{Coordinate, Line};
+    ///
+    /// let line = Line::new(vec![
+    ///     Coordinate::new(0.0, 0.0),
+    ///     Coordinate::new(1.0, 0.0),
+    ///     Coordinate::new(2.0, 0.0),
+    ///     Coordinate::new(2.0, 1.0),
+    /// ]);
+    /// let merged = line.merge_collinear(1.0);
+    /// assert_eq!(merged.coordinates.len(), 3);
+    /// ```
+    pub fn merge_collinear(&self, tolerance: f64) -> Line {
+        if self.coordinates.len() <= 2 {
+            return self.clone();
+        }
+
+        let last = self.coordinates.len() - 1;
+        let mut merged = vec![self.coordinates[0]];
+        for i in 1..last {
+            let anchor = geo::Coord::from((merged[merged.len() - 1].x, merged[merged.len() - 1].y));
+            let current = geo::Coord::from((self.coordinates[i].x, self.coordinates[i].y));
+            let next = geo::Coord::from((self.coordinates[i + 1].x, self.coordinates[i + 1].y));
+
+            let incoming = current - anchor;
+            let outgoing = next - current;
+            let outgoing_length = outgoing.x.hypot(outgoing.y);
+            if incoming.x.hypot(incoming.y) == 0.0 || outgoing_length == 0.0 {
+                // A repeated vertex carries no direction
+                continue;
+            }
+
+            // The distance of `next` from the incoming direction is the sine of the deflection
+            let sine = perpendicular_distance(&next, &anchor, &current) / outgoing_length;
+            let same_direction = incoming.x * outgoing.x + incoming.y * outgoing.y > 0.0;
+            if !same_direction || sine.min(1.0).asin().to_degrees() >= tolerance {
+                merged.push(self.coordinates[i]);
+            }
+        }
+        if merged[merged.len() - 1] != self.coordinates[last] || merged.len() == 1 {
+            merged.push(self.coordinates[last]);
+        }
+        Line::new(merged)
+    }
+
+    /// Drop vertices closer than `min_distance` to the previously kept vertex
+    ///
+    /// Meant for cleaning GPS traces, where a device reports nearly the same
+    /// location repeatedly, before simplifying them. The first vertex is
+    /// always kept and distances are planar, in the units of the coordinates.
+    /// The last vertex is dropped like any other when it is too close, so the
+    /// line may end up to `min_distance` short of its original end.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_distance` - The smallest distance between kept vertices
+    ///
+    /// # Returns
+    ///
+    /// * `Line` - A new line without the near-duplicate vertices
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::coordinates::{Coordinate, Line};
+    ///
+    /// let trace = Line::new(vec![
+    ///     Coordinate::new(0.0, 0.0),
+    ///     Coordinate::new(0.5, 0.0),
+    ///     Coordinate::new(1.2, 0.0),
+    ///     Coordinate::new(1.3, 0.1),
+    ///     Coordinate::new(3.0, 0.0),
+    /// ]);
+    /// let cleaned = trace.deduplicate_by_proximity(1.0);
+    /// assert_eq!(
+    ///     cleaned.coordinates,
+    ///     vec![Coordinate::new(0.0, 0.0), Coordinate::new(1.2, 0.0), Coordinate::new(3.0, 0.0)]
+    /// );
+    /// ```
+    pub fn deduplicate_by_proximity(&self, min_distance: f64) -> Line {
+        let mut kept: Vec<Coordinate> = Vec::with_capacity(self.coordinates.len());
+        for &coord in &self.coordinates {
+            let far_enough = kept
+                .last()
+                .is_none_or(|last| (coord.x - last.x).hypot(coord.y - last.y) >= min_distance);
+            if far_enough {
+                kept.push(coord);
+            }
+        }
+        Line::new(kept)
+    }
+
+    /// Resample the line to vertices at a fixed distance along it
+    ///
+    /// Vertices are placed every `spacing` units of length by linear
+    /// interpolation, and the exact start and end are kept. The last interval
+    /// is shorter when the length is not a multiple of `spacing`. Lines with
+    /// fewer than two vertices and non-positive spacings are returned unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `spacing` - The distance between vertices, in the units of the coordinates
+    ///
+    /// # Returns
+    ///
+    /// * `Line` - A new line with evenly spaced vertices
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::coordinates::{Coordinate, Line};
+    ///
+    /// let line = Line::new(vec![Coordinate::new(0.0, 0.0), Coordinate::new(10.0, 0.0)]);
+    /// let resampled = line.resample(2.0);
+    /// assert_eq!(resampled.coordinates.len(), 6);
+    /// assert_eq!(resampled.coordinates[1], Coordinate::new(2.0, 0.0));
+    /// ```
+    pub fn resample(&self, spacing: f64) -> Line {
+        if self.coordinates.len() < 2 || spacing <= 0.0 || !spacing.is_finite() {
+            return self.clone();
+        }
+
+        let length = |segment: &[Coordinate]| {
+            (segment[1].x - segment[0].x).hypot(segment[1].y - segment[0].y)
+        };
+        let total = compensated_sum(self.coordinates.windows(2).map(length));
+        // Stop just short of the end so rounding cannot add a vertex next to it
+        let end = total * (1.0 - 1e-9);
+
+        let mut resampled = vec![self.coordinates[0]];
+        let mut travelled = 0.0;
+        let mut step = 1;
+        for segment in self.coordinates.windows(2) {
+            let segment_length = length(segment);
+            let mut distance = step as f64 * spacing;
+            while distance < end && distance <= travelled + segment_length {
+                let t = (distance - travelled) / segment_length;
+                resampled.push(Coordinate::new(
+                    segment[0].x + t * (segment[1].x - segment[0].x),
+                    segment[0].y + t * (segment[1].y - segment[0].y),
+                ));
+                step += 1;
+                distance = step as f64 * spacing;
+            }
+            travelled += segment_length;
+        }
+        resampled.push(self.coordinates[self.coordinates.len() - 1]);
+        Line::new(resampled)
+    }
+
+    /// Insert vertices along the great circle so no segment is longer than `max_km`
+    ///
+    /// The coordinates are longitude and latitude in degrees. Inserted
+    /// vertices have their longitude normalized to ±180°, so a segment crossing
+    /// the antimeridian jumps from one side to the other. Segments between
+    /// antipodal points are left as they are.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_km` - The largest great-circle length of a segment, in kilometers
+    ///
+    /// # Returns
+    ///
+    /// * `Line` - A new line with every original vertex and the inserted ones
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::coordinates::{Coordinate, Line};
+    ///
+    /// let line = Line::new(vec![Coordinate::new(0.0, 0.0), Coordinate::new(10.0, 0.0)]);
+    /// // Ten degrees along the equator are about 1112 km
+    /// assert_eq!(line.densify_geodesic(500.0).coordinates.len(), 4);
+    /// ```
+    pub fn densify_geodesic(&self, max_km: f64) -> Line {
+        let densify = Densify::new(DensifyMethod::GreatCircle, max_km);
+        let positions = densify_positions(&self.to_vecs(), &densify);
+        Line::new(
+            positions
+                .iter()
+                .map(|p| Coordinate::new(p[0], p[1]))
+                .collect(),
+        )
+    }
+
+    /// Get the center of the bounding box of the line
+    ///
+    /// This is a fast approximation for display purposes such as label
+    /// placement. It is the bbox center, not the true geometric centroid, and
+    /// can be far from it for uneven vertex distributions. Use the `geo`
+    /// `Centroid` trait on `to_geo()` for precision-sensitive applications.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<Coordinate>` - The bbox center, or `None` for an empty line
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::coordinates::{Coordinate, Line};
+    ///
+    /// let line = Line::new(vec![
+    ///     Coordinate::new(0.0, 0.0),
+    ///     Coordinate::new(1.0, 4.0),
+    ///     Coordinate::new(10.0, 2.0),
+    /// ]);
+    /// assert_eq!(line.bbox_centroid(), Some(Coordinate::new(5.0, 2.0)));
+    /// assert_eq!(Line::new(vec![]).bbox_centroid(), None);
+    /// ```
+    pub fn bbox_centroid(&self) -> Option<Coordinate> {
+        let first = self.coordinates.first()?;
+        let (min, max) = self
+            .coordinates
+            .iter()
+            .fold((*first, *first), |(min, max), c| {
+                (
+                    Coordinate::new(min.x.min(c.x), min.y.min(c.y)),
+                    Coordinate::new(max.x.max(c.x), max.y.max(c.y)),
+                )
+            });
+        Some(Coordinate::new(
+            (min.x + max.x) / 2.0,
+            (min.y + max.y) / 2.0,
+        ))
+    }
+
+    /// Split the line into the parts inside and the parts outside a bounding box
+    ///
+    /// Segments crossing the box edge are cut at the crossing point, which
+    /// ends one part and starts the next, so joining the parts in order of
+    /// their first coordinate reproduces the line. Segments along the edge
+    /// count as inside, segments only touching a corner as outside. A line
+    /// with a single coordinate is returned whole on the side it lies on.
+    ///
+    /// # Arguments
+    ///
+    /// * `bbox` - The bounding box, in the CRS of the line
+    ///
+    /// # Returns
+    ///
+    /// * `(Vec<Line>, Vec<Line>)` - The parts inside and the parts outside the box, in line order
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::coordinates::{BoundingBox, Coordinate, Line};
+    ///
+    /// let line = Line::new(vec![Coordinate::new(-5.0, 5.0), Coordinate::new(15.0, 5.0)]);
+    /// let (inside, outside) = line.split_by_bbox(&BoundingBox::new(0.0, 10.0, 0.0, 10.0));
+    /// assert_eq!(inside.len(), 1);
+    /// assert_eq!(inside[0].coordinates, vec![Coordinate::new(0.0, 5.0), Coordinate::new(10.0, 5.0)]);
+    /// assert_eq!(outside.len(), 2);
+    /// ```
+    pub fn split_by_bbox(&self, bbox: &BoundingBox) -> (Vec<Line>, Vec<Line>) {
+        let (mut inside, mut outside) = (Vec::new(), Vec::new());
+        if let [coord] = self.coordinates[..] {
+            if bbox.contains(coord) {
+                inside.push(self.clone());
+            } else {
+                outside.push(self.clone());
+            }
+            return (inside, outside);
+        }
+
+        let rect = geo::Rect::new(
+            geo::Coord::from((bbox.min.x, bbox.min.y)),
+            geo::Coord::from((bbox.max.x, bbox.max.y)),
+        );
+        // Each part and whether it is inside, consecutive pieces on the same side are joined
+        let mut parts: Vec<(bool, Vec<Coordinate>)> = Vec::new();
+        let mut add = |is_inside: bool, from: Coordinate, to: Coordinate| match parts.last_mut() {
+            Some((state, part)) if *state == is_inside => part.push(to),
+            _ => parts.push((is_inside, vec![from, to])),
+        };
+        for segment in self.coordinates.windows(2) {
+            let (a, b) = (segment[0], segment[1]);
+            match clip_segment(
+                geo::Coord::from((a.x, a.y)),
+                geo::Coord::from((b.x, b.y)),
+                &rect,
+            ) {
+                Some((start, end)) if start != end => {
+                    let (start, end) = (
+                        Coordinate::new(start.x, start.y),
+                        Coordinate::new(end.x, end.y),
+                    );
+                    if start != a {
+                        add(false, a, start);
+                    }
+                    add(true, start, end);
+                    if end != b {
+                        add(false, end, b);
+                    }
+                }
+                _ => add(false, a, b),
+            }
+        }
+        for (is_inside, part) in parts {
+            if is_inside {
+                inside.push(Line::new(part));
+            } else {
+                outside.push(Line::new(part));
+            }
+        }
+        (inside, outside)
+    }
+}
+
+impl FromIterator<Coordinate> for Line {
+    /// Create a new line from an iterator of coordinates
+    ///
+    /// # Arguments
+    ///
+    /// * `iter` - An iterator of coordinates
+    ///
+    /// # Returns
+    ///
+    /// * `Line` - A new line
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::coordinates::Coordinate;
+    /// use proj_exercise_simple::coordinates::Line;
+    ///
+    /// let coords = vec![Coordinate::new(13.377, 52.518), Coordinate::new(13.377, 52.518)];
+    /// let line = Line::from_iter(coords);
+    /// ```
+    fn from_iter<T: IntoIterator<Item = Coordinate>>(iter: T) -> Self {
+        Self {
+            coordinates: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// A collection of lines that form a polygon
+#[derive(Debug, Clone)]
+pub struct Polygon {
+    pub exterior: Line,
+    pub interiors: Vec<Line>,
+}
+
+impl Polygon {
+    /// Create a new polygon from an exterior line and interior lines
+    ///
+    /// # Arguments
+    ///
+    /// * `exterior` - The exterior line
+    /// * `interiors` - The interior lines
+    ///
+    /// # Returns
+    ///
+    /// * `Polygon` - A new polygon
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::coordinates::Coordinate;
+    /// use proj_exercise_simple::coordinates::Line;
+    /// use proj_exercise_simple::coordinates::Polygon;
+    ///
+    /// let coords = vec![Coordinate::new(13.377, 52.518), Coordinate::new(13.377, 52.518)];
+    /// let line = Line::new(coords);
+    /// let polygon = Polygon::new(line, vec![]);
+    /// ```
+    pub fn new(exterior: Line, interiors: Vec<Line>) -> Self {
+        Self {
+            exterior,
+            interiors,
+        }
+    }
+
+    /// Create a polygon from the rings of a GeoJSON polygon
+    ///
+    /// The first ring is the exterior, the rest are interiors. Ring closure is
+    /// not checked here, use `Line::from_geojson_ring` for strict rings.
+    ///
+    /// # Arguments
+    ///
+    /// * `rings` - The coordinates of a GeoJSON polygon
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Polygon, ProjectionError>` - An error if there are no rings or a position has fewer than 2 elements
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::coordinates::Polygon;
+    ///
+    /// let rings = vec![vec![vec![0.0, 0.0], vec![1.0, 0.0], vec![1.0, 1.0], vec![0.0, 0.0]]];
+    /// let polygon = Polygon::from_geojson_rings(&rings).unwrap();
+    /// assert_eq!(polygon.exterior.coordinates.len(), 4);
+    /// assert!(polygon.interiors.is_empty());
+    /// assert!(Polygon::from_geojson_rings(&[]).is_err());
+    /// ```
+    pub fn from_geojson_rings(rings: &[Vec<Vec<f64>>]) -> Result<Self, ProjectionError> {
+        let (exterior, interiors) = rings.split_first().ok_or_else(|| {
+            ProjectionError::InvalidCoordinates("Polygon has no rings".to_string())
+        })?;
+        let interiors = interiors
+            .iter()
+            .map(|ring| Line::from_geojson_coords(ring))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::new(Line::from_geojson_coords(exterior)?, interiors))
+    }
+
+    /// Create a polygon without holes from the coordinates of its exterior ring
+    ///
+    /// The ring needs at least 3 distinct coordinates. It is closed by
+    /// repeating the first coordinate if the last one differs.
+    ///
+    /// # Arguments
+    ///
+    /// * `coords` - The coordinates of the exterior ring
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Polygon, ProjectionError>` - An error if the ring has fewer than 3 distinct coordinates
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::coordinates::{Coordinate, Polygon};
+    ///
+    /// let polygon = Polygon::from_exterior_coords(vec![
+    ///     Coordinate::new(0.0, 0.0),
+    ///     Coordinate::new(1.0, 0.0),
+    ///     Coordinate::new(1.0, 1.0),
+    /// ])
+    /// .unwrap();
+    /// assert_eq!(polygon.exterior.coordinates.len(), 4);
+    /// ```
+    pub fn from_exterior_coords(mut coords: Vec<Coordinate>) -> Result<Self, ProjectionError> {
+        let distinct: BTreeSet<&Coordinate> = coords.iter().collect();
+        if distinct.len() < 3 {
+            return Err(ProjectionError::InvalidCoordinates(format!(
+                "Polygon ring needs at least 3 distinct coordinates, found {}",
+                distinct.len()
+            )));
+        }
+        if coords.first() != coords.last() {
+            coords.push(coords[0]);
+        }
+        Ok(Self::new(Line::new(coords), vec![]))
+    }
+
+    /// Create a polygon without holes from the GeoJSON positions of its exterior ring
+    ///
+    /// Works like `from_exterior_coords`, ignoring elements beyond the first two.
+    ///
+    /// # Arguments
+    ///
+    /// * `vecs` - The positions of the exterior ring
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Polygon, ProjectionError>` - An error if a position has fewer than 2 elements or the ring is too small
+    pub fn from_exterior_vecs(vecs: Vec<Vec<f64>>) -> Result<Self, ProjectionError> {
+        Self::from_exterior_coords(Line::from_geojson_coords(&vecs)?.coordinates)
+    }
+
+    /// Convert a polygon to a GeoJSON polygon
+    ///
+    /// # Returns
+    ///
+    /// * `geojson::Value` - A GeoJSON polygon
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::coordinates::Coordinate;
+    /// use proj_exercise_simple::coordinates::Line;
+    /// use proj_exercise_simple::coordinates::Polygon;
+    ///
+    /// let coords = vec![Coordinate::new(13.377, 52.518), Coordinate::new(13.377, 52.518)];
+    /// let line = Line::new(coords);
+    /// let polygon = Polygon::new(line, vec![]);
+    /// let geojson = polygon.to_geojson_value();
+    /// ```
+    pub fn to_geojson_value(&self) -> geojson::Value {
+        let mut rings = vec![Coordinate::to_vecs(&self.exterior.coordinates)];
+        rings.extend(
+            self.interiors
+                .iter()
+                .map(|line| Coordinate::to_vecs(&line.coordinates)),
+        );
+        geojson::Value::Polygon(rings)
+    }
+
+    /// Convert a polygon to a GeoJSON polygon geometry
+    ///
+    /// # Returns
+    ///
+    /// * `geojson::Geometry` - A GeoJSON geometry wrapping `to_geojson_value`
+    pub fn to_geojson_geometry(&self) -> geojson::Geometry {
+        geojson::Geometry::new(self.to_geojson_value())
+    }
+
+    /// Convert a polygon to a GeoJSON polygon
+    #[deprecated(since = "0.2.0", note = "use to_geojson_value()")]
+    pub fn to_geojson(&self) -> geojson::Value {
+        self.to_geojson_value()
+    }
+
+    /// Convert a polygon to a GeoJSON feature without properties
+    ///
+    /// # Returns
+    ///
+    /// * `geojson::Feature` - A GeoJSON feature with a polygon geometry
+    pub fn to_geojson_feature(&self) -> geojson::Feature {
+        geojson::Feature {
+            geometry: Some(self.to_geojson_geometry()),
+            ..geojson::Feature::default()
+        }
+    }
+
+    /// Convert a polygon to a geo polygon
+    ///
+    /// # Returns
+    ///
+    /// * `geo::Polygon<f64>` - A geo polygon
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::coordinates::Coordinate;
+    /// use proj_exercise_simple::coordinates::Line;
+    /// use proj_exercise_simple::coordinates::Polygon;
+    ///
+    /// let coords = vec![Coordinate::new(13.377, 52.518), Coordinate::new(13.377, 52.518)];
+    /// let line = Line::new(coords);
+    /// let polygon = Polygon::new(line, vec![]);
+    /// let geo = polygon.to_geo();
+    /// ```
+    pub fn to_geo(&self) -> geo::Polygon<f64> {
+        let exterior = self.exterior.to_geo();
+        let interiors = self
+            .interiors
+            .iter()
+            .map(|line| line.to_geo())
+            .collect::<Vec<_>>();
+        geo::Polygon::new(exterior, interiors)
+    }
+
+    /// Get a copy of the polygon without its holes
+    ///
+    /// # Returns
+    ///
+    /// * `Polygon` - A new polygon with the same exterior and no interiors
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::coordinates::Coordinate;
+    /// use proj_exercise_simple::coordinates::Line;
+    /// use proj_exercise_simple::coordinates::Polygon;
+    ///
+    /// let exterior = Line::new(vec![Coordinate::new(0.0, 0.0), Coordinate::new(4.0, 0.0)]);
+    /// let hole = Line::new(vec![Coordinate::new(1.0, 1.0), Coordinate::new(2.0, 1.0)]);
+    /// let polygon = Polygon::new(exterior, vec![hole]);
+    /// assert!(polygon.exterior_only().holes().is_empty());
+    /// ```
+    pub fn exterior_only(&self) -> Polygon {
+        Polygon::new(self.exterior.clone(), vec![])
+    }
+
+    /// Get the holes of the polygon
+    ///
+    /// # Returns
+    ///
+    /// * `&[Line]` - The interior rings
+    pub fn holes(&self) -> &[Line] {
+        &self.interiors
+    }
+
+    /// Split the polygon into its exterior and interior rings
+    ///
+    /// # Returns
+    ///
+    /// * `(Line, Vec<Line>)` - The exterior ring and the interior rings
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::coordinates::Coordinate;
+    /// use proj_exercise_simple::coordinates::Line;
+    /// use proj_exercise_simple::coordinates::Polygon;
+    ///
+    /// let exterior = Line::new(vec![Coordinate::new(0.0, 0.0), Coordinate::new(4.0, 0.0)]);
+    /// let (exterior, interiors) = Polygon::new(exterior, vec![]).into_parts();
+    /// assert_eq!(exterior.coordinates.len(), 2);
+    /// assert!(interiors.is_empty());
+    /// ```
+    pub fn into_parts(self) -> (Line, Vec<Line>) {
+        (self.exterior, self.interiors)
+    }
+
+    /// Get the center of the bounding box of the polygon
+    ///
+    /// Holes lie inside the exterior, so only the exterior is considered. Like
+    /// `Line::bbox_centroid` this is the bbox center, not the true geometric
+    /// centroid, which for concave or holed polygons can differ a lot. Use the
+    /// `geo` `Centroid` trait on `to_geo()` for precision-sensitive applications.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<Coordinate>` - The bbox center, or `None` for an empty exterior
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::coordinates::{Coordinate, Line, Polygon};
+    ///
+    /// let exterior = Line::new(vec![
+    ///     Coordinate::new(0.0, 0.0),
+    ///     Coordinate::new(4.0, 0.0),
+    ///     Coordinate::new(4.0, 2.0),
+    ///     Coordinate::new(0.0, 0.0),
+    /// ]);
+    /// let polygon = Polygon::new(exterior, vec![]);
+    /// assert_eq!(polygon.bbox_centroid(), Some(Coordinate::new(2.0, 1.0)));
+    /// ```
+    pub fn bbox_centroid(&self) -> Option<Coordinate> {
+        self.exterior.bbox_centroid()
+    }
+
+    /// Expand the polygon outward by a distance
+    ///
+    /// Every ring edge is offset along its normal and the offset edges are
+    /// joined with arcs around convex corners (a Minkowski sum with a circle)
+    /// and by intersecting the offset edges at concave corners. Holes shrink
+    /// by the same distance and are dropped once they collapse. A negative
+    /// distance shrinks the polygon instead.
+    ///
+    /// This is a simple planar buffer: distances are measured in coordinate
+    /// units, so it is only correct for projected coordinates, not for
+    /// longitudes and latitudes. Offsets larger than the size of a feature of
+    /// the ring can produce self-intersecting rings.
+    ///
+    /// # Arguments
+    ///
+    /// * `distance` - The buffer distance in coordinate units
+    /// * `corner_segments` - The number of edges used to approximate each corner arc
+    ///
+    /// # Returns
+    ///
+    /// * `Polygon` - The buffered polygon, with closed rings
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::coordinates::{Coordinate, Line, Polygon};
+    ///
+    /// let square = Polygon::new(
+    ///     Line::new(vec![
+    ///         Coordinate::new(0.0, 0.0),
+    ///         Coordinate::new(10.0, 0.0),
+    ///         Coordinate::new(10.0, 10.0),
+    ///         Coordinate::new(0.0, 10.0),
+    ///         Coordinate::new(0.0, 0.0),
+    ///     ]),
+    ///     vec![],
+    /// );
+    /// let buffered = square.buffer(1.0, 4);
+    /// assert!(buffered.exterior.coordinates.contains(&Coordinate::new(11.0, 0.0)));
+    /// ```
+    pub fn buffer(&self, distance: f64, corner_segments: u32) -> Polygon {
+        let exterior = offset_ring(&self.exterior.coordinates, distance, corner_segments);
+        let interiors = self
+            .interiors
+            .iter()
+            .filter_map(|ring| {
+                let offset = offset_ring(&ring.coordinates, -distance, corner_segments);
+                // A hole that shrank past zero turns inside out, which moves
+                // some of its vertices closer to the original ring than `distance`
+                let min_distance = distance * (1.0 - 1e-9);
+                let collapsed = distance > 0.0
+                    && offset
+                        .iter()
+                        .any(|c| ring.is_within_distance(*c, min_distance));
+                (!collapsed).then(|| Line::new(offset))
+            })
+            .collect();
+        Polygon::new(Line::new(exterior), interiors)
+    }
+
+    /// Make every hole wind opposite to the exterior
+    ///
+    /// The exterior keeps its direction, whichever it is, so a clockwise
+    /// exterior gets counter-clockwise holes and the other way around.
+    /// Rings without area, including a degenerate exterior, are left as they are.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::coordinates::{Coordinate, Line, Polygon};
+    ///
+    /// let ring = |coords: &[(f64, f64)]| {
+    ///     Line::new(coords.iter().map(|&(x, y)| Coordinate::new(x, y)).collect())
+    /// };
+    /// // A clockwise exterior with a clockwise hole
+    /// let mut polygon = Polygon::new(
+    ///     ring(&[(0.0, 0.0), (0.0, 4.0), (4.0, 4.0), (4.0, 0.0), (0.0, 0.0)]),
+    ///     vec![ring(&[(1.0, 1.0), (1.0, 2.0), (2.0, 2.0), (2.0, 1.0), (1.0, 1.0)])],
+    /// );
+    /// polygon.enforce_hole_orientation();
+    /// assert_eq!(polygon.interiors[0].coordinates[1], Coordinate::new(2.0, 1.0));
+    /// ```
+    pub fn enforce_hole_orientation(&mut self) {
+        let shell = ring_signed_area(&self.exterior.coordinates);
+        if shell == 0.0 {
+            return;
+        }
+        for hole in &mut self.interiors {
+            let area = ring_signed_area(&hole.coordinates);
+            if area != 0.0 && area.signum() == shell.signum() {
+                hole.coordinates.reverse();
+            }
+        }
+    }
+}
+
+/// The signed shoelace area of a ring, positive for counter-clockwise rings
+fn ring_signed_area(ring: &[Coordinate]) -> f64 {
+    let points: Vec<(f64, f64)> = ring.iter().map(|c| (c.x, c.y)).collect();
+    shoelace_signed_area(&points)
+}
+
+/// Offset a closed ring away from its interior by `distance`
+///
+/// Negative distances move the ring towards its interior. Rings with fewer
+/// than three distinct vertices are returned unchanged.
+fn offset_ring(ring: &[Coordinate], distance: f64, corner_segments: u32) -> Vec<Coordinate> {
+    let mut vertices: Vec<Coordinate> = Vec::with_capacity(ring.len());
+    for coord in ring {
+        if vertices.last() != Some(coord) {
+            vertices.push(*coord);
+        }
+    }
+    if vertices.len() > 1 && vertices.first() == vertices.last() {
+        vertices.pop();
+    }
+    if vertices.len() < 3 || distance == 0.0 {
+        return ring.to_vec();
+    }
+
+    // The outward normal of an edge depends on the ring orientation
+    let orientation = ring_signed_area(&vertices).signum();
+    let normal = |a: Coordinate, b: Coordinate| {
+        let (dx, dy) = (b.x - a.x, b.y - a.y);
+        let length = dx.hypot(dy);
+        Coordinate::new(orientation * dy / length, -orientation * dx / length)
+    };
+
+    let n = vertices.len();
+    let segments = corner_segments.max(1);
+    let mut result = Vec::with_capacity(n * (segments as usize + 1) + 1);
+    for i in 0..n {
+        let prev = vertices[(i + n - 1) % n];
+        let vertex = vertices[i];
+        let next = vertices[(i + 1) % n];
+        let (n1, n2) = (normal(prev, vertex), normal(vertex, next));
+        let (e1, e2) = (
+            Coordinate::new(vertex.x - prev.x, vertex.y - prev.y),
+            Coordinate::new(next.x - vertex.x, next.y - vertex.y),
+        );
+        let cross = e1.x * e2.y - e1.y * e2.x;
+
+        if orientation * cross * distance > 0.0 {
+            // The offset edges open a gap around the corner, fill it with an arc
+            let sweep = (n1.x * n2.y - n1.y * n2.x).atan2(n1.x * n2.x + n1.y * n2.y);
+            let start = n1.y.atan2(n1.x);
+            for step in 0..=segments {
+                let angle = start + sweep * step as f64 / segments as f64;
+                result.push(Coordinate::new(
+                    vertex.x + distance * angle.cos(),
+                    vertex.y + distance * angle.sin(),
+                ));
+            }
+        } else if cross.abs() <= f64::EPSILON * e1.x.hypot(e1.y) * e2.x.hypot(e2.y) {
+            // Collinear edges share the offset point
+            result.push(Coordinate::new(
+                vertex.x + distance * n1.x,
+                vertex.y + distance * n1.y,
+            ));
+        } else {
+            // The offset edges overlap, join them at their intersection
+            let a = Coordinate::new(prev.x + distance * n1.x, prev.y + distance * n1.y);
+            let b = Coordinate::new(vertex.x + distance * n2.x, vertex.y + distance * n2.y);
+            let t = ((b.x - a.x) * e2.y - (b.y - a.y) * e2.x) / cross;
+            result.push(Coordinate::new(a.x + t * e1.x, a.y + t * e1.y));
+        }
     }
+    result.push(result[0]);
+    result
 }
 
 impl FromIterator<Line> for Polygon {
@@ -0,0 +1,174 @@
+use geojson::{GeoJson, Position, Value};
+
+/// The mean radius of the earth, in kilometers
+const EARTH_RADIUS_KM: f64 = 6371.0088;
+
+/// How vertices are placed between the ends of a segment
+///
+/// # Variants
+///
+/// * `Linear` - Interpolate longitude and latitude linearly, fine for short segments
+/// * `GreatCircle` - Follow the great circle through both ends, as flight paths and shipping lanes do
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DensifyMethod {
+    #[default]
+    Linear,
+    GreatCircle,
+}
+
+/// Insert vertices into segments longer than a maximum length
+///
+/// # Fields
+///
+/// * `method` - How the inserted vertices are placed
+/// * `max_segment_km` - The largest great-circle length a segment may have afterwards
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Densify {
+    pub method: DensifyMethod,
+    pub max_segment_km: f64,
+}
+
+impl Densify {
+    /// Create a densify setting
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - How the inserted vertices are placed
+    /// * `max_segment_km` - The largest segment length, in kilometers
+    ///
+    /// # Returns
+    ///
+    /// * `Densify` - A new densify setting
+    pub fn new(method: DensifyMethod, max_segment_km: f64) -> Self {
+        Self {
+            method,
+            max_segment_km,
+        }
+    }
+}
+
+fn to_unit_vector(lon: f64, lat: f64) -> [f64; 3] {
+    let (lon, lat) = (lon.to_radians(), lat.to_radians());
+    [lat.cos() * lon.cos(), lat.cos() * lon.sin(), lat.sin()]
+}
+
+/// The great-circle distance between two longitude and latitude pairs, in kilometers
+pub(crate) fn great_circle_km(a: [f64; 2], b: [f64; 2]) -> f64 {
+    let (lat1, lat2) = (a[1].to_radians(), b[1].to_radians());
+    let half_dlat = (lat2 - lat1) / 2.0;
+    let half_dlon = (b[0] - a[0]).to_radians() / 2.0;
+    let h = half_dlat.sin().powi(2) + lat1.cos() * lat2.cos() * half_dlon.sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * h.sqrt().min(1.0).asin()
+}
+
+/// The points at fractions `t` of the great circle from `a` to `b`
+///
+/// Uses spherical linear interpolation of unit vectors, which stays exact
+/// near the poles. Longitudes are normalized to ±180°. Returns `None` for
+/// antipodal ends, where the great circle is not unique.
+fn great_circle_points(a: [f64; 2], b: [f64; 2], ts: &[f64]) -> Option<Vec<[f64; 2]>> {
+    let (u, v) = (to_unit_vector(a[0], a[1]), to_unit_vector(b[0], b[1]));
+    let dot = (u[0] * v[0] + u[1] * v[1] + u[2] * v[2]).clamp(-1.0, 1.0);
+    let omega = dot.acos();
+    let sin_omega = omega.sin();
+    if sin_omega.abs() < 1e-12 {
+        return None;
+    }
+    Some(
+        ts.iter()
+            .map(|t| {
+                let s = ((1.0 - t) * omega).sin() / sin_omega;
+                let r = (t * omega).sin() / sin_omega;
+                let p = [0, 1, 2].map(|i| s * u[i] + r * v[i]);
+                let lat = p[2].atan2(p[0].hypot(p[1])).to_degrees();
+                let lon = p[1].atan2(p[0]).to_degrees();
+                [lon, lat]
+            })
+            .collect(),
+    )
+}
+
+/// The positions to insert between `a` and `b`, without the ends
+///
+/// Ordinates beyond longitude and latitude, such as an altitude, are
+/// interpolated linearly when both ends have them.
+fn segment_fill(a: &[f64], b: &[f64], densify: &Densify) -> Vec<Position> {
+    let ends = ([a[0], a[1]], [b[0], b[1]]);
+    let length = great_circle_km(ends.0, ends.1);
+    let max = densify.max_segment_km;
+    if !(max > 0.0 && max.is_finite() && length > max) {
+        return Vec::new();
+    }
+    let segments = (length / max).ceil() as usize;
+    let ts: Vec<f64> = (1..segments).map(|i| i as f64 / segments as f64).collect();
+    let points = match densify.method {
+        DensifyMethod::GreatCircle => match great_circle_points(ends.0, ends.1, &ts) {
+            Some(points) => points,
+            None => return Vec::new(),
+        },
+        DensifyMethod::Linear => ts
+            .iter()
+            .map(|t| [a[0] + t * (b[0] - a[0]), a[1] + t * (b[1] - a[1])])
+            .collect(),
+    };
+    points
+        .into_iter()
+        .zip(&ts)
+        .map(|(point, t)| {
+            let mut position = point.to_vec();
+            if a.len() == b.len() {
+                position.extend((2..a.len()).map(|i| a[i] + t * (b[i] - a[i])));
+            }
+            position
+        })
+        .collect()
+}
+
+/// Densify a sequence of positions, keeping every original position
+pub(crate) fn densify_positions(positions: &[Position], densify: &Densify) -> Vec<Position> {
+    let mut densified = Vec::with_capacity(positions.len());
+    for pair in positions.windows(2) {
+        densified.push(pair[0].clone());
+        if pair[0].len() >= 2 && pair[1].len() >= 2 {
+            densified.extend(segment_fill(&pair[0], &pair[1], densify));
+        }
+    }
+    densified.extend(positions.last().cloned());
+    densified
+}
+
+fn densify_value(value: &mut Value, densify: &Densify) {
+    let line = |positions: &mut Vec<Position>| *positions = densify_positions(positions, densify);
+    match value {
+        Value::Point(_) | Value::MultiPoint(_) => {}
+        Value::LineString(positions) => line(positions),
+        Value::MultiLineString(lines) | Value::Polygon(lines) => lines.iter_mut().for_each(line),
+        Value::MultiPolygon(polygons) => polygons
+            .iter_mut()
+            .flat_map(|rings| rings.iter_mut())
+            .for_each(line),
+        Value::GeometryCollection(geometries) => geometries
+            .iter_mut()
+            .for_each(|geometry| densify_value(&mut geometry.value, densify)),
+    }
+}
+
+/// Densify the lines and polygon rings of a GeoJSON object before projecting it
+///
+/// Bounding boxes are left as they are, they are recomputed or dropped by
+/// the later pipeline stages.
+pub(crate) fn densify_geojson(geojson: &mut GeoJson, densify: &Densify) {
+    match geojson {
+        GeoJson::FeatureCollection(collection) => collection
+            .features
+            .iter_mut()
+            .filter_map(|feature| feature.geometry.as_mut())
+            .for_each(|geometry| densify_value(&mut geometry.value, densify)),
+        GeoJson::Feature(feature) => {
+            if let Some(geometry) = feature.geometry.as_mut() {
+                densify_value(&mut geometry.value, densify);
+            }
+        }
+        GeoJson::Geometry(geometry) => densify_value(&mut geometry.value, densify),
+    }
+}
@@ -0,0 +1,126 @@
+use geo::{Coord, LineString};
+
+/// The vertex changes between two versions of a line string
+///
+/// # Fields
+///
+/// * `inserted` - Indices into the new line of vertices without a counterpart in the old line
+/// * `deleted` - Indices into the old line of vertices without a counterpart in the new line
+/// * `moved` - Pairs of old and new indices of vertices that moved by at most the tolerance
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GeometryDiff {
+    pub inserted: Vec<usize>,
+    pub deleted: Vec<usize>,
+    pub moved: Vec<(usize, usize)>,
+}
+
+impl GeometryDiff {
+    /// Whether the two versions have the same vertices
+    pub fn is_empty(&self) -> bool {
+        self.inserted.is_empty() && self.deleted.is_empty() && self.moved.is_empty()
+    }
+}
+
+/// The alignment step taken at a cell of the edit distance table
+#[derive(Clone, Copy)]
+enum Step {
+    Keep,
+    Move,
+    Insert,
+    Delete,
+}
+
+fn distance(a: Coord<f64>, b: Coord<f64>) -> f64 {
+    (a.x - b.x).hypot(a.y - b.y)
+}
+
+/// Compare the vertices of two versions of a line string
+///
+/// The vertices are aligned with an edit distance where inserting, deleting
+/// and moving a vertex each cost one. Only vertices within `tolerance` of each
+/// other can be paired, vertices further apart count as a deletion and an
+/// insertion. Alignment is quadratic in the number of vertices.
+///
+/// # Arguments
+///
+/// * `old` - The previous version
+/// * `new` - The current version
+/// * `tolerance` - The largest distance a vertex may move and still be reported as moved
+///
+/// # Returns
+///
+/// * `GeometryDiff` - The inserted, deleted and moved vertices
+///
+/// # Example
+///
+/// ```rust
+/// use geo::LineString;
+/// use proj_exercise_simple::diff::geometry_diff;
+///
+/// let old = LineString::from(vec![(0.0, 0.0), (2.0, 0.0)]);
+/// let new = LineString::from(vec![(0.0, 0.0), (1.0, 1.0), (2.0, 0.0)]);
+/// let diff = geometry_diff(&old, &new, 0.1);
+/// assert_eq!(diff.inserted, vec![1]);
+/// ```
+pub fn geometry_diff(old: &LineString<f64>, new: &LineString<f64>, tolerance: f64) -> GeometryDiff {
+    let (old, new) = (&old.0, &new.0);
+    let width = new.len() + 1;
+    let mut cost = vec![0usize; (old.len() + 1) * width];
+    let mut steps = vec![Step::Keep; (old.len() + 1) * width];
+    for i in 0..=old.len() {
+        for j in 0..=new.len() {
+            let cell = i * width + j;
+            let mut best = (usize::MAX, Step::Keep);
+            if i > 0 && j > 0 {
+                let d = distance(old[i - 1], new[j - 1]);
+                let step = if d == 0.0 {
+                    Some((cost[cell - width - 1], Step::Keep))
+                } else if d <= tolerance {
+                    Some((cost[cell - width - 1] + 1, Step::Move))
+                } else {
+                    None
+                };
+                if let Some(step) = step {
+                    best = step;
+                }
+            }
+            if i > 0 && cost[cell - width] + 1 < best.0 {
+                best = (cost[cell - width] + 1, Step::Delete);
+            }
+            if j > 0 && cost[cell - 1] + 1 < best.0 {
+                best = (cost[cell - 1] + 1, Step::Insert);
+            }
+            if i > 0 || j > 0 {
+                (cost[cell], steps[cell]) = best;
+            }
+        }
+    }
+
+    let mut diff = GeometryDiff::default();
+    let (mut i, mut j) = (old.len(), new.len());
+    while i > 0 || j > 0 {
+        match steps[i * width + j] {
+            Step::Keep => {
+                i -= 1;
+                j -= 1;
+            }
+            Step::Move => {
+                i -= 1;
+                j -= 1;
+                diff.moved.push((i, j));
+            }
+            Step::Insert => {
+                j -= 1;
+                diff.inserted.push(j);
+            }
+            Step::Delete => {
+                i -= 1;
+                diff.deleted.push(i);
+            }
+        }
+    }
+    diff.inserted.reverse();
+    diff.deleted.reverse();
+    diff.moved.reverse();
+    diff
+}
@@ -0,0 +1,223 @@
+use crate::error::ProjectionError;
+use crate::helpers::ProcessedGeometry;
+use geo::{LineString, Polygon};
+use geojson::{GeoJson, JsonObject, JsonValue};
+
+/// The property key receiving the geometry digest
+pub const GEOMETRY_HASH_KEY: &str = "geometry_hash";
+
+/// A 64-bit FNV-1a hasher
+///
+/// Implemented here rather than using `std::hash` so digests are identical
+/// across platforms, Rust versions and runs.
+pub(crate) struct Fnv1a(u64);
+
+impl Fnv1a {
+    pub(crate) fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    pub(crate) fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.write(&value.to_le_bytes());
+    }
+
+    pub(crate) fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Hashes quantized coordinates and the nesting structure of a geometry
+struct Digest {
+    scale: f64,
+}
+
+impl Digest {
+    fn coord(&self, hasher: &mut Fnv1a, coord: geo::Coord<f64>) {
+        for value in [coord.x, coord.y] {
+            // Adding zero turns negative zero into zero
+            let quantized = (value * self.scale).round() + 0.0;
+            hasher.write_u64(quantized.to_bits());
+        }
+    }
+
+    fn line(&self, hasher: &mut Fnv1a, line: &LineString<f64>) {
+        hasher.write_u64(line.0.len() as u64);
+        line.0.iter().for_each(|c| self.coord(hasher, *c));
+    }
+
+    fn ring(&self, ring: &LineString<f64>) -> u64 {
+        let mut hasher = Fnv1a::new();
+        self.line(&mut hasher, ring);
+        hasher.0
+    }
+
+    fn polygon(&self, hasher: &mut Fnv1a, polygon: &Polygon<f64>) {
+        self.line(hasher, polygon.exterior());
+        // Hole order carries no meaning, so combine the holes in a canonical order
+        let mut holes: Vec<u64> = polygon.interiors().iter().map(|r| self.ring(r)).collect();
+        holes.sort_unstable();
+        hasher.write_u64(holes.len() as u64);
+        holes.into_iter().for_each(|hole| hasher.write_u64(hole));
+    }
+
+    fn geometry(&self, hasher: &mut Fnv1a, geometry: &geo::Geometry<f64>) {
+        match geometry {
+            geo::Geometry::Point(point) => {
+                hasher.write(b"Point");
+                self.coord(hasher, point.0);
+            }
+            geo::Geometry::Line(line) => self.geometry(hasher, &LineString::from(*line).into()),
+            geo::Geometry::LineString(line) => {
+                hasher.write(b"LineString");
+                self.line(hasher, line);
+            }
+            geo::Geometry::Polygon(polygon) => {
+                hasher.write(b"Polygon");
+                self.polygon(hasher, polygon);
+            }
+            geo::Geometry::MultiPoint(points) => {
+                hasher.write(b"MultiPoint");
+                hasher.write_u64(points.0.len() as u64);
+                points.iter().for_each(|p| self.coord(hasher, p.0));
+            }
+            geo::Geometry::MultiLineString(lines) => {
+                hasher.write(b"MultiLineString");
+                hasher.write_u64(lines.0.len() as u64);
+                lines.iter().for_each(|line| self.line(hasher, line));
+            }
+            geo::Geometry::MultiPolygon(polygons) => {
+                hasher.write(b"MultiPolygon");
+                hasher.write_u64(polygons.0.len() as u64);
+                polygons.iter().for_each(|p| self.polygon(hasher, p));
+            }
+            geo::Geometry::GeometryCollection(collection) => {
+                hasher.write(b"GeometryCollection");
+                hasher.write_u64(collection.0.len() as u64);
+                collection.iter().for_each(|g| self.geometry(hasher, g));
+            }
+            geo::Geometry::Rect(rect) => self.geometry(hasher, &rect.to_polygon().into()),
+            geo::Geometry::Triangle(triangle) => {
+                self.geometry(hasher, &triangle.to_polygon().into())
+            }
+        }
+    }
+}
+
+impl ProcessedGeometry {
+    /// Compute a platform-independent fingerprint of the geometry
+    ///
+    /// Coordinates are quantized to `precision` decimal places before hashing,
+    /// so differences below that quantum do not change the digest. The
+    /// geometry type and the nesting of parts are hashed too, while the order
+    /// of polygon holes is ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `precision` - The number of decimal places that are significant
+    ///
+    /// # Returns
+    ///
+    /// * `u64` - The digest
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use geojson::{Geometry, Value};
+    /// use proj_exercise_simple::geometry_processor::GeometryProcessor;
+    /// use proj_exercise_simple::pool::CoordinateBufferPool;
+    /// use proj_exercise_simple::transformer::TransformerConfig;
+    ///
+    /// let geometry = Geometry::new(Value::Point(vec![13.377, 52.518]));
+    /// let mut config = TransformerConfig::default();
+    /// let mut pool = CoordinateBufferPool::new(10, 100);
+    /// let first = GeometryProcessor::new(&geometry, &mut config).process(&mut pool).unwrap();
+    /// let second = GeometryProcessor::new(&geometry, &mut config).process(&mut pool).unwrap();
+    /// assert_eq!(first.geohash_digest(3), second.geohash_digest(3));
+    /// ```
+    pub fn geohash_digest(&self, precision: u8) -> u64 {
+        let geometry = match self {
+            ProcessedGeometry::Point(g) => geo::Geometry::Point(*g),
+            ProcessedGeometry::LineString(g) => geo::Geometry::LineString(g.clone()),
+            ProcessedGeometry::Polygon(g) => geo::Geometry::Polygon(g.clone()),
+            ProcessedGeometry::MultiPoint(g) => geo::Geometry::MultiPoint(g.clone()),
+            ProcessedGeometry::MultiLineString(g) => geo::Geometry::MultiLineString(g.clone()),
+            ProcessedGeometry::MultiPolygon(g) => geo::Geometry::MultiPolygon(g.clone()),
+            ProcessedGeometry::GeometryCollection(g) => {
+                geo::Geometry::GeometryCollection(g.clone())
+            }
+        };
+        geometry_digest(&geometry, precision)
+    }
+}
+
+fn geometry_digest(geometry: &geo::Geometry<f64>, precision: u8) -> u64 {
+    let digest = Digest {
+        scale: 10f64.powi(precision as i32),
+    };
+    let mut hasher = Fnv1a::new();
+    digest.geometry(&mut hasher, geometry);
+    hasher.0
+}
+
+/// The digest of a GeoJSON geometry, as written by `attach_geometry_hashes`
+pub(crate) fn geojson_geometry_digest(
+    geometry: &geojson::Geometry,
+    precision: u8,
+) -> Result<u64, ProjectionError> {
+    let geometry = geo::Geometry::<f64>::try_from(geometry.value.clone())?;
+    Ok(geometry_digest(&geometry, precision))
+}
+
+fn attach(
+    properties: &mut Option<JsonObject>,
+    geometry: &geojson::Geometry,
+    precision: u8,
+) -> Result<(), ProjectionError> {
+    let digest = geojson_geometry_digest(geometry, precision)?;
+    properties.get_or_insert_with(JsonObject::new).insert(
+        GEOMETRY_HASH_KEY.to_string(),
+        // A hex string, since JSON readers often lose precision on large integers
+        JsonValue::String(format!("{digest:016x}")),
+    );
+    Ok(())
+}
+
+/// Write the digest of every feature's geometry into its properties
+///
+/// # Arguments
+///
+/// * `geojson` - The processed GeoJSON, changed in place
+/// * `precision` - The number of decimal places that are significant
+///
+/// # Returns
+///
+/// * `Result<(), ProjectionError>` - An error if a geometry cannot be converted
+pub(crate) fn attach_geometry_hashes(
+    geojson: &mut GeoJson,
+    precision: u8,
+) -> Result<(), ProjectionError> {
+    match geojson {
+        GeoJson::FeatureCollection(collection) => {
+            for feature in &mut collection.features {
+                if let Some(geometry) = &feature.geometry {
+                    attach(&mut feature.properties, geometry, precision)?;
+                }
+            }
+        }
+        GeoJson::Feature(feature) => {
+            if let Some(geometry) = &feature.geometry {
+                attach(&mut feature.properties, geometry, precision)?;
+            }
+        }
+        // A bare geometry has nowhere to store properties
+        GeoJson::Geometry(_) => {}
+    }
+    Ok(())
+}
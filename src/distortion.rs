@@ -0,0 +1,178 @@
+use crate::coordinates::Coordinate;
+use crate::error::ProjectionError;
+use crate::transformer::{TransformerConfig, TransformerError};
+use geo::Centroid;
+use geojson::{GeoJson, JsonObject, JsonValue};
+
+/// The radius of the reference sphere, the WGS84 semi-major axis in meters
+const REFERENCE_RADIUS: f64 = 6_378_137.0;
+
+/// The step of the numerical derivatives, in degrees
+const STEP_DEGREES: f64 = 1e-5;
+
+/// The property key receiving `Distortion::areal_scale`
+pub const AREAL_SCALE_KEY: &str = "distortion_areal_scale";
+/// The property key receiving `Distortion::max_scale`
+pub const MAX_SCALE_KEY: &str = "distortion_max_scale";
+/// The property key receiving `Distortion::min_scale`
+pub const MIN_SCALE_KEY: &str = "distortion_min_scale";
+/// The property key receiving `Distortion::max_angular_distortion`
+pub const MAX_ANGULAR_KEY: &str = "distortion_max_angular_deg";
+
+/// The local distortion of a projection, as described by the Tissot indicatrix
+///
+/// # Fields
+///
+/// * `areal_scale` - The factor by which small areas are enlarged
+/// * `max_scale` - The largest linear scale factor over all directions
+/// * `min_scale` - The smallest linear scale factor over all directions
+/// * `max_angular_distortion` - The largest change of an angle, in degrees
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Distortion {
+    pub areal_scale: f64,
+    pub max_scale: f64,
+    pub min_scale: f64,
+    pub max_angular_distortion: f64,
+}
+
+/// Compute the distortion of the projection of a config at a coordinate
+///
+/// The projection is differentiated numerically by projecting a small cross
+/// of points around the coordinate. Scales are relative to a sphere with the
+/// radius of the WGS84 semi-major axis, and assume the target CRS is in
+/// meters. Near the poles the cross is shifted to stay within ±90°.
+///
+/// # Arguments
+///
+/// * `coord` - A longitude and latitude in degrees
+/// * `config` - A transformer config from a geographic to a projected CRS
+///
+/// # Returns
+///
+/// * `Result<Distortion, ProjectionError>` - The distortion at the coordinate
+///
+/// # Example
+///
+/// ```rust
+/// use proj_exercise_simple::coordinates::Coordinate;
+/// use proj_exercise_simple::distortion::distortion_at;
+/// use proj_exercise_simple::transformer::TransformerConfig;
+///
+/// let mut config = TransformerConfig::default();
+/// let equator = distortion_at(&Coordinate::new(10.0, 0.0), &mut config).unwrap();
+/// assert!((equator.areal_scale - 1.0).abs() < 1e-6);
+/// // Web Mercator is conformal
+/// assert!(equator.max_angular_distortion < 1e-3);
+/// ```
+pub fn distortion_at(
+    coord: &Coordinate,
+    config: &mut TransformerConfig,
+) -> Result<Distortion, ProjectionError> {
+    let transformer = config.get_transformer()?;
+    let project = |lon: f64, lat: f64| -> Result<(f64, f64), ProjectionError> {
+        Ok(transformer.convert((lon, lat))?)
+    };
+
+    let lat = coord.y.clamp(-90.0 + STEP_DEGREES, 90.0 - STEP_DEGREES);
+    let step = STEP_DEGREES.to_radians();
+    let (east, west) = (
+        project(coord.x + STEP_DEGREES, lat)?,
+        project(coord.x - STEP_DEGREES, lat)?,
+    );
+    let (north, south) = (
+        project(coord.x, lat + STEP_DEGREES)?,
+        project(coord.x, lat - STEP_DEGREES)?,
+    );
+    let dx_dlon = (east.0 - west.0) / (2.0 * step);
+    let dy_dlon = (east.1 - west.1) / (2.0 * step);
+    let dx_dlat = (north.0 - south.0) / (2.0 * step);
+    let dy_dlat = (north.1 - south.1) / (2.0 * step);
+
+    let cos_lat = lat.to_radians().cos();
+    // Scale along the meridian and along the parallel
+    let h = dx_dlat.hypot(dy_dlat) / REFERENCE_RADIUS;
+    let k = dx_dlon.hypot(dy_dlon) / (REFERENCE_RADIUS * cos_lat);
+    let areal_scale =
+        (dy_dlat * dx_dlon - dx_dlat * dy_dlon).abs() / (REFERENCE_RADIUS.powi(2) * cos_lat);
+
+    let sum = (h * h + k * k + 2.0 * areal_scale).sqrt();
+    let difference = (h * h + k * k - 2.0 * areal_scale).max(0.0).sqrt();
+    let (max_scale, min_scale) = ((sum + difference) / 2.0, (sum - difference) / 2.0);
+    let max_angular_distortion = 2.0
+        * ((max_scale - min_scale) / (max_scale + min_scale))
+            .asin()
+            .to_degrees();
+    Ok(Distortion {
+        areal_scale,
+        max_scale,
+        min_scale,
+        max_angular_distortion,
+    })
+}
+
+fn attach(
+    properties: &mut Option<JsonObject>,
+    geometry: &geojson::Geometry,
+    config: &mut TransformerConfig,
+) -> Result<(), ProjectionError> {
+    let geometry = geo::Geometry::<f64>::try_from(geometry.value.clone())?;
+    let Some(centroid) = geometry.centroid() else {
+        return Ok(());
+    };
+    let (lon, lat) = config
+        .get_inverse_transformer()?
+        .convert((centroid.x(), centroid.y()))?;
+    let distortion = distortion_at(&Coordinate::new(lon, lat), config)?;
+    let properties = properties.get_or_insert_with(JsonObject::new);
+    for (key, value) in [
+        (AREAL_SCALE_KEY, distortion.areal_scale),
+        (MAX_SCALE_KEY, distortion.max_scale),
+        (MIN_SCALE_KEY, distortion.min_scale),
+        (MAX_ANGULAR_KEY, distortion.max_angular_distortion),
+    ] {
+        properties.insert(key.to_string(), JsonValue::from(value));
+    }
+    Ok(())
+}
+
+/// Write the distortion at the centroid of every projected feature into its properties
+///
+/// The centroid is taken of the projected geometry and projected back to
+/// the source CRS to evaluate the distortion there.
+///
+/// # Arguments
+///
+/// * `geojson` - The processed GeoJSON, changed in place
+/// * `config` - The transformer config used to produce it
+///
+/// # Returns
+///
+/// * `Result<(), ProjectionError>` - An error if the source is not geographic or the target not projected
+pub(crate) fn attach_distortion(
+    geojson: &mut GeoJson,
+    config: &mut TransformerConfig,
+) -> Result<(), ProjectionError> {
+    if !config.source_is_geographic()? || !config.target_is_projected()? {
+        return Err(TransformerError::InvalidCrs(
+            "Distortion needs a geographic source and a projected target CRS".to_string(),
+        )
+        .into());
+    }
+    match geojson {
+        GeoJson::FeatureCollection(collection) => {
+            for feature in &mut collection.features {
+                if let Some(geometry) = &feature.geometry {
+                    attach(&mut feature.properties, geometry, config)?;
+                }
+            }
+        }
+        GeoJson::Feature(feature) => {
+            if let Some(geometry) = &feature.geometry {
+                attach(&mut feature.properties, geometry, config)?;
+            }
+        }
+        // A bare geometry has nowhere to store properties
+        GeoJson::Geometry(_) => {}
+    }
+    Ok(())
+}
@@ -1,7 +1,9 @@
 use crate::pool::BufferPoolError;
 use crate::transformer::TransformerError;
 use geojson::Error as GeoJsonError;
+#[cfg(feature = "proj")]
 use proj::ProjCreateError;
+#[cfg(feature = "proj")]
 use proj::ProjError;
 use thiserror::Error;
 
@@ -11,14 +13,115 @@ pub enum ProjectionError {
     InvalidGeometryType,
     #[error("Invalid coordinates: {0}")]
     InvalidCoordinates(String),
+    #[cfg(feature = "proj")]
     #[error("Projection error: {0}")]
     ProjError(#[from] ProjError),
+    #[cfg(feature = "proj")]
     #[error("Projection creation error: {0}")]
     ProjCreateError(#[from] ProjCreateError),
     #[error("GeoJSON error: {0}")]
-    GeoJsonError(#[from] GeoJsonError),
+    GeoJsonError(#[source] Box<GeoJsonError>),
     #[error("Transformer error: {0}")]
     TransformerError(#[from] TransformerError),
     #[error("Buffer pool error: {0}")]
     BufferPoolError(#[from] BufferPoolError),
+    #[error("Unexpected geometry in feature {feature_index}: {reason}")]
+    UnexpectedGeometry {
+        feature_index: usize,
+        reason: String,
+    },
+    #[error("Feature range {start}..{end} is out of bounds for {feature_count} features")]
+    InvalidFeatureRange {
+        start: usize,
+        end: usize,
+        feature_count: usize,
+    },
+    #[error("Vertex budget exceeded: got {got} vertices, the limit is {limit}")]
+    VertexBudgetExceeded { got: usize, limit: usize },
+    #[error("Processing feature {feature_index} exceeded the timeout")]
+    Timeout { feature_index: usize },
+    #[error("Processing was cancelled")]
+    Cancelled,
+    #[error("Duplicate feature ids: {}", .0.join(", "))]
+    DuplicateIds(Vec<String>),
+    #[error("Feature {feature_index} does not conform to RFC 7946: {source}")]
+    NotRfc7946 {
+        feature_index: usize,
+        #[source]
+        source: GeometryValidationError,
+    },
+    #[error("Invalid checkpoint: {0}")]
+    InvalidCheckpoint(String),
+    #[error("Post-transform hook failed for feature {feature_index}: {source}")]
+    PostTransformHook {
+        feature_index: usize,
+        #[source]
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+    #[error(
+        "Invalid properties of feature {feature_index}{}: {source}",
+        .field.as_ref().map(|field| format!(" at `{field}`")).unwrap_or_default()
+    )]
+    InvalidProperties {
+        feature_index: usize,
+        field: Option<String>,
+        #[source]
+        source: serde_json::Error,
+    },
+    #[error("Ring {ring} of polygon {polygon} in feature {feature_index} intersects itself")]
+    SelfIntersectingRing {
+        feature_index: usize,
+        polygon: usize,
+        ring: usize,
+    },
+    #[error("Incompatible options: {0}")]
+    IncompatibleOptions(String),
+    #[error("No value at JSON pointer `{0}`")]
+    PointerNotFound(String),
+    #[error("I/O error: {0}")]
+    IoError(#[from] std::io::Error),
+    #[cfg(feature = "arrow")]
+    #[error("Arrow error: {0}")]
+    ArrowError(#[from] arrow_schema::ArrowError),
+}
+
+// `geojson::Error` alone is 200 bytes, so it is boxed to keep `Result<_, ProjectionError>` small
+impl From<GeoJsonError> for ProjectionError {
+    fn from(error: GeoJsonError) -> Self {
+        ProjectionError::GeoJsonError(Box::new(error))
+    }
+}
+
+/// A problem found by validating input ahead of projection
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum ValidationError {
+    #[error("Feature has no geometry")]
+    MissingGeometry,
+    #[error("Invalid position {position:?}: {reason}")]
+    InvalidPosition { position: Vec<f64>, reason: String },
+    #[error("Ring {ring} of polygon {polygon}: {source}")]
+    InvalidRing {
+        polygon: usize,
+        ring: usize,
+        source: GeometryValidationError,
+    },
+}
+
+/// The reason a geometry fails structural validation
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum GeometryValidationError {
+    #[error("Ring is not closed, its last position differs from the first")]
+    UnclosedRing,
+    #[error("Too few points: expected at least {expected}, found {found}")]
+    TooFewPoints { expected: usize, found: usize },
+    #[error("Ring intersects itself")]
+    SelfIntersection,
+    #[error("Position contains a NaN or infinite value")]
+    NaN,
+    #[error("Position ({x}, {y}) is outside the WGS84 range")]
+    OutOfRange { x: f64, y: f64 },
+    #[error("Geometry has no coordinates")]
+    EmptyGeometry,
+    #[error("Bounding box has {0} values, expected 4 or 6")]
+    InvalidBbox(usize),
 }
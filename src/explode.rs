@@ -0,0 +1,95 @@
+use crate::helpers::ProcessedGeometry;
+use geojson::feature::Id;
+use geojson::Feature;
+
+/// Which geometries are split into one output feature per part
+///
+/// # Variants
+///
+/// * `None` - Keep every geometry in a single feature
+/// * `Collections` - Split geometry collections into their members
+/// * `All` - Also split multi geometries into single geometries
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExplodePolicy {
+    #[default]
+    None,
+    Collections,
+    All,
+}
+
+/// Split a processed geometry into its parts according to the policy
+///
+/// # Arguments
+///
+/// * `geometry` - The processed geometry
+/// * `policy` - The explode policy
+///
+/// # Returns
+///
+/// * `Result<Vec<ProcessedGeometry>, ProcessedGeometry>` - The parts, or the
+///   unchanged geometry if the policy does not split it
+pub(crate) fn explode_geometry(
+    geometry: ProcessedGeometry,
+    policy: ExplodePolicy,
+) -> Result<Vec<ProcessedGeometry>, ProcessedGeometry> {
+    match (policy, geometry) {
+        (ExplodePolicy::None, geometry) => Err(geometry),
+        (_, ProcessedGeometry::GeometryCollection(collection)) => Ok(collection
+            .into_iter()
+            .map(ProcessedGeometry::from_geo)
+            .flat_map(|member| match explode_geometry(member, policy) {
+                Ok(parts) => parts,
+                Err(member) => vec![member],
+            })
+            .collect()),
+        (ExplodePolicy::All, ProcessedGeometry::MultiPoint(multi)) => {
+            Ok(multi.into_iter().map(ProcessedGeometry::Point).collect())
+        }
+        (ExplodePolicy::All, ProcessedGeometry::MultiLineString(multi)) => Ok(multi
+            .into_iter()
+            .map(ProcessedGeometry::LineString)
+            .collect()),
+        (ExplodePolicy::All, ProcessedGeometry::MultiPolygon(multi)) => {
+            Ok(multi.into_iter().map(ProcessedGeometry::Polygon).collect())
+        }
+        (_, geometry) => Err(geometry),
+    }
+}
+
+/// Create the output feature for one part of an exploded feature
+///
+/// Properties are copied and get a `part_index` entry. String ids become
+/// `{id}:{part_index}` so they stay unique, numeric ids are dropped unless
+/// `keep_numeric_ids` is set, in which case they are copied unchanged.
+///
+/// # Arguments
+///
+/// * `template` - The source feature, its geometry is ignored
+/// * `geometry` - The geometry of the part
+/// * `part_index` - The position of the part within the source feature
+/// * `keep_numeric_ids` - Whether numeric ids are copied to every part
+///
+/// # Returns
+///
+/// * `Feature` - The output feature for the part
+pub(crate) fn part_feature(
+    template: &Feature,
+    geometry: geojson::Geometry,
+    part_index: usize,
+    keep_numeric_ids: bool,
+) -> Feature {
+    let id = match &template.id {
+        Some(Id::String(id)) => Some(Id::String(format!("{}:{}", id, part_index))),
+        Some(Id::Number(id)) if keep_numeric_ids => Some(Id::Number(id.clone())),
+        _ => None,
+    };
+    let mut properties = template.properties.clone().unwrap_or_default();
+    properties.insert("part_index".to_string(), part_index.into());
+    Feature {
+        bbox: None,
+        geometry: Some(geometry),
+        id,
+        properties: Some(properties),
+        foreign_members: template.foreign_members.clone(),
+    }
+}
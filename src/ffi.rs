@@ -0,0 +1,237 @@
+//! C-compatible entry points for the processing pipeline
+//!
+//! Build a shared library with
+//! `cargo rustc --lib --release --features ffi --crate-type cdylib`, the
+//! matching declarations are in `include/geojson_projection.h`. Every
+//! function catches panics at the boundary and reports them as
+//! `GP_ERROR_PANIC`. Strings returned through `output` and `error` are owned
+//! by the caller and must be released with `gp_free_string`.
+
+use crate::error::ProjectionError;
+use crate::helpers::process_feature_collection;
+use crate::transformer::TransformerConfig;
+use std::ffi::{c_char, CStr, CString};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+
+/// The call succeeded and `output` holds the processed GeoJSON
+pub const GP_OK: i32 = 0;
+/// A required pointer was null or a string was not valid UTF-8
+pub const GP_ERROR_INVALID_ARGUMENT: i32 = 1;
+/// The input could not be parsed or projected
+pub const GP_ERROR_PROCESSING: i32 = 2;
+/// The pipeline panicked, the panic was caught at the boundary
+pub const GP_ERROR_PANIC: i32 = 3;
+
+/// A processing session, reusing one transformer configuration across calls
+///
+/// Transformers are cached per thread, so calls made from the same thread
+/// reuse the transformer created by the first call.
+pub struct GpSession {
+    config: TransformerConfig,
+}
+
+/// Read a borrowed C string, rejecting null pointers and invalid UTF-8
+///
+/// # Safety
+///
+/// `value` must be null or point to a NUL-terminated string.
+unsafe fn read_str<'a>(value: *const c_char, name: &str) -> Result<&'a str, (i32, String)> {
+    if value.is_null() {
+        return Err((GP_ERROR_INVALID_ARGUMENT, format!("{name} is null")));
+    }
+    CStr::from_ptr(value).to_str().map_err(|_| {
+        (
+            GP_ERROR_INVALID_ARGUMENT,
+            format!("{name} is not valid UTF-8"),
+        )
+    })
+}
+
+/// Hand a string to the caller, replacing interior NUL bytes so it always converts
+fn into_c_string(value: String) -> *mut c_char {
+    CString::new(value.replace('\0', "\u{FFFD}"))
+        .expect("NUL bytes were replaced")
+        .into_raw()
+}
+
+fn process(input: &str, config: &mut TransformerConfig) -> Result<String, (i32, String)> {
+    let processing_error = |e: ProjectionError| (GP_ERROR_PROCESSING, e.to_string());
+    let json_value: serde_json::Value = serde_json::from_str(input)
+        .map_err(|e| processing_error(geojson::Error::from(e).into()))?;
+    let output = process_feature_collection(json_value, config).map_err(processing_error)?;
+    Ok(output.to_string())
+}
+
+/// Run a call behind the panic boundary and write its result to the out parameters
+///
+/// # Safety
+///
+/// `output` and `error` must each be null or valid for writes.
+unsafe fn respond(
+    call: impl FnOnce() -> Result<String, (i32, String)>,
+    output: *mut *mut c_char,
+    error: *mut *mut c_char,
+) -> i32 {
+    let result = panic::catch_unwind(AssertUnwindSafe(call)).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string());
+        Err((GP_ERROR_PANIC, format!("panic: {message}")))
+    });
+    if !output.is_null() {
+        *output = ptr::null_mut();
+    }
+    if !error.is_null() {
+        *error = ptr::null_mut();
+    }
+    match result {
+        Ok(json) if output.is_null() => {
+            drop(json);
+            GP_ERROR_INVALID_ARGUMENT
+        }
+        Ok(json) => {
+            *output = into_c_string(json);
+            GP_OK
+        }
+        Err((code, message)) => {
+            if !error.is_null() {
+                *error = into_c_string(message);
+            }
+            code
+        }
+    }
+}
+
+/// Project a GeoJSON document from one CRS to another
+///
+/// # Arguments
+///
+/// * `input` - The GeoJSON document, NUL-terminated UTF-8
+/// * `from_crs` - The source CRS, such as `EPSG:4326`
+/// * `to_crs` - The target CRS, such as `EPSG:3857`
+/// * `output` - Receives the processed GeoJSON on success
+/// * `error` - Receives an error message on failure, may be null
+///
+/// # Returns
+///
+/// * `i32` - `GP_OK` or one of the `GP_ERROR_*` codes
+///
+/// # Safety
+///
+/// The string arguments must be null or point to NUL-terminated strings, and
+/// `output` and `error` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn gp_process_json(
+    input: *const c_char,
+    from_crs: *const c_char,
+    to_crs: *const c_char,
+    output: *mut *mut c_char,
+    error: *mut *mut c_char,
+) -> i32 {
+    respond(
+        || {
+            let input = read_str(input, "input")?;
+            let from = read_str(from_crs, "from_crs")?;
+            let to = read_str(to_crs, "to_crs")?;
+            let mut config = TransformerConfig::new(from.to_string(), to.to_string())
+                .map_err(|e| (GP_ERROR_PROCESSING, e.to_string()))?;
+            process(input, &mut config)
+        },
+        output,
+        error,
+    )
+}
+
+/// Create a processing session for a pair of CRS
+///
+/// # Arguments
+///
+/// * `from_crs` - The source CRS
+/// * `to_crs` - The target CRS
+///
+/// # Returns
+///
+/// * `*mut GpSession` - The session, or null if a CRS is invalid
+///
+/// # Safety
+///
+/// The arguments must be null or point to NUL-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn gp_session_create(
+    from_crs: *const c_char,
+    to_crs: *const c_char,
+) -> *mut GpSession {
+    panic::catch_unwind(|| {
+        let from = read_str(from_crs, "from_crs").ok()?;
+        let to = read_str(to_crs, "to_crs").ok()?;
+        let config = TransformerConfig::new(from.to_string(), to.to_string()).ok()?;
+        Some(Box::into_raw(Box::new(GpSession { config })))
+    })
+    .ok()
+    .flatten()
+    .unwrap_or(ptr::null_mut())
+}
+
+/// Project a GeoJSON document with the CRS of a session
+///
+/// # Arguments
+///
+/// * `session` - A session from `gp_session_create`
+/// * `input` - The GeoJSON document, NUL-terminated UTF-8
+/// * `output` - Receives the processed GeoJSON on success
+/// * `error` - Receives an error message on failure, may be null
+///
+/// # Returns
+///
+/// * `i32` - `GP_OK` or one of the `GP_ERROR_*` codes
+///
+/// # Safety
+///
+/// `session` must be null or a live session, not used from two threads at
+/// once. The other pointers follow the rules of `gp_process_json`.
+#[no_mangle]
+pub unsafe extern "C" fn gp_session_process(
+    session: *mut GpSession,
+    input: *const c_char,
+    output: *mut *mut c_char,
+    error: *mut *mut c_char,
+) -> i32 {
+    respond(
+        || {
+            let session = session
+                .as_mut()
+                .ok_or_else(|| (GP_ERROR_INVALID_ARGUMENT, "session is null".to_string()))?;
+            let input = read_str(input, "input")?;
+            process(input, &mut session.config)
+        },
+        output,
+        error,
+    )
+}
+
+/// Destroy a session, null is ignored
+///
+/// # Safety
+///
+/// `session` must be null or a session from `gp_session_create` that was not destroyed yet.
+#[no_mangle]
+pub unsafe extern "C" fn gp_session_destroy(session: *mut GpSession) {
+    if !session.is_null() {
+        drop(Box::from_raw(session));
+    }
+}
+
+/// Free a string returned by this library, null is ignored
+///
+/// # Safety
+///
+/// `value` must be null or a string returned through `output` or `error` that was not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn gp_free_string(value: *mut c_char) {
+    if !value.is_null() {
+        drop(CString::from_raw(value));
+    }
+}
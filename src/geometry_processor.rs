@@ -1,11 +1,65 @@
-use crate::coordinates::{Coordinate, Line, Polygon as ProjectPolygon};
-use crate::error::ProjectionError;
+use crate::coordinates::{Coordinate, CoordinateBounds, Line, Polygon as ProjectPolygon};
+use crate::error::{ProjectionError, ValidationError};
 use crate::helpers::ProcessedGeometry;
+use crate::options::{CancellationToken, FeatureContext, PostTransformHook, RingClosure};
 use crate::pool::CoordinateBufferPool;
 use crate::simplification::Simplify;
-use crate::transformer::TransformerConfig;
-use geo::{LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon as GeoPolygon};
+use crate::transformer::{Transformer, TransformerConfig};
+use crate::validation::collect_validation_errors;
+use crate::vertices::for_each_position;
+use geo::{
+    LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon as GeoPolygon, Rect,
+    Triangle,
+};
 use geojson::Geometry;
+use std::time::{Duration, Instant};
+
+/// The point in time by which a feature must be projected, and the token that cancels it
+///
+/// Processors check it between batches of coordinates, so a feature that
+/// runs out of time or is cancelled stops within one batch instead of
+/// running to its end.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Deadline<'a> {
+    at: Option<Instant>,
+    cancellation: Option<&'a CancellationToken>,
+    feature_index: usize,
+}
+
+impl<'a> Deadline<'a> {
+    /// A deadline `timeout` from now, `None` when there is neither a timeout nor a token
+    ///
+    /// A timeout too far away to represent is ignored.
+    pub(crate) fn new(
+        timeout: Option<Duration>,
+        cancellation: Option<&'a CancellationToken>,
+        feature_index: usize,
+    ) -> Option<Self> {
+        let at = timeout.and_then(|timeout| Instant::now().checked_add(timeout));
+        (at.is_some() || cancellation.is_some()).then_some(Self {
+            at,
+            cancellation,
+            feature_index,
+        })
+    }
+
+    /// Fail with `ProjectionError::Cancelled` once the token is cancelled,
+    /// or with `ProjectionError::Timeout` once the deadline has passed
+    pub(crate) fn check(&self) -> Result<(), ProjectionError> {
+        if self
+            .cancellation
+            .is_some_and(CancellationToken::is_cancelled)
+        {
+            return Err(ProjectionError::Cancelled);
+        }
+        if self.at.is_some_and(|at| Instant::now() > at) {
+            return Err(ProjectionError::Timeout {
+                feature_index: self.feature_index,
+            });
+        }
+        Ok(())
+    }
+}
 
 // Trait for geometry-specific processing
 pub(crate) trait GeometryProcessorTrait {
@@ -13,6 +67,7 @@ pub(crate) trait GeometryProcessorTrait {
         &self,
         config: &mut TransformerConfig,
         buffer_pool: &mut CoordinateBufferPool,
+        deadline: Option<Deadline>,
     ) -> Result<ProcessedGeometry, ProjectionError>;
 }
 
@@ -32,10 +87,19 @@ impl GeometryProcessorTrait for PointProcessor {
         &self,
         config: &mut TransformerConfig,
         _buffer_pool: &mut CoordinateBufferPool,
+        deadline: Option<Deadline>,
     ) -> Result<ProcessedGeometry, ProjectionError> {
         let transformer = config.get_transformer()?;
-        let projected = transformer.convert(self.point)?;
-        Ok(ProcessedGeometry::Point(projected))
+        let mut projected = Vec::with_capacity(1);
+        let coordinate = Coordinate::new(self.point.x(), self.point.y());
+        convert_into(
+            &[coordinate],
+            config,
+            &transformer,
+            deadline,
+            &mut projected,
+        )?;
+        Ok(ProcessedGeometry::Point(projected[0]))
     }
 }
 
@@ -55,32 +119,16 @@ impl GeometryProcessorTrait for LineStringProcessor {
         &self,
         config: &mut TransformerConfig,
         buffer_pool: &mut CoordinateBufferPool,
+        deadline: Option<Deadline>,
     ) -> Result<ProcessedGeometry, ProjectionError> {
         let transformer = config.get_transformer()?;
-        let mut projected_coords = buffer_pool.get_point_buffer()?;
-        projected_coords.clear();
-        projected_coords.reserve(self.coordinates.len());
-
-        // Process coordinates in batches of 1000
-        let mut batch_buffer = Vec::with_capacity(1000);
-        for chunk in self.coordinates.chunks(1000) {
-            batch_buffer.clear();
-            batch_buffer.reserve(chunk.len());
-            for coord in chunk {
-                let point = Point::new(coord.x, coord.y);
-                let projected = transformer.convert(point)?;
-                batch_buffer.push(projected.into());
-            }
-            projected_coords.extend_from_slice(&batch_buffer);
-        }
-
-        let line_string = LineString::from(
-            projected_coords
-                .iter()
-                .map(|c| geo::Coord::from((c.x, c.y)))
-                .collect::<Vec<_>>(),
-        );
-        buffer_pool.return_point_buffer(projected_coords)?;
+        let line_string = convert_ring(
+            &self.coordinates,
+            config,
+            &transformer,
+            buffer_pool,
+            deadline,
+        )?;
         Ok(ProcessedGeometry::LineString(line_string))
     }
 }
@@ -101,64 +149,11 @@ impl GeometryProcessorTrait for PolygonProcessor {
         &self,
         config: &mut TransformerConfig,
         buffer_pool: &mut CoordinateBufferPool,
+        deadline: Option<Deadline>,
     ) -> Result<ProcessedGeometry, ProjectionError> {
         let transformer = config.get_transformer()?;
-
-        // Process exterior ring
-        let mut projected_exterior = buffer_pool.get_point_buffer()?;
-        projected_exterior.clear();
-        projected_exterior.reserve(self.polygon.exterior.coordinates.len());
-
-        let mut batch_buffer = Vec::with_capacity(1000);
-        for chunk in self.polygon.exterior.coordinates.chunks(1000) {
-            batch_buffer.clear();
-            batch_buffer.reserve(chunk.len());
-            for coord in chunk {
-                let point = Point::new(coord.x, coord.y);
-                let projected = transformer.convert(point)?;
-                batch_buffer.push(projected.into());
-            }
-            projected_exterior.extend_from_slice(&batch_buffer);
-        }
-
-        let exterior = LineString::from(
-            projected_exterior
-                .iter()
-                .map(|c| geo::Coord::from((c.x, c.y)))
-                .collect::<Vec<_>>(),
-        );
-        buffer_pool.return_point_buffer(projected_exterior)?;
-
-        // Process interior rings
-        let mut projected_interiors_geo = Vec::new();
-        let mut ring_buffer = buffer_pool.get_point_buffer()?;
-        for interior in &self.polygon.interiors {
-            ring_buffer.clear();
-            ring_buffer.reserve(interior.coordinates.len());
-
-            for chunk in interior.coordinates.chunks(1000) {
-                batch_buffer.clear();
-                batch_buffer.reserve(chunk.len());
-                for coord in chunk {
-                    let point = Point::new(coord.x, coord.y);
-                    let projected = transformer.convert(point)?;
-                    batch_buffer.push(projected.into());
-                }
-                ring_buffer.extend_from_slice(&batch_buffer);
-            }
-
-            let line_string = LineString::from(
-                ring_buffer
-                    .iter()
-                    .map(|c| geo::Coord::from((c.x, c.y)))
-                    .collect::<Vec<_>>(),
-            );
-            projected_interiors_geo.push(line_string);
-        }
-        buffer_pool.return_point_buffer(ring_buffer)?;
-
-        let geo_polygon = GeoPolygon::new(exterior, projected_interiors_geo);
-        Ok(ProcessedGeometry::Polygon(geo_polygon))
+        let polygon = convert_polygon(&self.polygon, config, &transformer, buffer_pool, deadline)?;
+        Ok(ProcessedGeometry::Polygon(polygon))
     }
 }
 
@@ -177,25 +172,17 @@ impl GeometryProcessorTrait for MultiPointProcessor {
         &self,
         config: &mut TransformerConfig,
         buffer_pool: &mut CoordinateBufferPool,
+        deadline: Option<Deadline>,
     ) -> Result<ProcessedGeometry, ProjectionError> {
         let transformer = config.get_transformer()?;
-        let mut projected_coords = buffer_pool.get_point_buffer()?;
-        projected_coords.clear();
-        projected_coords.reserve(self.coordinates.len());
-
-        for coord in &self.coordinates {
-            let point = Point::new(coord.x, coord.y);
-            let projected = transformer.convert(point)?;
-            projected_coords.push(projected.into());
-        }
-        let multi_point = MultiPoint::from(
-            projected_coords
-                .iter()
-                .map(|c| geo::Coord::from((c.x, c.y)))
-                .collect::<Vec<_>>(),
-        );
-        buffer_pool.return_point_buffer(projected_coords)?;
-        Ok(ProcessedGeometry::MultiPoint(multi_point))
+        let points = convert_ring(
+            &self.coordinates,
+            config,
+            &transformer,
+            buffer_pool,
+            deadline,
+        )?;
+        Ok(ProcessedGeometry::MultiPoint(MultiPoint::from(points.0)))
     }
 }
 
@@ -214,39 +201,24 @@ impl GeometryProcessorTrait for MultiLineStringProcessor {
         &self,
         config: &mut TransformerConfig,
         buffer_pool: &mut CoordinateBufferPool,
+        deadline: Option<Deadline>,
     ) -> Result<ProcessedGeometry, ProjectionError> {
         let transformer = config.get_transformer()?;
-        let mut projected_lines = Vec::new();
-
-        for line in &self.lines {
-            let mut projected_coords = buffer_pool.get_point_buffer()?;
-            projected_coords.clear();
-            projected_coords.reserve(line.coordinates.len());
-
-            let mut batch_buffer = Vec::with_capacity(1000);
-            for chunk in line.coordinates.chunks(1000) {
-                batch_buffer.clear();
-                batch_buffer.reserve(chunk.len());
-                for coord in chunk {
-                    let point = Point::new(coord.x, coord.y);
-                    let projected = transformer.convert(point)?;
-                    batch_buffer.push(projected.into());
-                }
-                projected_coords.extend_from_slice(&batch_buffer);
-            }
-
-            let projected_line = LineString::from(
-                projected_coords
-                    .iter()
-                    .map(|c| geo::Coord::from((c.x, c.y)))
-                    .collect::<Vec<_>>(),
-            );
-            buffer_pool.return_point_buffer(projected_coords)?;
-            projected_lines.push(projected_line);
-        }
-
+        let lines = self
+            .lines
+            .iter()
+            .map(|line| {
+                convert_ring(
+                    &line.coordinates,
+                    config,
+                    &transformer,
+                    buffer_pool,
+                    deadline,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
         Ok(ProcessedGeometry::MultiLineString(MultiLineString::new(
-            projected_lines,
+            lines,
         )))
     }
 }
@@ -266,231 +238,748 @@ impl GeometryProcessorTrait for MultiPolygonProcessor {
         &self,
         config: &mut TransformerConfig,
         buffer_pool: &mut CoordinateBufferPool,
+        deadline: Option<Deadline>,
     ) -> Result<ProcessedGeometry, ProjectionError> {
         let transformer = config.get_transformer()?;
-        let mut projected_polygons = Vec::new();
-        let mut batch_buffer = Vec::with_capacity(1000);
-        let mut ring_buffer = buffer_pool.get_point_buffer()?;
-        let mut projected_exterior = buffer_pool.get_point_buffer()?;
-
-        for polygon in &self.polygons {
-            // Process exterior ring
-            projected_exterior.clear();
-            projected_exterior.reserve(polygon.exterior.coordinates.len());
-
-            for chunk in polygon.exterior.coordinates.chunks(1000) {
-                batch_buffer.clear();
-                batch_buffer.reserve(chunk.len());
-                for coord in chunk {
-                    let point = Point::new(coord.x, coord.y);
-                    let projected = transformer.convert(point)?;
-                    batch_buffer.push(projected.into());
-                }
-                projected_exterior.extend_from_slice(&batch_buffer);
-            }
+        let polygons = self
+            .polygons
+            .iter()
+            .map(|polygon| convert_polygon(polygon, config, &transformer, buffer_pool, deadline))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(ProcessedGeometry::MultiPolygon(MultiPolygon::from(
+            polygons,
+        )))
+    }
+}
 
-            let exterior = LineString::from(
-                projected_exterior
-                    .iter()
-                    .map(|c| geo::Coord::from((c.x, c.y)))
-                    .collect::<Vec<_>>(),
-            );
+// Specialized processor for rectangles, projected as a closed 4-corner polygon
+struct RectProcessor {
+    rect: Rect<f64>,
+}
 
-            // Process interior rings
-            let mut projected_interiors_geo = Vec::new();
+impl RectProcessor {
+    fn new(rect: Rect<f64>) -> Self {
+        Self { rect }
+    }
+}
 
-            for interior in &polygon.interiors {
-                ring_buffer.clear();
-                ring_buffer.reserve(interior.coordinates.len());
+impl GeometryProcessorTrait for RectProcessor {
+    fn process(
+        &self,
+        config: &mut TransformerConfig,
+        buffer_pool: &mut CoordinateBufferPool,
+        deadline: Option<Deadline>,
+    ) -> Result<ProcessedGeometry, ProjectionError> {
+        let (min, max) = (self.rect.min(), self.rect.max());
+        let exterior = Line::new(vec![
+            Coordinate::new(min.x, min.y),
+            Coordinate::new(max.x, min.y),
+            Coordinate::new(max.x, max.y),
+            Coordinate::new(min.x, max.y),
+            Coordinate::new(min.x, min.y),
+        ]);
+        PolygonProcessor::new(ProjectPolygon::new(exterior, vec![])).process(
+            config,
+            buffer_pool,
+            deadline,
+        )
+    }
+}
 
-                for chunk in interior.coordinates.chunks(1000) {
-                    batch_buffer.clear();
-                    batch_buffer.reserve(chunk.len());
-                    for coord in chunk {
-                        let point = Point::new(coord.x, coord.y);
-                        let projected = transformer.convert(point)?;
-                        batch_buffer.push(projected.into());
-                    }
-                    ring_buffer.extend_from_slice(&batch_buffer);
-                }
+// Specialized processor for triangles, projected as a closed 3-corner polygon
+struct TriangleProcessor {
+    triangle: Triangle<f64>,
+}
 
-                let line_string = LineString::from(
-                    ring_buffer
-                        .iter()
-                        .map(|c| geo::Coord::from((c.x, c.y)))
-                        .collect::<Vec<_>>(),
-                );
-                projected_interiors_geo.push(line_string);
-            }
+impl TriangleProcessor {
+    fn new(triangle: Triangle<f64>) -> Self {
+        Self { triangle }
+    }
+}
 
-            let geo_polygon = GeoPolygon::new(exterior, projected_interiors_geo);
-            projected_polygons.push(geo_polygon);
+impl GeometryProcessorTrait for TriangleProcessor {
+    fn process(
+        &self,
+        config: &mut TransformerConfig,
+        buffer_pool: &mut CoordinateBufferPool,
+        deadline: Option<Deadline>,
+    ) -> Result<ProcessedGeometry, ProjectionError> {
+        let Triangle(a, b, c) = self.triangle;
+        let exterior = Line::new(vec![
+            Coordinate::new(a.x, a.y),
+            Coordinate::new(b.x, b.y),
+            Coordinate::new(c.x, c.y),
+            Coordinate::new(a.x, a.y),
+        ]);
+        PolygonProcessor::new(ProjectPolygon::new(exterior, vec![])).process(
+            config,
+            buffer_pool,
+            deadline,
+        )
+    }
+}
+
+/// Project a point, counting a failure in the statistics of the config
+///
+/// Only failures of the transformer itself are transform errors, problems
+/// found while validating or assembling geometries are not counted.
+fn convert_point(
+    config: &TransformerConfig,
+    transformer: &Transformer,
+    point: Point<f64>,
+) -> Result<Point<f64>, ProjectionError> {
+    transformer.convert(point).map_err(|error| {
+        config.record_transform_error();
+        error.into()
+    })
+}
+
+/// The number of coordinates projected between two updates of the statistics
+const BATCH_SIZE: usize = 1000;
+
+/// Project coordinates onto the end of `out`, without rounding
+///
+/// Every processor and the public ring functions project through here.
+/// Coordinates are projected in batches of `BATCH_SIZE`. Each completed
+/// batch is counted in the statistics of the config, then the deadline is
+/// checked.
+fn convert_into<T: From<Point<f64>>>(
+    coords: &[Coordinate],
+    config: &TransformerConfig,
+    transformer: &Transformer,
+    deadline: Option<Deadline>,
+    out: &mut Vec<T>,
+) -> Result<(), ProjectionError> {
+    out.reserve(coords.len());
+    for batch in coords.chunks(BATCH_SIZE) {
+        for coord in batch {
+            out.push(convert_point(config, transformer, Point::new(coord.x, coord.y))?.into());
         }
+        config.record_transformations(batch.len());
+        deadline.as_ref().map_or(Ok(()), Deadline::check)?;
+    }
+    Ok(())
+}
+
+/// Project a ring through a pooled buffer, returning the buffer on success and error alike
+fn convert_ring(
+    coords: &[Coordinate],
+    config: &TransformerConfig,
+    transformer: &Transformer,
+    pool: &CoordinateBufferPool,
+    deadline: Option<Deadline>,
+) -> Result<LineString<f64>, ProjectionError> {
+    let mut buffer = pool.get_point_buffer_with_minimum_capacity(coords.len())?;
+    let ring = convert_into(coords, config, transformer, deadline, &mut buffer).map(|()| {
+        buffer
+            .iter()
+            .map(|c| geo::Coord::from((c.x, c.y)))
+            .collect::<LineString<f64>>()
+    });
+    pool.return_point_buffer(buffer)?;
+    ring
+}
 
-        buffer_pool.return_point_buffer(ring_buffer)?;
-        buffer_pool.return_point_buffer(projected_exterior)?;
+fn convert_polygon(
+    polygon: &ProjectPolygon,
+    config: &TransformerConfig,
+    transformer: &Transformer,
+    pool: &CoordinateBufferPool,
+    deadline: Option<Deadline>,
+) -> Result<GeoPolygon<f64>, ProjectionError> {
+    let exterior = convert_ring(
+        &polygon.exterior.coordinates,
+        config,
+        transformer,
+        pool,
+        deadline,
+    )?;
+    let interiors = polygon
+        .interiors
+        .iter()
+        .map(|ring| convert_ring(&ring.coordinates, config, transformer, pool, deadline))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(GeoPolygon::new(exterior, interiors))
+}
 
-        Ok(ProcessedGeometry::MultiPolygon(MultiPolygon::from(
-            projected_polygons,
-        )))
+/// Project the coordinates of a ring into a `geo` line string
+///
+/// This is the primitive the polygon processors are built on, for callers
+/// assembling their own polygons. The ring is neither validated nor closed.
+/// The precision of the config is applied and the projected coordinates are
+/// counted in its statistics.
+///
+/// # Arguments
+///
+/// * `coords` - The coordinates of the ring in the source CRS
+/// * `config` - A transformer config
+/// * `pool` - A buffer pool used for the intermediate coordinates
+///
+/// # Returns
+///
+/// * `Result<LineString<f64>, ProjectionError>` - The projected ring
+///
+/// # Example
+///
+/// ```rust
+/// use proj_exercise_simple::coordinates::Coordinate;
+/// use proj_exercise_simple::geometry_processor::project_ring;
+/// use proj_exercise_simple::pool::CoordinateBufferPool;
+/// use proj_exercise_simple::transformer::TransformerConfig;
+///
+/// let coords = [Coordinate::new(0.0, 0.0), Coordinate::new(1.0, 2.0), Coordinate::new(0.0, 0.0)];
+/// let config = TransformerConfig::default().with_precision(2);
+/// let ring = project_ring(&coords, &config, &CoordinateBufferPool::new(10, 100)).unwrap();
+/// assert_eq!(ring.0[1], geo::coord! { x: 111319.49, y: 222684.21 });
+/// ```
+pub fn project_ring(
+    coords: &[Coordinate],
+    config: &TransformerConfig,
+    pool: &CoordinateBufferPool,
+) -> Result<LineString<f64>, ProjectionError> {
+    let transformer = config.get_transformer()?;
+    let mut ring = convert_ring(coords, config, &transformer, pool, None)?;
+    if config.precision.is_some() {
+        for c in &mut ring.0 {
+            c.x = config.round_output(c.x);
+            c.y = config.round_output(c.y);
+        }
+    }
+    Ok(ring)
+}
+
+/// Project the coordinates of a ring onto the end of a buffer
+///
+/// Like `project_ring`, but writing into a buffer the caller owns, e.g. to
+/// reuse it across rings. Existing contents are kept, so clear the buffer
+/// first to hold a single ring. On error `out` may hold part of the ring.
+///
+/// # Arguments
+///
+/// * `coords` - The coordinates of the ring in the source CRS
+/// * `config` - A transformer config
+/// * `out` - The buffer receiving the projected coordinates
+///
+/// # Returns
+///
+/// * `Result<(), ProjectionError>` - An error if a coordinate cannot be projected
+///
+/// # Example
+///
+/// ```rust
+/// use proj_exercise_simple::coordinates::Coordinate;
+/// use proj_exercise_simple::geometry_processor::project_ring_into;
+/// use proj_exercise_simple::transformer::TransformerConfig;
+///
+/// let mut out = Vec::new();
+/// let config = TransformerConfig::default();
+/// project_ring_into(&[Coordinate::new(1.0, 2.0)], &config, &mut out).unwrap();
+/// assert!((out[0].x - 111319.49079327357).abs() < 1e-6);
+/// ```
+pub fn project_ring_into(
+    coords: &[Coordinate],
+    config: &TransformerConfig,
+    out: &mut Vec<geo::Coord<f64>>,
+) -> Result<(), ProjectionError> {
+    let transformer = config.get_transformer()?;
+    let start = out.len();
+    convert_into(coords, config, &transformer, None, out)?;
+    if config.precision.is_some() {
+        for c in &mut out[start..] {
+            c.x = config.round_output(c.x);
+            c.y = config.round_output(c.y);
+        }
+    }
+    Ok(())
+}
+
+/// Project a geo geometry, including the variants GeoJSON cannot express
+///
+/// Rectangles and triangles are validated and projected as closed polygons,
+/// since their edges are no longer axis-aligned or straight after most
+/// projections. Lines become line strings. All other variants go through
+/// `GeometryProcessor`, so simplification and precision apply as usual.
+///
+/// # Arguments
+///
+/// * `geometry` - A geo geometry
+/// * `config` - A transformer config
+/// * `buffer_pool` - A buffer pool used for intermediate coordinates
+/// * `bounds` - The valid input coordinate range
+///
+/// # Returns
+///
+/// * `Result<ProcessedGeometry, ProjectionError>` - The processed geometry
+///
+/// # Example
+///
+/// ```rust
+/// use geo::{coord, Rect};
+/// use proj_exercise_simple::coordinates::CoordinateBounds;
+/// use proj_exercise_simple::geometry_processor::process_geo_geometry;
+/// use proj_exercise_simple::helpers::ProcessedGeometry;
+/// use proj_exercise_simple::pool::CoordinateBufferPool;
+/// use proj_exercise_simple::transformer::TransformerConfig;
+///
+/// let rect = Rect::new(coord! { x: 13.0, y: 52.0 }, coord! { x: 14.0, y: 53.0 });
+/// let mut config = TransformerConfig::default();
+/// let mut pool = CoordinateBufferPool::new(10, 100);
+/// let processed =
+///     process_geo_geometry(&rect.into(), &mut config, &mut pool, CoordinateBounds::wgs84())
+///         .unwrap();
+/// assert!(matches!(processed, ProcessedGeometry::Polygon(p) if p.exterior().0.len() == 5));
+/// ```
+pub fn process_geo_geometry(
+    geometry: &geo::Geometry<f64>,
+    config: &mut TransformerConfig,
+    buffer_pool: &mut CoordinateBufferPool,
+    bounds: CoordinateBounds,
+) -> Result<ProcessedGeometry, ProjectionError> {
+    let mut processed = match geometry {
+        geo::Geometry::Rect(rect) => {
+            bounds.validate(rect.min().x, rect.min().y)?;
+            bounds.validate(rect.max().x, rect.max().y)?;
+            RectProcessor::new(*rect).process(config, buffer_pool, None)?
+        }
+        geo::Geometry::Triangle(triangle) => {
+            for corner in triangle.to_array() {
+                bounds.validate(corner.x, corner.y)?;
+            }
+            TriangleProcessor::new(*triangle).process(config, buffer_pool, None)?
+        }
+        geo::Geometry::GeometryCollection(collection) => {
+            let geometries = collection
+                .iter()
+                .map(|member| {
+                    process_geo_geometry(member, config, buffer_pool, bounds)
+                        .map(ProcessedGeometry::into_geo)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            return Ok(ProcessedGeometry::GeometryCollection(
+                geo::GeometryCollection::from(geometries),
+            ));
+        }
+        _ => {
+            let geometry = Geometry::new(geojson::Value::from(geometry));
+            return GeometryProcessor::new(&geometry, config)
+                .with_coordinate_bounds(bounds)
+                .process(buffer_pool);
+        }
+    };
+    if config.precision.is_some() {
+        processed.map_coords_in_place(|c| geo::Coord {
+            x: config.round_output(c.x),
+            y: config.round_output(c.y),
+        });
     }
+    Ok(processed)
+}
+
+/// Check every coordinate of a geometry against the valid range
+///
+/// Validation needs no transformer, so it can run ahead of projection or on
+/// its own, e.g. for dry runs.
+///
+/// # Arguments
+///
+/// * `value` - A GeoJSON geometry value, including nested collection members
+/// * `bounds` - The valid coordinate range
+///
+/// # Returns
+///
+/// * `Result<(), ProjectionError>` - The error for the first invalid coordinate
+pub(crate) fn check_input_geometry(
+    value: &geojson::Value,
+    bounds: CoordinateBounds,
+) -> Result<(), ProjectionError> {
+    let mut result = Ok(());
+    for_each_position(value, &mut |position| {
+        if result.is_ok() {
+            result = bounds.validate_position(position);
+        }
+    });
+    result.and(validate_rings(value))
+}
+
+/// Reject polygons without an exterior ring, which cannot be processed
+fn validate_rings(value: &geojson::Value) -> Result<(), ProjectionError> {
+    let no_rings = || ProjectionError::InvalidCoordinates("Polygon has no rings".to_string());
+    match value {
+        geojson::Value::Polygon(rings) if rings.is_empty() => Err(no_rings()),
+        geojson::Value::MultiPolygon(polygons) if polygons.iter().any(Vec::is_empty) => {
+            Err(no_rings())
+        }
+        geojson::Value::GeometryCollection(geometries) => geometries
+            .iter()
+            .try_for_each(|geometry| validate_rings(&geometry.value)),
+        _ => Ok(()),
+    }
+}
+
+/// Count the positions of a geometry, including nested collection members
+#[cfg(feature = "tracing")]
+fn position_count(value: &geojson::Value) -> usize {
+    let mut count = 0;
+    for_each_position(value, &mut |_| count += 1);
+    count
 }
 
 /// Main geometry processor that uses specialized processors
 pub struct GeometryProcessor<'a> {
     geometry: &'a Geometry,
     config: &'a mut TransformerConfig,
+    bounds: CoordinateBounds,
+    ring_closure: RingClosure,
+    deadline: Option<Deadline<'a>>,
+    post_transform_hook: Option<(&'a PostTransformHook, FeatureContext<'a>)>,
 }
 
 impl<'a> GeometryProcessor<'a> {
     pub fn new(geometry: &'a Geometry, config: &'a mut TransformerConfig) -> Self {
-        Self { geometry, config }
+        Self {
+            geometry,
+            config,
+            bounds: CoordinateBounds::default(),
+            ring_closure: RingClosure::default(),
+            deadline: None,
+            post_transform_hook: None,
+        }
     }
 
-    fn validate_coordinate(x: f64, y: f64) -> Result<(), ProjectionError> {
-        if x.is_nan() || y.is_nan() {
-            return Err(ProjectionError::InvalidCoordinates(
-                "NaN coordinates are not allowed".to_string(),
-            ));
-        }
-        if !(-180.0..=180.0).contains(&x) || !(-90.0..=90.0).contains(&y) {
-            return Err(ProjectionError::InvalidCoordinates(
-                "Coordinates out of valid range".to_string(),
-            ));
-        }
-        Ok(())
+    /// Validate every feature of a collection in one pass
+    ///
+    /// Unlike processing, validation does not stop at the first problem: every
+    /// position outside `bounds` and every polygon ring that is too short or
+    /// not closed is reported. Features without problems are left out.
+    ///
+    /// # Arguments
+    ///
+    /// * `fc` - The feature collection to validate
+    /// * `bounds` - The valid coordinate range, e.g. `CoordinateBounds::wgs84()`
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<(usize, Vec<ValidationError>)>` - The errors of each invalid feature, by feature index
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use geojson::{Feature, FeatureCollection, Geometry, Value};
+    /// use proj_exercise_simple::coordinates::CoordinateBounds;
+    /// use proj_exercise_simple::error::ValidationError;
+    /// use proj_exercise_simple::geometry_processor::GeometryProcessor;
+    ///
+    /// let fc = FeatureCollection {
+    ///     bbox: None,
+    ///     features: vec![
+    ///         Feature::from(Geometry::new(Value::Point(vec![1.0, 2.0]))),
+    ///         Feature::default(),
+    ///     ],
+    ///     foreign_members: None,
+    /// };
+    /// let errors = GeometryProcessor::validate_feature_collection(&fc, CoordinateBounds::wgs84());
+    /// assert_eq!(errors, vec![(1, vec![ValidationError::MissingGeometry])]);
+    /// ```
+    pub fn validate_feature_collection(
+        fc: &geojson::FeatureCollection,
+        bounds: CoordinateBounds,
+    ) -> Vec<(usize, Vec<ValidationError>)> {
+        fc.features
+            .iter()
+            .enumerate()
+            .filter_map(|(index, feature)| {
+                let Some(geometry) = &feature.geometry else {
+                    return Some((index, vec![ValidationError::MissingGeometry]));
+                };
+                let errors = collect_validation_errors(&geometry.value, bounds);
+                (!errors.is_empty()).then_some((index, errors))
+            })
+            .collect()
+    }
+
+    /// Set the range input coordinates are validated against, WGS84 by default
+    ///
+    /// # Arguments
+    ///
+    /// * `bounds` - The valid coordinate range
+    pub fn with_coordinate_bounds(mut self, bounds: CoordinateBounds) -> Self {
+        self.bounds = bounds;
+        self
+    }
+
+    /// Set how unclosed polygon rings are handled, an error by default
+    ///
+    /// # Arguments
+    ///
+    /// * `ring_closure` - The ring closure strategy
+    pub fn with_ring_closure(mut self, ring_closure: RingClosure) -> Self {
+        self.ring_closure = ring_closure;
+        self
+    }
+
+    /// Fail once the deadline has passed or its token is cancelled, checked between batches
+    pub(crate) fn with_deadline(mut self, deadline: Option<Deadline<'a>>) -> Self {
+        self.deadline = deadline;
+        self
+    }
+
+    /// Call a post-transform hook on the projected geometry, before simplification
+    ///
+    /// An error from the hook fails processing with `ProjectionError::PostTransformHook`.
+    pub(crate) fn with_post_transform_hook(
+        mut self,
+        hook: Option<&'a PostTransformHook>,
+        context: FeatureContext<'a>,
+    ) -> Self {
+        self.post_transform_hook = hook.map(|hook| (hook, context));
+        self
+    }
+
+    /// Project the geometry straight into GeoJSON-shaped coordinate arrays
+    ///
+    /// This skips building `geo` geometries and is meant for callers that only
+    /// need projected positions. The result is a list of parts, each a list of
+    /// positions: the rings of a polygon, the lines of a multi line string, a
+    /// single part for a point, line string or multi point, all rings of all
+    /// polygons of a multi polygon, and the parts of every member of a geometry
+    /// collection in order. Simplification is not applied on this path, and
+    /// ordinates beyond x and y are copied through unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer_pool` - A buffer pool used for the projected positions
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<Vec<Vec<f64>>>, ProjectionError>` - The projected coordinate arrays
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use geojson::{Geometry, Value};
+    /// use proj_exercise_simple::geometry_processor::GeometryProcessor;
+    /// use proj_exercise_simple::pool::CoordinateBufferPool;
+    /// use proj_exercise_simple::transformer::TransformerConfig;
+    ///
+    /// let geometry = Geometry::new(Value::Point(vec![1.0, 2.0]));
+    /// let mut config = TransformerConfig::default();
+    /// let mut pool = CoordinateBufferPool::new(10, 100);
+    /// let parts = GeometryProcessor::new(&geometry, &mut config)
+    ///     .project_coordinates(&mut pool)
+    ///     .unwrap();
+    /// assert_eq!(parts.len(), 1);
+    /// ```
+    pub fn project_coordinates(
+        &mut self,
+        buffer_pool: &mut CoordinateBufferPool,
+    ) -> Result<Vec<Vec<Vec<f64>>>, ProjectionError> {
+        let transformer = self.config.get_transformer()?;
+        let config = self.config.clone();
+        let bounds = self.bounds;
+        let deadline = self.deadline;
+        let mut parts = Vec::new();
+        let mut source = buffer_pool.get_point_buffer()?;
+        let mut projected = buffer_pool.get_point_buffer()?;
+        let mut project_part = |positions: &[Vec<f64>]| -> Result<(), ProjectionError> {
+            source.clear();
+            for position in positions {
+                bounds.validate_position(position)?;
+                source.push(Coordinate::new(position[0], position[1]));
+            }
+            projected.clear();
+            convert_into(&source, &config, &transformer, deadline, &mut projected)?;
+            // Ordinates beyond x and y, such as Z and M values, are kept unchanged
+            parts.push(
+                projected
+                    .iter()
+                    .zip(positions)
+                    .map(|(c, position)| {
+                        let mut output = vec![config.round_output(c.x), config.round_output(c.y)];
+                        output.extend_from_slice(position.get(2..).unwrap_or_default());
+                        output
+                    })
+                    .collect(),
+            );
+            Ok(())
+        };
+
+        let result = match &self.geometry.value {
+            geojson::Value::Point(point) => project_part(std::slice::from_ref(point)),
+            geojson::Value::LineString(positions) | geojson::Value::MultiPoint(positions) => {
+                project_part(positions)
+            }
+            geojson::Value::Polygon(rings) | geojson::Value::MultiLineString(rings) => {
+                rings.iter().try_for_each(|ring| project_part(ring))
+            }
+            geojson::Value::MultiPolygon(polygons) => polygons
+                .iter()
+                .flatten()
+                .try_for_each(|ring| project_part(ring)),
+            geojson::Value::GeometryCollection(geometries) => {
+                geometries.iter().try_for_each(|geometry| {
+                    let mut processor = GeometryProcessor::new(geometry, self.config)
+                        .with_coordinate_bounds(self.bounds)
+                        .with_deadline(self.deadline);
+                    parts.extend(processor.project_coordinates(buffer_pool)?);
+                    Ok(())
+                })
+            }
+        };
+        buffer_pool.return_point_buffers(vec![source, projected])?;
+        result?;
+        Ok(parts)
     }
 
+    /// Project the geometry and apply simplification and precision settings
+    ///
+    /// The geometry is validated, projected, passed to the post-transform
+    /// hook if one is set, simplified and finally rounded.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer_pool` - A buffer pool used for intermediate coordinates
+    ///
+    /// # Returns
+    ///
+    /// * `Result<ProcessedGeometry, ProjectionError>` - The processed geometry
     pub fn process(
         &mut self,
         buffer_pool: &mut CoordinateBufferPool,
     ) -> Result<ProcessedGeometry, ProjectionError> {
-        let epsilon = self.config.simplification_epsilon.unwrap_or(0.0);
-        match &self.geometry.value {
-            geojson::Value::Point(point) => {
-                Self::validate_coordinate(point[0], point[1])?;
-                let processor = PointProcessor::new(Point::new(point[0], point[1]));
-                let processed = processor.process(self.config, buffer_pool)?;
-                if let ProcessedGeometry::Point(p) = processed {
-                    Ok(ProcessedGeometry::Point(p.simplify(epsilon)))
-                } else {
-                    Ok(processed)
-                }
+        #[cfg(feature = "tracing")]
+        let _span = {
+            let span = tracing::span!(
+                tracing::Level::DEBUG,
+                "project_geometry",
+                geometry_type = self.geometry.value.type_name(),
+                coordinate_count = tracing::field::Empty
+            );
+            // Counting walks the geometry, so skip it when nobody listens
+            if !span.is_disabled() {
+                span.record("coordinate_count", position_count(&self.geometry.value));
             }
-            geojson::Value::LineString(line_string) => {
-                for point in line_string {
-                    Self::validate_coordinate(point[0], point[1])?;
+            span.entered()
+        };
+        check_input_geometry(&self.geometry.value, self.bounds).inspect_err(|_error| {
+            #[cfg(feature = "tracing")]
+            tracing::event!(tracing::Level::WARN, error = %_error, "validation failed");
+        })?;
+        let mut projected = self.project(buffer_pool)?;
+        if let Some((hook, context)) = &self.post_transform_hook {
+            hook.call(&mut projected, context).map_err(|source| {
+                ProjectionError::PostTransformHook {
+                    feature_index: context.feature_index,
+                    source,
                 }
-                let coords = line_string
-                    .iter()
-                    .map(|p| Coordinate::new(p[0], p[1]))
-                    .collect();
-                let processor = LineStringProcessor::new(coords);
-                let processed = processor.process(self.config, buffer_pool)?;
-                if let ProcessedGeometry::LineString(ls) = processed {
-                    Ok(ProcessedGeometry::LineString(ls.simplify(epsilon)))
-                } else {
-                    Ok(processed)
-                }
-            }
-            geojson::Value::Polygon(polygon) => {
-                for ring in polygon {
-                    for point in ring {
-                        Self::validate_coordinate(point[0], point[1])?;
+            })?;
+        }
+        let epsilon = self.config.simplification_epsilon.unwrap_or(0.0);
+        let mut processed = projected.simplify(epsilon);
+        #[cfg(feature = "tracing")]
+        if let Some(epsilon) = self.config.simplification_epsilon {
+            tracing::event!(
+                tracing::Level::DEBUG,
+                epsilon,
+                vertices_before = position_count(&self.geometry.value),
+                vertices_after = processed.coords_count(),
+                "simplified geometry"
+            );
+        }
+        if self.config.precision.is_some() {
+            let config = &*self.config;
+            processed.map_coords_in_place(|c| geo::Coord {
+                x: config.round_output(c.x),
+                y: config.round_output(c.y),
+            });
+        }
+        Ok(processed)
+    }
+
+    /// Build a polygon, closing or rejecting unclosed rings according to the ring closure strategy
+    fn polygon(&self, rings: &[Vec<Vec<f64>>]) -> Result<ProjectPolygon, ProjectionError> {
+        let polygon = ProjectPolygon::from_geojson_rings(rings)?;
+        let close = |mut line: Line| match self.ring_closure {
+            RingClosure::Error => line.into_checked_ring(),
+            RingClosure::AutoClose => {
+                if let (Some(&first), Some(last)) =
+                    (line.coordinates.first(), line.coordinates.last())
+                {
+                    if first != *last {
+                        line.coordinates.push(first);
                     }
                 }
-                let exterior = polygon[0]
-                    .iter()
-                    .map(|p| Coordinate::new(p[0], p[1]))
-                    .collect();
-                let interiors = polygon[1..]
-                    .iter()
-                    .map(|ring| {
-                        Line::new(ring.iter().map(|p| Coordinate::new(p[0], p[1])).collect())
-                    })
-                    .collect();
-                let processor =
-                    PolygonProcessor::new(ProjectPolygon::new(Line::new(exterior), interiors));
-                let processed = processor.process(self.config, buffer_pool)?;
-                if let ProcessedGeometry::Polygon(p) = processed {
-                    Ok(ProcessedGeometry::Polygon(p.simplify(epsilon)))
-                } else {
-                    Ok(processed)
-                }
+                Ok(line)
+            }
+            RingClosure::Ignore => Ok(line),
+        };
+        let interiors = polygon
+            .interiors
+            .into_iter()
+            .map(close)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(ProjectPolygon::new(close(polygon.exterior)?, interiors))
+    }
+
+    fn project(
+        &mut self,
+        buffer_pool: &mut CoordinateBufferPool,
+    ) -> Result<ProcessedGeometry, ProjectionError> {
+        match &self.geometry.value {
+            geojson::Value::Point(point) => PointProcessor::new(Point::new(point[0], point[1]))
+                .process(self.config, buffer_pool, self.deadline),
+            geojson::Value::LineString(line_string) => {
+                let line = Line::from_geojson_coords(line_string)?;
+                LineStringProcessor::new(line.coordinates).process(
+                    self.config,
+                    buffer_pool,
+                    self.deadline,
+                )
             }
+            geojson::Value::Polygon(polygon) => PolygonProcessor::new(self.polygon(polygon)?)
+                .process(self.config, buffer_pool, self.deadline),
             geojson::Value::MultiPoint(points) => {
-                for point in points {
-                    Self::validate_coordinate(point[0], point[1])?;
-                }
-                let coords = points.iter().map(|p| Coordinate::new(p[0], p[1])).collect();
-                let processor = MultiPointProcessor::new(coords);
-                let processed = processor.process(self.config, buffer_pool)?;
-                if let ProcessedGeometry::MultiPoint(mp) = processed {
-                    Ok(ProcessedGeometry::MultiPoint(mp.simplify(epsilon)))
-                } else {
-                    Ok(processed)
-                }
+                let points = Line::from_geojson_coords(points)?;
+                MultiPointProcessor::new(points.coordinates).process(
+                    self.config,
+                    buffer_pool,
+                    self.deadline,
+                )
             }
             geojson::Value::MultiLineString(lines) => {
                 let mut project_lines = Vec::new();
                 for line in lines {
-                    for point in line {
-                        Self::validate_coordinate(point[0], point[1])?;
-                    }
-                    let coords = line.iter().map(|p| Coordinate::new(p[0], p[1])).collect();
-                    project_lines.push(Line::new(coords));
-                }
-                let processor = MultiLineStringProcessor::new(project_lines);
-                let processed = processor.process(self.config, buffer_pool)?;
-                if let ProcessedGeometry::MultiLineString(mls) = processed {
-                    Ok(ProcessedGeometry::MultiLineString(mls.simplify(epsilon)))
-                } else {
-                    Ok(processed)
+                    project_lines.push(Line::from_geojson_coords(line)?);
                 }
+                MultiLineStringProcessor::new(project_lines).process(
+                    self.config,
+                    buffer_pool,
+                    self.deadline,
+                )
             }
             geojson::Value::MultiPolygon(polygons) => {
                 let mut project_polygons = Vec::new();
                 for polygon in polygons {
-                    let exterior = polygon[0]
-                        .iter()
-                        .map(|p| Coordinate::new(p[0], p[1]))
-                        .collect();
-                    let interiors = polygon[1..]
-                        .iter()
-                        .map(|ring| {
-                            Line::new(ring.iter().map(|p| Coordinate::new(p[0], p[1])).collect())
-                        })
-                        .collect();
-                    project_polygons.push(ProjectPolygon::new(Line::new(exterior), interiors));
-                }
-                let processor = MultiPolygonProcessor::new(project_polygons);
-                let processed = processor.process(self.config, buffer_pool)?;
-                if let ProcessedGeometry::MultiPolygon(mp) = processed {
-                    Ok(ProcessedGeometry::MultiPolygon(mp.simplify(epsilon)))
-                } else {
-                    Ok(processed)
+                    project_polygons.push(self.polygon(polygon)?);
                 }
+                MultiPolygonProcessor::new(project_polygons).process(
+                    self.config,
+                    buffer_pool,
+                    self.deadline,
+                )
             }
             geojson::Value::GeometryCollection(geometries) => {
                 let mut processed_geometries: Vec<ProcessedGeometry> = Vec::new();
                 for geometry in geometries {
-                    let mut processor = GeometryProcessor::new(geometry, self.config);
-                    let result = processor.process(buffer_pool)?;
+                    let mut processor = GeometryProcessor::new(geometry, self.config)
+                        .with_coordinate_bounds(self.bounds)
+                        .with_ring_closure(self.ring_closure)
+                        .with_deadline(self.deadline);
+                    let result = processor.project(buffer_pool)?;
                     processed_geometries.push(result);
                 }
                 let geometries: Vec<geo::Geometry<f64>> = processed_geometries
                     .into_iter()
-                    .map(|g| match g {
-                        ProcessedGeometry::Point(p) => geo::Geometry::Point(p),
-                        ProcessedGeometry::LineString(ls) => geo::Geometry::LineString(ls),
-                        ProcessedGeometry::Polygon(p) => geo::Geometry::Polygon(p),
-                        ProcessedGeometry::MultiPoint(mp) => geo::Geometry::MultiPoint(mp),
-                        ProcessedGeometry::MultiLineString(mls) => {
-                            geo::Geometry::MultiLineString(mls)
-                        }
-                        ProcessedGeometry::MultiPolygon(mp) => geo::Geometry::MultiPolygon(mp),
-                        ProcessedGeometry::GeometryCollection(_) => unreachable!(),
-                    })
+                    .map(ProcessedGeometry::into_geo)
                     .collect();
                 Ok(ProcessedGeometry::GeometryCollection(
                     geo::GeometryCollection::from(geometries),
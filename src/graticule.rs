@@ -0,0 +1,106 @@
+use crate::coordinates::MAX_MERCATOR_LATITUDE;
+use crate::error::ProjectionError;
+use crate::helpers::process_feature_collection;
+use crate::transformer::TransformerConfig;
+use geojson::{Feature, FeatureCollection, Geometry, JsonObject, Value};
+
+/// The values of `spacing` multiples within `min..=max`
+fn multiples(spacing: f64, min: f64, max: f64) -> Vec<f64> {
+    let first = (min / spacing).ceil() as i64;
+    let last = (max / spacing).floor() as i64;
+    (first..=last).map(|k| k as f64 * spacing).collect()
+}
+
+/// Positions from `start` to `end` no more than `step` apart, both ends included
+fn densify(start: f64, end: f64, step: f64, position: impl Fn(f64) -> Vec<f64>) -> Vec<Vec<f64>> {
+    let segments = ((end - start) / step).ceil().max(1.0) as usize;
+    (0..=segments)
+        .map(|i| position(start + (end - start) * i as f64 / segments as f64))
+        .collect()
+}
+
+fn graticule_line(kind: &str, value: f64, positions: Vec<Vec<f64>>) -> Feature {
+    let mut properties = JsonObject::new();
+    properties.insert("type".to_string(), kind.into());
+    properties.insert("value".to_string(), value.into());
+    Feature {
+        geometry: Some(Geometry::new(Value::LineString(positions))),
+        properties: Some(properties),
+        ..Feature::default()
+    }
+}
+
+/// Generate meridians and parallels and project them into the target CRS
+///
+/// Lines are densified before projecting so they curve correctly in the
+/// target projection. Every feature has a `type` property, `meridian` or
+/// `parallel`, and a `value` property with its longitude or latitude. When
+/// the target cannot represent the poles, as in Web Mercator, latitudes are
+/// clamped to ±85.0511°. Parallels at the poles are left out since they are
+/// single points.
+///
+/// # Arguments
+///
+/// * `spacing_deg` - The spacing between lines, in degrees
+/// * `bbox` - The extent as `[min_lon, min_lat, max_lon, max_lat]`, the whole world when `None`
+/// * `densify_step_deg` - The largest distance between vertices along a line, in degrees
+/// * `config` - A transformer config with a WGS84 source
+///
+/// # Returns
+///
+/// * `Result<FeatureCollection, ProjectionError>` - The projected graticule lines
+///
+/// # Example
+///
+/// ```rust
+/// use proj_exercise_simple::graticule::generate_graticule;
+/// use proj_exercise_simple::transformer::TransformerConfig;
+///
+/// let mut config = TransformerConfig::default();
+/// let graticule = generate_graticule(10.0, Some([0.0, 40.0, 20.0, 60.0]), 1.0, &mut config).unwrap();
+/// // 3 meridians and 3 parallels
+/// assert_eq!(graticule.features.len(), 6);
+/// ```
+pub fn generate_graticule(
+    spacing_deg: f64,
+    bbox: Option<[f64; 4]>,
+    densify_step_deg: f64,
+    config: &mut TransformerConfig,
+) -> Result<FeatureCollection, ProjectionError> {
+    let positive = |value: f64| value.is_finite() && value > 0.0;
+    if !positive(spacing_deg) || !positive(densify_step_deg) {
+        return Err(ProjectionError::InvalidCoordinates(
+            "Graticule spacing and densify step must be positive".to_string(),
+        ));
+    }
+    let [min_lon, mut min_lat, max_lon, mut max_lat] = bbox.unwrap_or([-180.0, -90.0, 180.0, 90.0]);
+    if min_lon > max_lon || min_lat > max_lat {
+        return Err(ProjectionError::InvalidCoordinates(
+            "Graticule bounding box minimum exceeds its maximum".to_string(),
+        ));
+    }
+    let pole = config.get_transformer()?.convert((0.0, 90.0));
+    if !matches!(pole, Ok((x, y)) if f64::is_finite(x) && f64::is_finite(y)) {
+        min_lat = min_lat.max(-MAX_MERCATOR_LATITUDE);
+        max_lat = max_lat.min(MAX_MERCATOR_LATITUDE);
+    }
+
+    let meridians = multiples(spacing_deg, min_lon, max_lon)
+        .into_iter()
+        .map(|lon| {
+            let positions = densify(min_lat, max_lat, densify_step_deg, |lat| vec![lon, lat]);
+            graticule_line("meridian", lon, positions)
+        });
+    let parallels = multiples(spacing_deg, min_lat, max_lat)
+        .into_iter()
+        .filter(|lat| lat.abs() < 90.0)
+        .map(|lat| {
+            let positions = densify(min_lon, max_lon, densify_step_deg, |lon| vec![lon, lat]);
+            graticule_line("parallel", lat, positions)
+        });
+    let collection: FeatureCollection = meridians.chain(parallels).collect();
+
+    let json_value = serde_json::to_value(collection).map_err(geojson::Error::from)?;
+    let projected = process_feature_collection(json_value, config)?;
+    Ok(FeatureCollection::try_from(projected)?)
+}
@@ -0,0 +1,259 @@
+use crate::coordinates::{Coordinate, CoordinateBounds};
+use crate::error::ProjectionError;
+use crate::transformer::TransformerConfig;
+
+/// Project a regular grid of coordinates using interpolation between control points
+///
+/// The grid has `nx` points along x and `ny` points along y, evenly spaced
+/// from the minimum to the maximum of `bounds`. Only every `control_step`-th
+/// point along each axis (and the last row and column) is projected exactly,
+/// the points in between are bilinearly interpolated from the four surrounding
+/// control points.
+///
+/// Accuracy: the interpolation error grows with the square of the control
+/// point spacing and with the curvature of the projection over the grid.
+/// For Web Mercator at mid latitudes, control points 0.1 degrees apart keep
+/// the error at a few meters, while 0.01 degrees keeps it at a few
+/// centimeters. A `control_step` of 1 projects every point exactly.
+///
+/// # Arguments
+///
+/// * `bounds` - The extent of the grid in source CRS units
+/// * `nx` - The number of grid points along x, at least 2
+/// * `ny` - The number of grid points along y, at least 2
+/// * `config` - A transformer config
+/// * `control_step` - The spacing of exactly projected control points, in grid points
+///
+/// # Returns
+///
+/// * `Result<Vec<Vec<(f64, f64)>>, ProjectionError>` - The projected grid, one row per y step
+///
+/// # Example
+///
+/// ```rust
+/// use proj_exercise_simple::coordinates::CoordinateBounds;
+/// use proj_exercise_simple::grid::project_grid;
+/// use proj_exercise_simple::transformer::TransformerConfig;
+///
+/// let bounds = CoordinateBounds::new(13.0, 14.0, 52.0, 53.0);
+/// let config = TransformerConfig::default();
+/// let grid = project_grid(bounds, 11, 11, &config, 5).unwrap();
+/// assert_eq!(grid.len(), 11);
+/// assert_eq!(grid[0].len(), 11);
+/// ```
+pub fn project_grid(
+    bounds: CoordinateBounds,
+    nx: usize,
+    ny: usize,
+    config: &TransformerConfig,
+    control_step: usize,
+) -> Result<Vec<Vec<(f64, f64)>>, ProjectionError> {
+    if nx < 2 || ny < 2 || control_step == 0 {
+        return Err(ProjectionError::InvalidCoordinates(
+            "A grid needs at least 2 points per axis and a positive control step".to_string(),
+        ));
+    }
+    let transformer = config.get_transformer()?;
+    let x_at = |i: usize| bounds.min_x + (bounds.max_x - bounds.min_x) * i as f64 / (nx - 1) as f64;
+    let y_at = |j: usize| bounds.min_y + (bounds.max_y - bounds.min_y) * j as f64 / (ny - 1) as f64;
+
+    // Control point indices along each axis, always including the last point
+    let controls = |n: usize| -> Vec<usize> {
+        let mut indices: Vec<usize> = (0..n).step_by(control_step).collect();
+        if indices.last() != Some(&(n - 1)) {
+            indices.push(n - 1);
+        }
+        indices
+    };
+    let (control_x, control_y) = (controls(nx), controls(ny));
+
+    let mut projected_controls = Vec::with_capacity(control_y.len());
+    for &j in &control_y {
+        let row = control_x
+            .iter()
+            .map(|&i| transformer.convert((x_at(i), y_at(j))))
+            .collect::<Result<Vec<(f64, f64)>, _>>()?;
+        projected_controls.push(row);
+    }
+
+    // For each grid index, the control cell it falls in and its position inside it
+    let cells = |n: usize, control: &[usize]| -> Vec<(usize, f64)> {
+        (0..n)
+            .map(|i| {
+                let cell = control
+                    .windows(2)
+                    .position(|w| i <= w[1])
+                    .unwrap_or(control.len() - 2);
+                let (start, end) = (control[cell], control[cell + 1]);
+                (cell, (i - start) as f64 / (end - start) as f64)
+            })
+            .collect()
+    };
+    let (cells_x, cells_y) = (cells(nx, &control_x), cells(ny, &control_y));
+
+    Ok(cells_y
+        .iter()
+        .map(|&(cy, ty)| {
+            cells_x
+                .iter()
+                .map(|&(cx, tx)| {
+                    let p00 = projected_controls[cy][cx];
+                    let p10 = projected_controls[cy][cx + 1];
+                    let p01 = projected_controls[cy + 1][cx];
+                    let p11 = projected_controls[cy + 1][cx + 1];
+                    let lerp = |a: f64, b: f64, c: f64, d: f64| {
+                        (a * (1.0 - tx) + b * tx) * (1.0 - ty) + (c * (1.0 - tx) + d * tx) * ty
+                    };
+                    (
+                        lerp(p00.0, p10.0, p01.0, p11.0),
+                        lerp(p00.1, p10.1, p01.1, p11.1),
+                    )
+                })
+                .collect()
+        })
+        .collect())
+}
+
+/// Project every point of a regular grid exactly, row by row
+///
+/// Unlike `project_grid`, nothing is interpolated. Points are generated one
+/// row at a time and projected in a single batch per row, so no GeoJSON is
+/// built and only one row of source coordinates is held at once.
+///
+/// # Arguments
+///
+/// * `origin` - The first grid point, in source CRS units
+/// * `dx` - The spacing between columns
+/// * `dy` - The spacing between rows
+/// * `cols` - The number of points per row
+/// * `rows` - The number of rows
+/// * `config` - A transformer config
+///
+/// # Returns
+///
+/// * `Result<Vec<Coordinate>, ProjectionError>` - The projected points in row-major order
+///
+/// # Example
+///
+/// ```rust
+/// use proj_exercise_simple::coordinates::Coordinate;
+/// use proj_exercise_simple::grid::project_regular_grid;
+/// use proj_exercise_simple::transformer::TransformerConfig;
+///
+/// let config = TransformerConfig::default();
+/// let grid = project_regular_grid(Coordinate::new(13.0, 52.0), 0.5, 0.5, 3, 2, &config).unwrap();
+/// assert_eq!(grid.len(), 6);
+/// ```
+pub fn project_regular_grid(
+    origin: Coordinate,
+    dx: f64,
+    dy: f64,
+    cols: usize,
+    rows: usize,
+    config: &TransformerConfig,
+) -> Result<Vec<Coordinate>, ProjectionError> {
+    let mut output = Vec::new();
+    project_regular_grid_into(origin, dx, dy, cols, rows, config, &mut output)?;
+    Ok(output)
+}
+
+/// Project every point of a regular grid into a caller-provided buffer
+///
+/// The buffer is cleared first, its capacity is reused. See
+/// `project_regular_grid` for the layout of the output.
+///
+/// # Arguments
+///
+/// * `origin` - The first grid point, in source CRS units
+/// * `dx` - The spacing between columns
+/// * `dy` - The spacing between rows
+/// * `cols` - The number of points per row
+/// * `rows` - The number of rows
+/// * `config` - A transformer config
+/// * `output` - The buffer receiving the projected points in row-major order
+///
+/// # Returns
+///
+/// * `Result<(), ProjectionError>` - An error if a point cannot be projected
+pub fn project_regular_grid_into(
+    origin: Coordinate,
+    dx: f64,
+    dy: f64,
+    cols: usize,
+    rows: usize,
+    config: &TransformerConfig,
+    output: &mut Vec<Coordinate>,
+) -> Result<(), ProjectionError> {
+    output.clear();
+    output.reserve(cols * rows);
+    let transformer = config.get_transformer()?;
+    let mut row = Vec::with_capacity(cols);
+    for j in 0..rows {
+        let y = origin.y + dy * j as f64;
+        row.clear();
+        row.extend((0..cols).map(|i| (origin.x + dx * i as f64, y)));
+        transformer.convert_array(&mut row)?;
+        output.extend(row.iter().map(|&(x, y)| Coordinate::new(x, y)));
+    }
+    Ok(())
+}
+
+/// Project only the outline of a regular grid, for drawing graticules and extents
+///
+/// The outline runs counter-clockwise from `origin` along the first row,
+/// up the last column, back along the last row and down the first column,
+/// and is closed. The grid needs at least 2 points per axis.
+///
+/// # Arguments
+///
+/// * `origin` - The first grid point, in source CRS units
+/// * `dx` - The spacing between columns
+/// * `dy` - The spacing between rows
+/// * `cols` - The number of points per row
+/// * `rows` - The number of rows
+/// * `config` - A transformer config
+///
+/// # Returns
+///
+/// * `Result<Vec<Coordinate>, ProjectionError>` - The projected outline, `2 * (cols + rows) - 3` points
+///
+/// # Example
+///
+/// ```rust
+/// use proj_exercise_simple::coordinates::Coordinate;
+/// use proj_exercise_simple::grid::project_regular_grid_edge;
+/// use proj_exercise_simple::transformer::TransformerConfig;
+///
+/// let config = TransformerConfig::default();
+/// let edge = project_regular_grid_edge(Coordinate::new(13.0, 52.0), 0.5, 0.5, 3, 3, &config).unwrap();
+/// assert_eq!(edge.len(), 9);
+/// assert_eq!(edge.first(), edge.last());
+/// ```
+pub fn project_regular_grid_edge(
+    origin: Coordinate,
+    dx: f64,
+    dy: f64,
+    cols: usize,
+    rows: usize,
+    config: &TransformerConfig,
+) -> Result<Vec<Coordinate>, ProjectionError> {
+    if cols < 2 || rows < 2 {
+        return Err(ProjectionError::InvalidCoordinates(
+            "A grid edge needs at least 2 points per axis".to_string(),
+        ));
+    }
+    let point = |i: usize, j: usize| (origin.x + dx * i as f64, origin.y + dy * j as f64);
+    let (last_col, last_row) = (cols - 1, rows - 1);
+    let mut edge: Vec<(f64, f64)> = (0..last_col)
+        .map(|i| point(i, 0))
+        .chain((0..last_row).map(|j| point(last_col, j)))
+        .chain((1..=last_col).rev().map(|i| point(i, last_row)))
+        .chain((1..=last_row).rev().map(|j| point(0, j)))
+        .chain(std::iter::once(point(0, 0)))
+        .collect();
+    config.get_transformer()?.convert_array(&mut edge)?;
+    Ok(edge
+        .into_iter()
+        .map(|(x, y)| Coordinate::new(x, y))
+        .collect())
+}
@@ -1,14 +1,40 @@
+use crate::axis_order::correct_axis_order;
+use crate::coerce::coerce_geometry;
 use crate::coordinates::{Line, Polygon};
-use crate::geometry_processor::GeometryProcessor;
+use crate::densify::densify_geojson;
+use crate::digest::attach_geometry_hashes;
+use crate::distortion::attach_distortion;
+use crate::explode::{explode_geometry, part_feature};
+use crate::geometry_processor::{Deadline, GeometryProcessor};
+use crate::measure::attach_measures;
+use crate::normalize::normalize_geojson_to_extent;
+use crate::options::{
+    ErrorPolicy, FeatureContext, NullGeometryPolicy, ProcessingOptions, ValidationStrategy,
+};
+use crate::order::{dedupe_features, sort_features, DEFAULT_HASH_PRECISION};
+use crate::plan::{plan_geojson, PlannedAction};
 use crate::pool::CoordinateBufferPool;
+use crate::self_intersection::check_self_intersections;
+use crate::spatial_index::SpatialIndex;
+use crate::stable::stabilize;
 use crate::transformer::TransformerConfig;
-use crate::{coordinates::Coordinate, error::ProjectionError};
+use crate::validation::validate_rfc7946;
+use crate::vertices::{extra_ordinates, for_each_feature_position, restore_extra_ordinates};
+use crate::{
+    coordinates::{Coordinate, CoordinateBounds},
+    error::ProjectionError,
+};
 
 use geo::{
-    CoordsIter, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon, Point,
-    Polygon as GeoPolygon,
+    CoordsIter, GeometryCollection, LineString, MapCoordsInPlace, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon as GeoPolygon,
 };
+use geojson::feature::Id;
 use geojson::{Feature, Geometry};
+use std::collections::{BTreeSet, HashMap};
+
+/// The result of projecting a GeoJSON object
+pub type ProjectionResult = Result<geojson::GeoJson, ProjectionError>;
 
 #[derive(Debug)]
 pub enum ProcessedGeometry {
@@ -21,7 +47,127 @@ pub enum ProcessedGeometry {
     GeometryCollection(GeometryCollection<f64>),
 }
 
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<ProcessedGeometry>();
+};
+
 impl ProcessedGeometry {
+    /// Convert a geo geometry into a processed geometry
+    ///
+    /// Lines become line strings, rectangles and triangles become polygons.
+    pub(crate) fn from_geo(geometry: geo::Geometry<f64>) -> Self {
+        match geometry {
+            geo::Geometry::Point(g) => ProcessedGeometry::Point(g),
+            geo::Geometry::Line(g) => ProcessedGeometry::LineString(g.into()),
+            geo::Geometry::LineString(g) => ProcessedGeometry::LineString(g),
+            geo::Geometry::Polygon(g) => ProcessedGeometry::Polygon(g),
+            geo::Geometry::MultiPoint(g) => ProcessedGeometry::MultiPoint(g),
+            geo::Geometry::MultiLineString(g) => ProcessedGeometry::MultiLineString(g),
+            geo::Geometry::MultiPolygon(g) => ProcessedGeometry::MultiPolygon(g),
+            geo::Geometry::GeometryCollection(g) => ProcessedGeometry::GeometryCollection(g),
+            geo::Geometry::Rect(g) => ProcessedGeometry::Polygon(g.to_polygon()),
+            geo::Geometry::Triangle(g) => ProcessedGeometry::Polygon(g.to_polygon()),
+        }
+    }
+
+    /// The number of coordinates, including closing ring coordinates
+    #[cfg(feature = "tracing")]
+    pub(crate) fn coords_count(&self) -> usize {
+        match self {
+            ProcessedGeometry::Point(g) => g.coords_count(),
+            ProcessedGeometry::LineString(g) => g.coords_count(),
+            ProcessedGeometry::Polygon(g) => g.coords_count(),
+            ProcessedGeometry::MultiPoint(g) => g.coords_count(),
+            ProcessedGeometry::MultiLineString(g) => g.coords_count(),
+            ProcessedGeometry::MultiPolygon(g) => g.coords_count(),
+            ProcessedGeometry::GeometryCollection(g) => g.coords_count(),
+        }
+    }
+
+    /// Convert a processed geometry back into a geo geometry
+    pub(crate) fn into_geo(self) -> geo::Geometry<f64> {
+        match self {
+            ProcessedGeometry::Point(g) => geo::Geometry::Point(g),
+            ProcessedGeometry::LineString(g) => geo::Geometry::LineString(g),
+            ProcessedGeometry::Polygon(g) => geo::Geometry::Polygon(g),
+            ProcessedGeometry::MultiPoint(g) => geo::Geometry::MultiPoint(g),
+            ProcessedGeometry::MultiLineString(g) => geo::Geometry::MultiLineString(g),
+            ProcessedGeometry::MultiPolygon(g) => geo::Geometry::MultiPolygon(g),
+            ProcessedGeometry::GeometryCollection(g) => geo::Geometry::GeometryCollection(g),
+        }
+    }
+
+    /// Apply a function to every coordinate of the processed geometry in place
+    ///
+    /// # Arguments
+    ///
+    /// * `func` - The function mapping each coordinate
+    pub fn map_coords_in_place(&mut self, func: impl Fn(geo::Coord) -> geo::Coord + Copy) {
+        match self {
+            ProcessedGeometry::Point(g) => g.map_coords_in_place(func),
+            ProcessedGeometry::LineString(g) => g.map_coords_in_place(func),
+            ProcessedGeometry::Polygon(g) => g.map_coords_in_place(func),
+            ProcessedGeometry::MultiPoint(g) => g.map_coords_in_place(func),
+            ProcessedGeometry::MultiLineString(g) => g.map_coords_in_place(func),
+            ProcessedGeometry::MultiPolygon(g) => g.map_coords_in_place(func),
+            ProcessedGeometry::GeometryCollection(g) => g.map_coords_in_place(func),
+        }
+    }
+
+    /// Remove interior rings that enclose no area
+    ///
+    /// A hole is degenerate when it has fewer than 3 distinct consecutive
+    /// positions, as happens when simplification or rounding collapses it.
+    /// The remaining holes keep their order. This is the one place holes are
+    /// dropped after projection, so every path reports them the same way.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<(usize, usize)>` - The polygon and hole index of every removed hole, with polygons counted in document order
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use geo::polygon;
+    /// use proj_exercise_simple::helpers::ProcessedGeometry;
+    ///
+    /// let mut geometry = ProcessedGeometry::Polygon(polygon!(
+    ///     exterior: [(x: 0.0, y: 0.0), (x: 10.0, y: 0.0), (x: 10.0, y: 10.0), (x: 0.0, y: 10.0)],
+    ///     interiors: [
+    ///         [(x: 1.0, y: 1.0), (x: 2.0, y: 1.0), (x: 1.0, y: 1.0)],
+    ///         [(x: 5.0, y: 5.0), (x: 6.0, y: 5.0), (x: 6.0, y: 6.0)],
+    ///     ],
+    /// ));
+    /// assert_eq!(geometry.remove_degenerate_holes(), vec![(0, 0)]);
+    /// ```
+    pub fn remove_degenerate_holes(&mut self) -> Vec<(usize, usize)> {
+        let mut removed = Vec::new();
+        let mut polygon_index = 0;
+        match self {
+            ProcessedGeometry::Polygon(polygon) => {
+                retain_holes(polygon, &mut polygon_index, &mut removed)
+            }
+            ProcessedGeometry::MultiPolygon(polygons) => polygons
+                .iter_mut()
+                .for_each(|polygon| retain_holes(polygon, &mut polygon_index, &mut removed)),
+            ProcessedGeometry::GeometryCollection(collection) => collection
+                .iter_mut()
+                .for_each(|member| retain_member_holes(member, &mut polygon_index, &mut removed)),
+            _ => {}
+        }
+        removed
+    }
+
+    /// Convert a processed geometry to a geojson value
+    ///
+    /// # Returns
+    ///
+    /// * `geojson::Value` - A geojson geometry value
+    pub fn to_geojson_value(self) -> geojson::Value {
+        self.to_geojson_geometry().value
+    }
+
     /// Convert a processed geometry to a geojson geometry
     ///
     /// # Returns
@@ -29,17 +175,14 @@ impl ProcessedGeometry {
     /// * `geojson::Geometry` - A geojson geometry
     pub fn to_geojson_geometry(self) -> geojson::Geometry {
         match self {
-            ProcessedGeometry::Point(point) => {
-                let coord = Coordinate::from(point);
-                geojson::Geometry::new(geojson::Value::Point(vec![coord.x, coord.y]))
-            }
+            ProcessedGeometry::Point(point) => Coordinate::from(point).to_geojson_geometry(),
             ProcessedGeometry::LineString(line_string) => {
                 let coords: Vec<Coordinate> = line_string
                     .coords_iter()
                     .map(|coord| Coordinate::new(coord.x, coord.y))
                     .collect();
                 let line = Line::new(coords);
-                geojson::Geometry::new(line.to_geojson())
+                line.to_geojson_geometry()
             }
             ProcessedGeometry::Polygon(polygon) => {
                 let exterior = Line::new(
@@ -61,7 +204,7 @@ impl ProcessedGeometry {
                     })
                     .collect();
                 let polygon = Polygon::new(exterior, interiors);
-                geojson::Geometry::new(polygon.to_geojson())
+                polygon.to_geojson_geometry()
             }
             ProcessedGeometry::MultiPoint(multi_point) => {
                 let coords = multi_point.iter().map(|p| vec![p.x(), p.y()]).collect();
@@ -98,112 +241,11 @@ impl ProcessedGeometry {
                 geojson::Geometry::new(geojson::Value::MultiPolygon(polygons))
             }
             ProcessedGeometry::GeometryCollection(collection) => {
+                // Members are converted like top-level geometries, so lines,
+                // rectangles and triangles share the canonical conversions
                 let geometries = collection
-                    .iter()
-                    .map(|geom| match geom {
-                        geo::Geometry::Point(p) => {
-                            let coord = Coordinate::from(*p);
-                            geojson::Geometry::new(geojson::Value::Point(vec![coord.x, coord.y]))
-                        }
-                        geo::Geometry::LineString(ls) => {
-                            let coords: Vec<Coordinate> = ls
-                                .coords_iter()
-                                .map(|coord| Coordinate::new(coord.x, coord.y))
-                                .collect();
-                            let line = Line::new(coords);
-                            geojson::Geometry::new(line.to_geojson())
-                        }
-                        geo::Geometry::Polygon(poly) => {
-                            let exterior = Line::new(
-                                poly.exterior()
-                                    .coords_iter()
-                                    .map(|coord| Coordinate::new(coord.x, coord.y))
-                                    .collect(),
-                            );
-                            let interiors = poly
-                                .interiors()
-                                .iter()
-                                .map(|ring| {
-                                    Line::new(
-                                        ring.coords_iter()
-                                            .map(|coord| Coordinate::new(coord.x, coord.y))
-                                            .collect(),
-                                    )
-                                })
-                                .collect();
-                            let polygon = Polygon::new(exterior, interiors);
-                            geojson::Geometry::new(polygon.to_geojson())
-                        }
-                        geo::Geometry::MultiPoint(mp) => {
-                            let coords = mp.iter().map(|p| vec![p.x(), p.y()]).collect();
-                            geojson::Geometry::new(geojson::Value::MultiPoint(coords))
-                        }
-                        geo::Geometry::MultiLineString(mls) => {
-                            let lines = mls
-                                .iter()
-                                .map(|ls| {
-                                    ls.coords_iter()
-                                        .map(|coord| vec![coord.x, coord.y])
-                                        .collect()
-                                })
-                                .collect();
-                            geojson::Geometry::new(geojson::Value::MultiLineString(lines))
-                        }
-                        geo::Geometry::MultiPolygon(mp) => {
-                            let polygons = mp
-                                .iter()
-                                .map(|poly| {
-                                    let mut rings = vec![poly
-                                        .exterior()
-                                        .coords_iter()
-                                        .map(|coord| vec![coord.x, coord.y])
-                                        .collect()];
-                                    rings.extend(poly.interiors().iter().map(|ring| {
-                                        ring.coords_iter()
-                                            .map(|coord| vec![coord.x, coord.y])
-                                            .collect()
-                                    }));
-                                    rings
-                                })
-                                .collect();
-                            geojson::Geometry::new(geojson::Value::MultiPolygon(polygons))
-                        }
-                        geo::Geometry::GeometryCollection(_) => {
-                            // Nested geometry collections are not supported in GeoJSON
-                            panic!("Nested geometry collections are not supported")
-                        }
-                        geo::Geometry::Line(line) => {
-                            let coords: Vec<Coordinate> = vec![
-                                Coordinate::new(line.start.x, line.start.y),
-                                Coordinate::new(line.end.x, line.end.y),
-                            ];
-                            let line = Line::new(coords);
-                            geojson::Geometry::new(line.to_geojson())
-                        }
-                        geo::Geometry::Rect(rect) => {
-                            let coords: Vec<Coordinate> = vec![
-                                Coordinate::new(rect.min().x, rect.min().y),
-                                Coordinate::new(rect.max().x, rect.min().y),
-                                Coordinate::new(rect.max().x, rect.max().y),
-                                Coordinate::new(rect.min().x, rect.max().y),
-                                Coordinate::new(rect.min().x, rect.min().y), // Close the polygon
-                            ];
-                            let line = Line::new(coords);
-                            let polygon = Polygon::new(line, vec![]);
-                            geojson::Geometry::new(polygon.to_geojson())
-                        }
-                        geo::Geometry::Triangle(triangle) => {
-                            let coords: Vec<Coordinate> = vec![
-                                Coordinate::new(triangle.0.x, triangle.0.y),
-                                Coordinate::new(triangle.1.x, triangle.1.y),
-                                Coordinate::new(triangle.2.x, triangle.2.y),
-                                Coordinate::new(triangle.0.x, triangle.0.y), // Close the polygon
-                            ];
-                            let line = Line::new(coords);
-                            let polygon = Polygon::new(line, vec![]);
-                            geojson::Geometry::new(polygon.to_geojson())
-                        }
-                    })
+                    .into_iter()
+                    .map(|geom| ProcessedGeometry::from_geo(geom).to_geojson_geometry())
                     .collect();
                 geojson::Geometry::new(geojson::Value::GeometryCollection(geometries))
             }
@@ -211,6 +253,67 @@ impl ProcessedGeometry {
     }
 }
 
+/// Whether a ring has fewer than 3 distinct consecutive positions
+fn is_degenerate_ring(ring: &LineString<f64>) -> bool {
+    let mut distinct = 0;
+    let mut previous = None;
+    for coord in &ring.0 {
+        if previous != Some(coord) {
+            distinct += 1;
+            previous = Some(coord);
+        }
+    }
+    if distinct > 1 && ring.0.first() == ring.0.last() {
+        distinct -= 1;
+    }
+    distinct < 3
+}
+
+/// Drop the degenerate holes of a polygon, recording their indices
+fn retain_holes(
+    polygon: &mut GeoPolygon<f64>,
+    polygon_index: &mut usize,
+    removed: &mut Vec<(usize, usize)>,
+) {
+    let index = *polygon_index;
+    *polygon_index += 1;
+    if !polygon.interiors().iter().any(is_degenerate_ring) {
+        return;
+    }
+    let empty = GeoPolygon::new(LineString::new(Vec::new()), Vec::new());
+    let (exterior, interiors) = std::mem::replace(polygon, empty).into_inner();
+    let interiors = interiors
+        .into_iter()
+        .enumerate()
+        .filter_map(|(hole, ring)| {
+            if is_degenerate_ring(&ring) {
+                removed.push((index, hole));
+                None
+            } else {
+                Some(ring)
+            }
+        })
+        .collect();
+    *polygon = GeoPolygon::new(exterior, interiors);
+}
+
+fn retain_member_holes(
+    geometry: &mut geo::Geometry<f64>,
+    polygon_index: &mut usize,
+    removed: &mut Vec<(usize, usize)>,
+) {
+    match geometry {
+        geo::Geometry::Polygon(polygon) => retain_holes(polygon, polygon_index, removed),
+        geo::Geometry::MultiPolygon(polygons) => polygons
+            .iter_mut()
+            .for_each(|polygon| retain_holes(polygon, polygon_index, removed)),
+        geo::Geometry::GeometryCollection(collection) => collection
+            .iter_mut()
+            .for_each(|member| retain_member_holes(member, polygon_index, removed)),
+        _ => {}
+    }
+}
+
 #[allow(dead_code)]
 fn convert_multi_line_string(
     lines: Vec<Line>,
@@ -297,7 +400,7 @@ fn convert_point(
     let transformer = config.get_transformer()?;
     let geo_point = Point::new(point.x, point.y);
     let projected = transformer.convert(geo_point)?;
-    Ok(ProcessedGeometry::Point(projected.into()))
+    Ok(ProcessedGeometry::Point(projected))
 }
 
 /// Convert a line string
@@ -432,6 +535,10 @@ fn convert_multi_polygon(
 ///
 /// * `feature` - A feature with a geometry
 /// * `config` - A transformer config
+/// * `bounds` - The valid input coordinate range
+/// * `options` - Processing options, for the ring closure and the post-transform hook
+/// * `deadline` - When projecting the feature times out, if ever
+/// * `context` - The feature as the post-transform hook sees it
 ///
 /// # Returns
 ///
@@ -440,20 +547,61 @@ fn process_feature_geometry(
     feature: Feature,
     config: &mut TransformerConfig,
     buffer_pool: &mut CoordinateBufferPool,
+    bounds: CoordinateBounds,
+    options: &ProcessingOptions,
+    deadline: Option<Deadline>,
+    context: FeatureContext,
 ) -> Result<ProcessedGeometry, ProjectionError> {
     if let Some(geometry) = feature.geometry {
-        process_geometry(geometry, config, buffer_pool)
+        process_geometry(
+            geometry,
+            config,
+            buffer_pool,
+            bounds,
+            options,
+            deadline,
+            context,
+        )
     } else {
         Err(ProjectionError::InvalidGeometryType)
     }
 }
 
+/// The simplification epsilon of a feature, chosen by `options.feature_simplification` or the config default
+fn feature_epsilon(
+    feature: &Feature,
+    config: &TransformerConfig,
+    options: &ProcessingOptions,
+) -> Option<f64> {
+    options
+        .feature_simplification
+        .as_ref()
+        .and_then(|selector| selector.select(feature))
+        .or(config.simplification_epsilon)
+}
+
+/// Run `process` with the simplification epsilon of the config set to `epsilon`, restoring it afterwards
+fn with_epsilon<T>(
+    config: &mut TransformerConfig,
+    epsilon: Option<f64>,
+    process: impl FnOnce(&mut TransformerConfig) -> T,
+) -> T {
+    let default = std::mem::replace(&mut config.simplification_epsilon, epsilon);
+    let result = process(config);
+    config.simplification_epsilon = default;
+    result
+}
+
 /// Process a geometry
 ///
 /// # Arguments
 ///
 /// * `geometry` - A geometry
 /// * `config` - A transformer config
+/// * `bounds` - The valid input coordinate range
+/// * `options` - Processing options, for the ring closure and the post-transform hook
+/// * `deadline` - When projecting the geometry times out, if ever
+/// * `context` - The feature as the post-transform hook sees it
 ///
 /// # Returns
 ///
@@ -462,11 +610,28 @@ fn process_geometry(
     geometry: Geometry,
     config: &mut TransformerConfig,
     buffer_pool: &mut CoordinateBufferPool,
+    bounds: CoordinateBounds,
+    options: &ProcessingOptions,
+    deadline: Option<Deadline>,
+    context: FeatureContext,
 ) -> Result<ProcessedGeometry, ProjectionError> {
-    let mut processor = GeometryProcessor::new(&geometry, config);
+    let mut processor = GeometryProcessor::new(&geometry, config)
+        .with_coordinate_bounds(bounds)
+        .with_ring_closure(options.ring_closure)
+        .with_deadline(deadline)
+        .with_post_transform_hook(options.post_transform_hook.as_ref(), context);
     processor.process(buffer_pool)
 }
 
+/// The feature a post-transform hook is called for, from its template
+fn feature_context(template: &Feature, feature_index: usize) -> FeatureContext<'_> {
+    FeatureContext {
+        feature_index,
+        id: template.id.as_ref(),
+        properties: template.properties.as_ref(),
+    }
+}
+
 /// Process a feature collection
 ///
 /// # Arguments
@@ -478,44 +643,904 @@ fn process_geometry(
 /// * `geojson::GeoJson` - A processed feature collection
 pub fn process_feature_collection(
     json_value: serde_json::Value,
-    mut config: &mut TransformerConfig,
-) -> Result<geojson::GeoJson, ProjectionError> {
+    config: &mut TransformerConfig,
+) -> ProjectionResult {
+    let geojson = geojson::GeoJson::from_json_value(json_value)?;
+    process_geojson(
+        geojson,
+        config,
+        &ProcessingOptions::default(),
+        &mut Vec::new(),
+    )
+}
+
+/// Project the same input into several target CRS
+///
+/// The input is parsed once and projected once per distinct target, from
+/// WGS84 (EPSG:4326) as RFC 7946 requires of GeoJSON. Default processing
+/// options apply.
+///
+/// # Arguments
+///
+/// * `json_value` - A JSON value in WGS84
+/// * `targets` - The target CRS, such as `EPSG:3857`
+///
+/// # Returns
+///
+/// * `Result<HashMap<String, geojson::GeoJson>, ProjectionError>` - The output per target, or the first error
+///
+/// # Example
+///
+/// ```rust
+/// use proj_exercise_simple::helpers::process_to_multiple;
+///
+/// let input = serde_json::json!({ "type": "Point", "coordinates": [13.4, 52.5] });
+/// let outputs =
+///     process_to_multiple(input, vec!["EPSG:3857".to_string(), "EPSG:4326".to_string()]).unwrap();
+/// assert_eq!(outputs.len(), 2);
+/// ```
+pub fn process_to_multiple(
+    json_value: serde_json::Value,
+    targets: Vec<String>,
+) -> Result<HashMap<String, geojson::GeoJson>, ProjectionError> {
     let geojson = geojson::GeoJson::from_json_value(json_value)?;
-    let mut buffer_pool = CoordinateBufferPool::new(10, 100);
+    let mut outputs = HashMap::with_capacity(targets.len());
+    for target in targets {
+        if outputs.contains_key(&target) {
+            continue;
+        }
+        let mut config = TransformerConfig::new("EPSG:4326".to_string(), target.clone())?;
+        let output = process_geojson(
+            geojson.clone(),
+            &mut config,
+            &ProcessingOptions::default(),
+            &mut Vec::new(),
+        )?;
+        outputs.insert(target, output);
+    }
+    Ok(outputs)
+}
+
+/// Project GeoJSON embedded in a larger JSON document
+///
+/// The value at `pointer` is projected like `process_feature_collection`
+/// and the document is returned with that value replaced. The rest of the
+/// document is left as it was.
+///
+/// # Arguments
+///
+/// * `doc` - A JSON document holding GeoJSON
+/// * `pointer` - A JSON Pointer (RFC 6901) to the GeoJSON, such as `/data/geo`
+/// * `config` - A transformer config
+///
+/// # Returns
+///
+/// * `Result<serde_json::Value, ProjectionError>` - The document with the GeoJSON projected
+///
+/// # Example
+///
+/// ```rust
+/// use proj_exercise_simple::helpers::process_at_pointer;
+/// use proj_exercise_simple::transformer::TransformerConfig;
+/// use serde_json::json;
+///
+/// let doc = json!({
+///     "name": "stops",
+///     "data": { "geo": { "type": "Point", "coordinates": [1.0, 2.0] } }
+/// });
+/// let projected = process_at_pointer(&doc, "/data/geo", &mut TransformerConfig::default()).unwrap();
+/// assert_eq!(projected["name"], "stops");
+/// assert_ne!(projected["data"]["geo"], doc["data"]["geo"]);
+/// ```
+pub fn process_at_pointer(
+    doc: &serde_json::Value,
+    pointer: &str,
+    config: &mut TransformerConfig,
+) -> Result<serde_json::Value, ProjectionError> {
+    let mut output = doc.clone();
+    let target = output
+        .pointer_mut(pointer)
+        .ok_or_else(|| ProjectionError::PointerNotFound(pointer.to_string()))?;
+    let projected = process_feature_collection(target.take(), config)?;
+    *target = serde_json::Value::from(projected);
+    Ok(output)
+}
+
+/// Process a contiguous range of the features of a feature collection
+///
+/// Only features `start..end` are projected and returned, so a large
+/// collection can be split across workers. Default processing options apply.
+///
+/// # Arguments
+///
+/// * `json_value` - A JSON value holding a feature collection
+/// * `start` - The index of the first feature to process
+/// * `end` - The index after the last feature to process
+/// * `config` - A transformer config
+///
+/// # Returns
+///
+/// * `ProjectionResult` - A feature collection of the processed range
+///
+/// # Example
+///
+/// ```rust
+/// use geojson::{Feature, FeatureCollection, Geometry, Value};
+/// use proj_exercise_simple::helpers::process_feature_range;
+/// use proj_exercise_simple::transformer::TransformerConfig;
+///
+/// let collection: FeatureCollection = (0..3)
+///     .map(|i| Feature::from(Geometry::new(Value::Point(vec![i as f64, 0.0]))))
+///     .collect();
+/// let json = serde_json::to_value(collection).unwrap();
+/// let output = process_feature_range(json, 1, 3, &mut TransformerConfig::default()).unwrap();
+/// ```
+pub fn process_feature_range(
+    json_value: serde_json::Value,
+    start: usize,
+    end: usize,
+    config: &mut TransformerConfig,
+) -> ProjectionResult {
+    let mut collection = geojson::FeatureCollection::try_from(json_value)?;
+    let feature_count = collection.features.len();
+    if start > end || end > feature_count {
+        return Err(ProjectionError::InvalidFeatureRange {
+            start,
+            end,
+            feature_count,
+        });
+    }
+    let features = collection.features.drain(start..end).collect();
+    process_geojson(
+        output_collection(features),
+        config,
+        &ProcessingOptions::default(),
+        &mut Vec::new(),
+    )
+}
+
+/// Process a GeoJSON object in place, replacing every geometry with its projection
+///
+/// Unlike `process_feature_collection`, no second copy of the input is built:
+/// each geometry is projected and written back into its feature, so peak
+/// memory grows by one geometry instead of by the whole collection. Default
+/// processing options apply. Ids, properties and foreign members are left
+/// untouched, bounding boxes are removed because they no longer match the
+/// coordinates. If processing fails, the features before the failing one are
+/// already projected.
+///
+/// # Arguments
+///
+/// * `geojson` - The GeoJSON object to project
+/// * `config` - A transformer config
+///
+/// # Returns
+///
+/// * `Result<(), ProjectionError>` - An error for the first feature that cannot be processed
+///
+/// # Example
+///
+/// ```rust
+/// use geojson::{GeoJson, Geometry, Value};
+/// use proj_exercise_simple::helpers::process_feature_collection_in_place;
+/// use proj_exercise_simple::transformer::TransformerConfig;
+///
+/// let mut geojson = GeoJson::Geometry(Geometry::new(Value::Point(vec![1.0, 2.0])));
+/// process_feature_collection_in_place(&mut geojson, &mut TransformerConfig::default()).unwrap();
+/// ```
+pub fn process_feature_collection_in_place(
+    geojson: &mut geojson::GeoJson,
+    config: &mut TransformerConfig,
+) -> Result<(), ProjectionError> {
+    // Nothing to project, so don't build a pool or a transformer
+    if matches!(&geojson, geojson::GeoJson::FeatureCollection(c) if c.features.is_empty()) {
+        return Ok(());
+    }
+    let mut buffer_pool = CoordinateBufferPool::default();
+    let bounds = resolve_bounds(config, &ProcessingOptions::default())?;
     match geojson {
+        geojson::GeoJson::FeatureCollection(collection) => {
+            collection.bbox = None;
+            for feature in &mut collection.features {
+                project_feature_in_place(feature, config, &mut buffer_pool, bounds)?;
+            }
+        }
         geojson::GeoJson::Feature(feature) => {
-            let geometry = process_feature_geometry(feature, &mut config, &mut buffer_pool)?;
-            Ok(geojson::GeoJson::Feature(geojson::Feature {
-                bbox: None,
-                geometry: Some(geometry.to_geojson_geometry()),
-                id: None,
-                properties: None,
-                foreign_members: None,
-            }))
+            project_feature_in_place(feature, config, &mut buffer_pool, bounds)?;
+        }
+        geojson::GeoJson::Geometry(geometry) => {
+            project_geometry_in_place(geometry, config, &mut buffer_pool, bounds)?;
+        }
+    }
+    Ok(())
+}
+
+/// Replace the geometry of a feature with its projection
+fn project_feature_in_place(
+    feature: &mut Feature,
+    config: &mut TransformerConfig,
+    buffer_pool: &mut CoordinateBufferPool,
+    bounds: CoordinateBounds,
+) -> Result<(), ProjectionError> {
+    let geometry = feature
+        .geometry
+        .as_mut()
+        .ok_or(ProjectionError::InvalidGeometryType)?;
+    project_geometry_in_place(geometry, config, buffer_pool, bounds)?;
+    feature.bbox = None;
+    Ok(())
+}
+
+/// Replace the value of a geometry with its projection, keeping Z and M values
+fn project_geometry_in_place(
+    geometry: &mut Geometry,
+    config: &mut TransformerConfig,
+    buffer_pool: &mut CoordinateBufferPool,
+    bounds: CoordinateBounds,
+) -> Result<(), ProjectionError> {
+    let extras = extra_ordinates(&geometry.value);
+    let mut processed = GeometryProcessor::new(geometry, config)
+        .with_coordinate_bounds(bounds)
+        .process(buffer_pool)?;
+    // There is no report to warn in, the removal is the same as elsewhere
+    processed.remove_degenerate_holes();
+    let mut projected = processed.to_geojson_geometry().value;
+    if let Some(extras) = extras {
+        restore_extra_ordinates(&mut [&mut projected], &extras);
+    }
+    geometry.value = projected;
+    geometry.bbox = None;
+    Ok(())
+}
+
+/// Process a parsed GeoJSON object
+///
+/// # Arguments
+///
+/// * `geojson` - A GeoJSON object
+/// * `config` - A transformer config
+///
+/// # Returns
+///
+/// * `geojson::GeoJson` - The processed GeoJSON object
+fn process_geojson(
+    geojson: geojson::GeoJson,
+    config: &mut TransformerConfig,
+    options: &ProcessingOptions,
+    warnings: &mut Vec<ProcessingWarning>,
+) -> ProjectionResult {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::span!(
+        tracing::Level::INFO,
+        "process_feature_collection",
+        feature_count = match &geojson {
+            geojson::GeoJson::FeatureCollection(collection) => collection.features.len(),
+            _ => 1,
+        }
+    )
+    .entered();
+    // Nothing to project, so don't build a pool or a transformer
+    if matches!(&geojson, geojson::GeoJson::FeatureCollection(c) if c.features.is_empty()) {
+        return Ok(output_collection(Vec::new()));
+    }
+    let mut buffer_pool = CoordinateBufferPool::default();
+    let bounds = resolve_bounds(config, options)?;
+    if options.validation == ValidationStrategy::EagerAll {
+        validate_all(&geojson, bounds)?;
+    }
+    match geojson {
+        geojson::GeoJson::Feature(mut feature) => {
+            #[cfg(feature = "tracing")]
+            let _span = tracing::span!(
+                tracing::Level::DEBUG,
+                "feature",
+                feature_index = 0,
+                feature_id = tracing::field::debug(&feature.id)
+            )
+            .entered();
+            if feature.geometry.is_none() {
+                return Ok(
+                    match without_geometry(feature, options.null_geometry, 0, warnings)? {
+                        Some(feature) => geojson::GeoJson::Feature(feature),
+                        None => output_collection(Vec::new()),
+                    },
+                );
+            }
+            let epsilon = feature_epsilon(&feature, config, options);
+            let template = take_template(&mut feature);
+            let extras = feature
+                .geometry
+                .as_ref()
+                .and_then(|g| extra_ordinates(&g.value));
+            let deadline = feature_deadline(options, 0);
+            let geometry = with_epsilon(config, epsilon, |config| {
+                process_feature_geometry(
+                    feature,
+                    config,
+                    &mut buffer_pool,
+                    bounds,
+                    options,
+                    deadline,
+                    feature_context(&template, 0),
+                )
+            })
+            .and_then(|geometry| check_deadline(deadline).map(|()| geometry));
+            let Some(geometry) = skip_failed_feature(geometry, options.error_policy, 0, warnings)?
+            else {
+                return Ok(output_collection(Vec::new()));
+            };
+            let mut features = output_features(&template, geometry, extras, options, 0, warnings)?;
+            if features.len() == 1 {
+                Ok(geojson::GeoJson::Feature(features.remove(0)))
+            } else {
+                Ok(output_collection(features))
+            }
         }
         geojson::GeoJson::FeatureCollection(feature_collection) => {
             let mut features = Vec::with_capacity(feature_collection.features.len());
-            for feature in feature_collection.features {
-                let geometry = process_feature_geometry(feature, &mut config, &mut buffer_pool)?;
-                features.push(geojson::Feature {
-                    bbox: None,
-                    geometry: Some(geometry.to_geojson_geometry()),
-                    id: None,
-                    properties: None,
-                    foreign_members: None,
-                });
+            for (feature_index, mut feature) in feature_collection.features.into_iter().enumerate()
+            {
+                #[cfg(feature = "tracing")]
+                let _span = tracing::span!(
+                    tracing::Level::DEBUG,
+                    "feature",
+                    feature_index,
+                    feature_id = tracing::field::debug(&feature.id)
+                )
+                .entered();
+                if feature.geometry.is_none() {
+                    features.extend(without_geometry(
+                        feature,
+                        options.null_geometry,
+                        feature_index,
+                        warnings,
+                    )?);
+                    continue;
+                }
+                let epsilon = feature_epsilon(&feature, config, options);
+                let template = take_template(&mut feature);
+                let extras = feature
+                    .geometry
+                    .as_ref()
+                    .and_then(|g| extra_ordinates(&g.value));
+                let deadline = feature_deadline(options, feature_index);
+                let geometry = with_epsilon(config, epsilon, |config| {
+                    process_feature_geometry(
+                        feature,
+                        config,
+                        &mut buffer_pool,
+                        bounds,
+                        options,
+                        deadline,
+                        feature_context(&template, feature_index),
+                    )
+                })
+                .and_then(|geometry| check_deadline(deadline).map(|()| geometry));
+                let Some(geometry) =
+                    skip_failed_feature(geometry, options.error_policy, feature_index, warnings)?
+                else {
+                    continue;
+                };
+                features.extend(output_features(
+                    &template,
+                    geometry,
+                    extras,
+                    options,
+                    feature_index,
+                    warnings,
+                )?);
             }
-            Ok(geojson::GeoJson::FeatureCollection(
-                geojson::FeatureCollection {
-                    bbox: None,
-                    features,
-                    foreign_members: None,
-                },
-            ))
+            Ok(output_collection(features))
         }
         geojson::GeoJson::Geometry(geometry) => {
-            let geometry = process_geometry(geometry, &mut config, &mut buffer_pool)?;
-            Ok(geojson::GeoJson::Geometry(geometry.to_geojson_geometry()))
+            let extras = extra_ordinates(&geometry.value);
+            let deadline = feature_deadline(options, 0);
+            let template = geojson::Feature::default();
+            let geometry = process_geometry(
+                geometry,
+                config,
+                &mut buffer_pool,
+                bounds,
+                options,
+                deadline,
+                feature_context(&template, 0),
+            )
+            .and_then(|geometry| check_deadline(deadline).map(|()| geometry));
+            let Some(geometry) = skip_failed_feature(geometry, options.error_policy, 0, warnings)?
+            else {
+                return Ok(output_collection(Vec::new()));
+            };
+            let mut features = output_features(&template, geometry, extras, options, 0, warnings)?;
+            if features.len() == 1 {
+                Ok(geojson::GeoJson::Geometry(
+                    features
+                        .remove(0)
+                        .geometry
+                        .expect("output features have a geometry"),
+                ))
+            } else {
+                Ok(output_collection(features))
+            }
+        }
+    }
+}
+
+/// The valid input range: the configured bounds, else WGS84 for geographic
+/// sources and unbounded otherwise
+pub(crate) fn resolve_bounds(
+    config: &TransformerConfig,
+    options: &ProcessingOptions,
+) -> Result<CoordinateBounds, ProjectionError> {
+    Ok(match options.coordinate_bounds {
+        Some(bounds) => bounds,
+        None if config.source_is_geographic()? => CoordinateBounds::wgs84(),
+        None => CoordinateBounds::unbounded(),
+    })
+}
+
+/// Check the vertex counts of the input against the limits of the options
+///
+/// # Returns
+///
+/// * `Result<(), ProjectionError>` - The first exceeded limit
+pub(crate) fn check_vertex_budget(
+    geojson: &geojson::GeoJson,
+    options: &ProcessingOptions,
+) -> Result<(), ProjectionError> {
+    if options.max_vertices_per_feature.is_none() && options.max_vertices_total.is_none() {
+        return Ok(());
+    }
+    let mut counts: Vec<usize> = Vec::new();
+    for_each_feature_position(geojson, |feature_index, _| {
+        if counts.len() <= feature_index {
+            counts.resize(feature_index + 1, 0);
+        }
+        counts[feature_index] += 1;
+    });
+    if let Some(limit) = options.max_vertices_per_feature {
+        if let Some(&got) = counts.iter().find(|&&count| count > limit) {
+            return Err(ProjectionError::VertexBudgetExceeded { got, limit });
+        }
+    }
+    if let Some(limit) = options.max_vertices_total {
+        let got = counts.iter().sum();
+        if got > limit {
+            return Err(ProjectionError::VertexBudgetExceeded { got, limit });
+        }
+    }
+    Ok(())
+}
+
+/// The deadline of a feature whose processing starts now, if a timeout or a token is configured
+fn feature_deadline(options: &ProcessingOptions, feature_index: usize) -> Option<Deadline<'_>> {
+    Deadline::new(
+        options.feature_timeout,
+        options.cancellation.as_ref(),
+        feature_index,
+    )
+}
+
+/// Fail if the deadline of a feature has passed or its token is cancelled
+fn check_deadline(deadline: Option<Deadline>) -> Result<(), ProjectionError> {
+    deadline.as_ref().map_or(Ok(()), Deadline::check)
+}
+
+/// Drop a feature whose processing failed when the error policy allows it
+///
+/// Under `ErrorPolicy::Lenient` a feature that timed out or whose
+/// post-transform hook failed is dropped and a warning with the error is
+/// recorded. Every other error, and every error
+/// under `ErrorPolicy::Strict`, is returned.
+///
+/// # Returns
+///
+/// * `Result<Option<T>, ProjectionError>` - The result of the feature, `None` when it was dropped
+fn skip_failed_feature<T>(
+    result: Result<T, ProjectionError>,
+    policy: ErrorPolicy,
+    feature_index: usize,
+    warnings: &mut Vec<ProcessingWarning>,
+) -> Result<Option<T>, ProjectionError> {
+    match result {
+        Err(
+            error @ (ProjectionError::Timeout { .. } | ProjectionError::PostTransformHook { .. }),
+        ) if policy == ErrorPolicy::Lenient => {
+            warnings.push(ProcessingWarning::new(
+                feature_index,
+                format!("feature was skipped: {error}"),
+            ));
+            Ok(None)
+        }
+        result => result.map(Some),
+    }
+}
+
+/// Check every input coordinate against the valid range without projecting
+///
+/// # Returns
+///
+/// * `Result<(), ProjectionError>` - The first invalid coordinate, with its feature index
+pub(crate) fn validate_all(
+    geojson: &geojson::GeoJson,
+    bounds: CoordinateBounds,
+) -> Result<(), ProjectionError> {
+    let mut result = Ok(());
+    for_each_feature_position(geojson, |feature_index, position| {
+        if result.is_ok() {
+            result = bounds.validate_position(position).map_err(|e| {
+                #[cfg(feature = "tracing")]
+                tracing::event!(
+                    tracing::Level::WARN,
+                    feature_index,
+                    error = %e,
+                    "validation failed"
+                );
+                match e {
+                    ProjectionError::InvalidCoordinates(message) => {
+                        ProjectionError::InvalidCoordinates(format!(
+                            "{message} in feature {feature_index}"
+                        ))
+                    }
+                    other => other,
+                }
+            });
+        }
+    });
+    result
+}
+
+/// Fail if two features of a collection share an id
+///
+/// # Returns
+///
+/// * `Result<(), ProjectionError>` - Every duplicated id once, in order of first repetition
+pub(crate) fn check_unique_ids(geojson: &geojson::GeoJson) -> Result<(), ProjectionError> {
+    let geojson::GeoJson::FeatureCollection(collection) = geojson else {
+        return Ok(());
+    };
+    let mut seen = BTreeSet::new();
+    let mut duplicates = Vec::new();
+    for id in collection.features.iter().filter_map(|f| f.id.as_ref()) {
+        let key = match id {
+            Id::String(s) => (false, s.clone()),
+            Id::Number(n) => (true, n.to_string()),
+        };
+        if !seen.insert(key.clone()) && !duplicates.contains(&key) {
+            duplicates.push(key);
+        }
+    }
+    if duplicates.is_empty() {
+        Ok(())
+    } else {
+        Err(ProjectionError::DuplicateIds(
+            duplicates.into_iter().map(|(_, id)| id).collect(),
+        ))
+    }
+}
+
+/// Apply the null geometry policy to a feature without a geometry
+///
+/// # Returns
+///
+/// * `Result<Option<Feature>, ProjectionError>` - The feature to emit, if any
+pub(crate) fn without_geometry(
+    feature: Feature,
+    policy: NullGeometryPolicy,
+    feature_index: usize,
+    warnings: &mut Vec<ProcessingWarning>,
+) -> Result<Option<Feature>, ProjectionError> {
+    match policy {
+        NullGeometryPolicy::Error => Err(ProjectionError::InvalidGeometryType),
+        NullGeometryPolicy::PassThrough => Ok(Some(feature)),
+        NullGeometryPolicy::Skip => {
+            warnings.push(ProcessingWarning::new(
+                feature_index,
+                "feature has no geometry and was skipped",
+            ));
+            Ok(None)
         }
     }
 }
+
+/// Move the id, properties and foreign members of a feature into a template
+///
+/// The template carries everything except the geometry over to the output.
+fn take_template(feature: &mut geojson::Feature) -> geojson::Feature {
+    geojson::Feature {
+        bbox: None,
+        geometry: None,
+        id: feature.id.take(),
+        properties: feature.properties.take(),
+        foreign_members: feature.foreign_members.take(),
+    }
+}
+
+/// The output geometries of one feature after the explode and coerce stages
+pub(crate) enum ShapedGeometry {
+    /// The geometry was not exploded and keeps the source feature's identity
+    Whole(ProcessedGeometry),
+    /// The geometry was exploded, each part becomes its own feature
+    Parts(Vec<ProcessedGeometry>),
+}
+
+/// Apply the explode and coerce stages to a geometry
+///
+/// Geometries are exploded into their parts if requested, then every part is
+/// coerced to the requested type family. This works on projected and
+/// unprojected geometries alike, so dry runs can plan with the same logic.
+///
+/// # Arguments
+///
+/// * `geometry` - The geometry
+/// * `options` - Processing options
+/// * `feature_index` - The index of the feature, used in errors
+///
+/// # Returns
+///
+/// * `Result<ShapedGeometry, ProjectionError>` - The output geometries
+pub(crate) fn shape_geometry(
+    geometry: ProcessedGeometry,
+    options: &ProcessingOptions,
+    feature_index: usize,
+) -> Result<ShapedGeometry, ProjectionError> {
+    let coerce = |geometry: ProcessedGeometry| match options.coerce {
+        Some(coerce) => coerce_geometry(geometry, coerce, feature_index),
+        None => Ok(geometry),
+    };
+    match explode_geometry(geometry, options.explode) {
+        Ok(parts) => Ok(ShapedGeometry::Parts(
+            parts.into_iter().map(coerce).collect::<Result<_, _>>()?,
+        )),
+        Err(geometry) => Ok(ShapedGeometry::Whole(coerce(geometry)?)),
+    }
+}
+
+/// Apply the output stages to a processed geometry and build the output features
+///
+/// Unexploded geometries keep the id and properties of the source feature.
+/// Z and M values are not projected; they are copied back onto the output
+/// positions unchanged, or dropped with a warning if simplification removed
+/// vertices.
+///
+/// # Arguments
+///
+/// * `template` - The source feature without its geometry
+/// * `geometry` - The processed geometry
+/// * `extras` - The ordinates beyond x and y of the input positions, if any
+/// * `options` - Processing options
+/// * `feature_index` - The index of the feature, used in errors
+/// * `warnings` - Receives a warning per removed degenerate hole, per self-intersecting ring under `SelfIntersectionPolicy::Warn` and if the extra ordinates could not be restored
+///
+/// # Returns
+///
+/// * `Result<Vec<geojson::Feature>, ProjectionError>` - The output features
+fn output_features(
+    template: &geojson::Feature,
+    geometry: ProcessedGeometry,
+    extras: Option<Vec<Vec<f64>>>,
+    options: &ProcessingOptions,
+    feature_index: usize,
+    warnings: &mut Vec<ProcessingWarning>,
+) -> Result<Vec<geojson::Feature>, ProjectionError> {
+    let mut geometry = geometry;
+    for (polygon, hole) in geometry.remove_degenerate_holes() {
+        warnings.push(ProcessingWarning::new(
+            feature_index,
+            format!("Removed degenerate hole {hole} of polygon {polygon}"),
+        ));
+    }
+    check_self_intersections(
+        &mut geometry,
+        options.self_intersection,
+        feature_index,
+        warnings,
+    )?;
+    let mut features = shaped_features(template, geometry, options, feature_index)?;
+    if let Some(extras) = extras {
+        let mut values: Vec<&mut geojson::Value> = features
+            .iter_mut()
+            .filter_map(|feature| feature.geometry.as_mut())
+            .map(|geometry| &mut geometry.value)
+            .collect();
+        if !restore_extra_ordinates(&mut values, &extras) {
+            warnings.push(ProcessingWarning::new(
+                feature_index,
+                "Z and M values were dropped because processing changed the vertex count",
+            ));
+        }
+    }
+    Ok(features)
+}
+
+/// Build the output features from the shaped geometries of one input geometry
+fn shaped_features(
+    template: &geojson::Feature,
+    geometry: ProcessedGeometry,
+    options: &ProcessingOptions,
+    feature_index: usize,
+) -> Result<Vec<geojson::Feature>, ProjectionError> {
+    match shape_geometry(geometry, options, feature_index)? {
+        ShapedGeometry::Parts(parts) => Ok(parts
+            .into_iter()
+            .enumerate()
+            .map(|(part_index, part)| {
+                part_feature(
+                    template,
+                    part.to_geojson_geometry(),
+                    part_index,
+                    options.keep_numeric_part_ids,
+                )
+            })
+            .collect()),
+        ShapedGeometry::Whole(geometry) => Ok(vec![geojson::Feature {
+            geometry: Some(geometry.to_geojson_geometry()),
+            ..template.clone()
+        }]),
+    }
+}
+
+/// Wrap output features into a feature collection
+fn output_collection(features: Vec<geojson::Feature>) -> geojson::GeoJson {
+    geojson::GeoJson::FeatureCollection(geojson::FeatureCollection {
+        bbox: None,
+        features,
+        foreign_members: None,
+    })
+}
+
+/// A non-fatal issue found while processing a feature
+///
+/// # Fields
+///
+/// * `feature_index` - The position of the feature in the input, 0 for a single feature or geometry
+/// * `message` - A description of the issue
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcessingWarning {
+    pub feature_index: usize,
+    pub message: String,
+}
+
+impl ProcessingWarning {
+    pub fn new(feature_index: usize, message: impl Into<String>) -> Self {
+        let message = message.into();
+        #[cfg(feature = "tracing")]
+        tracing::event!(
+            tracing::Level::WARN,
+            feature_index,
+            warning = message.as_str(),
+            "processing warning"
+        );
+        Self {
+            feature_index,
+            message,
+        }
+    }
+}
+
+/// Diagnostics collected while processing a feature collection
+///
+/// # Fields
+///
+/// * `warnings` - Non-fatal issues, in input order
+/// * `planned` - What a dry run found the pipeline would do, empty for real runs
+/// * `duplicates_removed` - The number of features dropped by `ProcessingOptions::dedupe`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProcessingReport {
+    pub warnings: Vec<ProcessingWarning>,
+    pub planned: Vec<PlannedAction>,
+    pub duplicates_removed: usize,
+}
+
+/// The result of processing a feature collection with options
+///
+/// # Fields
+///
+/// * `geojson` - The processed GeoJSON
+/// * `index` - A spatial index over the processed features, if requested
+/// * `transform` - The affine transform applied by the fit-to-extent stage, if requested
+/// * `report` - Warnings and other diagnostics collected while processing
+#[derive(Debug)]
+pub struct ProcessingOutput {
+    pub geojson: geojson::GeoJson,
+    pub index: Option<SpatialIndex>,
+    pub transform: Option<geo::AffineTransform<f64>>,
+    pub report: ProcessingReport,
+}
+
+/// Process a feature collection with additional pipeline options
+///
+/// # Arguments
+///
+/// * `json_value` - A JSON value
+/// * `config` - A transformer config
+/// * `options` - Processing options
+///
+/// # Returns
+///
+/// * `ProcessingOutput` - The processed GeoJSON and any requested extras
+///
+/// # Example
+///
+/// ```rust
+/// use proj_exercise_simple::coordinates::Coordinate;
+/// use proj_exercise_simple::helpers::process_feature_collection_with_options;
+/// use proj_exercise_simple::options::ProcessingOptions;
+/// use proj_exercise_simple::transformer::TransformerConfig;
+/// use serde_json::json;
+///
+/// let json_value = json!({
+///     "type": "Feature",
+///     "properties": null,
+///     "geometry": { "type": "Point", "coordinates": [0.0, 0.0] }
+/// });
+/// let mut config = TransformerConfig::default();
+/// let options = ProcessingOptions::new().with_index(true);
+/// let output = process_feature_collection_with_options(json_value, &mut config, &options).unwrap();
+/// let index = output.index.unwrap();
+/// assert_eq!(index.query_point(Coordinate::new(0.0, 0.0)), vec![0]);
+/// ```
+pub fn process_feature_collection_with_options(
+    json_value: serde_json::Value,
+    config: &mut TransformerConfig,
+    options: &ProcessingOptions,
+) -> Result<ProcessingOutput, ProjectionError> {
+    let mut report = ProcessingReport::default();
+    let mut geojson = geojson::GeoJson::from_json_value(json_value)?;
+    correct_axis_order(&mut geojson, options.input_axis_order, &mut report.warnings);
+    if options.strict_rfc7946 {
+        validate_rfc7946(&geojson)?;
+    }
+    check_vertex_budget(&geojson, options)?;
+    if let Some(densify) = &options.densify {
+        densify_geojson(&mut geojson, densify);
+    }
+    if options.dry_run {
+        let bounds = resolve_bounds(config, options)?;
+        plan_geojson(&geojson, bounds, options, &mut report)?;
+        return Ok(ProcessingOutput {
+            geojson: output_collection(Vec::new()),
+            index: None,
+            transform: None,
+            report,
+        });
+    }
+
+    let mut geojson = process_geojson(geojson, config, options, &mut report.warnings)?;
+    // Measure before fitting to an extent, which changes the units
+    if let Some(keys) = &options.measure {
+        attach_measures(&mut geojson, config, keys)?;
+    }
+    if options.distortion {
+        attach_distortion(&mut geojson, config)?;
+    }
+    let transform = options
+        .fit_to_extent
+        .as_ref()
+        .map(|fit| normalize_geojson_to_extent(&mut geojson, fit))
+        .transpose()?;
+    if let Some(precision) = options.geometry_hash {
+        attach_geometry_hashes(&mut geojson, precision)?;
+    }
+    let hash_precision = options.geometry_hash.unwrap_or(DEFAULT_HASH_PRECISION);
+    if options.dedupe {
+        report.duplicates_removed = dedupe_features(&mut geojson, hash_precision)?;
+    }
+    if options.require_unique_ids {
+        check_unique_ids(&geojson)?;
+    }
+    if let Some(key) = &options.sort_by {
+        sort_features(&mut geojson, key, hash_precision)?;
+    }
+    if options.stable_output {
+        stabilize(&mut geojson);
+    }
+    let index = options
+        .build_index
+        .then(|| SpatialIndex::from_geojson(&geojson));
+    Ok(ProcessingOutput {
+        geojson,
+        index,
+        transform,
+        report,
+    })
+}
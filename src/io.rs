@@ -0,0 +1,106 @@
+use crate::error::ProjectionError;
+use crate::helpers::process_feature_collection;
+use crate::transformer::TransformerConfig;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+/// The first two bytes of every gzip stream
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Read a GeoJSON file, decompressing it if it is gzipped
+///
+/// Compression is detected from the gzip magic bytes, not from the file
+/// extension, so mislabelled files are handled as well.
+///
+/// # Arguments
+///
+/// * `path` - The path of a plain or gzipped GeoJSON file
+///
+/// # Returns
+///
+/// * `Result<serde_json::Value, ProjectionError>` - The parsed JSON value
+pub fn read_geojson_file(path: impl AsRef<Path>) -> Result<serde_json::Value, ProjectionError> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let mut magic = [0u8; 2];
+    let read = reader.read(&mut magic)?;
+    let prefix = &magic[..read];
+    let reader = prefix.chain(reader);
+
+    let value = if prefix == GZIP_MAGIC {
+        serde_json::from_reader(GzDecoder::new(reader))
+    } else {
+        serde_json::from_reader(reader)
+    };
+    Ok(value.map_err(geojson::Error::from)?)
+}
+
+/// Write a GeoJSON object to a file, gzipping it if requested
+///
+/// # Arguments
+///
+/// * `path` - The output path
+/// * `geojson` - The GeoJSON object to write
+/// * `compress` - Whether to gzip the output
+///
+/// # Returns
+///
+/// * `Result<(), ProjectionError>` - An error if the file cannot be written
+pub fn write_geojson_file(
+    path: impl AsRef<Path>,
+    geojson: &geojson::GeoJson,
+    compress: bool,
+) -> Result<(), ProjectionError> {
+    let writer = BufWriter::new(File::create(path)?);
+    if compress {
+        let mut encoder = GzEncoder::new(writer, Compression::default());
+        serde_json::to_writer(&mut encoder, geojson).map_err(geojson::Error::from)?;
+        encoder.finish()?.flush()?;
+    } else {
+        let mut writer = writer;
+        serde_json::to_writer(&mut writer, geojson).map_err(geojson::Error::from)?;
+        writer.flush()?;
+    }
+    Ok(())
+}
+
+/// Process a GeoJSON file and write the projected result to another file
+///
+/// Gzipped input is decompressed transparently. The output is gzipped when
+/// its path ends in `.gz`.
+///
+/// # Arguments
+///
+/// * `input` - The path of a plain or gzipped GeoJSON file
+/// * `output` - The path to write the processed GeoJSON to
+/// * `config` - A transformer config
+///
+/// # Returns
+///
+/// * `Result<(), ProjectionError>` - An error if reading, processing or writing fails
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use proj_exercise_simple::io::process_file;
+/// use proj_exercise_simple::transformer::TransformerConfig;
+///
+/// let mut config = TransformerConfig::default();
+/// process_file("input.json.gz", "output.json.gz", &mut config).unwrap();
+/// ```
+pub fn process_file(
+    input: impl AsRef<Path>,
+    output: impl AsRef<Path>,
+    config: &mut TransformerConfig,
+) -> Result<(), ProjectionError> {
+    let json_value = read_geojson_file(input)?;
+    let processed = process_feature_collection(json_value, config)?;
+    let compress = output
+        .as_ref()
+        .extension()
+        .is_some_and(|extension| extension == "gz");
+    write_geojson_file(output, &processed, compress)
+}
@@ -0,0 +1,143 @@
+use crate::helpers::ProcessedGeometry;
+use std::fmt;
+
+/// The GeoJSON type of a geometry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GeometryKind {
+    Point,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+}
+
+impl GeometryKind {
+    /// The GeoJSON type name, e.g. `"MultiPolygon"`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GeometryKind::Point => "Point",
+            GeometryKind::LineString => "LineString",
+            GeometryKind::Polygon => "Polygon",
+            GeometryKind::MultiPoint => "MultiPoint",
+            GeometryKind::MultiLineString => "MultiLineString",
+            GeometryKind::MultiPolygon => "MultiPolygon",
+            GeometryKind::GeometryCollection => "GeometryCollection",
+        }
+    }
+}
+
+impl fmt::Display for GeometryKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The geometry types the processing pipeline accepts
+const SUPPORTED_GEOMETRY_TYPES: [GeometryKind; 7] = [
+    GeometryKind::Point,
+    GeometryKind::LineString,
+    GeometryKind::Polygon,
+    GeometryKind::MultiPoint,
+    GeometryKind::MultiLineString,
+    GeometryKind::MultiPolygon,
+    GeometryKind::GeometryCollection,
+];
+
+/// List the geometry types the crate can process
+///
+/// # Returns
+///
+/// * `&'static [GeometryKind]` - The supported types, in GeoJSON specification order
+///
+/// # Example
+///
+/// ```rust
+/// use proj_exercise_simple::kind::{supported_geometry_types, GeometryKind};
+///
+/// assert!(supported_geometry_types().contains(&GeometryKind::MultiPolygon));
+/// ```
+pub fn supported_geometry_types() -> &'static [GeometryKind] {
+    &SUPPORTED_GEOMETRY_TYPES
+}
+
+/// Check whether a geometry and, for collections, every member can be processed
+///
+/// Only the geometry type is checked, not the coordinates.
+///
+/// # Arguments
+///
+/// * `value` - A GeoJSON geometry value
+///
+/// # Returns
+///
+/// * `bool` - Whether the geometry type is supported
+///
+/// # Example
+///
+/// ```rust
+/// use geojson::Value;
+/// use proj_exercise_simple::kind::is_supported;
+///
+/// assert!(is_supported(&Value::Point(vec![13.377, 52.518])));
+/// ```
+pub fn is_supported(value: &geojson::Value) -> bool {
+    supported_geometry_types().contains(&value.geometry_kind())
+        && match value {
+            geojson::Value::GeometryCollection(geometries) => geometries
+                .iter()
+                .all(|geometry| is_supported(&geometry.value)),
+            _ => true,
+        }
+}
+
+/// Geometries that can report their GeoJSON type without a full `match`
+///
+/// # Example
+///
+/// ```rust
+/// use geojson::{Geometry, Value};
+/// use proj_exercise_simple::kind::{GeometryKind, HasGeometryKind};
+///
+/// let geometry = Geometry::new(Value::Point(vec![13.377, 52.518]));
+/// assert_eq!(geometry.geometry_kind(), GeometryKind::Point);
+/// assert_eq!(geometry.geometry_kind().to_string(), "Point");
+/// ```
+pub trait HasGeometryKind {
+    fn geometry_kind(&self) -> GeometryKind;
+}
+
+impl HasGeometryKind for ProcessedGeometry {
+    fn geometry_kind(&self) -> GeometryKind {
+        match self {
+            ProcessedGeometry::Point(_) => GeometryKind::Point,
+            ProcessedGeometry::LineString(_) => GeometryKind::LineString,
+            ProcessedGeometry::Polygon(_) => GeometryKind::Polygon,
+            ProcessedGeometry::MultiPoint(_) => GeometryKind::MultiPoint,
+            ProcessedGeometry::MultiLineString(_) => GeometryKind::MultiLineString,
+            ProcessedGeometry::MultiPolygon(_) => GeometryKind::MultiPolygon,
+            ProcessedGeometry::GeometryCollection(_) => GeometryKind::GeometryCollection,
+        }
+    }
+}
+
+impl HasGeometryKind for geojson::Value {
+    fn geometry_kind(&self) -> GeometryKind {
+        match self {
+            geojson::Value::Point(_) => GeometryKind::Point,
+            geojson::Value::LineString(_) => GeometryKind::LineString,
+            geojson::Value::Polygon(_) => GeometryKind::Polygon,
+            geojson::Value::MultiPoint(_) => GeometryKind::MultiPoint,
+            geojson::Value::MultiLineString(_) => GeometryKind::MultiLineString,
+            geojson::Value::MultiPolygon(_) => GeometryKind::MultiPolygon,
+            geojson::Value::GeometryCollection(_) => GeometryKind::GeometryCollection,
+        }
+    }
+}
+
+impl HasGeometryKind for geojson::Geometry {
+    fn geometry_kind(&self) -> GeometryKind {
+        self.value.geometry_kind()
+    }
+}
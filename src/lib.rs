@@ -1,8 +1,48 @@
+#[cfg(feature = "arrow")]
+pub mod arrow;
+pub mod axis_order;
+pub mod coerce;
 pub mod conversions;
 pub mod coordinates;
+pub mod densify;
+pub mod diff;
+pub mod digest;
+pub mod distortion;
 pub mod error;
+pub mod explode;
+#[cfg(feature = "ffi")]
+pub mod ffi;
 pub mod geometry_processor;
+pub mod graticule;
+pub mod grid;
 pub mod helpers;
+pub mod io;
+pub mod kind;
+pub mod measure;
+pub mod ndjson;
+#[cfg(feature = "async")]
+pub mod nonblocking;
+pub mod normalize;
+pub mod options;
+pub mod order;
+pub mod plan;
 pub mod pool;
+pub mod roundtrip;
+pub mod schema;
+pub mod self_intersection;
 pub mod simplification;
+pub mod smoothing;
+pub mod spatial_index;
+pub mod stable;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod tiles;
 pub mod transformer;
+pub mod typed;
+pub mod validation;
+pub mod vertices;
+
+pub use coordinates::{Coordinate, Line, Polygon};
+pub use error::ProjectionError;
+pub use helpers::{process_feature_collection, ProcessedGeometry, ProjectionResult};
+pub use transformer::TransformerConfig;
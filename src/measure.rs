@@ -0,0 +1,134 @@
+use crate::coordinates::compensated_sum;
+use crate::error::ProjectionError;
+use crate::transformer::{TransformerConfig, TransformerError};
+use geo::{Area, EuclideanLength};
+use geojson::{GeoJson, JsonObject, JsonValue};
+
+/// Property keys for the measurements attached to processed features
+///
+/// # Fields
+///
+/// * `area_key` - The property receiving the area of polygonal geometries, `area_m2` by default
+/// * `length_key` - The property receiving the length of lineal geometries, `length_m` by default
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MeasureProperties {
+    pub area_key: String,
+    pub length_key: String,
+}
+
+impl Default for MeasureProperties {
+    fn default() -> Self {
+        Self::new("area_m2", "length_m")
+    }
+}
+
+impl MeasureProperties {
+    /// Create measurement property keys
+    ///
+    /// # Arguments
+    ///
+    /// * `area_key` - The property receiving the area
+    /// * `length_key` - The property receiving the length
+    pub fn new(area_key: impl Into<String>, length_key: impl Into<String>) -> Self {
+        Self {
+            area_key: area_key.into(),
+            length_key: length_key.into(),
+        }
+    }
+}
+
+/// The length of a line, summed with compensation so long lines do not drift
+fn line_length(line: &geo::LineString<f64>) -> f64 {
+    compensated_sum(line.lines().map(|segment| segment.euclidean_length()))
+}
+
+/// Planar area of the polygonal parts and length of the lineal parts of a geometry
+fn measure(geometry: &geo::Geometry<f64>) -> (Option<f64>, Option<f64>) {
+    match geometry {
+        geo::Geometry::Polygon(_)
+        | geo::Geometry::MultiPolygon(_)
+        | geo::Geometry::Rect(_)
+        | geo::Geometry::Triangle(_) => (Some(geometry.unsigned_area()), None),
+        geo::Geometry::Line(line) => (None, Some(line.euclidean_length())),
+        geo::Geometry::LineString(line) => (None, Some(line_length(line))),
+        geo::Geometry::MultiLineString(lines) => {
+            (None, Some(compensated_sum(lines.iter().map(line_length))))
+        }
+        geo::Geometry::Point(_) | geo::Geometry::MultiPoint(_) => (None, None),
+        geo::Geometry::GeometryCollection(collection) => {
+            let add = |a: Option<f64>, b: Option<f64>| match (a, b) {
+                (Some(a), Some(b)) => Some(a + b),
+                (a, b) => a.or(b),
+            };
+            collection
+                .iter()
+                .map(measure)
+                .fold((None, None), |(area, length), (a, l)| {
+                    (add(area, a), add(length, l))
+                })
+        }
+    }
+}
+
+fn attach(
+    properties: &mut Option<JsonObject>,
+    geometry: &geojson::Geometry,
+    keys: &MeasureProperties,
+) -> Result<(), ProjectionError> {
+    let geometry = geo::Geometry::<f64>::try_from(geometry.value.clone())?;
+    let (area, length) = measure(&geometry);
+    let properties = properties.get_or_insert_with(JsonObject::new);
+    if let Some(area) = area {
+        properties.insert(keys.area_key.clone(), JsonValue::from(area));
+    }
+    if let Some(length) = length {
+        properties.insert(keys.length_key.clone(), JsonValue::from(length));
+    }
+    Ok(())
+}
+
+/// Write the area or length of every projected feature into its properties
+///
+/// Polygonal geometries receive an area, lineal geometries a length, and
+/// geometry collections the sums over their members. Measurements are planar
+/// in target CRS units, so the target must be projected; note that conformal
+/// projections such as Web Mercator inflate areas away from the equator.
+///
+/// # Arguments
+///
+/// * `geojson` - The processed GeoJSON, changed in place
+/// * `config` - The transformer config used to produce it
+/// * `keys` - The property keys to write
+///
+/// # Returns
+///
+/// * `Result<(), ProjectionError>` - An error if the target CRS is not projected
+pub(crate) fn attach_measures(
+    geojson: &mut GeoJson,
+    config: &TransformerConfig,
+    keys: &MeasureProperties,
+) -> Result<(), ProjectionError> {
+    if !config.target_is_projected()? {
+        return Err(TransformerError::InvalidCrs(
+            "Measuring area and length needs a projected target CRS".to_string(),
+        )
+        .into());
+    }
+    match geojson {
+        GeoJson::FeatureCollection(collection) => {
+            for feature in &mut collection.features {
+                if let Some(geometry) = &feature.geometry {
+                    attach(&mut feature.properties, geometry, keys)?;
+                }
+            }
+        }
+        GeoJson::Feature(feature) => {
+            if let Some(geometry) = &feature.geometry {
+                attach(&mut feature.properties, geometry, keys)?;
+            }
+        }
+        // A bare geometry has nowhere to store properties
+        GeoJson::Geometry(_) => {}
+    }
+    Ok(())
+}
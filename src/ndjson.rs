@@ -0,0 +1,328 @@
+//! Line-delimited GeoJSON (NDJSON, GeoJSONL) processing with checkpoints
+//!
+//! Every non-empty input line holds one GeoJSON object, every output line
+//! holds one processed feature. Because the output is only ever appended to,
+//! a job interrupted after a checkpoint can continue from it: the output is
+//! truncated back to the checkpointed offset and the input is read from the
+//! checkpointed offset on.
+
+use crate::digest::Fnv1a;
+use crate::error::ProjectionError;
+use crate::helpers::process_feature_collection_with_options;
+use crate::options::{CheckpointConfig, ProcessingOptions};
+use crate::transformer::TransformerConfig;
+use serde_json::json;
+use std::ffi::OsString;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+/// Running totals of a line-delimited job, kept across resumes
+///
+/// # Fields
+///
+/// * `features_read` - Input objects processed, blank lines are not counted
+/// * `features_written` - Output lines written, exploded parts count separately
+/// * `warnings` - Processing warnings reported for the input objects
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NdjsonStats {
+    pub features_read: usize,
+    pub features_written: usize,
+    pub warnings: usize,
+}
+
+/// The position of a job in both files, as recorded in a checkpoint
+#[derive(Debug, Clone, Copy, Default)]
+struct Progress {
+    input_offset: u64,
+    output_offset: u64,
+    last_line_offset: u64,
+    last_line_hash: u64,
+    stats: NdjsonStats,
+}
+
+/// A job projecting a line-delimited GeoJSON file into another one
+///
+/// # Example
+///
+/// ```rust
+/// use proj_exercise_simple::ndjson::NdjsonJob;
+/// use proj_exercise_simple::options::ProcessingOptions;
+/// use proj_exercise_simple::transformer::TransformerConfig;
+///
+/// let dir = std::env::temp_dir();
+/// let input = dir.join("ndjson_job_doc_input.geojsonl");
+/// let output = dir.join("ndjson_job_doc_output.geojsonl");
+/// std::fs::write(&input, "{\"type\":\"Point\",\"coordinates\":[1.0,2.0]}\n").unwrap();
+///
+/// let stats = NdjsonJob::new(&input, &output)
+///     .run(&mut TransformerConfig::default(), &ProcessingOptions::new())
+///     .unwrap();
+/// assert_eq!(stats.features_written, 1);
+/// ```
+#[derive(Debug)]
+pub struct NdjsonJob {
+    input: PathBuf,
+    output: PathBuf,
+    progress: Progress,
+    resumed: bool,
+    #[cfg(feature = "testing")]
+    interrupt_after: Option<usize>,
+}
+
+impl NdjsonJob {
+    /// Create a job starting at the beginning of the input
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The line-delimited input file
+    /// * `output` - The output file, created or truncated when the job runs
+    ///
+    /// # Returns
+    ///
+    /// * `NdjsonJob` - A new job
+    pub fn new(input: impl Into<PathBuf>, output: impl Into<PathBuf>) -> Self {
+        Self {
+            input: input.into(),
+            output: output.into(),
+            progress: Progress::default(),
+            resumed: false,
+            #[cfg(feature = "testing")]
+            interrupt_after: None,
+        }
+    }
+
+    /// Create a job continuing from a checkpoint
+    ///
+    /// The input and output paths are taken from the checkpoint. When the job
+    /// runs, the last output line before the checkpointed offset is hashed and
+    /// compared with the checkpoint, so an output file that was changed or
+    /// replaced in the meantime is rejected instead of being appended to.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - A checkpoint file written by a job with `ProcessingOptions::checkpoint`
+    ///
+    /// # Returns
+    ///
+    /// * `Result<NdjsonJob, ProjectionError>` - The job, or an error if the checkpoint cannot be read
+    pub fn resume_from_checkpoint(path: impl AsRef<Path>) -> Result<Self, ProjectionError> {
+        let text = fs::read_to_string(path)?;
+        let value: serde_json::Value = serde_json::from_str(&text).map_err(geojson::Error::from)?;
+        let string = |key: &str| {
+            value[key]
+                .as_str()
+                .map(PathBuf::from)
+                .ok_or_else(|| ProjectionError::InvalidCheckpoint(format!("missing {key}")))
+        };
+        let number = |key: &str| {
+            value[key]
+                .as_u64()
+                .ok_or_else(|| ProjectionError::InvalidCheckpoint(format!("missing {key}")))
+        };
+        let progress = Progress {
+            input_offset: number("input_offset")?,
+            output_offset: number("output_offset")?,
+            last_line_offset: number("last_line_offset")?,
+            last_line_hash: number("last_line_hash")?,
+            stats: NdjsonStats {
+                features_read: number("features_read")? as usize,
+                features_written: number("features_written")? as usize,
+                warnings: number("warnings")? as usize,
+            },
+        };
+        Ok(Self {
+            input: string("input")?,
+            output: string("output")?,
+            progress,
+            resumed: true,
+            #[cfg(feature = "testing")]
+            interrupt_after: None,
+        })
+    }
+
+    /// Stop the next run after this many input features without a final checkpoint
+    ///
+    /// Simulates a crash: lines written after the last checkpoint stay in the
+    /// output and are discarded when the job is resumed.
+    #[cfg(feature = "testing")]
+    pub fn with_interruption_after(mut self, features: usize) -> Self {
+        self.interrupt_after = Some(features);
+        self
+    }
+
+    /// The totals of the job so far, including runs before a resume
+    pub fn stats(&self) -> NdjsonStats {
+        self.progress.stats
+    }
+
+    /// Process the remaining input
+    ///
+    /// With `options.checkpoint` set, a checkpoint is written every
+    /// `every_n_features` input features and once more at the end of the
+    /// input. The output is flushed and synced before each checkpoint.
+    /// `options.sort_by` is rejected, and `options.dedupe` only compares the
+    /// features of one input line.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - A transformer config
+    /// * `options` - Processing options, applied to every input object
+    ///
+    /// # Returns
+    ///
+    /// * `Result<NdjsonStats, ProjectionError>` - The totals of the job
+    pub fn run(
+        &mut self,
+        config: &mut TransformerConfig,
+        options: &ProcessingOptions,
+    ) -> Result<NdjsonStats, ProjectionError> {
+        if options.sort_by.is_some() {
+            return Err(ProjectionError::IncompatibleOptions(
+                "sorting needs the whole collection, but line-delimited output is written feature by feature; disable sort_by".to_string(),
+            ));
+        }
+        let mut input = BufReader::new(File::open(&self.input)?);
+        input.seek(SeekFrom::Start(self.progress.input_offset))?;
+        let output = if self.resumed {
+            self.reopen_output()?
+        } else {
+            File::create(&self.output)?
+        };
+        // Later runs continue the output instead of truncating it
+        self.resumed = true;
+        let mut output = BufWriter::new(output);
+
+        let mut line = String::new();
+        let mut processed = 0;
+        loop {
+            line.clear();
+            let read = input.read_line(&mut line)?;
+            if read == 0 {
+                break;
+            }
+            self.progress.input_offset += read as u64;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let value: serde_json::Value =
+                serde_json::from_str(&line).map_err(geojson::Error::from)?;
+            let result = process_feature_collection_with_options(value, config, options)?;
+            self.progress.stats.features_read += 1;
+            self.progress.stats.warnings += result.report.warnings.len();
+            for text in output_lines(result.geojson)? {
+                output.write_all(text.as_bytes())?;
+                output.write_all(b"\n")?;
+                self.progress.last_line_offset = self.progress.output_offset;
+                self.progress.last_line_hash = line_hash(text.as_bytes());
+                self.progress.output_offset += text.len() as u64 + 1;
+                self.progress.stats.features_written += 1;
+            }
+            processed += 1;
+
+            #[cfg(feature = "testing")]
+            if self.interrupt_after == Some(processed) {
+                output.flush()?;
+                return Ok(self.progress.stats);
+            }
+            if let Some(checkpoint) = &options.checkpoint {
+                if processed % checkpoint.every_n_features == 0 {
+                    self.write_checkpoint(&mut output, checkpoint)?;
+                }
+            }
+        }
+
+        output.flush()?;
+        if let Some(checkpoint) = &options.checkpoint {
+            self.write_checkpoint(&mut output, checkpoint)?;
+        }
+        Ok(self.progress.stats)
+    }
+
+    /// Open the output of a resumed job, verify it and drop lines after the checkpoint
+    fn reopen_output(&self) -> Result<File, ProjectionError> {
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(&self.output)?;
+        let Progress {
+            output_offset,
+            last_line_offset,
+            last_line_hash,
+            stats,
+            ..
+        } = self.progress;
+        if file.metadata()?.len() < output_offset {
+            return Err(ProjectionError::InvalidCheckpoint(format!(
+                "output is shorter than the checkpointed {output_offset} bytes"
+            )));
+        }
+        if stats.features_written > 0 {
+            // The newline is not part of the hash
+            let mut last_line = vec![0; (output_offset - last_line_offset - 1) as usize];
+            file.seek(SeekFrom::Start(last_line_offset))?;
+            file.read_exact(&mut last_line)?;
+            if line_hash(&last_line) != last_line_hash {
+                return Err(ProjectionError::InvalidCheckpoint(
+                    "last checkpointed output line does not match".to_string(),
+                ));
+            }
+        }
+        file.set_len(output_offset)?;
+        file.seek(SeekFrom::End(0))?;
+        Ok(file)
+    }
+
+    /// Flush the output and atomically replace the checkpoint file
+    fn write_checkpoint(
+        &self,
+        output: &mut BufWriter<File>,
+        checkpoint: &CheckpointConfig,
+    ) -> Result<(), ProjectionError> {
+        output.flush()?;
+        output.get_ref().sync_data()?;
+
+        let progress = &self.progress;
+        let value = json!({
+            "input": self.input.to_string_lossy(),
+            "output": self.output.to_string_lossy(),
+            "input_offset": progress.input_offset,
+            "output_offset": progress.output_offset,
+            "last_line_offset": progress.last_line_offset,
+            "last_line_hash": progress.last_line_hash,
+            "features_read": progress.stats.features_read,
+            "features_written": progress.stats.features_written,
+            "warnings": progress.stats.warnings,
+        });
+        let mut temporary = OsString::from(checkpoint.path.as_os_str());
+        temporary.push(".tmp");
+        let temporary = PathBuf::from(temporary);
+        {
+            let mut file = File::create(&temporary)?;
+            file.write_all(value.to_string().as_bytes())?;
+            file.sync_all()?;
+        }
+        fs::rename(&temporary, &checkpoint.path)?;
+        Ok(())
+    }
+}
+
+/// Serialize a processed object as output lines, one per feature
+pub(crate) fn output_lines(geojson: geojson::GeoJson) -> Result<Vec<String>, ProjectionError> {
+    let lines = match geojson {
+        geojson::GeoJson::FeatureCollection(collection) => collection
+            .features
+            .iter()
+            .map(serde_json::to_string)
+            .collect(),
+        geojson::GeoJson::Feature(feature) => serde_json::to_string(&feature).map(|l| vec![l]),
+        geojson::GeoJson::Geometry(geometry) => serde_json::to_string(&geometry).map(|l| vec![l]),
+    };
+    Ok(lines.map_err(geojson::Error::from)?)
+}
+
+fn line_hash(bytes: &[u8]) -> u64 {
+    let mut hasher = Fnv1a::new();
+    hasher.write(bytes);
+    hasher.finish()
+}
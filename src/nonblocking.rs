@@ -0,0 +1,203 @@
+use crate::error::ProjectionError;
+use crate::helpers::{process_feature_collection_with_options, ProcessingOutput};
+use crate::ndjson::{output_lines, NdjsonStats};
+use crate::options::{CancellationToken, ProcessingOptions};
+use crate::transformer::TransformerConfig;
+use std::future::Future;
+use std::panic;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{ready, Context, Poll};
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::task::{self, JoinError, JoinHandle};
+
+/// Cancels the token of a job when dropped before the job has finished
+struct CancelOnDrop(Option<CancellationToken>);
+
+impl CancelOnDrop {
+    /// Keep the token of the job options, or give the job a token of its own
+    fn new(options: &mut ProcessingOptions) -> Self {
+        Self(Some(
+            options
+                .cancellation
+                .get_or_insert_with(CancellationToken::new)
+                .clone(),
+        ))
+    }
+
+    fn disarm(&mut self) {
+        self.0 = None;
+    }
+}
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        if let Some(token) = &self.0 {
+            token.cancel();
+        }
+    }
+}
+
+/// The result of a blocking task, resuming its panic on the caller
+fn join_result<T>(
+    result: Result<Result<T, ProjectionError>, JoinError>,
+) -> Result<T, ProjectionError> {
+    match result {
+        Ok(result) => result,
+        Err(error) if error.is_panic() => panic::resume_unwind(error.into_panic()),
+        // The runtime shut down before the task ran
+        Err(_) => Err(ProjectionError::Cancelled),
+    }
+}
+
+/// A future resolving to the output of a feature collection processed on the blocking pool
+///
+/// The projection runs on Tokio's blocking thread pool, so polling the
+/// future never blocks the runtime. Dropping the future before it resolves
+/// cancels the job, which stops within one batch of coordinates.
+pub struct ProcessingTask {
+    handle: JoinHandle<Result<ProcessingOutput, ProjectionError>>,
+    guard: CancelOnDrop,
+}
+
+impl Future for ProcessingTask {
+    type Output = Result<ProcessingOutput, ProjectionError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let result = ready!(Pin::new(&mut self.handle).poll(cx));
+        self.guard.disarm();
+        Poll::Ready(join_result(result))
+    }
+}
+
+/// Process a feature collection without blocking the async runtime
+///
+/// Proj calls are CPU bound and blocking, so calling `process_feature_collection`
+/// directly inside an async handler stalls the runtime. This runs
+/// `process_feature_collection_with_options` on `tokio::task::spawn_blocking`.
+/// Dropping the returned future cancels `options.cancellation`, or a token of
+/// the job's own when none is set.
+///
+/// # Arguments
+///
+/// * `json_value` - A JSON value
+/// * `config` - A transformer config, moved to the blocking pool
+/// * `options` - Processing options
+///
+/// # Returns
+///
+/// * `ProcessingTask` - A future resolving to the processed feature collection
+///
+/// # Panics
+///
+/// Panics when called outside of a Tokio runtime.
+///
+/// # Example
+///
+/// ```rust
+/// use proj_exercise_simple::nonblocking::process_feature_collection_async;
+/// use proj_exercise_simple::options::ProcessingOptions;
+/// use proj_exercise_simple::transformer::TransformerConfig;
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let input = serde_json::json!({ "type": "Point", "coordinates": [13.4, 52.5] });
+/// let output = process_feature_collection_async(
+///     input,
+///     TransformerConfig::default(),
+///     ProcessingOptions::new(),
+/// )
+/// .await
+/// .unwrap();
+/// assert!(matches!(output.geojson, geojson::GeoJson::Geometry(_)));
+/// # });
+/// ```
+pub fn process_feature_collection_async(
+    json_value: serde_json::Value,
+    mut config: TransformerConfig,
+    mut options: ProcessingOptions,
+) -> ProcessingTask {
+    let guard = CancelOnDrop::new(&mut options);
+    let handle = task::spawn_blocking(move || {
+        process_feature_collection_with_options(json_value, &mut config, &options)
+    });
+    ProcessingTask { handle, guard }
+}
+
+/// Project line-delimited GeoJSON from a reader into a writer without blocking the async runtime
+///
+/// The streaming counterpart of `ndjson::NdjsonJob`: every non-empty input
+/// line holds one GeoJSON object and is processed on the blocking pool,
+/// every output line holds one processed feature. Dropping the future
+/// cancels the line in progress like `process_feature_collection_async`.
+/// Checkpoints need file offsets and `options.sort_by` needs the whole
+/// input, so both are rejected.
+///
+/// # Arguments
+///
+/// * `reader` - The line-delimited input
+/// * `writer` - Where the processed features are written, flushed at the end
+/// * `config` - A transformer config
+/// * `options` - Processing options, applied to every input object
+///
+/// # Returns
+///
+/// * `Result<NdjsonStats, ProjectionError>` - The totals of the stream
+pub async fn process_ndjson_async<R, W>(
+    reader: R,
+    mut writer: W,
+    mut config: TransformerConfig,
+    mut options: ProcessingOptions,
+) -> Result<NdjsonStats, ProjectionError>
+where
+    R: AsyncBufRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    if options.sort_by.is_some() {
+        return Err(ProjectionError::IncompatibleOptions(
+            "sorting needs the whole collection, but line-delimited output is written feature by feature; disable sort_by".to_string(),
+        ));
+    }
+    if options.checkpoint.is_some() {
+        return Err(ProjectionError::IncompatibleOptions(
+            "checkpoints record file offsets, which a stream does not have; use ndjson::NdjsonJob"
+                .to_string(),
+        ));
+    }
+    let mut guard = CancelOnDrop::new(&mut options);
+    let options = Arc::new(options);
+
+    let mut stats = NdjsonStats::default();
+    let mut lines = reader.lines();
+    while let Some(line) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let line_options = Arc::clone(&options);
+        let mut line_config = config;
+        let (returned, output) = join_result(
+            task::spawn_blocking(move || {
+                let value: serde_json::Value =
+                    serde_json::from_str(&line).map_err(geojson::Error::from)?;
+                let output = process_feature_collection_with_options(
+                    value,
+                    &mut line_config,
+                    &line_options,
+                )?;
+                Ok((line_config, output))
+            })
+            .await,
+        )?;
+        config = returned;
+
+        stats.features_read += 1;
+        stats.warnings += output.report.warnings.len();
+        for text in output_lines(output.geojson)? {
+            writer.write_all(text.as_bytes()).await?;
+            writer.write_all(b"\n").await?;
+            stats.features_written += 1;
+        }
+    }
+    writer.flush().await?;
+    guard.disarm();
+    Ok(stats)
+}
@@ -0,0 +1,159 @@
+use crate::error::ProjectionError;
+use geo::{AffineOps, AffineTransform, BoundingRect, Rect};
+use geojson::GeoJson;
+
+/// Parameters for fitting processed output into a target rectangle
+///
+/// # Fields
+///
+/// * `target` - The rectangle the data should fill
+/// * `preserve_aspect` - Scale both axes by the same factor and center the result
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FitToExtent {
+    pub target: Rect<f64>,
+    pub preserve_aspect: bool,
+}
+
+impl FitToExtent {
+    /// Create fit-to-extent parameters
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The rectangle the data should fill
+    /// * `preserve_aspect` - Whether both axes share the same scale factor
+    pub fn new(target: Rect<f64>, preserve_aspect: bool) -> Self {
+        Self {
+            target,
+            preserve_aspect,
+        }
+    }
+
+    /// Compute the affine transform mapping `bounds` into the target rectangle
+    ///
+    /// An axis without extent (a single point or a vertical/horizontal line)
+    /// is not scaled and is centered in the target instead, so no division by
+    /// zero can occur. With `preserve_aspect`, the smaller of the usable scale
+    /// factors is applied to both axes and the result is centered.
+    ///
+    /// # Arguments
+    ///
+    /// * `bounds` - The bounding box of the data to fit
+    ///
+    /// # Returns
+    ///
+    /// * `AffineTransform<f64>` - A scale followed by a translation
+    pub fn transform_for(&self, bounds: Rect<f64>) -> AffineTransform<f64> {
+        let (width, height) = (bounds.width(), bounds.height());
+        let (target_width, target_height) = (self.target.width(), self.target.height());
+
+        let scale_x = (width > 0.0).then(|| target_width / width);
+        let scale_y = (height > 0.0).then(|| target_height / height);
+        let (scale_x, scale_y) = if self.preserve_aspect {
+            let scale = match (scale_x, scale_y) {
+                (Some(x), Some(y)) => x.min(y),
+                (Some(s), None) | (None, Some(s)) => s,
+                (None, None) => 1.0,
+            };
+            (scale, scale)
+        } else {
+            (scale_x.unwrap_or(1.0), scale_y.unwrap_or(1.0))
+        };
+
+        // Center the scaled data inside the target on both axes
+        let x_offset =
+            self.target.min().x + (target_width - width * scale_x) / 2.0 - bounds.min().x * scale_x;
+        let y_offset = self.target.min().y + (target_height - height * scale_y) / 2.0
+            - bounds.min().y * scale_y;
+        AffineTransform::new(scale_x, 0.0, x_offset, 0.0, scale_y, y_offset)
+    }
+}
+
+/// Scale and translate a geometry so it exactly fills a target rectangle
+///
+/// # Arguments
+///
+/// * `geometry` - A geometry or geometry collection, usually projected output
+/// * `target` - The rectangle the geometry should fill
+/// * `preserve_aspect` - Keep the aspect ratio and center the geometry
+///
+/// # Returns
+///
+/// * `(geo::Geometry<f64>, AffineTransform<f64>)` - The transformed geometry and
+///   the transform used, so other data can be mapped consistently. Empty
+///   geometries are returned unchanged with the identity transform.
+///
+/// # Example
+///
+/// ```rust
+/// use geo::{coord, Rect};
+/// use proj_exercise_simple::normalize::normalize_to_extent;
+///
+/// let line: geo::Geometry<f64> = geo::LineString::from(vec![(0.0, 0.0), (10.0, 20.0)]).into();
+/// let target = Rect::new(coord! { x: 0.0, y: 0.0 }, coord! { x: 100.0, y: 100.0 });
+/// let (_, transform) = normalize_to_extent(&line, target, true);
+/// assert_eq!(transform.apply(coord! { x: 10.0, y: 20.0 }), coord! { x: 75.0, y: 100.0 });
+/// ```
+pub fn normalize_to_extent(
+    geometry: &geo::Geometry<f64>,
+    target: Rect<f64>,
+    preserve_aspect: bool,
+) -> (geo::Geometry<f64>, AffineTransform<f64>) {
+    let transform = match geometry.bounding_rect() {
+        Some(bounds) => FitToExtent::new(target, preserve_aspect).transform_for(bounds),
+        None => AffineTransform::identity(),
+    };
+    (geometry.affine_transform(&transform), transform)
+}
+
+/// Scale and translate every geometry in a GeoJSON object into a target rectangle
+///
+/// The bounding box is computed over all geometries together, so the relative
+/// placement of features is preserved. Ids and properties are left untouched.
+///
+/// # Arguments
+///
+/// * `geojson` - The GeoJSON object to transform in place
+/// * `fit` - The target rectangle and aspect handling
+///
+/// # Returns
+///
+/// * `Result<AffineTransform<f64>, ProjectionError>` - The transform that was applied
+pub fn normalize_geojson_to_extent(
+    geojson: &mut GeoJson,
+    fit: &FitToExtent,
+) -> Result<AffineTransform<f64>, ProjectionError> {
+    let mut geometries: Vec<&mut geojson::Geometry> = match geojson {
+        GeoJson::FeatureCollection(collection) => collection
+            .features
+            .iter_mut()
+            .filter_map(|feature| feature.geometry.as_mut())
+            .collect(),
+        GeoJson::Feature(feature) => feature.geometry.as_mut().into_iter().collect(),
+        GeoJson::Geometry(geometry) => vec![geometry],
+    };
+
+    let converted = geometries
+        .iter()
+        .map(|geometry| {
+            geo::Geometry::<f64>::try_from(geometry.value.clone()).map_err(ProjectionError::from)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let bounds = converted
+        .iter()
+        .filter_map(|geometry| geometry.bounding_rect())
+        .reduce(|a, b| {
+            Rect::new(
+                geo::coord! { x: a.min().x.min(b.min().x), y: a.min().y.min(b.min().y) },
+                geo::coord! { x: a.max().x.max(b.max().x), y: a.max().y.max(b.max().y) },
+            )
+        });
+    let Some(bounds) = bounds else {
+        return Ok(AffineTransform::identity());
+    };
+
+    let transform = fit.transform_for(bounds);
+    for (geometry, converted) in geometries.iter_mut().zip(converted) {
+        geometry.value = geojson::Value::from(&converted.affine_transform(&transform));
+    }
+    Ok(transform)
+}
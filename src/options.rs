@@ -0,0 +1,589 @@
+use crate::axis_order::AxisOrder;
+use crate::coerce::CoerceTo;
+use crate::coordinates::CoordinateBounds;
+use crate::densify::Densify;
+use crate::explode::ExplodePolicy;
+use crate::helpers::ProcessedGeometry;
+use crate::measure::MeasureProperties;
+use crate::normalize::FitToExtent;
+use crate::order::SortKey;
+use crate::self_intersection::SelfIntersectionPolicy;
+use geo::Rect;
+use geojson::feature::Id;
+use geojson::{Feature, JsonObject};
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// How a feature that fails while being processed is handled
+///
+/// Applies to features that exceed the feature timeout and to features
+/// whose post-transform hook fails. Invalid input and transformer failures
+/// always abort processing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ErrorPolicy {
+    /// Abort processing with the error of the feature
+    #[default]
+    Strict,
+    /// Drop the feature and record a warning with the error
+    Lenient,
+}
+
+/// How features without a geometry are handled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NullGeometryPolicy {
+    /// Abort processing with `ProjectionError::InvalidGeometryType`
+    #[default]
+    Error,
+    /// Emit the feature unchanged, keeping its id and properties
+    PassThrough,
+    /// Drop the feature and record a warning
+    Skip,
+}
+
+/// When input coordinates are checked against the valid range
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ValidationStrategy {
+    /// Validate each geometry right before projecting it
+    #[default]
+    PerGeometry,
+    /// Validate every coordinate of the input in one pass before projecting anything
+    EagerAll,
+}
+
+/// How polygon rings whose last position differs from the first are handled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RingClosure {
+    /// Abort processing with `ProjectionError::InvalidCoordinates`
+    #[default]
+    Error,
+    /// Close the ring by appending its first position
+    AutoClose,
+    /// Pass the ring through unchanged
+    Ignore,
+}
+
+/// Where and how often a line-delimited job records its progress
+///
+/// # Fields
+///
+/// * `path` - The checkpoint file, replaced atomically on every write
+/// * `every_n_features` - The number of input features between checkpoints
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckpointConfig {
+    pub path: PathBuf,
+    pub every_n_features: usize,
+}
+
+impl CheckpointConfig {
+    /// Create a checkpoint config
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The checkpoint file
+    /// * `every_n_features` - The number of input features between checkpoints, at least 1
+    ///
+    /// # Returns
+    ///
+    /// * `CheckpointConfig` - A new checkpoint config
+    pub fn new(path: impl Into<PathBuf>, every_n_features: usize) -> Self {
+        Self {
+            path: path.into(),
+            every_n_features: every_n_features.max(1),
+        }
+    }
+}
+
+/// The input feature a post-transform hook is called for
+///
+/// # Fields
+///
+/// * `feature_index` - The index of the feature in the input
+/// * `id` - The id of the feature
+/// * `properties` - The properties of the feature
+#[derive(Debug, Clone, Copy)]
+pub struct FeatureContext<'a> {
+    pub feature_index: usize,
+    pub id: Option<&'a Id>,
+    pub properties: Option<&'a JsonObject>,
+}
+
+/// The error a post-transform hook can return
+pub type HookError = Box<dyn std::error::Error + Send + Sync>;
+
+type HookFn =
+    dyn Fn(&mut ProcessedGeometry, &FeatureContext) -> Result<(), HookError> + Send + Sync;
+
+/// A user function applied to every projected geometry, see `ProcessingOptions::with_post_transform_hook`
+#[derive(Clone)]
+pub struct PostTransformHook(Arc<HookFn>);
+
+impl PostTransformHook {
+    /// Call the hook for a geometry
+    pub fn call(
+        &self,
+        geometry: &mut ProcessedGeometry,
+        context: &FeatureContext,
+    ) -> Result<(), HookError> {
+        (self.0)(geometry, context)
+    }
+}
+
+impl fmt::Debug for PostTransformHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PostTransformHook")
+    }
+}
+
+type SelectFn = dyn Fn(&Feature) -> Option<f64> + Send + Sync;
+
+/// A user function choosing the simplification epsilon of each feature, see `ProcessingOptions::with_feature_simplification`
+#[derive(Clone)]
+pub struct EpsilonSelector(Arc<SelectFn>);
+
+impl EpsilonSelector {
+    /// Choose the epsilon for a feature, `None` to keep the default
+    pub fn select(&self, feature: &Feature) -> Option<f64> {
+        (self.0)(feature)
+    }
+}
+
+impl fmt::Debug for EpsilonSelector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("EpsilonSelector")
+    }
+}
+
+/// A flag that stops processing once set, shared by all of its clones
+///
+/// Processing checks the token between batches of coordinates and after
+/// every feature, and fails with `ProjectionError::Cancelled` once it is
+/// cancelled. Cancelling cannot be undone.
+///
+/// # Example
+///
+/// ```rust
+/// use proj_exercise_simple::options::{CancellationToken, ProcessingOptions};
+///
+/// let token = CancellationToken::new();
+/// let options = ProcessingOptions::new().with_cancellation(token.clone());
+/// token.cancel();
+/// assert!(options.cancellation.unwrap().is_cancelled());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stop every job processing with this token or one of its clones
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Options for the feature collection processing pipeline
+///
+/// # Fields
+///
+/// * `build_index` - Build a spatial index over the processed features
+/// * `fit_to_extent` - Scale and translate the output into a target rectangle
+/// * `input_axis_order` - The axis order of the input positions
+/// * `coerce` - Coerce output geometries to single or multi geometry types
+/// * `explode` - Split geometries into one output feature per part
+/// * `keep_numeric_part_ids` - Copy numeric ids to every exploded part instead of dropping them
+/// * `coordinate_bounds` - The valid input range, WGS84 for geographic sources and unbounded otherwise when unset
+/// * `validation` - Whether coordinates are validated per geometry or all up front
+/// * `null_geometry` - How features with a null geometry are handled
+/// * `measure` - Write the area or length of each projected feature into its properties
+/// * `geometry_hash` - Write a digest of each output geometry, quantized to this many decimals, into its properties
+/// * `dry_run` - Validate and plan every feature without projecting or producing output
+/// * `stable_output` - Sort properties and foreign members by key and normalize negative zero coordinates
+/// * `require_unique_ids` - Fail when two output features share an id
+/// * `ring_closure` - How unclosed polygon rings are handled
+/// * `self_intersection` - How projected polygon rings that intersect themselves are handled
+/// * `max_vertices_per_feature` - Reject input with a feature of more vertices
+/// * `max_vertices_total` - Reject input with more vertices in total
+/// * `feature_timeout` - Fail when processing a single feature takes longer
+/// * `error_policy` - Whether a feature that times out or fails its post-transform hook aborts processing or is dropped
+/// * `checkpoint` - Where and how often `ndjson::NdjsonJob` records its progress
+/// * `densify` - Insert vertices into long segments before projecting
+/// * `strict_rfc7946` - Reject input whose geometries do not conform to RFC 7946
+/// * `distortion` - Write the projection distortion at each feature's centroid into its properties
+/// * `post_transform_hook` - A user function applied to every projected geometry
+/// * `feature_simplification` - A user function choosing the simplification epsilon of each feature
+/// * `sort_by` - Sort output features by a key
+/// * `dedupe` - Drop output features equal to an earlier one in geometry and properties
+/// * `cancellation` - A token that stops processing once cancelled
+#[derive(Debug, Clone, Default)]
+pub struct ProcessingOptions {
+    pub build_index: bool,
+    pub fit_to_extent: Option<FitToExtent>,
+    pub input_axis_order: AxisOrder,
+    pub coerce: Option<CoerceTo>,
+    pub explode: ExplodePolicy,
+    pub keep_numeric_part_ids: bool,
+    pub coordinate_bounds: Option<CoordinateBounds>,
+    pub validation: ValidationStrategy,
+    pub null_geometry: NullGeometryPolicy,
+    pub measure: Option<MeasureProperties>,
+    pub geometry_hash: Option<u8>,
+    pub stable_output: bool,
+    pub dry_run: bool,
+    pub require_unique_ids: bool,
+    pub ring_closure: RingClosure,
+    pub self_intersection: SelfIntersectionPolicy,
+    pub max_vertices_per_feature: Option<usize>,
+    pub max_vertices_total: Option<usize>,
+    pub feature_timeout: Option<Duration>,
+    pub error_policy: ErrorPolicy,
+    pub checkpoint: Option<CheckpointConfig>,
+    pub densify: Option<Densify>,
+    pub strict_rfc7946: bool,
+    pub distortion: bool,
+    pub post_transform_hook: Option<PostTransformHook>,
+    pub feature_simplification: Option<EpsilonSelector>,
+    pub sort_by: Option<SortKey>,
+    pub dedupe: bool,
+    pub cancellation: Option<CancellationToken>,
+}
+
+impl ProcessingOptions {
+    /// Create processing options with every optional stage disabled
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::options::ProcessingOptions;
+    /// let options = ProcessingOptions::new().with_index(true);
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_index(mut self, build_index: bool) -> Self {
+        self.build_index = build_index;
+        self
+    }
+
+    /// Fit the projected output into a target rectangle as the final stage
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The rectangle the output should fill
+    /// * `preserve_aspect` - Keep the aspect ratio and center the output
+    pub fn with_fit_to_extent(mut self, target: Rect<f64>, preserve_aspect: bool) -> Self {
+        self.fit_to_extent = Some(FitToExtent::new(target, preserve_aspect));
+        self
+    }
+
+    pub fn with_input_axis_order(mut self, order: AxisOrder) -> Self {
+        self.input_axis_order = order;
+        self
+    }
+
+    pub fn with_coerce(mut self, coerce: CoerceTo) -> Self {
+        self.coerce = Some(coerce);
+        self
+    }
+
+    pub fn with_explode(mut self, explode: ExplodePolicy) -> Self {
+        self.explode = explode;
+        self
+    }
+
+    pub fn with_keep_numeric_part_ids(mut self, keep_numeric_part_ids: bool) -> Self {
+        self.keep_numeric_part_ids = keep_numeric_part_ids;
+        self
+    }
+
+    pub fn with_coordinate_bounds(mut self, bounds: CoordinateBounds) -> Self {
+        self.coordinate_bounds = Some(bounds);
+        self
+    }
+
+    pub fn with_validation(mut self, validation: ValidationStrategy) -> Self {
+        self.validation = validation;
+        self
+    }
+
+    pub fn with_null_geometry(mut self, policy: NullGeometryPolicy) -> Self {
+        self.null_geometry = policy;
+        self
+    }
+
+    pub fn with_ring_closure(mut self, ring_closure: RingClosure) -> Self {
+        self.ring_closure = ring_closure;
+        self
+    }
+
+    /// Check projected polygon rings for self-intersection
+    ///
+    /// Projections can twist rings that were simple in the source CRS, for
+    /// example near the antimeridian. The check runs after projection, the
+    /// post-transform hook and degenerate hole removal.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - How self-intersecting rings are handled
+    pub fn with_self_intersection(mut self, policy: SelfIntersectionPolicy) -> Self {
+        self.self_intersection = policy;
+        self
+    }
+
+    /// Attach the projected area or length of each feature as properties
+    ///
+    /// The target CRS must be projected, processing fails otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The property keys to write
+    pub fn with_measure_properties(mut self, keys: MeasureProperties) -> Self {
+        self.measure = Some(keys);
+        self
+    }
+
+    /// Attach a `geometry_hash` property to every output feature
+    ///
+    /// # Arguments
+    ///
+    /// * `precision` - The number of decimal places that are significant to the digest
+    pub fn with_geometry_hash(mut self, precision: u8) -> Self {
+        self.geometry_hash = Some(precision);
+        self
+    }
+
+    /// Produce canonical output suitable for byte-wise diffing
+    ///
+    /// Pair with `stable::to_stable_string` to serialize the result.
+    pub fn with_stable_output(mut self, stable_output: bool) -> Self {
+        self.stable_output = stable_output;
+        self
+    }
+
+    /// Report what processing would do without transforming any coordinates
+    ///
+    /// Parsing, axis order correction, validation, null geometry handling,
+    /// explosion and coercion are planned exactly as in a real run and fail
+    /// with the same errors, but the output is an empty feature collection.
+    /// The planned per-feature actions are listed in `ProcessingReport::planned`.
+    /// Unless coordinate bounds are set, the transformer is still created to
+    /// inspect the source CRS.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Fail with `ProjectionError::DuplicateIds` when output features share an id
+    ///
+    /// The check runs on the processed output, so parts of an exploded feature
+    /// that keep its numeric id count as duplicates. String and numeric ids
+    /// never match each other.
+    pub fn with_require_unique_ids(mut self, require_unique_ids: bool) -> Self {
+        self.require_unique_ids = require_unique_ids;
+        self
+    }
+
+    /// Fail with `ProjectionError::VertexBudgetExceeded` when a feature has more vertices
+    ///
+    /// Vertices are counted before anything is projected, so oversized input
+    /// is rejected without creating a transformer.
+    pub fn with_max_vertices_per_feature(mut self, limit: usize) -> Self {
+        self.max_vertices_per_feature = Some(limit);
+        self
+    }
+
+    /// Fail with `ProjectionError::VertexBudgetExceeded` when the input has more vertices
+    ///
+    /// Counted together with the per-feature limit, before any projection.
+    pub fn with_max_vertices_total(mut self, limit: usize) -> Self {
+        self.max_vertices_total = Some(limit);
+        self
+    }
+
+    /// Fail with `ProjectionError::Timeout` when a feature takes longer to process
+    ///
+    /// The time is checked after every batch of 1000 projected coordinates
+    /// and once more after the feature, so a large feature is interrupted
+    /// within one batch. Set a lenient error policy to drop such features
+    /// instead of aborting.
+    pub fn with_feature_timeout(mut self, timeout: Duration) -> Self {
+        self.feature_timeout = Some(timeout);
+        self
+    }
+
+    /// Fail with `ProjectionError::Cancelled` once a token is cancelled
+    ///
+    /// Like the feature timeout, the token is checked after every batch of
+    /// 1000 projected coordinates and after every feature. A cancelled job
+    /// always fails, whatever the error policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - The token, usually a clone of one kept by the caller
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Choose whether a failing feature aborts processing or is dropped
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - How features that fail while being processed are handled
+    pub fn with_error_policy(mut self, policy: ErrorPolicy) -> Self {
+        self.error_policy = policy;
+        self
+    }
+
+    /// Record the progress of line-delimited jobs so they can be resumed
+    ///
+    /// Only `ndjson::NdjsonJob` writes checkpoints, the other entry points
+    /// ignore this option.
+    pub fn with_checkpoint(mut self, checkpoint: CheckpointConfig) -> Self {
+        self.checkpoint = Some(checkpoint);
+        self
+    }
+
+    /// Insert vertices into segments longer than `densify.max_segment_km` before projecting
+    ///
+    /// Long segments stay straight in the source CRS and become straight
+    /// chords in the target. Densifying first lets them follow the curve of
+    /// a parallel or, with `DensifyMethod::GreatCircle`, a great circle.
+    pub fn with_densify(mut self, densify: Densify) -> Self {
+        self.densify = Some(densify);
+        self
+    }
+
+    /// Reject input that does not conform to RFC 7946 before processing it
+    ///
+    /// Unclosed rings, too short lines and rings, positions outside the WGS84
+    /// range and malformed bounding boxes fail with
+    /// `ProjectionError::NotRfc7946`, regardless of `ring_closure`.
+    pub fn with_strict_rfc7946(mut self, strict_rfc7946: bool) -> Self {
+        self.strict_rfc7946 = strict_rfc7946;
+        self
+    }
+
+    /// Annotate every output feature with the distortion at its centroid
+    ///
+    /// The keys are the `*_KEY` constants of the `distortion` module. The
+    /// source CRS must be geographic and the target projected.
+    pub fn with_distortion(mut self, distortion: bool) -> Self {
+        self.distortion = distortion;
+        self
+    }
+
+    /// Apply a function to the geometry of every feature after projection
+    ///
+    /// The hook runs right after projection, before the simplification and
+    /// precision configured on the transformer and before coercion,
+    /// exploding, measuring and serialization. It can change the geometry in
+    /// place and read the index, id and properties of the feature. An error
+    /// from the hook aborts processing with `ProjectionError::PostTransformHook`,
+    /// or drops the feature with a warning under `ErrorPolicy::Lenient`.
+    ///
+    /// # Arguments
+    ///
+    /// * `hook` - The function to apply
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::helpers::process_feature_collection_with_options;
+    /// use proj_exercise_simple::options::ProcessingOptions;
+    /// use proj_exercise_simple::transformer::TransformerConfig;
+    ///
+    /// // Shift every output geometry 100 meters east
+    /// let options = ProcessingOptions::new().with_post_transform_hook(|geometry, _| {
+    ///     geometry.map_coords_in_place(|c| geo::Coord { x: c.x + 100.0, y: c.y });
+    ///     Ok(())
+    /// });
+    /// let input = serde_json::json!({ "type": "Point", "coordinates": [0.0, 0.0] });
+    /// let output =
+    ///     process_feature_collection_with_options(input, &mut TransformerConfig::default(), &options)
+    ///         .unwrap();
+    /// assert!(output.geojson.to_string().contains("[100.0,0.0]"));
+    /// ```
+    pub fn with_post_transform_hook(
+        mut self,
+        hook: impl Fn(&mut ProcessedGeometry, &FeatureContext) -> Result<(), HookError>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        self.post_transform_hook = Some(PostTransformHook(Arc::new(hook)));
+        self
+    }
+
+    /// Choose the simplification epsilon of each feature, for scale-dependent generalization
+    ///
+    /// The function sees the input feature, before projection, and returns
+    /// the epsilon in target CRS units. Features it returns `None` for use
+    /// `TransformerConfig::simplification_epsilon`. Bare geometries have no
+    /// feature and always use the default.
+    ///
+    /// # Arguments
+    ///
+    /// * `select` - The function choosing the epsilon
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::options::ProcessingOptions;
+    ///
+    /// // Less important features are generalized more
+    /// let options = ProcessingOptions::new().with_feature_simplification(|feature| {
+    ///     let importance = feature.property("importance")?.as_f64()?;
+    ///     Some(10_000.0 / importance)
+    /// });
+    /// ```
+    pub fn with_feature_simplification(
+        mut self,
+        select: impl Fn(&Feature) -> Option<f64> + Send + Sync + 'static,
+    ) -> Self {
+        self.feature_simplification = Some(EpsilonSelector(Arc::new(select)));
+        self
+    }
+
+    /// Sort the output features of a collection
+    ///
+    /// Sorting runs after projection and the geometry hash stage, so sorting
+    /// by `SortKey::GeometryHash` orders by the projected geometry, quantized
+    /// like `with_geometry_hash` or to `order::DEFAULT_HASH_PRECISION`
+    /// decimals when it is unset. It needs the whole collection, so
+    /// `ndjson::NdjsonJob` rejects it.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - What to sort by
+    pub fn with_sort_by(mut self, key: SortKey) -> Self {
+        self.sort_by = Some(key);
+        self
+    }
+
+    /// Drop output features whose geometry and properties equal those of an earlier feature
+    ///
+    /// Geometries are compared by their digest, quantized like
+    /// `with_geometry_hash` or to `order::DEFAULT_HASH_PRECISION` decimals
+    /// when it is unset. Ids are not compared. The number of dropped features
+    /// is reported in `ProcessingReport::duplicates_removed`. Deduplication
+    /// runs before sorting, so the first of equal features in input order is
+    /// kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `dedupe` - Whether to drop duplicates
+    pub fn with_dedupe(mut self, dedupe: bool) -> Self {
+        self.dedupe = dedupe;
+        self
+    }
+}
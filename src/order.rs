@@ -0,0 +1,147 @@
+use crate::digest::geojson_geometry_digest;
+use crate::error::ProjectionError;
+use geojson::feature::Id;
+use geojson::{Feature, GeoJson, JsonObject, JsonValue};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+/// The decimals significant to geometry digests when `ProcessingOptions::geometry_hash` is unset
+pub const DEFAULT_HASH_PRECISION: u8 = 6;
+
+/// What output features are sorted by
+///
+/// Features without a value for the key are placed last. Ties keep their
+/// input order.
+///
+/// # Variants
+///
+/// * `Id` - The feature id, numbers before strings
+/// * `Property` - The value of a property, ordered booleans, numbers, strings, then arrays and objects by their JSON text
+/// * `GeometryHash` - The digest of the projected geometry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SortKey {
+    Id,
+    Property(String),
+    GeometryHash,
+}
+
+/// The digest of a feature's geometry, `None` without a geometry
+fn feature_digest(feature: &Feature, precision: u8) -> Result<Option<u64>, ProjectionError> {
+    feature
+        .geometry
+        .as_ref()
+        .map(|geometry| geojson_geometry_digest(geometry, precision))
+        .transpose()
+}
+
+/// Compare two optional values, with `None` last
+fn none_last<T>(a: Option<T>, b: Option<T>, compare: impl Fn(T, T) -> Ordering) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => compare(a, b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+fn compare_ids(a: &Id, b: &Id) -> Ordering {
+    match (a, b) {
+        (Id::Number(a), Id::Number(b)) => none_last(a.as_f64(), b.as_f64(), |a, b| a.total_cmp(&b)),
+        (Id::Number(_), Id::String(_)) => Ordering::Less,
+        (Id::String(_), Id::Number(_)) => Ordering::Greater,
+        (Id::String(a), Id::String(b)) => a.cmp(b),
+    }
+}
+
+fn compare_values(a: &JsonValue, b: &JsonValue) -> Ordering {
+    let rank = |value: &JsonValue| match value {
+        JsonValue::Bool(_) => 0,
+        JsonValue::Number(_) => 1,
+        JsonValue::String(_) => 2,
+        JsonValue::Array(_) | JsonValue::Object(_) => 3,
+        JsonValue::Null => 4,
+    };
+    match (a, b) {
+        (JsonValue::Bool(a), JsonValue::Bool(b)) => a.cmp(b),
+        (JsonValue::Number(a), JsonValue::Number(b)) => {
+            none_last(a.as_f64(), b.as_f64(), |a, b| a.total_cmp(&b))
+        }
+        (JsonValue::String(a), JsonValue::String(b)) => a.cmp(b),
+        _ if rank(a) == 3 && rank(b) == 3 => a.to_string().cmp(&b.to_string()),
+        _ => rank(a).cmp(&rank(b)),
+    }
+}
+
+/// Sort the features of a collection by a key
+///
+/// # Arguments
+///
+/// * `geojson` - The processed GeoJSON, changed in place
+/// * `key` - What to sort by
+/// * `precision` - The decimals significant to geometry digests
+///
+/// # Returns
+///
+/// * `Result<(), ProjectionError>` - An error if a geometry cannot be digested
+pub(crate) fn sort_features(
+    geojson: &mut GeoJson,
+    key: &SortKey,
+    precision: u8,
+) -> Result<(), ProjectionError> {
+    let GeoJson::FeatureCollection(collection) = geojson else {
+        return Ok(());
+    };
+    match key {
+        SortKey::Id => collection
+            .features
+            .sort_by(|a, b| none_last(a.id.as_ref(), b.id.as_ref(), compare_ids)),
+        SortKey::Property(name) => collection
+            .features
+            .sort_by(|a, b| none_last(a.property(name), b.property(name), compare_values)),
+        SortKey::GeometryHash => {
+            let mut keyed = std::mem::take(&mut collection.features)
+                .into_iter()
+                .map(|feature| Ok((feature_digest(&feature, precision)?, feature)))
+                .collect::<Result<Vec<_>, ProjectionError>>()?;
+            keyed.sort_by(|a, b| none_last(a.0, b.0, |a, b| a.cmp(&b)));
+            collection.features = keyed.into_iter().map(|(_, feature)| feature).collect();
+        }
+    }
+    Ok(())
+}
+
+/// Drop features whose geometry digest and properties equal those of an earlier feature
+///
+/// Ids and foreign members are not compared.
+///
+/// # Arguments
+///
+/// * `geojson` - The processed GeoJSON, changed in place
+/// * `precision` - The decimals significant to geometry digests
+///
+/// # Returns
+///
+/// * `Result<usize, ProjectionError>` - The number of features dropped
+pub(crate) fn dedupe_features(
+    geojson: &mut GeoJson,
+    precision: u8,
+) -> Result<usize, ProjectionError> {
+    let GeoJson::FeatureCollection(collection) = geojson else {
+        return Ok(0);
+    };
+    let mut seen: HashMap<Option<u64>, Vec<Option<JsonObject>>> = HashMap::new();
+    let total = collection.features.len();
+    let mut kept = Vec::with_capacity(total);
+    for feature in std::mem::take(&mut collection.features) {
+        let emitted = seen
+            .entry(feature_digest(&feature, precision)?)
+            .or_default();
+        if !emitted.contains(&feature.properties) {
+            emitted.push(feature.properties.clone());
+            kept.push(feature);
+        }
+    }
+    let dropped = total - kept.len();
+    collection.features = kept;
+    Ok(dropped)
+}
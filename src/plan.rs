@@ -0,0 +1,128 @@
+use crate::coordinates::CoordinateBounds;
+use crate::error::ProjectionError;
+use crate::geometry_processor::check_input_geometry;
+use crate::helpers::{
+    shape_geometry, validate_all, without_geometry, ProcessedGeometry, ProcessingReport,
+    ShapedGeometry,
+};
+use crate::kind::HasGeometryKind;
+use crate::options::{ProcessingOptions, ValidationStrategy};
+use geojson::{Feature, GeoJson};
+
+/// Something a real run would do to a feature, found by a dry run
+///
+/// # Fields
+///
+/// * `feature_index` - The position of the feature in the input, 0 for a single feature or geometry
+/// * `description` - What would happen to the feature
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedAction {
+    pub feature_index: usize,
+    pub description: String,
+}
+
+impl PlannedAction {
+    pub fn new(feature_index: usize, description: impl Into<String>) -> Self {
+        Self {
+            feature_index,
+            description: description.into(),
+        }
+    }
+}
+
+/// Plan a single geometry: validate it and run the output stages on the unprojected shape
+fn plan_geometry(
+    geometry: &geojson::Geometry,
+    bounds: CoordinateBounds,
+    options: &ProcessingOptions,
+    feature_index: usize,
+    report: &mut ProcessingReport,
+) -> Result<(), ProjectionError> {
+    if options.validation == ValidationStrategy::PerGeometry {
+        check_input_geometry(&geometry.value, bounds)?;
+    }
+    let source_kind = geometry.geometry_kind();
+    let geometry = ProcessedGeometry::from_geo(geo::Geometry::try_from(geometry.value.clone())?);
+    match shape_geometry(geometry, options, feature_index)? {
+        ShapedGeometry::Parts(parts) => report.planned.push(PlannedAction::new(
+            feature_index,
+            format!("{source_kind} would be split into {} features", parts.len()),
+        )),
+        ShapedGeometry::Whole(shaped) if shaped.geometry_kind() != source_kind => {
+            report.planned.push(PlannedAction::new(
+                feature_index,
+                format!(
+                    "{source_kind} would be coerced to {}",
+                    shaped.geometry_kind()
+                ),
+            ))
+        }
+        ShapedGeometry::Whole(_) => {}
+    }
+    Ok(())
+}
+
+fn plan_feature(
+    feature: &Feature,
+    bounds: CoordinateBounds,
+    options: &ProcessingOptions,
+    feature_index: usize,
+    report: &mut ProcessingReport,
+) -> Result<(), ProjectionError> {
+    match &feature.geometry {
+        Some(geometry) => plan_geometry(geometry, bounds, options, feature_index, report),
+        None => {
+            let kept = without_geometry(
+                feature.clone(),
+                options.null_geometry,
+                feature_index,
+                &mut report.warnings,
+            )?;
+            if kept.is_some() {
+                report.planned.push(PlannedAction::new(
+                    feature_index,
+                    "feature without geometry would be passed through",
+                ));
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Run every check and planning step of the pipeline without projecting
+///
+/// Features are visited in input order and the first error a real run would
+/// hit is returned. Warnings are added to the report as in a real run, and
+/// the explode, coerce and pass-through decisions are listed as planned
+/// actions.
+///
+/// # Arguments
+///
+/// * `geojson` - The parsed, axis-corrected input
+/// * `bounds` - The valid input coordinate range
+/// * `options` - Processing options
+/// * `report` - The report receiving warnings and planned actions
+///
+/// # Returns
+///
+/// * `Result<(), ProjectionError>` - The error a real run would fail with
+pub(crate) fn plan_geojson(
+    geojson: &GeoJson,
+    bounds: CoordinateBounds,
+    options: &ProcessingOptions,
+    report: &mut ProcessingReport,
+) -> Result<(), ProjectionError> {
+    if options.validation == ValidationStrategy::EagerAll {
+        validate_all(geojson, bounds)?;
+    }
+    match geojson {
+        GeoJson::FeatureCollection(collection) => {
+            for (feature_index, feature) in collection.features.iter().enumerate() {
+                plan_feature(feature, bounds, options, feature_index, report)?;
+            }
+            Ok(())
+        }
+        GeoJson::Feature(feature) => plan_feature(feature, bounds, options, 0, report),
+        GeoJson::Geometry(geometry) => plan_geometry(geometry, bounds, options, 0, report),
+    }
+}
@@ -1,5 +1,6 @@
 use std::collections::VecDeque;
-use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, PoisonError};
 use thiserror::Error;
 
 use crate::coordinates::{Coordinate, Line};
@@ -7,15 +8,102 @@ use crate::coordinates::{Coordinate, Line};
 #[derive(Error, Debug)]
 pub enum BufferPoolError {
     #[error("Mutex was poisoned: {0}")]
-    MutexPoisoned(String),
-    #[error("Buffer pool is full")]
-    PoolFull,
+    MutexPoisoned(#[source] Box<dyn std::error::Error + Send + Sync>),
+    #[cfg(feature = "proj")]
     #[error("Projection error: {0}")]
     ProjError(#[from] proj::ProjCreateError),
 }
 
+impl<T> From<PoisonError<T>> for BufferPoolError {
+    /// Keep the poisoning as the error source, without the guard it holds
+    ///
+    /// The guard borrows the mutex, so the source is a `PoisonError<()>`.
+    fn from(_: PoisonError<T>) -> Self {
+        Self::MutexPoisoned(Box::new(PoisonError::new(())))
+    }
+}
+
+static DEFAULT_INITIAL_CAPACITY: AtomicUsize = AtomicUsize::new(10);
+static DEFAULT_MAX_SIZE: AtomicUsize = AtomicUsize::new(100);
+
+/// Set the parameters of the pools created by `CoordinateBufferPool::default`
+///
+/// The convenience functions in `helpers`, such as `process_feature_collection`,
+/// create their pool with these parameters. The setting is process wide and
+/// applies to pools created after the call.
+///
+/// # Arguments
+///
+/// * `initial_capacity` - The initial capacity of newly allocated buffers
+/// * `max_size` - The maximum number of buffers kept per queue
+///
+/// # Example
+///
+/// ```rust
+/// use proj_exercise_simple::pool::{default_pool_params, set_default_pool_params};
+///
+/// set_default_pool_params(1024, 16);
+/// assert_eq!(default_pool_params(), (1024, 16));
+/// ```
+pub fn set_default_pool_params(initial_capacity: usize, max_size: usize) {
+    DEFAULT_INITIAL_CAPACITY.store(initial_capacity, Ordering::Relaxed);
+    DEFAULT_MAX_SIZE.store(max_size, Ordering::Relaxed);
+}
+
+/// The parameters of the pools created by `CoordinateBufferPool::default`
+///
+/// # Returns
+///
+/// * `(usize, usize)` - The initial capacity and the maximum size, `(10, 100)` unless changed
+pub fn default_pool_params() -> (usize, usize) {
+    (
+        DEFAULT_INITIAL_CAPACITY.load(Ordering::Relaxed),
+        DEFAULT_MAX_SIZE.load(Ordering::Relaxed),
+    )
+}
+
+/// The kind of buffer a pool event concerns
+///
+/// # Variants
+///
+/// * `Point` - A buffer of coordinates
+/// * `Line` - A buffer of lines
+/// * `Polygon` - A buffer of polygon rings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BufferType {
+    Point,
+    Line,
+    Polygon,
+}
+
+/// Something that happened in a buffer pool, passed to its event hook
+///
+/// # Variants
+///
+/// * `Get` - A buffer was handed out, with its capacity
+/// * `Return` - A buffer was taken back into the pool, with its capacity. Buffers
+///   dropped because the pool is full are not reported
+/// * `Miss` - No pooled buffer could be reused, so a new one was allocated; followed by a `Get`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PoolEvent {
+    Get {
+        buffer_type: BufferType,
+        capacity: usize,
+    },
+    Return {
+        buffer_type: BufferType,
+        capacity: usize,
+    },
+    Miss,
+}
+
+type EventHook = dyn Fn(PoolEvent) + Send + Sync;
+
 /// A pool of coordinate buffers
 ///
+/// Buffers are never resized by the pool. A reused buffer keeps the capacity
+/// it grew to during its last use, so repeated retrievals do not inflate it.
+///
 /// # Fields
 ///
 /// * `point_buffers` - A mutex-protected deque of point buffers
@@ -23,24 +111,66 @@ pub enum BufferPoolError {
 /// * `polygon_buffers` - A mutex-protected deque of polygon buffers
 /// * `initial_capacity` - The initial capacity of the buffers
 /// * `max_size` - The maximum size of the pool
-/// * `growth_factor` - The growth factor for the buffers
 /// * `stats` - Statistics about the buffer pool
+/// * `event_hook` - A callback receiving every get, return and miss
 pub struct CoordinateBufferPool {
     pub point_buffers: Mutex<VecDeque<Vec<Coordinate>>>,
     pub line_buffers: Mutex<VecDeque<Vec<Line>>>,
     pub polygon_buffers: Mutex<VecDeque<Vec<Line>>>,
     initial_capacity: usize,
     max_size: usize,
-    growth_factor: f64,
     stats: Mutex<BufferPoolStats>,
+    event_hook: Option<Box<EventHook>>,
 }
 
+// The pool can be shared between threads, e.g. behind an `Arc`
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<CoordinateBufferPool>();
+};
+
 #[derive(Debug, Default, Clone)]
 pub struct BufferPoolStats {
     total_allocations: usize,
     total_deallocations: usize,
     peak_usage: usize,
     current_usage: usize,
+    prefilled_buffers: usize,
+}
+
+impl BufferPoolStats {
+    /// Number of point buffers handed out by the pool
+    pub fn total_allocations(&self) -> usize {
+        self.total_allocations
+    }
+
+    /// Number of point buffers returned to the pool
+    pub fn total_deallocations(&self) -> usize {
+        self.total_deallocations
+    }
+
+    /// Highest number of point buffers in use at the same time
+    pub fn peak_usage(&self) -> usize {
+        self.peak_usage
+    }
+
+    /// Number of point buffers currently in use
+    pub fn current_usage(&self) -> usize {
+        self.current_usage
+    }
+
+    /// Number of buffers eagerly created by `prefill`, across all queues
+    pub fn prefilled_buffers(&self) -> usize {
+        self.prefilled_buffers
+    }
+}
+
+impl Default for CoordinateBufferPool {
+    /// Create a buffer pool with the parameters set by `set_default_pool_params`
+    fn default() -> Self {
+        let (initial_capacity, max_size) = default_pool_params();
+        Self::new(initial_capacity, max_size)
+    }
 }
 
 impl CoordinateBufferPool {
@@ -61,142 +191,332 @@ impl CoordinateBufferPool {
             polygon_buffers: Mutex::new(VecDeque::new()),
             initial_capacity,
             max_size,
-            growth_factor: 1.5,
             stats: Mutex::new(BufferPoolStats::default()),
+            event_hook: None,
+        }
+    }
+
+    /// Set a callback invoked on every get and return
+    ///
+    /// The hook runs on the calling thread after the pool's locks are
+    /// released, so it may use the pool itself. It should be cheap, as it
+    /// runs for every buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `hook` - The callback receiving the events
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::pool::{CoordinateBufferPool, PoolEvent};
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    ///
+    /// let misses = Arc::new(AtomicUsize::new(0));
+    /// let counter = Arc::clone(&misses);
+    /// let pool = CoordinateBufferPool::new(10, 100).with_event_hook(move |event| {
+    ///     if event == PoolEvent::Miss {
+    ///         counter.fetch_add(1, Ordering::Relaxed);
+    ///     }
+    /// });
+    /// let buffer = pool.get_point_buffer().unwrap();
+    /// pool.return_point_buffer(buffer).unwrap();
+    /// pool.get_point_buffer().unwrap();
+    /// assert_eq!(misses.load(Ordering::Relaxed), 1);
+    /// ```
+    pub fn with_event_hook<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(PoolEvent) + Send + Sync + 'static,
+    {
+        self.event_hook = Some(Box::new(hook));
+        self
+    }
+
+    fn emit(&self, event: PoolEvent) {
+        if let Some(hook) = &self.event_hook {
+            hook(event);
+        }
+    }
+
+    /// Report the return of a buffer of each capacity
+    fn emit_returns(&self, buffer_type: BufferType, capacities: &[usize]) {
+        for &capacity in capacities {
+            self.emit(PoolEvent::Return {
+                buffer_type,
+                capacity,
+            });
+        }
+    }
+
+    /// Clear a pooled buffer or allocate a new one, and report it
+    fn hand_out<T>(
+        &self,
+        buffer_type: BufferType,
+        pooled: Option<Vec<T>>,
+        capacity: usize,
+    ) -> Vec<T> {
+        let buffer = match pooled {
+            Some(mut buffer) => {
+                buffer.clear();
+                buffer
+            }
+            None => {
+                self.emit(PoolEvent::Miss);
+                Vec::with_capacity(capacity)
+            }
+        };
+        self.emit(PoolEvent::Get {
+            buffer_type,
+            capacity: buffer.capacity(),
+        });
+        buffer
+    }
+
+    /// Eagerly fill every queue with empty buffers
+    ///
+    /// Each queue receives up to `count` buffers of `initial_capacity`, so the
+    /// first processing run can reuse buffers instead of allocating. Queues
+    /// are never filled beyond `max_size`.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - The number of buffers to add to each queue
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), BufferPoolError>` - An error if a mutex is poisoned
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::pool::CoordinateBufferPool;
+    ///
+    /// let pool = CoordinateBufferPool::new(10, 100);
+    /// pool.prefill(5).unwrap();
+    /// assert_eq!(pool.point_buffers.lock().unwrap().len(), 5);
+    /// ```
+    pub fn prefill(&self, count: usize) -> Result<(), BufferPoolError> {
+        let mut prefilled = 0;
+        {
+            let mut buffers = self.point_buffers.lock()?;
+            let missing = count.min(self.max_size).saturating_sub(buffers.len());
+            buffers.extend((0..missing).map(|_| Vec::with_capacity(self.initial_capacity)));
+            prefilled += missing;
         }
+        for queue in [&self.line_buffers, &self.polygon_buffers] {
+            let mut buffers = queue.lock()?;
+            let missing = count.min(self.max_size).saturating_sub(buffers.len());
+            buffers.extend((0..missing).map(|_| Vec::with_capacity(self.initial_capacity)));
+            prefilled += missing;
+        }
+
+        let mut stats = self.stats.lock()?;
+        stats.prefilled_buffers += prefilled;
+        Ok(())
     }
 
     /// Update the statistics for the buffer pool
     ///
     /// # Arguments
     ///
-    /// * `delta` - The change in the number of buffers
+    /// * `delta` - The change in the number of buffers in use
     ///
     /// # Returns
     ///
     /// * `Result<(), BufferPoolError>` - An error if the mutex is poisoned
     fn update_stats(&self, delta: isize) -> Result<(), BufferPoolError> {
-        let mut stats = self
-            .stats
-            .lock()
-            .map_err(|e| BufferPoolError::MutexPoisoned(e.to_string()))?;
+        let mut stats = self.stats.lock()?;
 
         stats.current_usage = (stats.current_usage as isize + delta) as usize;
         stats.peak_usage = stats.peak_usage.max(stats.current_usage);
         if delta > 0 {
-            stats.total_allocations += 1;
+            stats.total_allocations += delta.unsigned_abs();
         } else {
-            stats.total_deallocations += 1;
+            stats.total_deallocations += delta.unsigned_abs();
         }
         Ok(())
     }
 
-    /// Resize a buffer
+    /// Get a buffer for a point
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<Coordinate>, BufferPoolError>` - A buffer for a point
+    pub fn get_point_buffer(&self) -> Result<Vec<Coordinate>, BufferPoolError> {
+        let pooled = self.point_buffers.lock()?.pop_front();
+        let buffer = self.hand_out(BufferType::Point, pooled, self.initial_capacity);
+
+        self.update_stats(1)?;
+        Ok(buffer)
+    }
+
+    /// Get a buffer for a point with room for at least `min_cap` coordinates
+    ///
+    /// The pool is scanned for the first buffer with enough capacity, which
+    /// takes time linear in the number of pooled buffers. If there is none, a
+    /// new buffer of `min_cap` capacity is allocated and the pooled buffers
+    /// are left for smaller requests.
     ///
     /// # Arguments
     ///
-    /// * `buffer` - The buffer to resize
-    /// * `current_capacity` - The current capacity of the buffer
+    /// * `min_cap` - The number of coordinates the buffer must hold without reallocating
     ///
     /// # Returns
     ///
-    /// * `usize` - The new capacity of the buffer
-    /// * `T` - The type of the buffer
-    /// * `Result<usize, BufferPoolError>` - An error if the mutex is poisoned
-    fn resize_buffer<T>(&self, buffer: &mut Vec<T>, current_capacity: usize) -> usize {
-        let new_capacity = (current_capacity as f64 * self.growth_factor) as usize;
-        buffer.reserve(new_capacity - current_capacity);
-        new_capacity
+    /// * `Result<Vec<Coordinate>, BufferPoolError>` - An empty buffer for a point
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::pool::CoordinateBufferPool;
+    ///
+    /// let pool = CoordinateBufferPool::new(10, 100);
+    /// pool.prefill(3).unwrap();
+    /// let buffer = pool.get_point_buffer_with_minimum_capacity(10_000).unwrap();
+    /// assert!(buffer.capacity() >= 10_000);
+    /// // The small pooled buffers were not used
+    /// assert_eq!(pool.point_buffers.lock().unwrap().len(), 3);
+    /// ```
+    pub fn get_point_buffer_with_minimum_capacity(
+        &self,
+        min_cap: usize,
+    ) -> Result<Vec<Coordinate>, BufferPoolError> {
+        let pooled = {
+            let mut buffers = self.point_buffers.lock()?;
+            buffers
+                .iter()
+                .position(|buffer| buffer.capacity() >= min_cap)
+                .and_then(|index| buffers.remove(index))
+        };
+        let buffer = self.hand_out(
+            BufferType::Point,
+            pooled,
+            min_cap.max(self.initial_capacity),
+        );
+
+        self.update_stats(1)?;
+        Ok(buffer)
     }
 
-    /// Get a buffer for a point
+    /// Get a buffer for a point, allocating a new one if none is pooled
+    ///
+    /// Unlike `get_point_buffer` this never fails: if the pool mutex is
+    /// poisoned the pooled buffers are left alone and a fresh buffer is
+    /// allocated instead.
     ///
     /// # Returns
     ///
-    /// * `Result<Vec<Coordinate>, BufferPoolError>` - A buffer for a point
-    pub fn get_point_buffer(&self) -> Result<Vec<Coordinate>, BufferPoolError> {
-        let mut buffers = self
+    /// * `Vec<Coordinate>` - An empty buffer for a point
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::pool::CoordinateBufferPool;
+    ///
+    /// let pool = CoordinateBufferPool::new(10, 1);
+    /// let first = pool.get_or_create_point_buffer();
+    /// let second = pool.get_or_create_point_buffer();
+    /// pool.return_point_buffer(first).unwrap();
+    /// // The pool is full, so the second buffer is dropped
+    /// pool.return_point_buffer(second).unwrap();
+    /// ```
+    pub fn get_or_create_point_buffer(&self) -> Vec<Coordinate> {
+        let pooled = self
             .point_buffers
             .lock()
-            .map_err(|e| BufferPoolError::MutexPoisoned(e.to_string()))?;
-
-        let buffer = if let Some(mut buffer) = buffers.pop_front() {
-            let capacity = buffer.capacity();
-            Self::resize_buffer(&self, &mut buffer, capacity);
-            buffer.clear();
-            buffer
-        } else {
-            Vec::with_capacity(self.initial_capacity)
-        };
-
-        self.update_stats(1)?;
-        Ok(buffer)
+            .ok()
+            .and_then(|mut buffers| buffers.pop_front());
+        // Statistics are best effort here, a poisoned stats mutex is not an error
+        let _ = self.update_stats(1);
+        self.hand_out(BufferType::Point, pooled, self.initial_capacity)
     }
 
     /// Return a buffer for a point
     ///
+    /// The pool keeps at most `max_size` point buffers. A buffer returned to a
+    /// full pool is dropped and not reported to the event hook.
+    ///
     /// # Arguments
     ///
     /// * `buffer` - The buffer to return
     /// * `Result<(), BufferPoolError>` - An error if the mutex is poisoned
-    pub fn return_point_buffer(&self, mut buffer: Vec<Coordinate>) -> Result<(), BufferPoolError> {
-        let mut buffers = self
-            .point_buffers
-            .lock()
-            .map_err(|e| BufferPoolError::MutexPoisoned(e.to_string()))?;
-
-        if buffers.len() >= self.max_size {
-            buffer.shrink_to_fit();
-            if buffers.len() >= self.max_size {
-                self.update_stats(-1)?;
-                return Err(BufferPoolError::PoolFull);
-            }
-        }
-
-        buffer.clear();
-        buffers.push_back(buffer);
+    pub fn return_point_buffer(&self, buffer: Vec<Coordinate>) -> Result<(), BufferPoolError> {
+        self.return_one(&self.point_buffers, BufferType::Point, buffer)?;
         self.update_stats(-1)?;
         Ok(())
     }
 
+    /// Return several buffers for points under a single lock
+    ///
+    /// Like `return_point_buffer` for each buffer, but the queue is locked
+    /// once, so no other thread sees part of the batch. Buffers beyond
+    /// `max_size` are dropped, and only the buffers kept are reported to the
+    /// event hook.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffers` - The buffers to return
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), BufferPoolError>` - An error if a mutex is poisoned
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::pool::CoordinateBufferPool;
+    ///
+    /// let pool = CoordinateBufferPool::new(10, 100);
+    /// let buffers = vec![pool.get_point_buffer().unwrap(), pool.get_point_buffer().unwrap()];
+    /// pool.return_point_buffers(buffers).unwrap();
+    /// assert_eq!(pool.point_buffers.lock().unwrap().len(), 2);
+    /// ```
+    pub fn return_point_buffers(
+        &self,
+        buffers: Vec<Vec<Coordinate>>,
+    ) -> Result<(), BufferPoolError> {
+        let count = buffers.len();
+        self.return_batch(&self.point_buffers, BufferType::Point, buffers)?;
+        self.update_stats(-(count as isize))?;
+        Ok(())
+    }
+
     /// Get a buffer for a line
     ///
     /// # Returns
     ///
     /// * `Result<Vec<Line>, BufferPoolError>` - A buffer for a line
     pub fn get_line_buffer(&self) -> Result<Vec<Line>, BufferPoolError> {
-        let mut buffers = self
-            .line_buffers
-            .lock()
-            .map_err(|e| BufferPoolError::MutexPoisoned(e.to_string()))?;
-
-        if let Some(mut buffer) = buffers.pop_front() {
-            buffer.clear();
-            Ok(buffer)
-        } else {
-            Ok(Vec::with_capacity(self.initial_capacity))
-        }
+        let pooled = self.line_buffers.lock()?.pop_front();
+        Ok(self.hand_out(BufferType::Line, pooled, self.initial_capacity))
     }
 
     /// Return a buffer for a line
     ///
+    /// Like `return_point_buffer`, a buffer returned to a full pool is dropped.
+    ///
     /// # Arguments
     ///
     /// * `buffer` - The buffer to return
     /// * `Result<(), BufferPoolError>` - An error if the mutex is poisoned
-    pub fn return_line_buffer(&self, mut buffer: Vec<Line>) -> Result<(), BufferPoolError> {
-        let mut buffers = self
-            .line_buffers
-            .lock()
-            .map_err(|e| BufferPoolError::MutexPoisoned(e.to_string()))?;
-
-        if buffers.len() >= self.max_size {
-            return Err(BufferPoolError::PoolFull);
-        }
+    pub fn return_line_buffer(&self, buffer: Vec<Line>) -> Result<(), BufferPoolError> {
+        self.return_one(&self.line_buffers, BufferType::Line, buffer)
+    }
 
-        buffer.clear();
-        buffers.push_back(buffer);
-        Ok(())
+    /// Return several buffers for lines under a single lock
+    ///
+    /// Like `return_point_buffers`, buffers beyond `max_size` are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffers` - The buffers to return
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), BufferPoolError>` - An error if the mutex is poisoned
+    pub fn return_line_buffers(&self, buffers: Vec<Vec<Line>>) -> Result<(), BufferPoolError> {
+        self.return_batch(&self.line_buffers, BufferType::Line, buffers)
     }
 
     /// Get a buffer for a polygon
@@ -205,37 +525,89 @@ impl CoordinateBufferPool {
     ///
     /// * `Result<Vec<Line>, BufferPoolError>` - A buffer for a polygon
     pub fn get_polygon_buffer(&self) -> Result<Vec<Line>, BufferPoolError> {
-        let mut buffers = self
-            .polygon_buffers
-            .lock()
-            .map_err(|e| BufferPoolError::MutexPoisoned(e.to_string()))?;
-
-        if let Some(mut buffer) = buffers.pop_front() {
-            buffer.clear();
-            Ok(buffer)
-        } else {
-            Ok(Vec::with_capacity(self.initial_capacity))
-        }
+        let pooled = self.polygon_buffers.lock()?.pop_front();
+        Ok(self.hand_out(BufferType::Polygon, pooled, self.initial_capacity))
     }
 
     /// Return a buffer for a polygon
     ///
+    /// Like `return_point_buffer`, a buffer returned to a full pool is dropped.
+    ///
     /// # Arguments
     ///
     /// * `buffer` - The buffer to return
     /// * `Result<(), BufferPoolError>` - An error if the mutex is poisoned
-    pub fn return_polygon_buffer(&self, mut buffer: Vec<Line>) -> Result<(), BufferPoolError> {
-        let mut buffers = self
-            .polygon_buffers
-            .lock()
-            .map_err(|e| BufferPoolError::MutexPoisoned(e.to_string()))?;
+    pub fn return_polygon_buffer(&self, buffer: Vec<Line>) -> Result<(), BufferPoolError> {
+        self.return_one(&self.polygon_buffers, BufferType::Polygon, buffer)
+    }
+
+    /// Return several buffers for polygons under a single lock
+    ///
+    /// Like `return_point_buffers`, buffers beyond `max_size` are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffers` - The buffers to return
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), BufferPoolError>` - An error if the mutex is poisoned
+    pub fn return_polygon_buffers(&self, buffers: Vec<Vec<Line>>) -> Result<(), BufferPoolError> {
+        self.return_batch(&self.polygon_buffers, BufferType::Polygon, buffers)
+    }
 
-        if buffers.len() >= self.max_size {
-            return Err(BufferPoolError::PoolFull);
+    /// Return a buffer to a queue, dropping it when the queue is full
+    ///
+    /// Only a buffer the pool keeps is reported to the event hook.
+    fn return_one<T>(
+        &self,
+        queue: &Mutex<VecDeque<Vec<T>>>,
+        buffer_type: BufferType,
+        mut buffer: Vec<T>,
+    ) -> Result<(), BufferPoolError> {
+        let capacity = buffer.capacity();
+        {
+            let mut buffers = queue.lock()?;
+            if buffers.len() >= self.max_size {
+                return Ok(());
+            }
+            buffer.clear();
+            buffers.push_back(buffer);
+            #[cfg(feature = "tracing")]
+            tracing::event!(
+                tracing::Level::TRACE,
+                ?buffer_type,
+                pooled = buffers.len(),
+                "buffer returned to pool"
+            );
         }
+        self.emit(PoolEvent::Return {
+            buffer_type,
+            capacity,
+        });
+        Ok(())
+    }
 
-        buffer.clear();
-        buffers.push_back(buffer);
+    /// Return a batch of buffers to a queue, dropping those beyond `max_size`
+    ///
+    /// Like `return_one`, only the buffers the pool keeps are reported.
+    fn return_batch<T>(
+        &self,
+        queue: &Mutex<VecDeque<Vec<T>>>,
+        buffer_type: BufferType,
+        buffers: Vec<Vec<T>>,
+    ) -> Result<(), BufferPoolError> {
+        let mut kept = Vec::with_capacity(buffers.len());
+        {
+            let mut pooled = queue.lock()?;
+            let room = self.max_size.saturating_sub(pooled.len());
+            for mut buffer in buffers.into_iter().take(room) {
+                kept.push(buffer.capacity());
+                buffer.clear();
+                pooled.push_back(buffer);
+            }
+        }
+        self.emit_returns(buffer_type, &kept);
         Ok(())
     }
 
@@ -245,18 +617,9 @@ impl CoordinateBufferPool {
     ///
     /// * `Result<(), BufferPoolError>` - An error if the mutex is poisoned
     pub fn clear(&self) -> Result<(), BufferPoolError> {
-        let mut point_buffers = self
-            .point_buffers
-            .lock()
-            .map_err(|e| BufferPoolError::MutexPoisoned(e.to_string()))?;
-        let mut line_buffers = self
-            .line_buffers
-            .lock()
-            .map_err(|e| BufferPoolError::MutexPoisoned(e.to_string()))?;
-        let mut polygon_buffers = self
-            .polygon_buffers
-            .lock()
-            .map_err(|e| BufferPoolError::MutexPoisoned(e.to_string()))?;
+        let mut point_buffers = self.point_buffers.lock()?;
+        let mut line_buffers = self.line_buffers.lock()?;
+        let mut polygon_buffers = self.polygon_buffers.lock()?;
 
         point_buffers.clear();
         line_buffers.clear();
@@ -271,10 +634,7 @@ impl CoordinateBufferPool {
     ///
     /// * `Result<BufferPoolStats, BufferPoolError>` - Statistics about the buffer pool
     pub fn stats(&self) -> Result<BufferPoolStats, BufferPoolError> {
-        let stats = self
-            .stats
-            .lock()
-            .map_err(|e| BufferPoolError::MutexPoisoned(e.to_string()))?;
+        let stats = self.stats.lock()?;
         Ok(stats.clone())
     }
 }
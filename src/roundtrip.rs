@@ -0,0 +1,235 @@
+use crate::error::ProjectionError;
+use crate::transformer::TransformerConfig;
+use crate::vertices::{for_each_feature_position, for_each_position};
+use geojson::feature::Id;
+use geojson::GeoJson;
+
+/// Summary statistics of positional errors, in source CRS units
+///
+/// # Fields
+///
+/// * `vertex_count` - The number of measured vertices
+/// * `max` - The largest error
+/// * `mean` - The mean error
+/// * `p50` - The median error
+/// * `p95` - The 95th percentile error
+/// * `p99` - The 99th percentile error
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ErrorSummary {
+    pub vertex_count: usize,
+    pub max: f64,
+    pub mean: f64,
+    pub p50: f64,
+    pub p95: f64,
+    pub p99: f64,
+}
+
+impl ErrorSummary {
+    /// Summarize a list of errors, all statistics are zero for an empty list
+    fn from_errors(mut errors: Vec<f64>) -> Self {
+        if errors.is_empty() {
+            return Self::default();
+        }
+        errors.sort_by(f64::total_cmp);
+        // Nearest-rank percentile on the sorted errors
+        let percentile = |p: f64| errors[((p * errors.len() as f64).ceil() as usize).max(1) - 1];
+        Self {
+            vertex_count: errors.len(),
+            max: errors[errors.len() - 1],
+            mean: errors.iter().sum::<f64>() / errors.len() as f64,
+            p50: percentile(0.5),
+            p95: percentile(0.95),
+            p99: percentile(0.99),
+        }
+    }
+}
+
+/// Round-trip errors of a single feature
+///
+/// # Fields
+///
+/// * `index` - The position of the feature in the collection
+/// * `id` - The id of the feature, if it has one
+/// * `error` - The error statistics of the feature's vertices
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeatureRoundTrip {
+    pub index: usize,
+    pub id: Option<Id>,
+    pub error: ErrorSummary,
+}
+
+/// The result of projecting data forward and back again
+///
+/// # Fields
+///
+/// * `overall` - The error statistics over all vertices
+/// * `features` - The error statistics per feature
+/// * `worst_feature` - The index of the feature with the largest max error
+/// * `worst_feature_id` - The id of that feature, if it has one
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoundTripReport {
+    pub overall: ErrorSummary,
+    pub features: Vec<FeatureRoundTrip>,
+    pub worst_feature: Option<usize>,
+    pub worst_feature_id: Option<Id>,
+}
+
+/// Measure how much a forward and inverse projection displaces the input vertices
+///
+/// Every vertex is projected with the configured transformer, rounded to the
+/// configured precision like the processing output, and projected back with
+/// the inverse transformer. The error of a vertex is the euclidean distance
+/// between its original and round-tripped position in source CRS units.
+///
+/// # Arguments
+///
+/// * `collection` - The GeoJSON data to measure, a single feature or geometry counts as feature 0
+/// * `config` - A transformer config
+///
+/// # Returns
+///
+/// * `Result<RoundTripReport, ProjectionError>` - The error statistics
+///
+/// # Example
+///
+/// ```rust
+/// use geojson::{GeoJson, Geometry, Value};
+/// use proj_exercise_simple::roundtrip::measure_roundtrip_error;
+/// use proj_exercise_simple::transformer::TransformerConfig;
+///
+/// let geojson = GeoJson::from(Geometry::new(Value::Point(vec![13.377, 52.518])));
+/// let config = TransformerConfig::default();
+/// let report = measure_roundtrip_error(&geojson, &config).unwrap();
+/// assert_eq!(report.overall.vertex_count, 1);
+/// assert!(report.overall.max < 1e-9);
+/// ```
+pub fn measure_roundtrip_error(
+    collection: &GeoJson,
+    config: &TransformerConfig,
+) -> Result<RoundTripReport, ProjectionError> {
+    let forward = config.get_transformer()?;
+    let inverse = config.get_inverse_transformer()?;
+
+    let ids: Vec<Option<Id>> = match collection {
+        GeoJson::FeatureCollection(collection) => {
+            collection.features.iter().map(|f| f.id.clone()).collect()
+        }
+        GeoJson::Feature(feature) => vec![feature.id.clone()],
+        GeoJson::Geometry(_) => vec![None],
+    };
+
+    let mut per_feature: Vec<Vec<f64>> = vec![Vec::new(); ids.len()];
+    let mut result = Ok(());
+    for_each_feature_position(collection, |index, position| {
+        if result.is_err() {
+            return;
+        }
+        let (x, y) = (position[0], position[1]);
+        let measured = forward.convert((x, y)).and_then(|(px, py)| {
+            inverse.convert((config.round_output(px), config.round_output(py)))
+        });
+        match measured {
+            Ok((rx, ry)) => per_feature[index].push((rx - x).hypot(ry - y)),
+            Err(e) => result = Err(e),
+        }
+    });
+    result?;
+
+    let overall = ErrorSummary::from_errors(per_feature.iter().flatten().copied().collect());
+    let features: Vec<FeatureRoundTrip> = per_feature
+        .into_iter()
+        .zip(ids)
+        .enumerate()
+        .map(|(index, (errors, id))| FeatureRoundTrip {
+            index,
+            id,
+            error: ErrorSummary::from_errors(errors),
+        })
+        .collect();
+    let worst = features
+        .iter()
+        .filter(|f| f.error.vertex_count > 0)
+        .max_by(|a, b| a.error.max.total_cmp(&b.error.max));
+
+    Ok(RoundTripReport {
+        overall,
+        worst_feature: worst.map(|f| f.index),
+        worst_feature_id: worst.and_then(|f| f.id.clone()),
+        features,
+    })
+}
+
+/// The largest displacement of a geometry's vertices after a forward and inverse projection
+///
+/// All vertices are projected in one batch, rounded to the configured
+/// precision, and projected back in a second batch.
+///
+/// # Arguments
+///
+/// * `geometry` - A GeoJSON geometry in the source CRS
+/// * `config` - A transformer config
+///
+/// # Returns
+///
+/// * `Result<f64, ProjectionError>` - The worst-case deviation in source CRS units, 0 without vertices
+pub fn max_roundtrip_deviation(
+    geometry: &geojson::Geometry,
+    config: &TransformerConfig,
+) -> Result<f64, ProjectionError> {
+    let mut original: Vec<(f64, f64)> = Vec::new();
+    for_each_position(&geometry.value, &mut |p| original.push((p[0], p[1])));
+    if original.is_empty() {
+        return Ok(0.0);
+    }
+
+    let mut points = original.clone();
+    config.get_transformer()?.convert_array(&mut points)?;
+    for point in &mut points {
+        *point = (config.round_output(point.0), config.round_output(point.1));
+    }
+    config
+        .get_inverse_transformer()?
+        .convert_array(&mut points)?;
+
+    Ok(original
+        .iter()
+        .zip(&points)
+        .map(|(a, b)| (a.0 - b.0).hypot(a.1 - b.1))
+        .fold(0.0, f64::max))
+}
+
+/// Check that every vertex of a geometry survives a forward and inverse projection
+///
+/// The worst-case deviation is logged at debug level; use
+/// `max_roundtrip_deviation` to get the value itself.
+///
+/// # Arguments
+///
+/// * `geometry` - A GeoJSON geometry in the source CRS
+/// * `config` - A transformer config
+/// * `tolerance` - The largest acceptable deviation in source CRS units
+///
+/// # Returns
+///
+/// * `Result<bool, ProjectionError>` - Whether all vertices return within `tolerance`
+///
+/// # Example
+///
+/// ```rust
+/// use geojson::{Geometry, Value};
+/// use proj_exercise_simple::roundtrip::verify_roundtrip;
+/// use proj_exercise_simple::transformer::TransformerConfig;
+///
+/// let geometry = Geometry::new(Value::Point(vec![13.377, 52.518]));
+/// let config = TransformerConfig::default();
+/// assert!(verify_roundtrip(&geometry, &config, 1e-6).unwrap());
+/// ```
+pub fn verify_roundtrip(
+    geometry: &geojson::Geometry,
+    config: &TransformerConfig,
+    tolerance: f64,
+) -> Result<bool, ProjectionError> {
+    let deviation = max_roundtrip_deviation(geometry, config)?;
+    log::debug!("Worst round-trip deviation: {deviation}");
+    Ok(deviation <= tolerance)
+}
@@ -0,0 +1,281 @@
+use geojson::{GeoJson, JsonObject, JsonValue};
+use std::collections::BTreeMap;
+
+/// The JSON type of a property value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PropertyType {
+    Null,
+    Bool,
+    Number,
+    String,
+    Array,
+    Object,
+}
+
+impl PropertyType {
+    /// The type of a JSON value
+    pub fn of(value: &JsonValue) -> Self {
+        match value {
+            JsonValue::Null => PropertyType::Null,
+            JsonValue::Bool(_) => PropertyType::Bool,
+            JsonValue::Number(_) => PropertyType::Number,
+            JsonValue::String(_) => PropertyType::String,
+            JsonValue::Array(_) => PropertyType::Array,
+            JsonValue::Object(_) => PropertyType::Object,
+        }
+    }
+}
+
+/// Observed values of a single property key
+///
+/// # Fields
+///
+/// * `types` - The number of non-null values per JSON type
+/// * `null_count` - The number of explicit `null` values
+/// * `min` - The smallest numeric value, if any
+/// * `max` - The largest numeric value, if any
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PropertySchema {
+    pub types: BTreeMap<PropertyType, usize>,
+    pub null_count: usize,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+}
+
+impl PropertySchema {
+    fn observe(&mut self, value: &JsonValue) {
+        match value {
+            JsonValue::Null => self.null_count += 1,
+            _ => *self.types.entry(PropertyType::of(value)).or_default() += 1,
+        }
+        if let Some(number) = value.as_f64() {
+            self.min = Some(self.min.map_or(number, |min| min.min(number)));
+            self.max = Some(self.max.map_or(number, |max| max.max(number)));
+        }
+    }
+
+    /// Whether the key holds values of more than one non-null type
+    pub fn is_conflicting(&self) -> bool {
+        self.types.len() > 1
+    }
+
+    /// The most frequent non-null type, ties go to the type listed first in `PropertyType`
+    pub fn majority_type(&self) -> Option<PropertyType> {
+        self.types
+            .iter()
+            .max_by(|a, b| a.1.cmp(b.1).then(b.0.cmp(a.0)))
+            .map(|(property_type, _)| *property_type)
+    }
+}
+
+/// The property schema of a collection of features
+///
+/// # Fields
+///
+/// * `feature_count` - The number of scanned features
+/// * `properties` - The observed values per property key
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Schema {
+    pub feature_count: usize,
+    pub properties: BTreeMap<String, PropertySchema>,
+}
+
+impl Schema {
+    /// The keys holding values of more than one non-null type
+    pub fn conflicts(&self) -> Vec<&str> {
+        self.properties
+            .iter()
+            .filter(|(_, property)| property.is_conflicting())
+            .map(|(key, _)| key.as_str())
+            .collect()
+    }
+}
+
+/// A property value changed by `coerce_properties`
+///
+/// # Fields
+///
+/// * `feature_index` - The position of the feature in the collection
+/// * `key` - The property key
+/// * `from` - The original value
+/// * `to` - The coerced value
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertyChange {
+    pub feature_index: usize,
+    pub key: String,
+    pub from: JsonValue,
+    pub to: JsonValue,
+}
+
+/// The result of coercing properties to a schema
+///
+/// # Fields
+///
+/// * `changes` - The values that were converted
+/// * `unconverted` - The values that could not be converted safely and were left as they are
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CoercionReport {
+    pub changes: Vec<PropertyChange>,
+    pub unconverted: Vec<(usize, String)>,
+}
+
+/// The property maps of all features, a single `Feature` counts as feature 0
+fn properties_mut(geojson: &mut GeoJson) -> Vec<Option<&mut JsonObject>> {
+    match geojson {
+        GeoJson::FeatureCollection(collection) => collection
+            .features
+            .iter_mut()
+            .map(|feature| feature.properties.as_mut())
+            .collect(),
+        GeoJson::Feature(feature) => vec![feature.properties.as_mut()],
+        GeoJson::Geometry(_) => Vec::new(),
+    }
+}
+
+/// Scan all features and report the observed property schema
+///
+/// # Arguments
+///
+/// * `collection` - The GeoJSON data to scan, a single feature counts as a collection of one
+///
+/// # Returns
+///
+/// * `Schema` - The observed types, null counts and numeric ranges per key
+///
+/// # Example
+///
+/// ```rust
+/// use geojson::GeoJson;
+/// use proj_exercise_simple::schema::{infer_schema, PropertyType};
+///
+/// let geojson: GeoJson = r#"{"type": "FeatureCollection", "features": [
+///     {"type": "Feature", "properties": {"height": 12}, "geometry": null},
+///     {"type": "Feature", "properties": {"height": "8"}, "geometry": null}
+/// ]}"#.parse().unwrap();
+/// let schema = infer_schema(&geojson);
+/// assert_eq!(schema.conflicts(), vec!["height"]);
+/// assert_eq!(schema.properties["height"].types[&PropertyType::Number], 1);
+/// ```
+pub fn infer_schema(collection: &GeoJson) -> Schema {
+    let features: Vec<Option<&JsonObject>> = match collection {
+        GeoJson::FeatureCollection(collection) => collection
+            .features
+            .iter()
+            .map(|feature| feature.properties.as_ref())
+            .collect(),
+        GeoJson::Feature(feature) => vec![feature.properties.as_ref()],
+        GeoJson::Geometry(_) => Vec::new(),
+    };
+
+    let mut schema = Schema {
+        feature_count: features.len(),
+        ..Schema::default()
+    };
+    for (key, value) in features.into_iter().flatten().flatten() {
+        schema
+            .properties
+            .entry(key.clone())
+            .or_default()
+            .observe(value);
+    }
+    schema
+}
+
+/// Convert a value to the given type if no information is lost
+fn convert(value: &JsonValue, target: PropertyType) -> Option<JsonValue> {
+    match (value, target) {
+        (JsonValue::String(text), PropertyType::Number) => {
+            let text = text.trim();
+            if let Ok(integer) = text.parse::<i64>() {
+                Some(JsonValue::from(integer))
+            } else {
+                text.parse::<f64>()
+                    .ok()
+                    .and_then(serde_json::Number::from_f64)
+                    .map(JsonValue::Number)
+            }
+        }
+        (JsonValue::String(text), PropertyType::Bool) => match text.trim() {
+            "true" => Some(JsonValue::Bool(true)),
+            "false" => Some(JsonValue::Bool(false)),
+            _ => None,
+        },
+        (JsonValue::Number(number), PropertyType::String) => {
+            Some(JsonValue::String(number.to_string()))
+        }
+        (JsonValue::Bool(flag), PropertyType::String) => Some(JsonValue::String(flag.to_string())),
+        _ => None,
+    }
+}
+
+/// Normalize conflicting properties to their majority type
+///
+/// Only keys that the schema reports as conflicting are touched. Values are
+/// converted where this is lossless (numeric or boolean strings to numbers or
+/// booleans, numbers and booleans to strings); `null` values are kept and
+/// everything else is left unchanged and listed as unconverted.
+///
+/// # Arguments
+///
+/// * `collection` - The GeoJSON data to change in place
+/// * `schema` - The schema to coerce to, usually from `infer_schema`
+///
+/// # Returns
+///
+/// * `CoercionReport` - The changed and unconvertible values
+///
+/// # Example
+///
+/// ```rust
+/// use geojson::GeoJson;
+/// use proj_exercise_simple::schema::{coerce_properties, infer_schema};
+///
+/// let mut geojson: GeoJson = r#"{"type": "FeatureCollection", "features": [
+///     {"type": "Feature", "properties": {"height": 12}, "geometry": null},
+///     {"type": "Feature", "properties": {"height": 7}, "geometry": null},
+///     {"type": "Feature", "properties": {"height": "8"}, "geometry": null}
+/// ]}"#.parse().unwrap();
+/// let schema = infer_schema(&geojson);
+/// let report = coerce_properties(&mut geojson, &schema);
+/// assert_eq!(report.changes.len(), 1);
+/// assert!(infer_schema(&geojson).conflicts().is_empty());
+/// ```
+pub fn coerce_properties(collection: &mut GeoJson, schema: &Schema) -> CoercionReport {
+    let targets: BTreeMap<&str, PropertyType> = schema
+        .properties
+        .iter()
+        .filter(|(_, property)| property.is_conflicting())
+        .filter_map(|(key, property)| Some((key.as_str(), property.majority_type()?)))
+        .collect();
+
+    let mut report = CoercionReport::default();
+    if targets.is_empty() {
+        return report;
+    }
+    for (feature_index, properties) in properties_mut(collection).into_iter().enumerate() {
+        let Some(properties) = properties else {
+            continue;
+        };
+        for (key, value) in properties.iter_mut() {
+            let Some(&target) = targets.get(key.as_str()) else {
+                continue;
+            };
+            let current = PropertyType::of(value);
+            if current == target || current == PropertyType::Null {
+                continue;
+            }
+            match convert(value, target) {
+                Some(converted) => {
+                    report.changes.push(PropertyChange {
+                        feature_index,
+                        key: key.clone(),
+                        from: std::mem::replace(value, converted.clone()),
+                        to: converted,
+                    });
+                }
+                None => report.unconverted.push((feature_index, key.clone())),
+            }
+        }
+    }
+    report
+}
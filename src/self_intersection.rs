@@ -0,0 +1,274 @@
+use crate::coordinates::shoelace_signed_area;
+use crate::error::ProjectionError;
+use crate::helpers::{ProcessedGeometry, ProcessingWarning};
+use crate::validation::ring_crossings;
+use geo::line_intersection::LineIntersection;
+use geo::{Coord, LineString, Polygon};
+use std::collections::HashMap;
+
+/// How projected polygon rings that intersect themselves are handled
+///
+/// Checking costs O(n log n) per ring, so it is skipped by default.
+///
+/// # Variants
+///
+/// * `Ignore` - Do not check rings
+/// * `Warn` - Record a warning per self-intersecting ring
+/// * `Error` - Abort processing with `ProjectionError::SelfIntersectingRing`
+/// * `Repair` - Replace the ring by the largest loop it forms when split at its intersections
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SelfIntersectionPolicy {
+    #[default]
+    Ignore,
+    Warn,
+    Error,
+    Repair,
+}
+
+/// Whether a closed ring intersects itself
+///
+/// # Arguments
+///
+/// * `ring` - A closed ring
+///
+/// # Returns
+///
+/// * `bool` - Whether two segments that are not neighbours intersect
+pub fn ring_self_intersects(ring: &LineString<f64>) -> bool {
+    !ring_crossings(&ring.0).is_empty()
+}
+
+/// Repair a self-intersecting ring by keeping its largest loop
+///
+/// The ring is split at every point where it crosses or touches itself into
+/// loops that no longer do, and the loop enclosing the largest area is kept.
+/// The loop keeps the direction it has in the ring. A ring that does not
+/// intersect itself, or whose loops enclose no area, is returned unchanged.
+///
+/// # Arguments
+///
+/// * `ring` - A closed ring
+///
+/// # Returns
+///
+/// * `LineString<f64>` - The closed, simple ring
+///
+/// # Example
+///
+/// ```rust
+/// use geo::{Coord, LineString};
+/// use proj_exercise_simple::self_intersection::{repair_ring, ring_self_intersects};
+///
+/// // A bow-tie with a larger right loop
+/// let ring = LineString::from(vec![(0.0, 0.0), (3.0, 2.0), (3.0, 0.0), (0.0, 1.0), (0.0, 0.0)]);
+/// let repaired = repair_ring(&ring);
+/// assert!(!ring_self_intersects(&repaired));
+/// assert!(repaired.0.contains(&Coord { x: 3.0, y: 2.0 }));
+/// ```
+pub fn repair_ring(ring: &LineString<f64>) -> LineString<f64> {
+    let crossings = ring_crossings(&ring.0);
+    if crossings.is_empty() || !ring.is_closed() {
+        return ring.clone();
+    }
+    let largest = split_loops(&node_ring(&ring.0, &crossings))
+        .into_iter()
+        .map(|ring_loop| (loop_area(&ring_loop).abs(), ring_loop))
+        .max_by(|a, b| a.0.total_cmp(&b.0));
+    match largest {
+        Some((area, ring_loop)) if area > 0.0 => LineString::new(ring_loop),
+        _ => ring.clone(),
+    }
+}
+
+fn loop_area(coords: &[Coord<f64>]) -> f64 {
+    let points: Vec<(f64, f64)> = coords.iter().map(|c| (c.x, c.y)).collect();
+    shoelace_signed_area(&points)
+}
+
+/// Insert the intersection points into the ring, in order along each segment
+fn node_ring(
+    ring: &[Coord<f64>],
+    crossings: &[(usize, usize, LineIntersection<f64>)],
+) -> Vec<Coord<f64>> {
+    let mut nodes: Vec<Vec<Coord<f64>>> = vec![Vec::new(); ring.len() - 1];
+    for &(a, b, intersection) in crossings {
+        let points = match intersection {
+            LineIntersection::SinglePoint { intersection, .. } => vec![intersection],
+            LineIntersection::Collinear { intersection } => {
+                vec![intersection.start, intersection.end]
+            }
+        };
+        // Both segments receive the same coordinates so the loops close exactly
+        let ends = [ring[a], ring[a + 1], ring[b], ring[b + 1]];
+        for point in points.into_iter().map(|point| snap(point, &ends)) {
+            nodes[a].push(point);
+            nodes[b].push(point);
+        }
+    }
+
+    let mut noded: Vec<Coord<f64>> = Vec::with_capacity(ring.len() + 2 * crossings.len());
+    for (index, mut points) in nodes.into_iter().enumerate() {
+        let start = ring[index];
+        let along = |point: &Coord<f64>| (point.x - start.x).hypot(point.y - start.y);
+        points.sort_by(|p, q| along(p).total_cmp(&along(q)));
+        noded.push(start);
+        noded.extend(points);
+    }
+    noded.push(ring[ring.len() - 1]);
+    noded.dedup();
+    noded
+}
+
+/// Use a segment end point for an intersection that lies on it within rounding
+fn snap(point: Coord<f64>, ends: &[Coord<f64>]) -> Coord<f64> {
+    let tolerance = 1e-12 * point.x.abs().max(point.y.abs()).max(1.0);
+    ends.iter()
+        .copied()
+        .find(|end| (end.x - point.x).abs() <= tolerance && (end.y - point.y).abs() <= tolerance)
+        .unwrap_or(point)
+}
+
+/// Split a noded ring into closed loops at every repeated coordinate
+fn split_loops(noded: &[Coord<f64>]) -> Vec<Vec<Coord<f64>>> {
+    let key = |coord: &Coord<f64>| (coord.x.to_bits(), coord.y.to_bits());
+    let mut path: Vec<Coord<f64>> = Vec::with_capacity(noded.len());
+    let mut positions: HashMap<(u64, u64), usize> = HashMap::new();
+    let mut loops = Vec::new();
+    for &coord in noded {
+        match positions.get(&key(&coord)) {
+            Some(&start) => {
+                let mut ring_loop = path.split_off(start + 1);
+                for removed in &ring_loop {
+                    positions.remove(&key(removed));
+                }
+                ring_loop.insert(0, coord);
+                ring_loop.push(coord);
+                loops.push(ring_loop);
+            }
+            None => {
+                positions.insert(key(&coord), path.len());
+                path.push(coord);
+            }
+        }
+    }
+    loops
+}
+
+/// Apply a policy to one ring, returning whether it intersects itself
+fn check_ring(ring: &mut LineString<f64>, policy: SelfIntersectionPolicy, exterior: bool) -> bool {
+    if !ring_self_intersects(ring) {
+        return false;
+    }
+    if policy == SelfIntersectionPolicy::Repair {
+        let mut repaired = repair_ring(ring);
+        // Loops of a bow-tie run in opposite directions, orient like RFC 7946
+        if (loop_area(&repaired.0) > 0.0) != exterior {
+            repaired.0.reverse();
+        }
+        *ring = repaired;
+    }
+    true
+}
+
+/// Apply a policy to every ring of a polygon, collecting the indices of self-intersecting rings
+fn check_polygon(
+    polygon: &mut Polygon<f64>,
+    policy: SelfIntersectionPolicy,
+    polygon_index: &mut usize,
+    found: &mut Vec<(usize, usize)>,
+) {
+    let index = *polygon_index;
+    *polygon_index += 1;
+    let mut exterior_intersects = false;
+    polygon.exterior_mut(|ring| exterior_intersects = check_ring(ring, policy, true));
+    if exterior_intersects {
+        found.push((index, 0));
+    }
+    let mut holes = Vec::new();
+    polygon.interiors_mut(|rings| {
+        for (hole, ring) in rings.iter_mut().enumerate() {
+            if check_ring(ring, policy, false) {
+                holes.push((index, hole + 1));
+            }
+        }
+    });
+    found.extend(holes);
+}
+
+fn check_member(
+    geometry: &mut geo::Geometry<f64>,
+    policy: SelfIntersectionPolicy,
+    polygon_index: &mut usize,
+    found: &mut Vec<(usize, usize)>,
+) {
+    match geometry {
+        geo::Geometry::Polygon(polygon) => check_polygon(polygon, policy, polygon_index, found),
+        geo::Geometry::MultiPolygon(polygons) => polygons
+            .iter_mut()
+            .for_each(|polygon| check_polygon(polygon, policy, polygon_index, found)),
+        geo::Geometry::GeometryCollection(collection) => collection
+            .iter_mut()
+            .for_each(|member| check_member(member, policy, polygon_index, found)),
+        _ => {}
+    }
+}
+
+/// Check the polygon rings of a projected geometry for self-intersection
+///
+/// Polygons are counted in document order, ring 0 is the exterior and ring
+/// `n` the `n`th hole. Repaired exteriors are made counter-clockwise and
+/// repaired holes clockwise.
+///
+/// # Arguments
+///
+/// * `geometry` - The projected geometry, changed in place when repairing
+/// * `policy` - How self-intersecting rings are handled
+/// * `feature_index` - The index of the feature, used in errors and warnings
+/// * `warnings` - Receives a warning per self-intersecting ring under `Warn`
+///
+/// # Returns
+///
+/// * `Result<(), ProjectionError>` - An error for the first self-intersecting ring under `Error`
+pub(crate) fn check_self_intersections(
+    geometry: &mut ProcessedGeometry,
+    policy: SelfIntersectionPolicy,
+    feature_index: usize,
+    warnings: &mut Vec<ProcessingWarning>,
+) -> Result<(), ProjectionError> {
+    if policy == SelfIntersectionPolicy::Ignore {
+        return Ok(());
+    }
+    let mut found = Vec::new();
+    let mut polygon_index = 0;
+    match geometry {
+        ProcessedGeometry::Polygon(polygon) => {
+            check_polygon(polygon, policy, &mut polygon_index, &mut found)
+        }
+        ProcessedGeometry::MultiPolygon(polygons) => polygons
+            .iter_mut()
+            .for_each(|polygon| check_polygon(polygon, policy, &mut polygon_index, &mut found)),
+        ProcessedGeometry::GeometryCollection(collection) => collection
+            .iter_mut()
+            .for_each(|member| check_member(member, policy, &mut polygon_index, &mut found)),
+        _ => {}
+    }
+    match (policy, found.first()) {
+        (SelfIntersectionPolicy::Error, Some(&(polygon, ring))) => {
+            Err(ProjectionError::SelfIntersectingRing {
+                feature_index,
+                polygon,
+                ring,
+            })
+        }
+        (SelfIntersectionPolicy::Warn, _) => {
+            warnings.extend(found.into_iter().map(|(polygon, ring)| {
+                ProcessingWarning::new(
+                    feature_index,
+                    format!("Ring {ring} of polygon {polygon} intersects itself"),
+                )
+            }));
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
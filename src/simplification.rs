@@ -1,4 +1,9 @@
-use geo::{LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon};
+use crate::coordinates::{Coordinate, Line};
+use crate::helpers::ProcessedGeometry;
+use geo::{
+    Area, BoundingRect, Geometry, GeometryCollection, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon,
+};
 /// Simplifies a geometry using the Douglas-Peucker algorithm
 pub trait Simplify {
     fn simplify(&self, epsilon: f64) -> Self;
@@ -54,6 +59,24 @@ impl Simplify for LineString {
     }
 }
 
+impl Simplify for Line {
+    fn simplify(&self, epsilon: f64) -> Self {
+        let coords: Vec<geo::Coord<f64>> = self
+            .coordinates
+            .iter()
+            .map(|c| geo::Coord { x: c.x, y: c.y })
+            .collect();
+        let mut simplified = Vec::new();
+        douglas_peucker(&coords, epsilon, &mut simplified);
+        Line::new(
+            simplified
+                .into_iter()
+                .map(|c| Coordinate::new(c.x, c.y))
+                .collect(),
+        )
+    }
+}
+
 impl Simplify for Polygon {
     fn simplify(&self, epsilon: f64) -> Self {
         let mut simplified_exterior = self.exterior().0.clone();
@@ -74,6 +97,8 @@ impl Simplify for Polygon {
             }
             simplified_exterior = result;
         }
+        // Holes that collapse are kept, in order, for
+        // `ProcessedGeometry::remove_degenerate_holes` to remove and report
         let mut simplified_interiors = Vec::new();
         for interior in self.interiors() {
             let mut simplified_interior = interior.0.clone();
@@ -86,10 +111,9 @@ impl Simplify for Polygon {
                 if result.len() > 1 && result.first() != result.last() {
                     result.push(*result.first().unwrap());
                 }
-                if result.len() >= 3 {
-                    simplified_interiors.push(LineString::from(result));
-                }
+                simplified_interior = result;
             }
+            simplified_interiors.push(LineString::from(simplified_interior));
         }
         Polygon::new(LineString::from(simplified_exterior), simplified_interiors)
     }
@@ -118,6 +142,174 @@ impl Simplify for MultiPolygon {
     }
 }
 
+impl Simplify for GeometryCollection {
+    fn simplify(&self, epsilon: f64) -> Self {
+        self.iter().map(|member| member.simplify(epsilon)).collect()
+    }
+}
+
+impl Simplify for Geometry {
+    fn simplify(&self, epsilon: f64) -> Self {
+        match self {
+            Geometry::LineString(line_string) => {
+                Geometry::LineString(line_string.simplify(epsilon))
+            }
+            Geometry::Polygon(polygon) => Geometry::Polygon(polygon.simplify(epsilon)),
+            Geometry::MultiLineString(lines) => Geometry::MultiLineString(lines.simplify(epsilon)),
+            Geometry::MultiPolygon(polygons) => Geometry::MultiPolygon(polygons.simplify(epsilon)),
+            Geometry::GeometryCollection(collection) => {
+                Geometry::GeometryCollection(collection.simplify(epsilon))
+            }
+            other => other.clone(),
+        }
+    }
+}
+
+impl Simplify for ProcessedGeometry {
+    fn simplify(&self, epsilon: f64) -> Self {
+        match self {
+            ProcessedGeometry::Point(point) => ProcessedGeometry::Point(point.simplify(epsilon)),
+            ProcessedGeometry::LineString(line_string) => {
+                ProcessedGeometry::LineString(line_string.simplify(epsilon))
+            }
+            ProcessedGeometry::Polygon(polygon) => {
+                ProcessedGeometry::Polygon(polygon.simplify(epsilon))
+            }
+            ProcessedGeometry::MultiPoint(points) => {
+                ProcessedGeometry::MultiPoint(points.simplify(epsilon))
+            }
+            ProcessedGeometry::MultiLineString(lines) => {
+                ProcessedGeometry::MultiLineString(lines.simplify(epsilon))
+            }
+            ProcessedGeometry::MultiPolygon(polygons) => {
+                ProcessedGeometry::MultiPolygon(polygons.simplify(epsilon))
+            }
+            ProcessedGeometry::GeometryCollection(collection) => {
+                ProcessedGeometry::GeometryCollection(collection.simplify(epsilon))
+            }
+        }
+    }
+}
+
+/// Simplifies a geometry as far as possible while keeping its area close to the original
+pub trait SimplifyAreaPreserving {
+    /// Simplify with the largest epsilon whose result changes the area by at most a ratio
+    ///
+    /// Epsilon is doubled from a tiny start value until the area change would
+    /// exceed the ratio, then narrowed down by bisection between the last
+    /// accepted and the first rejected value.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_area_change_ratio` - The largest accepted relative area change, e.g. 0.05 for 5%
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The simplified geometry, or a copy if nothing can be removed
+    fn simplify_area_preserving(&self, max_area_change_ratio: f64) -> Self;
+}
+
+/// Number of bisection steps between the last accepted and first rejected epsilon
+const AREA_BISECTION_STEPS: usize = 24;
+
+impl SimplifyAreaPreserving for Polygon {
+    fn simplify_area_preserving(&self, max_area_change_ratio: f64) -> Self {
+        let original_area = self.unsigned_area();
+        let Some(rect) = self.bounding_rect() else {
+            return self.clone();
+        };
+        let diagonal = rect.width().hypot(rect.height());
+        if original_area == 0.0 || diagonal == 0.0 || max_area_change_ratio <= 0.0 {
+            return self.clone();
+        }
+        let within_ratio = |candidate: &Polygon| {
+            (candidate.unsigned_area() - original_area).abs() / original_area
+                <= max_area_change_ratio
+        };
+
+        let mut accepted = (0.0, self.clone());
+        let mut epsilon = diagonal * 1e-9;
+        let mut rejected = loop {
+            if epsilon > diagonal {
+                // Every epsilon keeps the area within the ratio
+                return self.simplify(diagonal);
+            }
+            let candidate = self.simplify(epsilon);
+            if !within_ratio(&candidate) {
+                break epsilon;
+            }
+            accepted = (epsilon, candidate);
+            epsilon *= 2.0;
+        };
+
+        for _ in 0..AREA_BISECTION_STEPS {
+            let epsilon = (accepted.0 + rejected) / 2.0;
+            let candidate = self.simplify(epsilon);
+            if within_ratio(&candidate) {
+                accepted = (epsilon, candidate);
+            } else {
+                rejected = epsilon;
+            }
+        }
+        accepted.1
+    }
+}
+
+impl SimplifyAreaPreserving for MultiPolygon {
+    fn simplify_area_preserving(&self, max_area_change_ratio: f64) -> Self {
+        MultiPolygon::new(
+            self.0
+                .iter()
+                .map(|polygon| polygon.simplify_area_preserving(max_area_change_ratio))
+                .collect(),
+        )
+    }
+}
+
+/// Removes vertices where a line barely changes direction
+pub trait RemoveColinear {
+    /// Drop each middle vertex whose turn angle is below a tolerance
+    ///
+    /// The turn angle at a vertex is measured between the segment from the
+    /// last kept vertex and the segment to the next vertex, so a run of nearly
+    /// colinear vertices cannot drift away from the kept ones. Repeated
+    /// vertices are dropped too. The first and last vertex are always kept.
+    /// This is cheaper than Douglas-Peucker and does not need a distance
+    /// threshold in the units of the CRS.
+    ///
+    /// # Arguments
+    ///
+    /// * `angle_tolerance` - The smallest turn angle that keeps a vertex, in radians
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The geometry without the colinear vertices
+    fn remove_colinear(&self, angle_tolerance: f64) -> Self;
+}
+
+impl RemoveColinear for LineString {
+    fn remove_colinear(&self, angle_tolerance: f64) -> Self {
+        let coords = &self.0;
+        if coords.len() <= 2 {
+            return self.clone();
+        }
+        let mut kept = vec![coords[0]];
+        for window in coords.windows(2).skip(1) {
+            let (current, next) = (window[0], window[1]);
+            let previous = *kept.last().expect("the first vertex is always kept");
+            let (incoming, outgoing) = (current - previous, next - current);
+            let cross = incoming.x * outgoing.y - incoming.y * outgoing.x;
+            let dot = incoming.x * outgoing.x + incoming.y * outgoing.y;
+            // atan2 of zero vectors is zero, so repeated vertices are dropped as well
+            if cross.atan2(dot).abs() >= angle_tolerance {
+                kept.push(current);
+            }
+        }
+        kept.push(coords[coords.len() - 1]);
+        LineString::from(kept)
+    }
+}
+
 /// Implementation of the Douglas-Peucker algorithm
 fn douglas_peucker(points: &[geo::Coord<f64>], epsilon: f64, result: &mut Vec<geo::Coord<f64>>) {
     if points.len() <= 2 {
@@ -163,24 +355,23 @@ fn douglas_peucker(points: &[geo::Coord<f64>], epsilon: f64, result: &mut Vec<ge
 }
 
 /// Calculate the perpendicular distance from a point to a line segment
-fn perpendicular_distance(
+///
+/// Coordinates are taken relative to the start of the line, which keeps the
+/// cross product small for lines far from the origin, and `hypot` cannot
+/// overflow for long lines.
+pub(crate) fn perpendicular_distance(
     point: &geo::Coord<f64>,
     line_start: &geo::Coord<f64>,
     line_end: &geo::Coord<f64>,
 ) -> f64 {
-    let x = point.x;
-    let y = point.y;
-    let x1 = line_start.x;
-    let y1 = line_start.y;
-    let x2 = line_end.x;
-    let y2 = line_end.y;
-
-    let dx = x2 - x1;
-    let dy = y2 - y1;
+    let dx = line_end.x - line_start.x;
+    let dy = line_end.y - line_start.y;
+    let px = point.x - line_start.x;
+    let py = point.y - line_start.y;
 
     // Calculate the perpendicular distance
-    let numerator = (dy * x - dx * y + x2 * y1 - y2 * x1).abs();
-    let denominator = (dx * dx + dy * dy).sqrt();
+    let numerator = (dy * px - dx * py).abs();
+    let denominator = dx.hypot(dy);
 
     if denominator == 0.0 {
         0.0
@@ -225,6 +416,33 @@ mod tests {
         assert!(simplified.0.len() >= 2); // Should keep more than just start and end points
     }
 
+    #[test]
+    fn test_line_simplification_matches_line_string() {
+        let line = Line::new(vec![
+            Coordinate::new(0.0, 0.0),
+            Coordinate::new(1.0, 0.1),
+            Coordinate::new(2.0, 0.0),
+            Coordinate::new(3.0, 0.5),
+            Coordinate::new(4.0, 0.0),
+        ]);
+
+        let simplified = line.simplify(0.2);
+        assert_eq!(
+            simplified.coordinates,
+            vec![
+                Coordinate::new(0.0, 0.0),
+                Coordinate::new(2.0, 0.0),
+                Coordinate::new(3.0, 0.5),
+                Coordinate::new(4.0, 0.0),
+            ]
+        );
+        assert_eq!(
+            Line::from_geo(&line.to_geo().simplify(0.2)).coordinates,
+            simplified.coordinates
+        );
+        assert_eq!(line.simplify(0.0).coordinates, line.coordinates);
+    }
+
     #[test]
     fn test_polygon_simplification() {
         // Create a polygon with non-colinear points
@@ -359,6 +577,37 @@ mod tests {
         assert_eq!(simplified.0, vec![vec![0.0, 0.0], vec![4.0, 0.0],]);
     }
 
+    #[test]
+    fn test_area_preserving_simplification_stays_within_ratio() {
+        // A wobbly circle with many small dents
+        let exterior: Vec<geo::Coord<f64>> = (0..360)
+            .map(|i| {
+                let angle = (i as f64).to_radians();
+                let radius =
+                    100.0 + if i % 2 == 0 { 3.0 } else { -3.0 } + (angle * 5.0).sin() * 10.0;
+                coord! { x: radius * angle.cos(), y: radius * angle.sin() }
+            })
+            .collect();
+        let polygon = Polygon::new(LineString::from(exterior), vec![]);
+        let original_area = polygon.unsigned_area();
+
+        let simplified = polygon.simplify_area_preserving(0.05);
+        let change = (simplified.unsigned_area() - original_area).abs() / original_area;
+        assert!(change <= 0.05, "area changed by {change}");
+        assert!(simplified.exterior().0.len() < polygon.exterior().0.len() / 4);
+
+        // A tighter ratio keeps more vertices
+        let tighter = polygon.simplify_area_preserving(0.001);
+        assert!(tighter.exterior().0.len() > simplified.exterior().0.len());
+        assert!((tighter.unsigned_area() - original_area).abs() / original_area <= 0.001);
+    }
+
+    #[test]
+    fn test_area_preserving_simplification_of_degenerate_polygon() {
+        let polygon = Polygon::new(LineString::from(vec![coord! { x: 1.0, y: 1.0 }]), vec![]);
+        assert_eq!(polygon.simplify_area_preserving(0.05), polygon);
+    }
+
     #[test]
     fn test_geojson_line_string_zero_epsilon() {
         let coords = vec![vec![0.0, 0.0], vec![1.0, 1.0], vec![2.0, 2.0]];
@@ -368,4 +617,25 @@ mod tests {
 
         assert_eq!(simplified.0, coords);
     }
+
+    #[test]
+    fn test_remove_colinear_drops_straight_vertices() {
+        let line = LineString::from(vec![(0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (2.0, 1.0)]);
+        let cleaned = line.remove_colinear(1e-9);
+        assert_eq!(
+            cleaned,
+            LineString::from(vec![(0.0, 0.0), (2.0, 0.0), (2.0, 1.0)])
+        );
+    }
+
+    #[test]
+    fn test_remove_colinear_respects_tolerance() {
+        // A turn of about 5.7 degrees at the middle vertex
+        let line = LineString::from(vec![(0.0, 0.0), (10.0, 0.0), (20.0, 1.0)]);
+        assert_eq!(line.remove_colinear(5f64.to_radians()).0.len(), 3);
+        assert_eq!(line.remove_colinear(6f64.to_radians()).0.len(), 2);
+
+        let repeated = LineString::from(vec![(0.0, 0.0), (1.0, 1.0), (1.0, 1.0), (0.0, 2.0)]);
+        assert_eq!(repeated.remove_colinear(1e-9).0.len(), 3);
+    }
 }
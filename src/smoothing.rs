@@ -0,0 +1,149 @@
+use geo::{Coord, LineString, Polygon};
+/// Smooths a geometry using Chaikin's corner-cutting algorithm
+pub trait ChaikinSmooth {
+    fn chaikin_smooth(&self, iterations: usize) -> Self;
+}
+
+impl ChaikinSmooth for LineString {
+    fn chaikin_smooth(&self, iterations: usize) -> Self {
+        if self.is_closed() {
+            return LineString::from(smooth_ring(&self.0, iterations));
+        }
+        let mut coords = self.0.clone();
+        for _ in 0..iterations {
+            if coords.len() < 3 {
+                break;
+            }
+            let mut smoothed = Vec::with_capacity(coords.len() * 2);
+            // Keep the endpoints of open lines in place
+            smoothed.push(coords[0]);
+            for segment in coords.windows(2) {
+                let (quarter, three_quarters) = cut_corner(segment[0], segment[1]);
+                smoothed.push(quarter);
+                smoothed.push(three_quarters);
+            }
+            smoothed.push(coords[coords.len() - 1]);
+            coords = smoothed;
+        }
+        LineString::from(coords)
+    }
+}
+
+impl ChaikinSmooth for Polygon {
+    fn chaikin_smooth(&self, iterations: usize) -> Self {
+        Polygon::new(
+            LineString::from(smooth_ring(&self.exterior().0, iterations)),
+            self.interiors()
+                .iter()
+                .map(|ring| LineString::from(smooth_ring(&ring.0, iterations)))
+                .collect(),
+        )
+    }
+}
+
+/// Smooth a closed ring, the result is closed again
+fn smooth_ring(ring: &[Coord<f64>], iterations: usize) -> Vec<Coord<f64>> {
+    let mut coords = ring.to_vec();
+    // Remove the last duplicate point if it exists
+    if coords.len() > 1 && coords.first() == coords.last() {
+        coords.pop();
+    }
+    if coords.len() < 3 {
+        return ring.to_vec();
+    }
+    for _ in 0..iterations {
+        let mut smoothed = Vec::with_capacity(coords.len() * 2);
+        for i in 0..coords.len() {
+            let (quarter, three_quarters) = cut_corner(coords[i], coords[(i + 1) % coords.len()]);
+            smoothed.push(quarter);
+            smoothed.push(three_quarters);
+        }
+        coords = smoothed;
+    }
+    // Ensure the ring is closed
+    coords.push(coords[0]);
+    coords
+}
+
+/// The points at one and three quarters of a segment
+fn cut_corner(start: Coord<f64>, end: Coord<f64>) -> (Coord<f64>, Coord<f64>) {
+    (start * 0.75 + end * 0.25, start * 0.25 + end * 0.75)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use geo::{coord, polygon};
+
+    /// The largest change of direction between consecutive segments, in degrees
+    fn max_turn(coords: &[Coord<f64>]) -> f64 {
+        coords
+            .windows(3)
+            .map(|w| {
+                let a = w[1] - w[0];
+                let b = w[2] - w[1];
+                let cos = (a.x * b.x + a.y * b.y) / (a.x.hypot(a.y) * b.x.hypot(b.y));
+                cos.clamp(-1.0, 1.0).acos().to_degrees()
+            })
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn test_zig_zag_line_smoothing() {
+        let line = LineString::from(vec![
+            coord! { x: 0.0, y: 0.0 },
+            coord! { x: 1.0, y: 1.0 },
+            coord! { x: 2.0, y: 0.0 },
+            coord! { x: 3.0, y: 1.0 },
+            coord! { x: 4.0, y: 0.0 },
+        ]);
+        let smoothed = line.chaikin_smooth(2);
+
+        assert!(smoothed.0.len() > line.0.len());
+        assert!(max_turn(&smoothed.0) < max_turn(&line.0));
+        // Endpoints of open lines are preserved
+        assert_eq!(smoothed.0.first(), line.0.first());
+        assert_eq!(smoothed.0.last(), line.0.last());
+    }
+
+    #[test]
+    fn test_polygon_smoothing_keeps_rings_closed() {
+        let poly = polygon![
+            exterior: [
+                (x: 0.0, y: 0.0),
+                (x: 4.0, y: 0.0),
+                (x: 4.0, y: 4.0),
+                (x: 0.0, y: 4.0),
+                (x: 0.0, y: 0.0),
+            ],
+            interiors: [[
+                (x: 1.0, y: 1.0),
+                (x: 2.0, y: 1.0),
+                (x: 2.0, y: 2.0),
+                (x: 1.0, y: 1.0),
+            ]],
+        ];
+        let smoothed = poly.chaikin_smooth(1);
+
+        assert_eq!(smoothed.exterior().0.len(), 9);
+        assert!(smoothed.exterior().is_closed());
+        assert_eq!(smoothed.interiors()[0].0.len(), 7);
+        assert!(smoothed.interiors()[0].is_closed());
+        // Corner cutting never leaves the convex hull of the original ring
+        assert!(smoothed
+            .exterior()
+            .0
+            .iter()
+            .all(|c| (0.0..=4.0).contains(&c.x) && (0.0..=4.0).contains(&c.y)));
+    }
+
+    #[test]
+    fn test_smoothing_with_zero_iterations() {
+        let line = LineString::from(vec![
+            coord! { x: 0.0, y: 0.0 },
+            coord! { x: 1.0, y: 1.0 },
+            coord! { x: 2.0, y: 0.0 },
+        ]);
+        assert_eq!(line.chaikin_smooth(0), line);
+    }
+}
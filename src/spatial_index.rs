@@ -0,0 +1,238 @@
+use crate::coordinates::Coordinate;
+use geo::coordinate_position::{CoordPos, CoordinatePosition};
+use geo::BoundingRect;
+use geojson::{Feature, GeoJson};
+
+/// Default number of entries per node in the packed tree
+const DEFAULT_NODE_SIZE: usize = 16;
+
+/// An axis aligned box stored as `[min_x, min_y, max_x, max_y]`
+type Bounds = [f64; 4];
+
+/// A static, flat-packed R-tree over the bounding boxes of processed features
+///
+/// The tree is bulk loaded with the Sort-Tile-Recursive (STR) algorithm and
+/// stored level by level in flat arrays: the leaves come first, followed by
+/// each parent level up to the root. Leaf entries hold feature indices, parent
+/// entries hold the offset of their first child in the flat arrays.
+///
+/// # Fields
+///
+/// * `node_size` - The maximum number of children per node
+/// * `boxes` - The bounding boxes of all entries, level by level
+/// * `indices` - Feature indices for leaves, child offsets for parents
+/// * `level_bounds` - The end offset of each level in `boxes`
+/// * `geometries` - The feature geometries, used for exact point tests
+#[derive(Debug, Clone)]
+pub struct SpatialIndex {
+    node_size: usize,
+    boxes: Vec<Bounds>,
+    indices: Vec<usize>,
+    level_bounds: Vec<usize>,
+    geometries: Vec<Option<geo::Geometry<f64>>>,
+}
+
+impl SpatialIndex {
+    /// Build a spatial index over a list of features
+    ///
+    /// Features without a geometry, or with a geometry that has no extent,
+    /// are kept in the index numbering but never returned by queries.
+    ///
+    /// # Arguments
+    ///
+    /// * `features` - The features to index
+    ///
+    /// # Returns
+    ///
+    /// * `SpatialIndex` - A spatial index, queries return positions in `features`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use geojson::{Feature, Geometry, Value};
+    /// use proj_exercise_simple::coordinates::Coordinate;
+    /// use proj_exercise_simple::spatial_index::SpatialIndex;
+    ///
+    /// let feature = Feature::from(Geometry::new(Value::Point(vec![1.0, 2.0])));
+    /// let index = SpatialIndex::from_features(&[feature]);
+    /// assert_eq!(index.query_point(Coordinate::new(1.0, 2.0)), vec![0]);
+    /// ```
+    pub fn from_features(features: &[Feature]) -> Self {
+        let geometries = features
+            .iter()
+            .map(|feature| {
+                feature
+                    .geometry
+                    .as_ref()
+                    .and_then(|g| geo::Geometry::<f64>::try_from(g.value.clone()).ok())
+            })
+            .collect();
+        Self::from_geometries(geometries, DEFAULT_NODE_SIZE)
+    }
+
+    /// Build a spatial index over a GeoJSON object
+    ///
+    /// A `FeatureCollection` is indexed per feature, a single `Feature` or
+    /// `Geometry` becomes the only entry with index 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `geojson` - A GeoJSON object
+    ///
+    /// # Returns
+    ///
+    /// * `SpatialIndex` - A spatial index
+    pub fn from_geojson(geojson: &GeoJson) -> Self {
+        match geojson {
+            GeoJson::FeatureCollection(collection) => Self::from_features(&collection.features),
+            GeoJson::Feature(feature) => Self::from_features(std::slice::from_ref(feature)),
+            GeoJson::Geometry(geometry) => Self::from_geometries(
+                vec![geo::Geometry::<f64>::try_from(geometry.value.clone()).ok()],
+                DEFAULT_NODE_SIZE,
+            ),
+        }
+    }
+
+    fn from_geometries(geometries: Vec<Option<geo::Geometry<f64>>>, node_size: usize) -> Self {
+        let mut entries: Vec<(Bounds, usize)> = geometries
+            .iter()
+            .enumerate()
+            .filter_map(|(i, geometry)| {
+                let rect = geometry.as_ref()?.bounding_rect()?;
+                Some(([rect.min().x, rect.min().y, rect.max().x, rect.max().y], i))
+            })
+            .collect();
+
+        let mut index = Self {
+            node_size,
+            boxes: Vec::new(),
+            indices: Vec::new(),
+            level_bounds: Vec::new(),
+            geometries,
+        };
+        if entries.is_empty() {
+            return index;
+        }
+
+        loop {
+            str_sort(&mut entries, node_size);
+            let level_start = index.boxes.len();
+            for (bounds, i) in &entries {
+                index.boxes.push(*bounds);
+                index.indices.push(*i);
+            }
+            index.level_bounds.push(index.boxes.len());
+            if entries.len() == 1 {
+                break;
+            }
+
+            // Group consecutive entries of this level into parent nodes
+            entries = index.boxes[level_start..]
+                .chunks(node_size)
+                .enumerate()
+                .map(|(chunk_idx, chunk)| {
+                    let bounds = chunk.iter().fold(
+                        [
+                            f64::INFINITY,
+                            f64::INFINITY,
+                            f64::NEG_INFINITY,
+                            f64::NEG_INFINITY,
+                        ],
+                        |acc, b| {
+                            [
+                                acc[0].min(b[0]),
+                                acc[1].min(b[1]),
+                                acc[2].max(b[2]),
+                                acc[3].max(b[3]),
+                            ]
+                        },
+                    );
+                    (bounds, level_start + chunk_idx * node_size)
+                })
+                .collect();
+        }
+        index
+    }
+
+    /// Number of indexed features with a usable geometry
+    pub fn len(&self) -> usize {
+        self.level_bounds.first().copied().unwrap_or(0)
+    }
+
+    /// Whether the index holds no features
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Find all features whose bounding box intersects the given box
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - The lower left corner of the query box
+    /// * `max` - The upper right corner of the query box
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<usize>` - The sorted indices of the candidate features
+    pub fn query_bbox(&self, min: Coordinate, max: Coordinate) -> Vec<usize> {
+        let mut results = Vec::new();
+        let Some(root_level) = self.level_bounds.len().checked_sub(1) else {
+            return results;
+        };
+
+        let mut stack = vec![(self.boxes.len() - 1, root_level)];
+        while let Some((pos, level)) = stack.pop() {
+            let b = &self.boxes[pos];
+            if b[2] < min.x || b[0] > max.x || b[3] < min.y || b[1] > max.y {
+                continue;
+            }
+            if level == 0 {
+                results.push(self.indices[pos]);
+                continue;
+            }
+            let start = self.indices[pos];
+            let end = (start + self.node_size).min(self.level_bounds[level - 1]);
+            stack.extend((start..end).map(|child| (child, level - 1)));
+        }
+        results.sort_unstable();
+        results
+    }
+
+    /// Find all features containing a point
+    ///
+    /// Candidates from the tree are confirmed with an exact point-in-geometry
+    /// test. Points on a boundary count as contained.
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - The query point
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<usize>` - The sorted indices of the features containing the point
+    pub fn query_point(&self, point: Coordinate) -> Vec<usize> {
+        let coord = geo::Coord::from((point.x, point.y));
+        self.query_bbox(point, point)
+            .into_iter()
+            .filter(|&i| {
+                self.geometries[i]
+                    .as_ref()
+                    .is_some_and(|g| g.coordinate_position(&coord) != CoordPos::Outside)
+            })
+            .collect()
+    }
+}
+
+/// Sort entries in Sort-Tile-Recursive order for the given node size
+fn str_sort(entries: &mut [(Bounds, usize)], node_size: usize) {
+    let center_x = |b: &Bounds| b[0] + b[2];
+    let center_y = |b: &Bounds| b[1] + b[3];
+
+    entries.sort_by(|a, b| center_x(&a.0).total_cmp(&center_x(&b.0)));
+    let node_count = entries.len().div_ceil(node_size);
+    let slice_count = (node_count as f64).sqrt().ceil() as usize;
+    let slice_len = node_size * node_count.div_ceil(slice_count.max(1));
+    for slice in entries.chunks_mut(slice_len.max(1)) {
+        slice.sort_by(|a, b| center_y(&a.0).total_cmp(&center_y(&b.0)));
+    }
+}
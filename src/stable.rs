@@ -0,0 +1,90 @@
+use crate::vertices::for_each_position_mut;
+use geojson::{GeoJson, JsonObject, JsonValue};
+
+/// Rebuild a JSON object with its keys in sorted order
+///
+/// serde_json keeps insertion order when its `preserve_order` feature is
+/// enabled anywhere in the dependency graph, so sorting is done explicitly.
+fn sort_object(object: &mut JsonObject) {
+    let mut entries: Vec<(String, JsonValue)> = std::mem::take(object).into_iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+    for (key, mut value) in entries {
+        sort_value(&mut value);
+        object.insert(key, value);
+    }
+}
+
+/// Sort the keys of every object nested in a JSON value
+fn sort_value(value: &mut JsonValue) {
+    match value {
+        JsonValue::Object(object) => sort_object(object),
+        JsonValue::Array(values) => values.iter_mut().for_each(sort_value),
+        _ => {}
+    }
+}
+
+/// Put a GeoJSON object into a canonical form
+///
+/// Properties and foreign members are sorted by key and negative zero
+/// coordinates become zero. Feature order is left as it is.
+pub(crate) fn stabilize(geojson: &mut GeoJson) {
+    let mut stabilize_geometry = |geometry: &mut geojson::Geometry| {
+        // Rounding can produce negative zero, which serializes differently
+        for_each_position_mut(&mut geometry.value, &mut |position| {
+            position
+                .iter_mut()
+                .filter(|v| **v == 0.0)
+                .for_each(|v| *v = 0.0)
+        });
+        geometry.foreign_members.iter_mut().for_each(sort_object);
+    };
+    let mut stabilize_feature = |feature: &mut geojson::Feature| {
+        feature.properties.iter_mut().for_each(sort_object);
+        feature.foreign_members.iter_mut().for_each(sort_object);
+        feature
+            .geometry
+            .iter_mut()
+            .for_each(&mut stabilize_geometry);
+    };
+    match geojson {
+        GeoJson::FeatureCollection(collection) => {
+            collection.foreign_members.iter_mut().for_each(sort_object);
+            collection.features.iter_mut().for_each(stabilize_feature);
+        }
+        GeoJson::Feature(feature) => stabilize_feature(feature),
+        GeoJson::Geometry(geometry) => stabilize_geometry(geometry),
+    }
+}
+
+/// Serialize GeoJSON to a byte-stable string
+///
+/// Every object is written with its keys in sorted order and negative zero
+/// coordinates are written as zero. Floats use serde_json's shortest
+/// round-trip formatting, so equal values always produce equal text; combine
+/// with `TransformerConfig::with_precision` to also hide last-digit noise.
+///
+/// # Arguments
+///
+/// * `geojson` - The GeoJSON object to serialize
+///
+/// # Returns
+///
+/// * `String` - Compact JSON text
+///
+/// # Example
+///
+/// ```rust
+/// use geojson::GeoJson;
+/// use proj_exercise_simple::stable::to_stable_string;
+///
+/// let a: GeoJson = r#"{"type": "Feature", "properties": {"b": 1, "a": 2}, "geometry": null}"#.parse().unwrap();
+/// let b: GeoJson = r#"{"type": "Feature", "properties": {"a": 2, "b": 1}, "geometry": null}"#.parse().unwrap();
+/// assert_eq!(to_stable_string(&a), to_stable_string(&b));
+/// ```
+pub fn to_stable_string(geojson: &GeoJson) -> String {
+    let mut geojson = geojson.clone();
+    stabilize(&mut geojson);
+    let mut value = JsonValue::from(geojson);
+    sort_value(&mut value);
+    value.to_string()
+}
@@ -0,0 +1,394 @@
+//! Deterministic synthetic GeoJSON data for tests, benchmarks and fuzzing
+//!
+//! Every generator returns geometries inside the valid WGS84 range, and the
+//! random generator produces the same output for the same seed on every
+//! platform.
+
+use crate::coordinates::{BoundingBox, Coordinate};
+use crate::helpers::process_feature_collection;
+use crate::kind::GeometryKind;
+use crate::transformer::TransformerConfig;
+use crate::vertices::for_each_feature_position;
+use geojson::{Feature, FeatureCollection, Geometry, Value};
+use serde_json::{json, Value as JsonValue};
+use std::f64::consts::PI;
+
+/// The latitude limit used to keep generated data projectable to Web Mercator
+const MAX_LATITUDE: f64 = 85.0;
+
+/// A small deterministic random number generator (SplitMix64)
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A value in `[min, max)`
+    fn range(&mut self, min: f64, max: f64) -> f64 {
+        let unit = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        min + unit * (max - min)
+    }
+
+    /// An integer in `[min, max]`
+    fn int(&mut self, min: usize, max: usize) -> usize {
+        min + (self.next_u64() % (max - min + 1) as u64) as usize
+    }
+}
+
+/// Evenly spaced positions along the diagonal of a bounding box, clamped to the valid range
+fn line_positions(n: usize, bbox: BoundingBox) -> Vec<Vec<f64>> {
+    let steps = n.saturating_sub(1).max(1) as f64;
+    (0..n)
+        .map(|i| {
+            let t = i as f64 / steps;
+            vec![
+                (bbox.min.x + t * (bbox.max.x - bbox.min.x)).clamp(-180.0, 180.0),
+                (bbox.min.y + t * (bbox.max.y - bbox.min.y)).clamp(-MAX_LATITUDE, MAX_LATITUDE),
+            ]
+        })
+        .collect()
+}
+
+/// A closed counter-clockwise ring approximating an ellipse, clamped to the valid range
+fn ring_positions(
+    n_vertices: usize,
+    center: Coordinate,
+    radius_x: f64,
+    radius_y: f64,
+) -> Vec<Vec<f64>> {
+    let n_vertices = n_vertices.max(3);
+    let mut ring: Vec<Vec<f64>> = (0..n_vertices)
+        .map(|i| {
+            let angle = i as f64 / n_vertices as f64 * 2.0 * PI;
+            vec![
+                (center.x + angle.cos() * radius_x).clamp(-180.0, 180.0),
+                (center.y + angle.sin() * radius_y).clamp(-MAX_LATITUDE, MAX_LATITUDE),
+            ]
+        })
+        .collect();
+    ring.push(ring[0].clone());
+    ring
+}
+
+/// A line string with `n` evenly spaced vertices along the diagonal of a bounding box
+///
+/// # Arguments
+///
+/// * `n` - The number of vertices
+/// * `bbox` - The box whose south-west to north-east diagonal the line follows
+///
+/// # Returns
+///
+/// * `Geometry` - A GeoJSON line string
+///
+/// # Example
+///
+/// ```rust
+/// use proj_exercise_simple::coordinates::BoundingBox;
+/// use proj_exercise_simple::testing::synthetic_line_string;
+///
+/// let line = synthetic_line_string(1000, BoundingBox::new(-90.0, 90.0, -75.0, 75.0));
+/// ```
+pub fn synthetic_line_string(n: usize, bbox: BoundingBox) -> Geometry {
+    Geometry::new(Value::LineString(line_positions(n, bbox)))
+}
+
+/// A closed polygon approximating a circle
+///
+/// # Arguments
+///
+/// * `n_vertices` - The number of distinct vertices, at least 3
+/// * `center` - The center of the circle
+/// * `radius` - The radius in degrees
+///
+/// # Returns
+///
+/// * `Geometry` - A GeoJSON polygon without holes
+pub fn synthetic_polygon(n_vertices: usize, center: Coordinate, radius: f64) -> Geometry {
+    Geometry::new(Value::Polygon(vec![ring_positions(
+        n_vertices, center, radius, radius,
+    )]))
+}
+
+/// A multi polygon of `n` adjacent ellipses marching north-east from the origin
+///
+/// # Arguments
+///
+/// * `n` - The number of polygons
+/// * `points_per` - The number of distinct vertices of each polygon
+///
+/// # Returns
+///
+/// * `Geometry` - A GeoJSON multi polygon
+pub fn synthetic_multi_polygon(n: usize, points_per: usize) -> Geometry {
+    let polygons = (0..n)
+        .map(|i| {
+            // Wrap around before leaving the valid range
+            let center = Coordinate::new((i as f64 * 0.5) % 170.0, (i as f64 * 0.3) % 80.0);
+            vec![ring_positions(points_per, center, 0.5, 0.3)]
+        })
+        .collect();
+    Geometry::new(Value::MultiPolygon(polygons))
+}
+
+/// A random geometry of the given kind
+fn random_geometry(rng: &mut SplitMix64, kind: GeometryKind) -> Geometry {
+    let point = |rng: &mut SplitMix64| {
+        vec![
+            rng.range(-179.0, 179.0),
+            rng.range(-MAX_LATITUDE + 1.0, MAX_LATITUDE - 1.0),
+        ]
+    };
+    let line = |rng: &mut SplitMix64| {
+        let start = point(rng);
+        let bbox = BoundingBox::new(start[0], start[0] + 1.0, start[1], start[1] + 1.0);
+        line_positions(rng.int(2, 20), bbox)
+    };
+    let polygon = |rng: &mut SplitMix64| {
+        let center = Coordinate::new(rng.range(-178.0, 178.0), rng.range(-80.0, 80.0));
+        let radius = rng.range(0.01, 1.0);
+        vec![ring_positions(rng.int(3, 32), center, radius, radius)]
+    };
+    let value = match kind {
+        GeometryKind::Point => Value::Point(point(rng)),
+        GeometryKind::LineString => Value::LineString(line(rng)),
+        GeometryKind::Polygon => Value::Polygon(polygon(rng)),
+        GeometryKind::MultiPoint => {
+            Value::MultiPoint((0..rng.int(1, 8)).map(|_| point(rng)).collect())
+        }
+        GeometryKind::MultiLineString => {
+            Value::MultiLineString((0..rng.int(1, 4)).map(|_| line(rng)).collect())
+        }
+        GeometryKind::MultiPolygon => {
+            Value::MultiPolygon((0..rng.int(1, 4)).map(|_| polygon(rng)).collect())
+        }
+        GeometryKind::GeometryCollection => Value::GeometryCollection(vec![
+            random_geometry(rng, GeometryKind::Point),
+            random_geometry(rng, GeometryKind::LineString),
+        ]),
+    };
+    Geometry::new(value)
+}
+
+/// A feature collection of random geometries, reproducible from a seed
+///
+/// Features are emitted in the order of `counts_per_type`, each with an `id`
+/// of the form `"{kind}-{index}"` and a `kind` property.
+///
+/// # Arguments
+///
+/// * `seed` - The random seed, equal seeds give equal collections
+/// * `counts_per_type` - How many features of each geometry kind to generate
+///
+/// # Returns
+///
+/// * `FeatureCollection` - The generated features
+///
+/// # Example
+///
+/// ```rust
+/// use proj_exercise_simple::kind::GeometryKind;
+/// use proj_exercise_simple::testing::random_feature_collection;
+///
+/// let counts = [(GeometryKind::Point, 10), (GeometryKind::Polygon, 5)];
+/// let collection = random_feature_collection(42, &counts);
+/// assert_eq!(collection.features.len(), 15);
+/// assert_eq!(collection, random_feature_collection(42, &counts));
+/// ```
+pub fn random_feature_collection(
+    seed: u64,
+    counts_per_type: &[(GeometryKind, usize)],
+) -> FeatureCollection {
+    let mut rng = SplitMix64(seed);
+    let features = counts_per_type
+        .iter()
+        .flat_map(|&(kind, count)| (0..count).map(move |index| (kind, index)))
+        .map(|(kind, index)| {
+            let mut feature = Feature {
+                geometry: Some(random_geometry(&mut rng, kind)),
+                id: Some(geojson::feature::Id::String(format!("{kind}-{index}"))),
+                ..Feature::default()
+            };
+            feature.set_property("kind", kind.as_str());
+            feature
+        })
+        .collect();
+    FeatureCollection {
+        bbox: None,
+        features,
+        foreign_members: None,
+    }
+}
+
+/// Numbers that tend to break coordinate handling
+const EXTREME_NUMBERS: [f64; 12] = [
+    0.0,
+    -0.0,
+    180.0,
+    -180.0,
+    90.0,
+    -90.0,
+    180.000001,
+    1e-300,
+    1e300,
+    f64::MAX,
+    f64::MIN,
+    f64::EPSILON,
+];
+
+/// The geometry type names, including ones GeoJSON does not define
+const TYPE_NAMES: [&str; 8] = [
+    "Point",
+    "LineString",
+    "Polygon",
+    "MultiPoint",
+    "MultiLineString",
+    "MultiPolygon",
+    "GeometryCollection",
+    "Circle",
+];
+
+/// A JSON number, mostly plausible and sometimes extreme
+fn arbitrary_number(rng: &mut SplitMix64) -> JsonValue {
+    match rng.int(0, 9) {
+        0..=5 => json!(rng.range(-180.0, 180.0)),
+        6 | 7 => json!(EXTREME_NUMBERS[rng.int(0, EXTREME_NUMBERS.len() - 1)]),
+        8 => json!(rng.range(-1e6, 1e6)),
+        _ => json!(rng.int(0, 400) as i64 - 200),
+    }
+}
+
+/// A position with zero to four ordinates, usually two
+fn arbitrary_position(rng: &mut SplitMix64) -> JsonValue {
+    let len = match rng.int(0, 9) {
+        0 => rng.int(0, 1),
+        1 => rng.int(3, 4),
+        _ => 2,
+    };
+    JsonValue::Array((0..len).map(|_| arbitrary_number(rng)).collect())
+}
+
+/// Nested position arrays, `depth` levels above a position
+fn arbitrary_positions(rng: &mut SplitMix64, depth: usize) -> JsonValue {
+    if depth == 0 {
+        return arbitrary_position(rng);
+    }
+    let len = match rng.int(0, 9) {
+        0 => 0,
+        1 => 1,
+        _ => rng.int(2, 6),
+    };
+    let mut items: Vec<JsonValue> = (0..len)
+        .map(|_| arbitrary_positions(rng, depth - 1))
+        .collect();
+    // Close most rings so polygons are often valid
+    if depth == 1 && len > 2 && rng.int(0, 3) > 0 {
+        items.push(items[0].clone());
+    }
+    JsonValue::Array(items)
+}
+
+/// A geometry object whose shape may or may not match its type
+fn arbitrary_geometry(rng: &mut SplitMix64, nesting: usize) -> JsonValue {
+    let type_name = TYPE_NAMES[rng.int(0, TYPE_NAMES.len() - 1)];
+    if type_name == "GeometryCollection" {
+        let len = if nesting == 0 { 0 } else { rng.int(0, 3) };
+        let geometries: Vec<JsonValue> = (0..len)
+            .map(|_| arbitrary_geometry(rng, nesting - 1))
+            .collect();
+        return json!({ "type": type_name, "geometries": geometries });
+    }
+    let expected_depth = match type_name {
+        "Point" => 0,
+        "LineString" | "MultiPoint" => 1,
+        "Polygon" | "MultiLineString" => 2,
+        _ => 3,
+    };
+    // Occasionally use the wrong nesting depth for the type
+    let depth = if rng.int(0, 9) == 0 {
+        rng.int(0, 3)
+    } else {
+        expected_depth
+    };
+    json!({ "type": type_name, "coordinates": arbitrary_positions(rng, depth) })
+}
+
+/// An arbitrary GeoJSON-shaped feature collection for fuzzing, reproducible from a seed
+///
+/// The output is JSON rather than `geojson` types so it can also hold
+/// documents that fail to parse: wrong nesting depths, empty arrays, positions
+/// with too few ordinates, unknown geometry types, extreme floats, deeply
+/// nested geometry collections and features without geometry.
+///
+/// # Arguments
+///
+/// * `seed` - The random seed, equal seeds give equal documents
+///
+/// # Returns
+///
+/// * `serde_json::Value` - A JSON feature collection
+///
+/// # Example
+///
+/// ```rust
+/// use proj_exercise_simple::testing::arbitrary_geojson;
+///
+/// let document = arbitrary_geojson(3);
+/// assert_eq!(document["type"], "FeatureCollection");
+/// assert_eq!(document, arbitrary_geojson(3));
+/// ```
+pub fn arbitrary_geojson(seed: u64) -> JsonValue {
+    let mut rng = SplitMix64(seed);
+    let features: Vec<JsonValue> = (0..rng.int(0, 5))
+        .map(|_| {
+            let geometry = if rng.int(0, 9) == 0 {
+                JsonValue::Null
+            } else {
+                arbitrary_geometry(&mut rng, 6)
+            };
+            json!({ "type": "Feature", "geometry": geometry, "properties": {} })
+        })
+        .collect();
+    json!({ "type": "FeatureCollection", "features": features })
+}
+
+/// Run a document through the pipeline and panic if an invariant is broken
+///
+/// Errors are fine, but processing must not panic, and an `Ok` output must
+/// re-parse as GeoJSON with only finite coordinates. This is the oracle shared
+/// by the fuzz target and the smoke tests.
+///
+/// # Arguments
+///
+/// * `json_value` - The document to process
+/// * `config` - The transformer configuration, reusable across calls
+///
+/// # Example
+///
+/// ```rust
+/// use proj_exercise_simple::testing::{arbitrary_geojson, assert_pipeline_invariants};
+/// use proj_exercise_simple::transformer::TransformerConfig;
+///
+/// let mut config = TransformerConfig::default();
+/// assert_pipeline_invariants(arbitrary_geojson(0), &mut config);
+/// ```
+pub fn assert_pipeline_invariants(json_value: JsonValue, config: &mut TransformerConfig) {
+    let Ok(output) = process_feature_collection(json_value, config) else {
+        return;
+    };
+    let serialized = output.to_string();
+    let reparsed: geojson::GeoJson = serialized
+        .parse()
+        .unwrap_or_else(|e| panic!("output does not re-parse as GeoJSON: {e}: {serialized}"));
+    for_each_feature_position(&reparsed, |feature_index, position| {
+        assert!(
+            position.iter().all(|ordinate| ordinate.is_finite()),
+            "non-finite position {position:?} in feature {feature_index}: {serialized}"
+        );
+    });
+}
@@ -0,0 +1,294 @@
+use crate::error::ProjectionError;
+use crate::helpers::process_feature_collection;
+use crate::transformer::TransformerConfig;
+use geo::{
+    BoundingRect, Coord, Geometry, LineString, MultiLineString, MultiPolygon, Polygon, Rect,
+};
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+
+/// Half the extent of the Web Mercator plane, in meters
+const MERCATOR_HALF_EXTENT: f64 = 20_037_508.342_789_244;
+
+/// A tile address, `(zoom, x, y)` in Slippy Map numbering
+pub type TileKey = (u8, u32, u32);
+
+/// The Web Mercator extent of a tile
+fn tile_rect(zoom: u8, x: u32, y: u32) -> Rect<f64> {
+    let size = 2.0 * MERCATOR_HALF_EXTENT / 2f64.powi(zoom as i32);
+    let min_x = -MERCATOR_HALF_EXTENT + x as f64 * size;
+    let max_y = MERCATOR_HALF_EXTENT - y as f64 * size;
+    Rect::new(
+        Coord::from((min_x, max_y - size)),
+        Coord::from((min_x + size, max_y)),
+    )
+}
+
+/// The range of tile indices along one axis covering `min..=max`, measured from the tile origin
+fn tile_span(min: f64, max: f64, size: f64, tiles: u32) -> RangeInclusive<u32> {
+    let index = |value: f64| ((value / size).floor().max(0.0) as u32).min(tiles - 1);
+    index(min)..=index(max)
+}
+
+fn inside(coord: Coord<f64>, edge: usize, rect: &Rect<f64>) -> bool {
+    match edge {
+        0 => coord.x >= rect.min().x,
+        1 => coord.x <= rect.max().x,
+        2 => coord.y >= rect.min().y,
+        _ => coord.y <= rect.max().y,
+    }
+}
+
+/// The point where the segment from `a` to `b` crosses an edge of the rectangle
+fn crossing(a: Coord<f64>, b: Coord<f64>, edge: usize, rect: &Rect<f64>) -> Coord<f64> {
+    match edge {
+        0 | 1 => {
+            let x = if edge == 0 {
+                rect.min().x
+            } else {
+                rect.max().x
+            };
+            let t = (x - a.x) / (b.x - a.x);
+            Coord::from((x, a.y + t * (b.y - a.y)))
+        }
+        _ => {
+            let y = if edge == 2 {
+                rect.min().y
+            } else {
+                rect.max().y
+            };
+            let t = (y - a.y) / (b.y - a.y);
+            Coord::from((a.x + t * (b.x - a.x), y))
+        }
+    }
+}
+
+/// Clip a closed ring to a rectangle with the Sutherland-Hodgman algorithm
+///
+/// Parts of the ring outside the rectangle collapse onto its edges, so
+/// clipping a concave ring can leave zero-width spikes along the boundary.
+fn clip_ring(ring: &LineString<f64>, rect: &Rect<f64>) -> Option<LineString<f64>> {
+    let mut points: Vec<Coord<f64>> = ring.0.clone();
+    if ring.is_closed() {
+        points.pop();
+    }
+    for edge in 0..4 {
+        let last = *points.last()?;
+        let mut clipped = Vec::with_capacity(points.len() + 4);
+        let mut previous = last;
+        for &current in &points {
+            match (inside(previous, edge, rect), inside(current, edge, rect)) {
+                (true, true) => clipped.push(current),
+                (true, false) => clipped.push(crossing(previous, current, edge, rect)),
+                (false, true) => {
+                    clipped.push(crossing(previous, current, edge, rect));
+                    clipped.push(current);
+                }
+                (false, false) => {}
+            }
+            previous = current;
+        }
+        points = clipped;
+    }
+    points.dedup();
+    if points.len() < 3 {
+        return None;
+    }
+    let mut ring = LineString::from(points);
+    ring.close();
+    Some(ring)
+}
+
+/// Clip a segment to a rectangle with the Liang-Barsky algorithm
+pub(crate) fn clip_segment(
+    a: Coord<f64>,
+    b: Coord<f64>,
+    rect: &Rect<f64>,
+) -> Option<(Coord<f64>, Coord<f64>)> {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let (mut t0, mut t1) = (0.0, 1.0);
+    for (p, q) in [
+        (-dx, a.x - rect.min().x),
+        (dx, rect.max().x - a.x),
+        (-dy, a.y - rect.min().y),
+        (dy, rect.max().y - a.y),
+    ] {
+        if p == 0.0 {
+            if q < 0.0 {
+                return None;
+            }
+        } else if p < 0.0 {
+            t0 = f64::max(t0, q / p);
+        } else {
+            t1 = f64::min(t1, q / p);
+        }
+    }
+    if t0 > t1 {
+        return None;
+    }
+    let at = |t: f64| {
+        if t == 0.0 {
+            a
+        } else if t == 1.0 {
+            b
+        } else {
+            Coord::from((a.x + t * dx, a.y + t * dy))
+        }
+    };
+    Some((at(t0), at(t1)))
+}
+
+/// Clip a line string to a rectangle, splitting it where it leaves the rectangle
+fn clip_line(line: &LineString<f64>, rect: &Rect<f64>) -> Vec<LineString<f64>> {
+    let mut parts = Vec::new();
+    let mut current: Vec<Coord<f64>> = Vec::new();
+    for segment in line.lines() {
+        match clip_segment(segment.start, segment.end, rect) {
+            Some((start, end)) if start != end => {
+                if current.last() != Some(&start) {
+                    if current.len() >= 2 {
+                        parts.push(LineString::from(std::mem::take(&mut current)));
+                    }
+                    current = vec![start];
+                }
+                current.push(end);
+            }
+            _ => {
+                if current.len() >= 2 {
+                    parts.push(LineString::from(std::mem::take(&mut current)));
+                }
+                current.clear();
+            }
+        }
+    }
+    if current.len() >= 2 {
+        parts.push(LineString::from(current));
+    }
+    parts
+}
+
+fn clip_polygon(polygon: &Polygon<f64>, rect: &Rect<f64>) -> Option<Polygon<f64>> {
+    let exterior = clip_ring(polygon.exterior(), rect)?;
+    let interiors = polygon
+        .interiors()
+        .iter()
+        .filter_map(|ring| clip_ring(ring, rect))
+        .collect();
+    Some(Polygon::new(exterior, interiors))
+}
+
+/// Clip a geometry to a rectangle, `None` if nothing of it is left
+fn clip_geometry(geometry: &Geometry<f64>, rect: &Rect<f64>) -> Option<Geometry<f64>> {
+    let contains = |c: &Coord<f64>| {
+        (rect.min().x..=rect.max().x).contains(&c.x) && (rect.min().y..=rect.max().y).contains(&c.y)
+    };
+    match geometry {
+        Geometry::Point(point) => contains(&point.0).then(|| geometry.clone()),
+        Geometry::MultiPoint(points) => {
+            let points: Vec<_> = points.iter().filter(|p| contains(&p.0)).copied().collect();
+            (!points.is_empty()).then(|| Geometry::MultiPoint(points.into()))
+        }
+        Geometry::Line(line) => clip_geometry(&Geometry::LineString((*line).into()), rect),
+        Geometry::LineString(line) => match clip_line(line, rect) {
+            parts if parts.is_empty() => None,
+            mut parts if parts.len() == 1 => Some(Geometry::LineString(parts.remove(0))),
+            parts => Some(Geometry::MultiLineString(MultiLineString::new(parts))),
+        },
+        Geometry::MultiLineString(lines) => {
+            let parts: Vec<_> = lines.iter().flat_map(|l| clip_line(l, rect)).collect();
+            (!parts.is_empty()).then(|| Geometry::MultiLineString(MultiLineString::new(parts)))
+        }
+        Geometry::Polygon(polygon) => clip_polygon(polygon, rect).map(Geometry::Polygon),
+        Geometry::MultiPolygon(polygons) => {
+            let parts: Vec<_> = polygons
+                .iter()
+                .filter_map(|p| clip_polygon(p, rect))
+                .collect();
+            (!parts.is_empty()).then(|| Geometry::MultiPolygon(MultiPolygon::new(parts)))
+        }
+        Geometry::Rect(r) => clip_geometry(&Geometry::Polygon(r.to_polygon()), rect),
+        Geometry::Triangle(t) => clip_geometry(&Geometry::Polygon(t.to_polygon()), rect),
+        Geometry::GeometryCollection(collection) => {
+            let parts: Vec<_> = collection
+                .iter()
+                .filter_map(|g| clip_geometry(g, rect))
+                .collect();
+            (!parts.is_empty()).then(|| Geometry::GeometryCollection(parts.into()))
+        }
+    }
+}
+
+/// Project a geometry to Web Mercator and clip it into the map tiles it covers
+///
+/// For every zoom level in the range, the geometry is clipped to each tile
+/// its bounding box overlaps. Tiles the clipped geometry misses entirely are
+/// left out. Coordinates stay in Web Mercator meters, they are not scaled to
+/// tile pixels. Polygons are clipped with Sutherland-Hodgman, so concave
+/// polygons can gain zero-width spikes along tile edges.
+///
+/// # Arguments
+///
+/// * `geometry` - A GeoJSON geometry in the source CRS of `config`
+/// * `zoom_range` - The zoom levels to tile, such as `0..=14`, at most 31
+/// * `config` - A transformer config with Web Mercator as the target, such as `TransformerConfig::default()`
+///
+/// # Returns
+///
+/// * `Result<HashMap<TileKey, geojson::Feature>, ProjectionError>` - The clipped geometry per tile
+///
+/// # Example
+///
+/// ```rust
+/// use geojson::{Geometry, Value};
+/// use proj_exercise_simple::tiles::to_tile_features;
+/// use proj_exercise_simple::transformer::TransformerConfig;
+///
+/// let point = Geometry::new(Value::Point(vec![13.376, 52.518]));
+/// let tiles = to_tile_features(&point, 10..=10, &mut TransformerConfig::default()).unwrap();
+/// assert!(tiles.contains_key(&(10, 550, 335)));
+/// ```
+pub fn to_tile_features(
+    geometry: &geojson::Geometry,
+    zoom_range: RangeInclusive<u8>,
+    config: &mut TransformerConfig,
+) -> Result<HashMap<TileKey, geojson::Feature>, ProjectionError> {
+    let input = serde_json::to_value(geometry).map_err(geojson::Error::from)?;
+    let projected = match process_feature_collection(input, config)? {
+        geojson::GeoJson::Geometry(geometry) => geometry,
+        _ => return Err(ProjectionError::InvalidGeometryType),
+    };
+    let projected: Geometry<f64> = projected.value.try_into()?;
+
+    let mut tiles = HashMap::new();
+    let Some(bounds) = projected.bounding_rect() else {
+        return Ok(tiles);
+    };
+    for zoom in zoom_range {
+        let count = 1u32 << zoom;
+        let size = 2.0 * MERCATOR_HALF_EXTENT / count as f64;
+        let xs = tile_span(
+            bounds.min().x + MERCATOR_HALF_EXTENT,
+            bounds.max().x + MERCATOR_HALF_EXTENT,
+            size,
+            count,
+        );
+        let ys = tile_span(
+            MERCATOR_HALF_EXTENT - bounds.max().y,
+            MERCATOR_HALF_EXTENT - bounds.min().y,
+            size,
+            count,
+        );
+        for x in xs {
+            for y in ys.clone() {
+                if let Some(clipped) = clip_geometry(&projected, &tile_rect(zoom, x, y)) {
+                    let feature = geojson::Feature {
+                        geometry: Some(geojson::Geometry::new((&clipped).into())),
+                        ..geojson::Feature::default()
+                    };
+                    tiles.insert((zoom, x, y), feature);
+                }
+            }
+        }
+    }
+    Ok(tiles)
+}
@@ -1,25 +1,403 @@
+#[cfg(feature = "proj")]
 use proj::Proj;
-use std::sync::{Arc, Mutex};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Weak};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
 pub enum TransformerError {
-    #[error("Mutex was poisoned: {0}")]
-    MutexPoisoned(String),
     #[error("Invalid CRS: {0}")]
     InvalidCrs(String),
+    #[cfg(feature = "proj")]
     #[error("Projection error: {0}")]
     ProjError(#[from] proj::ProjCreateError),
+    #[cfg(feature = "proj")]
+    #[error("Transformation failed: {0}")]
+    TransformFailed(#[from] proj::ProjError),
+    #[error("Coordinate ({x}, {y}) is outside the domain of the transformation")]
+    OutsideDomain { x: f64, y: f64 },
+    #[error("Backend unavailable: {0}")]
+    BackendUnavailable(String),
 }
 
+/// The CRS the built-in transformations support without the `proj` feature
+#[cfg(not(feature = "proj"))]
+const BUILT_IN_CRS: [&str; 2] = ["EPSG:4326", "EPSG:3857"];
+
+/// The radius of the sphere used by Web Mercator, in metres
+const WEB_MERCATOR_RADIUS: f64 = 6_378_137.0;
+
+/// Wrap a longitude in degrees into -180..=180, like PROJ does before projecting
+///
+/// Like PROJ, values that exceed 180 by rounding only are left alone.
+fn wrap_longitude(lon: f64) -> f64 {
+    if lon.abs() <= 180.0 + 1e-10 {
+        lon
+    } else {
+        (lon + 180.0).rem_euclid(360.0) - 180.0
+    }
+}
+
+/// Project a WGS 84 coordinate to Web Mercator (EPSG:4326 to EPSG:3857)
+///
+/// This is the transformation used for that pair when the crate is built
+/// without the `proj` feature. It agrees with PROJ to well below a millimetre.
+///
+/// # Arguments
+///
+/// * `lon` - The longitude in degrees
+/// * `lat` - The latitude in degrees, strictly between -90 and 90
+///
+/// # Returns
+///
+/// * `Result<(f64, f64), TransformerError>` - The coordinate in metres, or
+///   `TransformerError::OutsideDomain` for a pole or a non-finite value
+///
+/// # Example
+///
+/// ```rust
+/// use proj_exercise_simple::transformer::to_web_mercator;
+/// let (x, y) = to_web_mercator(1.0, 2.0).unwrap();
+/// assert!((x - 111319.49079327357).abs() < 1e-6 && (y - 222684.20850554455).abs() < 1e-6);
+/// assert!(to_web_mercator(0.0, 90.0).is_err());
+/// ```
+pub fn to_web_mercator(lon: f64, lat: f64) -> Result<(f64, f64), TransformerError> {
+    if !lon.is_finite() || !lat.is_finite() || lat.abs() >= 90.0 {
+        return Err(TransformerError::OutsideDomain { x: lon, y: lat });
+    }
+    Ok((
+        WEB_MERCATOR_RADIUS * wrap_longitude(lon).to_radians(),
+        WEB_MERCATOR_RADIUS * lat.to_radians().tan().asinh(),
+    ))
+}
+
+/// Unproject a Web Mercator coordinate to WGS 84 (EPSG:3857 to EPSG:4326)
+///
+/// The inverse of `to_web_mercator`.
+///
+/// # Arguments
+///
+/// * `x` - The easting in metres
+/// * `y` - The northing in metres
+///
+/// # Returns
+///
+/// * `Result<(f64, f64), TransformerError>` - The longitude and latitude in
+///   degrees, or `TransformerError::OutsideDomain` for a non-finite value
+///
+/// # Example
+///
+/// ```rust
+/// use proj_exercise_simple::transformer::from_web_mercator;
+/// let (lon, lat) = from_web_mercator(111319.49079327357, 222684.20850554455).unwrap();
+/// assert!((lon - 1.0).abs() < 1e-9 && (lat - 2.0).abs() < 1e-9);
+/// ```
+pub fn from_web_mercator(x: f64, y: f64) -> Result<(f64, f64), TransformerError> {
+    if !x.is_finite() || !y.is_finite() {
+        return Err(TransformerError::OutsideDomain { x, y });
+    }
+    Ok((
+        wrap_longitude((x / WEB_MERCATOR_RADIUS).to_degrees()),
+        (y / WEB_MERCATOR_RADIUS).sinh().atan().to_degrees(),
+    ))
+}
+
+/// A coordinate type a `Transformer` can convert
+pub trait TransformCoord {
+    fn x(&self) -> f64;
+    fn y(&self) -> f64;
+    fn from_xy(x: f64, y: f64) -> Self;
+}
+
+impl TransformCoord for (f64, f64) {
+    fn x(&self) -> f64 {
+        self.0
+    }
+    fn y(&self) -> f64 {
+        self.1
+    }
+    fn from_xy(x: f64, y: f64) -> Self {
+        (x, y)
+    }
+}
+
+impl TransformCoord for geo::Point<f64> {
+    fn x(&self) -> f64 {
+        geo::Point::x(*self)
+    }
+    fn y(&self) -> f64 {
+        geo::Point::y(*self)
+    }
+    fn from_xy(x: f64, y: f64) -> Self {
+        geo::Point::new(x, y)
+    }
+}
+
+impl TransformCoord for geo::Coord<f64> {
+    fn x(&self) -> f64 {
+        self.x
+    }
+    fn y(&self) -> f64 {
+        self.y
+    }
+    fn from_xy(x: f64, y: f64) -> Self {
+        geo::Coord { x, y }
+    }
+}
+
+/// A built-in transformation between the CRS in `BUILT_IN_CRS`
+#[cfg(not(feature = "proj"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BuiltIn {
+    Identity { geographic: bool },
+    ToWebMercator,
+    FromWebMercator,
+}
+
+#[cfg(not(feature = "proj"))]
+impl BuiltIn {
+    fn between(from: &str, to: &str) -> Option<Self> {
+        let geographic = |crs: &str| crs.eq_ignore_ascii_case(BUILT_IN_CRS[0]);
+        let mercator = |crs: &str| crs.eq_ignore_ascii_case(BUILT_IN_CRS[1]);
+        match (
+            geographic(from),
+            mercator(from),
+            geographic(to),
+            mercator(to),
+        ) {
+            (true, _, true, _) => Some(BuiltIn::Identity { geographic: true }),
+            (_, true, _, true) => Some(BuiltIn::Identity { geographic: false }),
+            (true, _, _, true) => Some(BuiltIn::ToWebMercator),
+            (_, true, true, _) => Some(BuiltIn::FromWebMercator),
+            _ => None,
+        }
+    }
+}
+
+/// The operation behind a `Transformer`
+enum Backend {
+    #[cfg(feature = "proj")]
+    Proj(Proj),
+    #[cfg(not(feature = "proj"))]
+    BuiltIn(BuiltIn),
+}
+
+/// A transformation from one CRS to another, created by `TransformerConfig`
+///
+/// With the default `proj` feature any pair of CRS known to PROJ is
+/// supported. Without it, only the built-in transformations between
+/// EPSG:4326 and EPSG:3857 are available.
+pub struct Transformer {
+    backend: Backend,
+}
+
+impl Transformer {
+    /// Create the transformation between two CRS
+    fn new(from: &str, to: &str) -> Result<Self, TransformerError> {
+        #[cfg(feature = "proj")]
+        let backend = Backend::Proj(Proj::new_known_crs(from, to, None)?);
+        #[cfg(not(feature = "proj"))]
+        let backend = Backend::BuiltIn(BuiltIn::between(from, to).ok_or_else(|| {
+            TransformerError::BackendUnavailable(format!("{from} to {to} needs the `proj` feature"))
+        })?);
+        Ok(Self { backend })
+    }
+
+    /// Convert a coordinate
+    ///
+    /// # Arguments
+    ///
+    /// * `coord` - A coordinate in the source CRS
+    ///
+    /// # Returns
+    ///
+    /// * `Result<C, TransformerError>` - The coordinate in the target CRS
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::transformer::TransformerConfig;
+    /// let transformer = TransformerConfig::default().get_transformer().unwrap();
+    /// let point = transformer.convert(geo::Point::new(1.0, 2.0)).unwrap();
+    /// assert!((point.x() - 111319.49079327357).abs() < 1e-6);
+    /// ```
+    pub fn convert<C: TransformCoord>(&self, coord: C) -> Result<C, TransformerError> {
+        let (x, y) = match &self.backend {
+            #[cfg(feature = "proj")]
+            Backend::Proj(proj) => proj.convert((coord.x(), coord.y()))?,
+            #[cfg(not(feature = "proj"))]
+            Backend::BuiltIn(BuiltIn::Identity { .. }) => (coord.x(), coord.y()),
+            #[cfg(not(feature = "proj"))]
+            Backend::BuiltIn(BuiltIn::ToWebMercator) => to_web_mercator(coord.x(), coord.y())?,
+            #[cfg(not(feature = "proj"))]
+            Backend::BuiltIn(BuiltIn::FromWebMercator) => from_web_mercator(coord.x(), coord.y())?,
+        };
+        Ok(C::from_xy(x, y))
+    }
+
+    /// Convert coordinates in place
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - Coordinates in the source CRS, replaced by those in the target CRS
+    ///
+    /// # Returns
+    ///
+    /// * `Result<&mut [C], TransformerError>` - The converted coordinates
+    pub fn convert_array<'a, C: TransformCoord>(
+        &self,
+        points: &'a mut [C],
+    ) -> Result<&'a mut [C], TransformerError> {
+        match &self.backend {
+            #[cfg(feature = "proj")]
+            Backend::Proj(proj) => {
+                // One PROJ call for the whole array is much faster than one per point
+                let mut coords: Vec<(f64, f64)> = points.iter().map(|p| (p.x(), p.y())).collect();
+                proj.convert_array(&mut coords)?;
+                for (point, (x, y)) in points.iter_mut().zip(coords) {
+                    *point = C::from_xy(x, y);
+                }
+            }
+            #[cfg(not(feature = "proj"))]
+            Backend::BuiltIn(_) => {
+                for point in points.iter_mut() {
+                    *point = self.convert(C::from_xy(point.x(), point.y()))?;
+                }
+            }
+        }
+        Ok(points)
+    }
+
+    /// The definition of the PROJ operation
+    #[cfg(feature = "proj")]
+    fn definition(proj: &Proj) -> Result<String, TransformerError> {
+        proj.def()
+            .map_err(|e| TransformerError::InvalidCrs(e.to_string()))
+    }
+
+    /// Whether the source of the operation is geographic, see `TransformerConfig::source_is_geographic`
+    fn source_is_geographic(&self) -> Result<bool, TransformerError> {
+        match &self.backend {
+            #[cfg(feature = "proj")]
+            Backend::Proj(proj) => {
+                let definition = Self::definition(proj)?;
+                let tokens: Vec<&str> = definition.split_whitespace().collect();
+                Ok(!tokens.windows(2).any(|pair| {
+                    pair[0] == "inv"
+                        && pair[1]
+                            .strip_prefix("proj=")
+                            .is_some_and(|step| !NON_PROJECTION_STEPS.contains(&step))
+                }))
+            }
+            #[cfg(not(feature = "proj"))]
+            Backend::BuiltIn(built_in) => Ok(matches!(
+                built_in,
+                BuiltIn::Identity { geographic: true } | BuiltIn::ToWebMercator
+            )),
+        }
+    }
+
+    /// Whether the target of the operation is projected, see `TransformerConfig::target_is_projected`
+    fn target_is_projected(&self) -> Result<bool, TransformerError> {
+        match &self.backend {
+            #[cfg(feature = "proj")]
+            Backend::Proj(proj) => {
+                let definition = Self::definition(proj)?;
+                let tokens: Vec<&str> = definition.split_whitespace().collect();
+                Ok(tokens.iter().enumerate().any(|(i, token)| {
+                    (i == 0 || tokens[i - 1] != "inv")
+                        && token.strip_prefix("proj=").is_some_and(|step| {
+                            !NON_PROJECTION_STEPS.contains(&step)
+                                && step != "pipeline"
+                                && step != "noop"
+                        })
+                }))
+            }
+            #[cfg(not(feature = "proj"))]
+            Backend::BuiltIn(built_in) => Ok(*built_in == BuiltIn::ToWebMercator),
+        }
+    }
+}
+
+/// Pipeline steps that do not change between geographic and projected coordinates
+#[cfg(feature = "proj")]
+const NON_PROJECTION_STEPS: [&str; 9] = [
+    "axisswap",
+    "cart",
+    "helmert",
+    "hgridshift",
+    "vgridshift",
+    "unitconvert",
+    "molodensky",
+    "push",
+    "pop",
+];
+
+/// The direction of a cached transformer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Forward,
+    Inverse,
+}
+
+/// Identifies the transformers cached for a configuration and its clones
+///
+/// Bumping the generation invalidates the cached transformers on every thread.
+/// The transformation counters live here too, so clones share them.
+#[derive(Debug, Default)]
+struct CacheHandle {
+    generation: AtomicU64,
+    transformations: AtomicUsize,
+    transform_errors: AtomicUsize,
+}
+
+/// Counts of the coordinates projected through a configuration and its clones
+///
+/// # Fields
+///
+/// * `transformations` - Coordinates projected successfully
+/// * `transform_errors` - Projections that failed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TransformerStats {
+    pub transformations: usize,
+    pub transform_errors: usize,
+}
+
+/// A transformer cached on one thread
+struct CacheEntry {
+    handle: Weak<CacheHandle>,
+    direction: Direction,
+    generation: u64,
+    transformer: Rc<Transformer>,
+}
+
+thread_local! {
+    static TRANSFORMERS: RefCell<Vec<CacheEntry>> = const { RefCell::new(Vec::new()) };
+}
+
+/// The CRS pair and output settings for projecting coordinates
+///
+/// PROJ transformers are neither `Send` nor `Sync`, so they are not stored
+/// in the configuration. Transformers are created lazily and cached per thread,
+/// which makes the configuration `Send + Sync`: share it behind an `Arc` or
+/// clone it into every thread or task. Clones share their cache, and the
+/// transformers returned by `get_transformer` must stay on their thread.
 #[derive(Debug, Clone)]
 pub struct TransformerConfig {
-    from: String,
-    to: String,
-    transformer: Arc<Mutex<Option<Arc<Proj>>>>,
+    from: Arc<str>,
+    to: Arc<str>,
+    cache: Arc<CacheHandle>,
     pub simplification_epsilon: Option<f64>,
+    pub precision: Option<u32>,
 }
 
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<TransformerConfig>();
+};
+
 impl Default for TransformerConfig {
     fn default() -> Self {
         Self::new("EPSG:4326".to_string(), "EPSG:3857".to_string())
@@ -46,18 +424,19 @@ impl TransformerConfig {
         validate_crs(&to)?;
 
         Ok(Self {
-            from,
-            to,
-            transformer: Arc::new(Mutex::new(None)),
+            from: from.into(),
+            to: to.into(),
+            cache: Arc::default(),
             simplification_epsilon: None,
+            precision: None,
         })
     }
 
-    /// Get a transformer
+    /// Get a transformer, cached for the current thread
     ///
     /// # Returns
     ///
-    /// * `Arc<Proj>` - A transformer
+    /// * `Rc<Transformer>` - A transformer
     ///
     /// # Example
     ///
@@ -66,30 +445,112 @@ impl TransformerConfig {
     /// let config = TransformerConfig::new("EPSG:4326".to_string(), "EPSG:3857".to_string()).unwrap();
     /// let transformer = config.get_transformer();
     /// ```
-    pub fn get_transformer(&self) -> Result<Arc<Proj>, TransformerError> {
-        let mut transformer = self
-            .transformer
-            .lock()
-            .map_err(|e| TransformerError::MutexPoisoned(e.to_string()))?;
-
-        if transformer.is_none() {
-            let new_transformer = Proj::new_known_crs(&self.from, &self.to, None)?;
-            *transformer = Some(Arc::new(new_transformer));
-        }
+    pub fn get_transformer(&self) -> Result<Rc<Transformer>, TransformerError> {
+        self.cached_transformer(Direction::Forward)
+    }
 
-        Ok(transformer.as_ref().unwrap().clone())
+    /// Get a transformer for the inverse direction, from the target back to the source CRS
+    ///
+    /// # Returns
+    ///
+    /// * `Rc<Transformer>` - An inverse transformer, cached for the current thread
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::transformer::TransformerConfig;
+    /// let config = TransformerConfig::new("EPSG:4326".to_string(), "EPSG:3857".to_string()).unwrap();
+    /// let inverse = config.get_inverse_transformer().unwrap();
+    /// let (x, y): (f64, f64) = inverse.convert((111319.49079327357, 222684.20850554455)).unwrap();
+    /// assert!((x - 1.0).abs() < 1e-9 && (y - 2.0).abs() < 1e-9);
+    /// ```
+    pub fn get_inverse_transformer(&self) -> Result<Rc<Transformer>, TransformerError> {
+        self.cached_transformer(Direction::Inverse)
+    }
+
+    /// Look up or create the transformer for a direction on the current thread
+    fn cached_transformer(
+        &self,
+        direction: Direction,
+    ) -> Result<Rc<Transformer>, TransformerError> {
+        let generation = self.cache.generation.load(Ordering::Acquire);
+        TRANSFORMERS.with(|entries| {
+            let mut entries = entries.borrow_mut();
+            if let Some(entry) = entries.iter().find(|entry| {
+                self.owns(entry) && entry.direction == direction && entry.generation == generation
+            }) {
+                return Ok(entry.transformer.clone());
+            }
+
+            let (from, to) = match direction {
+                Direction::Forward => (&self.from, &self.to),
+                Direction::Inverse => (&self.to, &self.from),
+            };
+            let transformer = Rc::new(Transformer::new(from, to)?);
+            #[cfg(feature = "tracing")]
+            tracing::event!(
+                tracing::Level::DEBUG,
+                from = from.as_ref(),
+                to = to.as_ref(),
+                ?direction,
+                "transformer created"
+            );
+            // Drop stale entries and those of configurations that no longer exist
+            entries.retain(|entry| {
+                entry.handle.strong_count() > 0
+                    && !(self.owns(entry) && entry.direction == direction)
+            });
+            entries.push(CacheEntry {
+                handle: Arc::downgrade(&self.cache),
+                direction,
+                generation,
+                transformer: transformer.clone(),
+            });
+            Ok(transformer)
+        })
+    }
+
+    /// Whether a cache entry belongs to this configuration or one of its clones
+    fn owns(&self, entry: &CacheEntry) -> bool {
+        // The weak reference keeps the allocation, so the address is never reused
+        std::ptr::eq(entry.handle.as_ptr(), Arc::as_ptr(&self.cache))
     }
 
-    // Clear the cached transformer (useful if config changes)
+    /// Clear the cached transformers on every thread (useful if config changes)
+    ///
+    /// The transformation counters are reset as well.
     pub fn clear_cache(&self) -> Result<(), TransformerError> {
-        let mut transformer = self
-            .transformer
-            .lock()
-            .map_err(|e| TransformerError::MutexPoisoned(e.to_string()))?;
-        *transformer = None;
+        self.cache.generation.fetch_add(1, Ordering::AcqRel);
+        self.cache.transformations.store(0, Ordering::Relaxed);
+        self.cache.transform_errors.store(0, Ordering::Relaxed);
+        TRANSFORMERS.with(|entries| entries.borrow_mut().retain(|entry| !self.owns(entry)));
         Ok(())
     }
 
+    /// Get the source CRS
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::transformer::TransformerConfig;
+    /// assert_eq!(TransformerConfig::default().from(), "EPSG:4326");
+    /// ```
+    pub fn from(&self) -> &str {
+        &self.from
+    }
+
+    /// Get the target CRS
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::transformer::TransformerConfig;
+    /// assert_eq!(TransformerConfig::default().to(), "EPSG:3857");
+    /// ```
+    pub fn to(&self) -> &str {
+        &self.to
+    }
+
     /// Update the CRS
     ///
     /// # Arguments
@@ -108,23 +569,164 @@ impl TransformerConfig {
         validate_crs(&from)?;
         validate_crs(&to)?;
 
-        self.from = from;
-        self.to = to;
-        self.clear_cache()
+        self.from = from.into();
+        self.to = to.into();
+        // Clones keep their CRS pair, their transformers and their counters
+        self.cache = Arc::default();
+        Ok(())
     }
 
+    /// Check whether the forward transformer has been created on the current thread
     pub fn is_transformer_available(&self) -> Result<bool, TransformerError> {
-        Ok(self
-            .transformer
-            .lock()
-            .map_err(|e| TransformerError::MutexPoisoned(e.to_string()))?
-            .is_some())
+        let generation = self.cache.generation.load(Ordering::Acquire);
+        Ok(TRANSFORMERS.with(|entries| {
+            entries.borrow().iter().any(|entry| {
+                self.owns(entry)
+                    && entry.direction == Direction::Forward
+                    && entry.generation == generation
+            })
+        }))
+    }
+
+    /// Project a single coordinate, rounded to the configured precision
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The x coordinate in the source CRS
+    /// * `y` - The y coordinate in the source CRS
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(f64, f64), TransformerError>` - The coordinate in the target CRS
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::transformer::TransformerConfig;
+    /// let config = TransformerConfig::default().with_precision(2);
+    /// assert_eq!(config.transform_point(1.0, 2.0).unwrap(), (111319.49, 222684.21));
+    /// assert_eq!(config.transformation_count(), 1);
+    /// ```
+    pub fn transform_point(&self, x: f64, y: f64) -> Result<(f64, f64), TransformerError> {
+        let result = self
+            .get_transformer()
+            .and_then(|transformer| transformer.convert((x, y)));
+        match result {
+            Ok((x, y)) => {
+                self.record_transformations(1);
+                Ok((self.round_output(x), self.round_output(y)))
+            }
+            Err(error) => {
+                self.record_transform_error();
+                Err(error)
+            }
+        }
+    }
+
+    /// The number of coordinates projected successfully since creation or the last `clear_cache`
+    ///
+    /// Counts `transform_point` calls and the coordinates of geometries
+    /// projected by the processing pipeline, across all clones and threads.
+    pub fn transformation_count(&self) -> usize {
+        self.cache.transformations.load(Ordering::Relaxed)
+    }
+
+    /// The transformation counters since creation or the last `clear_cache`
+    ///
+    /// `update_crs` starts new counters, as the projections before it were of
+    /// another CRS pair.
+    ///
+    /// # Returns
+    ///
+    /// * `TransformerStats` - The successful and failed transformations
+    pub fn stats(&self) -> TransformerStats {
+        TransformerStats {
+            transformations: self.transformation_count(),
+            transform_errors: self.cache.transform_errors.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Count coordinates projected successfully
+    pub(crate) fn record_transformations(&self, count: usize) {
+        self.cache
+            .transformations
+            .fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// Count a failed projection
+    pub(crate) fn record_transform_error(&self) {
+        self.cache.transform_errors.fetch_add(1, Ordering::Relaxed);
     }
 
     pub fn with_simplification(mut self, epsilon: f64) -> Self {
         self.simplification_epsilon = Some(epsilon);
         self
     }
+
+    /// Check whether the source CRS is geographic
+    ///
+    /// The cached transformer operation is inspected: starting from a projected
+    /// source needs an inverse projection step, starting from a geographic
+    /// source at most needs axis, unit or datum changes before projecting.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<bool, TransformerError>` - Whether the source uses geographic coordinates
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::transformer::TransformerConfig;
+    /// let config = TransformerConfig::new("EPSG:3857".to_string(), "EPSG:4326".to_string()).unwrap();
+    /// assert!(!config.source_is_geographic().unwrap());
+    /// assert!(TransformerConfig::default().source_is_geographic().unwrap());
+    /// ```
+    pub fn source_is_geographic(&self) -> Result<bool, TransformerError> {
+        self.get_transformer()?.source_is_geographic()
+    }
+
+    /// Check whether the target CRS is projected
+    ///
+    /// The cached transformer operation is inspected: ending in a projected
+    /// CRS needs a forward projection step. A no-op operation between two
+    /// identical CRS is reported as not projected.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<bool, TransformerError>` - Whether the target uses projected coordinates
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use proj_exercise_simple::transformer::TransformerConfig;
+    /// let config = TransformerConfig::new("EPSG:3857".to_string(), "EPSG:4326".to_string()).unwrap();
+    /// assert!(!config.target_is_projected().unwrap());
+    /// assert!(TransformerConfig::default().target_is_projected().unwrap());
+    /// ```
+    pub fn target_is_projected(&self) -> Result<bool, TransformerError> {
+        self.get_transformer()?.target_is_projected()
+    }
+
+    /// Round projected output coordinates to a number of decimal places
+    ///
+    /// # Arguments
+    ///
+    /// * `decimals` - The number of decimal places to keep, in target CRS units
+    pub fn with_precision(mut self, decimals: u32) -> Self {
+        self.precision = Some(decimals);
+        self
+    }
+
+    /// Round a projected value according to the configured precision
+    pub(crate) fn round_output(&self, value: f64) -> f64 {
+        match self.precision {
+            Some(decimals) => {
+                let factor = 10f64.powi(decimals as i32);
+                (value * factor).round() / factor
+            }
+            None => value,
+        }
+    }
 }
 
 fn validate_crs(crs: &str) -> Result<(), TransformerError> {
@@ -135,8 +737,19 @@ fn validate_crs(crs: &str) -> Result<(), TransformerError> {
     }
 
     // Try to create a temporary transformer to validate the CRS
+    #[cfg(feature = "proj")]
     let _ = Proj::new_known_crs(crs, crs, None)
         .map_err(|e| TransformerError::InvalidCrs(format!("Invalid CRS {}: {}", crs, e)))?;
+    #[cfg(not(feature = "proj"))]
+    if !BUILT_IN_CRS
+        .iter()
+        .any(|known| crs.eq_ignore_ascii_case(known))
+    {
+        return Err(TransformerError::BackendUnavailable(format!(
+            "{crs} needs the `proj` feature, only {} are built in",
+            BUILT_IN_CRS.join(" and ")
+        )));
+    }
 
     Ok(())
 }
@@ -0,0 +1,173 @@
+use crate::error::ProjectionError;
+use crate::helpers::{process_feature_collection, ProcessedGeometry};
+use crate::transformer::TransformerConfig;
+use geojson::feature::Id;
+use geojson::{Feature, GeoJson, JsonObject, JsonValue};
+use serde::de::DeserializeOwned;
+use serde::ser::Error as _;
+use serde::Serialize;
+
+/// A feature whose properties are deserialized into a user type
+///
+/// # Fields
+///
+/// * `geometry` - The geometry of the feature
+/// * `id` - The id of the feature
+/// * `properties` - The properties of the feature
+#[derive(Debug)]
+pub struct TypedFeature<P> {
+    pub geometry: ProcessedGeometry,
+    pub id: Option<Id>,
+    pub properties: P,
+}
+
+impl<P: Serialize + DeserializeOwned> TypedFeature<P> {
+    /// Convert a GeoJSON feature, deserializing its properties
+    ///
+    /// A feature without properties is read as an empty object. Errors report
+    /// the feature as feature 0, use `process_typed_collection` for the index
+    /// within a collection.
+    ///
+    /// # Arguments
+    ///
+    /// * `feature` - A feature with a geometry
+    ///
+    /// # Returns
+    ///
+    /// * `Result<TypedFeature<P>, ProjectionError>` - The typed feature, or an error naming the offending property
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use geojson::{Feature, Geometry, Value};
+    /// use proj_exercise_simple::typed::TypedFeature;
+    ///
+    /// let mut feature = Feature::from(Geometry::new(Value::Point(vec![1.0, 2.0])));
+    /// feature.set_property("name", "Main Street");
+    /// let typed: TypedFeature<std::collections::HashMap<String, String>> =
+    ///     TypedFeature::from_geojson(feature).unwrap();
+    /// assert_eq!(typed.properties["name"], "Main Street");
+    /// ```
+    pub fn from_geojson(feature: Feature) -> Result<Self, ProjectionError> {
+        Self::from_feature(feature, 0)
+    }
+
+    fn from_feature(feature: Feature, feature_index: usize) -> Result<Self, ProjectionError> {
+        let geometry = feature
+            .geometry
+            .ok_or(ProjectionError::InvalidGeometryType)?;
+        let geometry = geo::Geometry::<f64>::try_from(geometry.value)?;
+        let properties = feature.properties.unwrap_or_default();
+        Ok(Self {
+            geometry: ProcessedGeometry::from_geo(geometry),
+            id: feature.id,
+            properties: deserialize_properties(properties, feature_index)?,
+        })
+    }
+
+    /// Convert into a GeoJSON feature, serializing the properties
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Feature, ProjectionError>` - The feature, or an error if the properties do not serialize to a JSON object
+    pub fn into_geojson(self) -> Result<Feature, ProjectionError> {
+        let properties = serde_json::to_value(&self.properties)
+            .and_then(|value| match value {
+                JsonValue::Object(properties) => Ok(properties),
+                _ => Err(serde_json::Error::custom(
+                    "properties must serialize to a JSON object",
+                )),
+            })
+            .map_err(|source| ProjectionError::InvalidProperties {
+                feature_index: 0,
+                field: None,
+                source,
+            })?;
+        Ok(Feature {
+            bbox: None,
+            geometry: Some(self.geometry.to_geojson_geometry()),
+            id: self.id,
+            properties: Some(properties),
+            foreign_members: None,
+        })
+    }
+}
+
+/// Deserialize properties, finding the property at fault when a value has the wrong type
+///
+/// serde_json does not report which key a type mismatch is at. Removing the
+/// key at fault changes the error, removing any other key does not, as
+/// values are checked while reading and missing fields only at the end.
+fn deserialize_properties<P: DeserializeOwned>(
+    properties: JsonObject,
+    feature_index: usize,
+) -> Result<P, ProjectionError> {
+    let source = match serde_json::from_value(JsonValue::Object(properties.clone())) {
+        Ok(value) => return Ok(value),
+        Err(source) => source,
+    };
+    let message = source.to_string();
+    let field = if message.starts_with("missing field") {
+        None
+    } else {
+        properties.keys().find(|key| {
+            let mut without = properties.clone();
+            without.remove(key.as_str());
+            serde_json::from_value::<P>(JsonValue::Object(without))
+                .err()
+                .is_none_or(|error| error.to_string() != message)
+        })
+    }
+    .cloned();
+    Err(ProjectionError::InvalidProperties {
+        feature_index,
+        field,
+        source,
+    })
+}
+
+/// Project a feature collection and deserialize the properties of every feature
+///
+/// # Arguments
+///
+/// * `json_value` - A GeoJSON feature collection, feature or geometry
+/// * `config` - A transformer config
+///
+/// # Returns
+///
+/// * `Result<Vec<TypedFeature<P>>, ProjectionError>` - The projected features
+///
+/// # Example
+///
+/// ```rust
+/// use proj_exercise_simple::transformer::TransformerConfig;
+/// use proj_exercise_simple::typed::process_typed_collection;
+/// use serde_json::json;
+///
+/// let input = json!({
+///     "type": "Feature",
+///     "geometry": { "type": "Point", "coordinates": [1.0, 2.0] },
+///     "properties": { "name": "Main Street" }
+/// });
+/// let features = process_typed_collection::<serde_json::Map<String, serde_json::Value>>(
+///     input,
+///     &mut TransformerConfig::default(),
+/// )
+/// .unwrap();
+/// assert_eq!(features[0].properties["name"], "Main Street");
+/// ```
+pub fn process_typed_collection<P: Serialize + DeserializeOwned>(
+    json_value: serde_json::Value,
+    config: &mut TransformerConfig,
+) -> Result<Vec<TypedFeature<P>>, ProjectionError> {
+    let features = match process_feature_collection(json_value, config)? {
+        GeoJson::FeatureCollection(collection) => collection.features,
+        GeoJson::Feature(feature) => vec![feature],
+        GeoJson::Geometry(geometry) => vec![Feature::from(geometry)],
+    };
+    features
+        .into_iter()
+        .enumerate()
+        .map(|(index, feature)| TypedFeature::from_feature(feature, index))
+        .collect()
+}
@@ -0,0 +1,529 @@
+use crate::coordinates::CoordinateBounds;
+use crate::digest::Fnv1a;
+use crate::error::{GeometryValidationError, ProjectionError, ValidationError};
+use crate::vertices::for_each_position;
+use geo::line_intersection::{line_intersection, LineIntersection};
+use geo::{Coord, Line};
+use geojson::{Bbox, GeoJson, Position, Value};
+use std::collections::{HashMap, VecDeque};
+
+/// What a validation pass checks beyond positions, lengths and ring closure
+#[derive(Clone, Copy)]
+struct Rules {
+    /// Reject geometries without coordinates
+    reject_empty: bool,
+    /// Reject rings crossing themselves
+    reject_self_intersection: bool,
+}
+
+/// The rules of `validate_geometry`
+const STRUCTURAL: Rules = Rules {
+    reject_empty: true,
+    reject_self_intersection: true,
+};
+
+/// The rules of RFC 7946, which allows empty geometries and says nothing about self-intersection
+const RFC_7946: Rules = Rules {
+    reject_empty: false,
+    reject_self_intersection: false,
+};
+
+/// Check a single position for missing ordinates, non-finite values and the WGS84 range
+fn validate_position(position: &[f64]) -> Result<(), GeometryValidationError> {
+    let [x, y, ..] = *position else {
+        return Err(GeometryValidationError::TooFewPoints {
+            expected: 2,
+            found: position.len(),
+        });
+    };
+    if !x.is_finite() || !y.is_finite() {
+        return Err(GeometryValidationError::NaN);
+    }
+    if CoordinateBounds::wgs84().validate(x, y).is_err() {
+        return Err(GeometryValidationError::OutOfRange { x, y });
+    }
+    Ok(())
+}
+
+fn empty(rules: Rules) -> Result<(), GeometryValidationError> {
+    if rules.reject_empty {
+        Err(GeometryValidationError::EmptyGeometry)
+    } else {
+        Ok(())
+    }
+}
+
+/// Check a sequence of positions, requiring at least `min_points` of them
+fn validate_positions(
+    positions: &[Position],
+    min_points: usize,
+    rules: Rules,
+) -> Result<(), GeometryValidationError> {
+    if positions.is_empty() {
+        return empty(rules);
+    }
+    positions
+        .iter()
+        .try_for_each(|position| validate_position(position))?;
+    validate_length(positions, min_points)
+}
+
+/// Require at least `min_points` positions
+fn validate_length(
+    positions: &[Position],
+    min_points: usize,
+) -> Result<(), GeometryValidationError> {
+    if positions.len() < min_points {
+        return Err(GeometryValidationError::TooFewPoints {
+            expected: min_points,
+            found: positions.len(),
+        });
+    }
+    Ok(())
+}
+
+/// Require a linear ring to have 4 positions and end where it starts
+fn validate_ring_shape(ring: &[Position]) -> Result<(), GeometryValidationError> {
+    validate_length(ring, 4)?;
+    let xy = |position: &Position| position.get(..2).map(<[f64]>::to_vec);
+    if ring.first().map(xy) != ring.last().map(xy) {
+        return Err(GeometryValidationError::UnclosedRing);
+    }
+    Ok(())
+}
+
+/// Check that a linear ring is long enough, closed and simple
+fn validate_ring(ring: &[Position], rules: Rules) -> Result<(), GeometryValidationError> {
+    validate_positions(ring, 4, rules)?;
+    validate_ring_shape(ring)?;
+    if ring.is_empty() || !rules.reject_self_intersection {
+        return Ok(());
+    }
+    let coords: Vec<Coord<f64>> = ring
+        .iter()
+        .map(|position| Coord::from((position[0], position[1])))
+        .collect();
+    if ring_crossings(&coords).is_empty() {
+        Ok(())
+    } else {
+        Err(GeometryValidationError::SelfIntersection)
+    }
+}
+
+/// Find the intersections between segments of a closed ring that are not neighbours
+///
+/// Segments are sorted by their smallest x and only compared while their x
+/// ranges overlap, so a typical ring costs O(n log n) rather than a
+/// comparison of every pair.
+///
+/// # Arguments
+///
+/// * `ring` - The coordinates of a closed ring
+///
+/// # Returns
+///
+/// * `Vec<(usize, usize, LineIntersection<f64>)>` - The indices of both segments, the lower first, and their intersection, ordered by segment indices
+pub(crate) fn ring_crossings(ring: &[Coord<f64>]) -> Vec<(usize, usize, LineIntersection<f64>)> {
+    let segments: Vec<Line<f64>> = ring
+        .windows(2)
+        .map(|pair| Line::new(pair[0], pair[1]))
+        .collect();
+    let Some(last) = segments.len().checked_sub(1) else {
+        return Vec::new();
+    };
+    let min_x = |segment: &Line<f64>| segment.start.x.min(segment.end.x);
+    let max_x = |segment: &Line<f64>| segment.start.x.max(segment.end.x);
+    let mut order: Vec<usize> = (0..segments.len()).collect();
+    order.sort_by(|&a, &b| min_x(&segments[a]).total_cmp(&min_x(&segments[b])));
+
+    let mut active: Vec<usize> = Vec::new();
+    let mut crossings = Vec::new();
+    for index in order {
+        let start = min_x(&segments[index]);
+        active.retain(|&other| max_x(&segments[other]) >= start);
+        for &other in &active {
+            let (a, b) = (index.min(other), index.max(other));
+            // Neighbouring segments share an end point, including the last and the first
+            if b == a + 1 || (a == 0 && b == last) {
+                continue;
+            }
+            if let Some(intersection) = line_intersection(segments[a], segments[b]) {
+                crossings.push((a, b, intersection));
+            }
+        }
+        active.push(index);
+    }
+    crossings.sort_by_key(|&(a, b, _)| (a, b));
+    crossings
+}
+
+fn validate_polygon(rings: &[Vec<Position>], rules: Rules) -> Result<(), GeometryValidationError> {
+    if rings.is_empty() {
+        return empty(rules);
+    }
+    rings.iter().try_for_each(|ring| validate_ring(ring, rules))
+}
+
+/// Validate the structure and coordinates of a GeoJSON geometry
+///
+/// Unlike the checks done while processing, this reports why a geometry is
+/// invalid: positions must have finite x and y values within the WGS84 range,
+/// line strings need 2 positions, polygon rings need 4 positions, must be
+/// closed and must not cross themselves, and no geometry may be empty.
+/// Validation stops at the first problem. Self-intersection is checked per
+/// ring, comparing segments whose x ranges overlap.
+///
+/// # Arguments
+///
+/// * `value` - A GeoJSON geometry value in WGS84
+///
+/// # Returns
+///
+/// * `Result<(), GeometryValidationError>` - The first problem found
+///
+/// # Example
+///
+/// ```rust
+/// use geojson::Value;
+/// use proj_exercise_simple::error::GeometryValidationError;
+/// use proj_exercise_simple::validation::validate_geometry;
+///
+/// assert!(validate_geometry(&Value::Point(vec![13.377, 52.518])).is_ok());
+/// assert_eq!(
+///     validate_geometry(&Value::LineString(vec![])),
+///     Err(GeometryValidationError::EmptyGeometry)
+/// );
+/// ```
+pub fn validate_geometry(value: &Value) -> Result<(), GeometryValidationError> {
+    validate_value(value, STRUCTURAL)
+}
+
+/// Collect every problem of a geometry instead of stopping at the first
+///
+/// Every position is checked against `bounds`, and every polygon ring for
+/// its length and closure with the same rules as `validate_geometry`.
+/// Polygons are counted in document order, ring 0 is the exterior and ring
+/// `n` the `n`th hole.
+///
+/// # Arguments
+///
+/// * `value` - A GeoJSON geometry value
+/// * `bounds` - The valid coordinate range
+///
+/// # Returns
+///
+/// * `Vec<ValidationError>` - The invalid positions, followed by the invalid rings
+pub(crate) fn collect_validation_errors(
+    value: &Value,
+    bounds: CoordinateBounds,
+) -> Vec<ValidationError> {
+    let mut errors = Vec::new();
+    for_each_position(value, &mut |position| {
+        if let Err(error) = bounds.validate_position(position) {
+            let reason = match error {
+                ProjectionError::InvalidCoordinates(reason) => reason,
+                other => other.to_string(),
+            };
+            errors.push(ValidationError::InvalidPosition {
+                position: position.to_vec(),
+                reason,
+            });
+        }
+    });
+    let mut polygon = 0;
+    collect_ring_errors(value, &mut polygon, &mut errors);
+    errors
+}
+
+fn collect_ring_errors(value: &Value, polygon: &mut usize, errors: &mut Vec<ValidationError>) {
+    match value {
+        Value::Polygon(rings) => collect_polygon_errors(rings, polygon, errors),
+        Value::MultiPolygon(polygons) => polygons
+            .iter()
+            .for_each(|rings| collect_polygon_errors(rings, polygon, errors)),
+        Value::GeometryCollection(geometries) => geometries
+            .iter()
+            .for_each(|geometry| collect_ring_errors(&geometry.value, polygon, errors)),
+        _ => {}
+    }
+}
+
+fn collect_polygon_errors(
+    rings: &[Vec<Position>],
+    polygon: &mut usize,
+    errors: &mut Vec<ValidationError>,
+) {
+    for (ring, positions) in rings.iter().enumerate() {
+        if let Err(source) = validate_ring_shape(positions) {
+            errors.push(ValidationError::InvalidRing {
+                polygon: *polygon,
+                ring,
+                source,
+            });
+        }
+    }
+    *polygon += 1;
+}
+
+/// Hash the exact coordinates and nesting of a geometry value
+///
+/// Unlike the geometry digest, nothing is quantized: two values only share a
+/// key when every ordinate has the same bits, so they validate the same.
+fn hash_value(hasher: &mut Fnv1a, value: &Value) {
+    let positions = |hasher: &mut Fnv1a, positions: &[Position]| {
+        hasher.write(&(positions.len() as u64).to_le_bytes());
+        for position in positions {
+            hasher.write(&(position.len() as u64).to_le_bytes());
+            position
+                .iter()
+                .for_each(|ordinate| hasher.write(&ordinate.to_bits().to_le_bytes()));
+        }
+    };
+    let polygon = |hasher: &mut Fnv1a, rings: &[Vec<Position>]| {
+        hasher.write(&(rings.len() as u64).to_le_bytes());
+        rings.iter().for_each(|ring| positions(hasher, ring));
+    };
+    match value {
+        Value::Point(position) => {
+            hasher.write(b"Point");
+            positions(hasher, std::slice::from_ref(position));
+        }
+        Value::MultiPoint(points) => {
+            hasher.write(b"MultiPoint");
+            positions(hasher, points);
+        }
+        Value::LineString(line) => {
+            hasher.write(b"LineString");
+            positions(hasher, line);
+        }
+        Value::MultiLineString(lines) => {
+            hasher.write(b"MultiLineString");
+            polygon(hasher, lines);
+        }
+        Value::Polygon(rings) => {
+            hasher.write(b"Polygon");
+            polygon(hasher, rings);
+        }
+        Value::MultiPolygon(polygons) => {
+            hasher.write(b"MultiPolygon");
+            hasher.write(&(polygons.len() as u64).to_le_bytes());
+            polygons.iter().for_each(|rings| polygon(hasher, rings));
+        }
+        Value::GeometryCollection(geometries) => {
+            hasher.write(b"GeometryCollection");
+            hasher.write(&(geometries.len() as u64).to_le_bytes());
+            geometries
+                .iter()
+                .for_each(|geometry| hash_value(hasher, &geometry.value));
+        }
+    }
+}
+
+/// A bounded cache of `validate_geometry` results
+///
+/// Results are keyed by a 64-bit hash of the exact coordinates, so a
+/// geometry seen before is not validated again. When the cache is full the
+/// oldest result is evicted. Two different geometries sharing a hash would
+/// share a result, which is unlikely enough to be ignored for a cache.
+///
+/// # Fields
+///
+/// * `results` - The cached results by geometry hash
+/// * `order` - The cached hashes, oldest first
+/// * `capacity` - The largest number of results kept
+/// * `hits` - The number of validations served from the cache
+/// * `misses` - The number of validations that had to be run
+#[derive(Debug)]
+pub struct ValidationCache {
+    results: HashMap<u64, Result<(), GeometryValidationError>>,
+    order: VecDeque<u64>,
+    capacity: usize,
+    hits: usize,
+    misses: usize,
+}
+
+impl ValidationCache {
+    /// Create an empty cache
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The largest number of results kept, a capacity of 0 caches nothing
+    ///
+    /// # Returns
+    ///
+    /// * `ValidationCache` - An empty cache
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            results: HashMap::with_capacity(capacity),
+            order: VecDeque::with_capacity(capacity),
+            capacity,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Validate a geometry like `validate_geometry`, reusing a cached result
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - A GeoJSON geometry value in WGS84
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), GeometryValidationError>` - The first problem found
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use geojson::Value;
+    /// use proj_exercise_simple::validation::ValidationCache;
+    ///
+    /// let mut cache = ValidationCache::new(100);
+    /// let point = Value::Point(vec![13.377, 52.518]);
+    /// assert!(cache.validate(&point).is_ok());
+    /// assert!(cache.validate(&point).is_ok());
+    /// assert_eq!((cache.hits(), cache.misses()), (1, 1));
+    /// ```
+    pub fn validate(&mut self, value: &Value) -> Result<(), GeometryValidationError> {
+        let mut hasher = Fnv1a::new();
+        hash_value(&mut hasher, value);
+        let key = hasher.finish();
+        if let Some(result) = self.results.get(&key) {
+            self.hits += 1;
+            return result.clone();
+        }
+        self.misses += 1;
+        let result = validate_geometry(value);
+        if self.capacity > 0 {
+            if self.order.len() == self.capacity {
+                if let Some(oldest) = self.order.pop_front() {
+                    self.results.remove(&oldest);
+                }
+            }
+            self.order.push_back(key);
+            self.results.insert(key, result.clone());
+        }
+        result
+    }
+
+    /// The number of validations served from the cache
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// The number of validations that had to be run
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    /// The number of cached results
+    pub fn len(&self) -> usize {
+        self.results.len()
+    }
+
+    /// Whether no results are cached
+    pub fn is_empty(&self) -> bool {
+        self.results.is_empty()
+    }
+
+    /// Drop every cached result, keeping the counters
+    pub fn clear(&mut self) {
+        self.results.clear();
+        self.order.clear();
+    }
+}
+
+fn validate_value(value: &Value, rules: Rules) -> Result<(), GeometryValidationError> {
+    match value {
+        Value::Point(position) if position.is_empty() => empty(rules),
+        Value::Point(position) => validate_position(position),
+        Value::MultiPoint(points) => validate_positions(points, 1, rules),
+        Value::LineString(line) => validate_positions(line, 2, rules),
+        Value::MultiLineString(lines) => {
+            if lines.is_empty() {
+                return empty(rules);
+            }
+            lines
+                .iter()
+                .try_for_each(|line| validate_positions(line, 2, rules))
+        }
+        Value::Polygon(rings) => validate_polygon(rings, rules),
+        Value::MultiPolygon(polygons) => {
+            if polygons.is_empty() {
+                return empty(rules);
+            }
+            polygons
+                .iter()
+                .try_for_each(|polygon| validate_polygon(polygon, rules))
+        }
+        Value::GeometryCollection(geometries) => {
+            if geometries.is_empty() {
+                return empty(rules);
+            }
+            geometries
+                .iter()
+                .try_for_each(|geometry| validate_value(&geometry.value, rules))
+        }
+    }
+}
+
+fn validate_bbox(bbox: Option<&Bbox>) -> Result<(), GeometryValidationError> {
+    match bbox {
+        Some(bbox) if bbox.len() != 4 && bbox.len() != 6 => {
+            Err(GeometryValidationError::InvalidBbox(bbox.len()))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn validate_rfc7946_geometry(geometry: &geojson::Geometry) -> Result<(), GeometryValidationError> {
+    validate_bbox(geometry.bbox.as_ref())?;
+    validate_value(&geometry.value, RFC_7946)
+}
+
+/// Check a GeoJSON object against the geometry rules of RFC 7946
+///
+/// Positions need finite values within the WGS84 range, line strings 2
+/// positions and rings 4 positions with the last equal to the first.
+/// Bounding boxes need 4 or 6 values. Empty geometries and features without
+/// a geometry are allowed, as the RFC allows them.
+///
+/// # Arguments
+///
+/// * `geojson` - The parsed input
+///
+/// # Returns
+///
+/// * `Result<(), ProjectionError>` - The first non-conformant feature and why
+pub(crate) fn validate_rfc7946(geojson: &GeoJson) -> Result<(), ProjectionError> {
+    let not_conformant = |feature_index: usize| {
+        move |source| ProjectionError::NotRfc7946 {
+            feature_index,
+            source,
+        }
+    };
+    let validate_feature = |index: usize, feature: &geojson::Feature| {
+        validate_bbox(feature.bbox.as_ref())
+            .and_then(|_| {
+                feature
+                    .geometry
+                    .iter()
+                    .try_for_each(validate_rfc7946_geometry)
+            })
+            .map_err(not_conformant(index))
+    };
+    match geojson {
+        GeoJson::FeatureCollection(collection) => {
+            validate_bbox(collection.bbox.as_ref()).map_err(not_conformant(0))?;
+            collection
+                .features
+                .iter()
+                .enumerate()
+                .try_for_each(|(index, feature)| validate_feature(index, feature))
+        }
+        GeoJson::Feature(feature) => validate_feature(0, feature),
+        GeoJson::Geometry(geometry) => {
+            validate_rfc7946_geometry(geometry).map_err(not_conformant(0))
+        }
+    }
+}
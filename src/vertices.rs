@@ -0,0 +1,273 @@
+use crate::coordinates::shoelace_signed_area;
+use geojson::{GeoJson, Value};
+
+/// Iterate lazily over every position of a GeoJSON geometry value
+///
+/// Positions are yielded in document order, descending into every ring, part
+/// and member of a geometry collection.
+///
+/// # Arguments
+///
+/// * `value` - A GeoJSON geometry value
+///
+/// # Returns
+///
+/// * `impl Iterator<Item = &Vec<f64>>` - The positions of the value
+///
+/// # Example
+///
+/// ```rust
+/// use geojson::Value;
+/// use proj_exercise_simple::vertices::iter_positions;
+///
+/// let line = Value::LineString(vec![vec![0.0, 0.0], vec![1.0, 1.0]]);
+/// assert!(iter_positions(&line).all(|p| p.len() == 2));
+/// ```
+pub fn iter_positions(value: &Value) -> impl Iterator<Item = &Vec<f64>> {
+    boxed_positions(value)
+}
+
+/// Compute the signed area of a GeoJSON linear ring with the shoelace formula
+///
+/// Works on the raw coordinate arrays, so the winding of a ring can be
+/// checked before converting it. The ring may be closed or not. Positions
+/// with fewer than 2 ordinates are skipped, extra ordinates are ignored.
+///
+/// # Arguments
+///
+/// * `ring` - The positions of the ring
+///
+/// # Returns
+///
+/// * `f64` - The area, positive for counter-clockwise and negative for clockwise rings
+///
+/// # Example
+///
+/// ```rust
+/// use proj_exercise_simple::vertices::ring_signed_area;
+///
+/// let ccw = vec![vec![0.0, 0.0], vec![2.0, 0.0], vec![2.0, 2.0], vec![0.0, 2.0], vec![0.0, 0.0]];
+/// assert_eq!(ring_signed_area(&ccw), 4.0);
+/// ```
+pub fn ring_signed_area(ring: &[Vec<f64>]) -> f64 {
+    let points: Vec<(f64, f64)> = ring
+        .iter()
+        .filter_map(|position| match position[..] {
+            [x, y, ..] => Some((x, y)),
+            _ => None,
+        })
+        .collect();
+    shoelace_signed_area(&points)
+}
+
+/// Compute the bounding box of a GeoJSON object without projecting it
+///
+/// Every position of every feature is visited in place, descending into
+/// geometry collections. Features without a geometry are skipped and
+/// ordinates beyond x and y, such as altitudes, are ignored.
+///
+/// # Arguments
+///
+/// * `geojson` - A GeoJSON object
+///
+/// # Returns
+///
+/// * `Option<[f64; 4]>` - `[min_x, min_y, max_x, max_y]`, or `None` without any position
+///
+/// # Example
+///
+/// ```rust
+/// use geojson::GeoJson;
+/// use proj_exercise_simple::vertices::compute_bbox;
+///
+/// let geojson: GeoJson = r#"{"type": "LineString", "coordinates": [[13.4, 52.5, 34.0], [2.35, 48.86, 35.0]]}"#
+///     .parse()
+///     .unwrap();
+/// assert_eq!(compute_bbox(&geojson), Some([2.35, 48.86, 13.4, 52.5]));
+/// ```
+pub fn compute_bbox(geojson: &GeoJson) -> Option<[f64; 4]> {
+    let mut bbox = None;
+    for_each_feature_position(geojson, |_, position| extend_bbox(&mut bbox, position));
+    bbox
+}
+
+/// Compute the bounding box of every feature of a GeoJSON object without projecting it
+///
+/// A single `Feature` or `Geometry` counts as one feature.
+///
+/// # Arguments
+///
+/// * `geojson` - A GeoJSON object
+///
+/// # Returns
+///
+/// * `Vec<Option<[f64; 4]>>` - The bounding box of each feature in input order, `None` for features without positions
+pub fn compute_bbox_per_feature(geojson: &GeoJson) -> Vec<Option<[f64; 4]>> {
+    let count = match geojson {
+        GeoJson::FeatureCollection(collection) => collection.features.len(),
+        GeoJson::Feature(_) | GeoJson::Geometry(_) => 1,
+    };
+    let mut bboxes = vec![None; count];
+    for_each_feature_position(geojson, |index, position| {
+        extend_bbox(&mut bboxes[index], position)
+    });
+    bboxes
+}
+
+/// Grow a bounding box to include the x and y of a position
+fn extend_bbox(bbox: &mut Option<[f64; 4]>, position: &[f64]) {
+    let [x, y, ..] = *position else {
+        return;
+    };
+    let [min_x, min_y, max_x, max_y] = bbox.get_or_insert([x, y, x, y]);
+    *min_x = min_x.min(x);
+    *min_y = min_y.min(y);
+    *max_x = max_x.max(x);
+    *max_y = max_y.max(y);
+}
+
+/// The iterator behind `iter_positions`, boxed so geometry collections can recurse
+fn boxed_positions(value: &Value) -> Box<dyn Iterator<Item = &Vec<f64>> + '_> {
+    match value {
+        Value::Point(position) => Box::new(std::iter::once(position)),
+        Value::MultiPoint(positions) | Value::LineString(positions) => Box::new(positions.iter()),
+        Value::MultiLineString(lines) | Value::Polygon(lines) => Box::new(lines.iter().flatten()),
+        Value::MultiPolygon(polygons) => Box::new(polygons.iter().flatten().flatten()),
+        Value::GeometryCollection(geometries) => Box::new(
+            geometries
+                .iter()
+                .flat_map(|geometry| boxed_positions(&geometry.value)),
+        ),
+    }
+}
+
+/// Call a function for every position of a GeoJSON geometry value
+///
+/// Positions are visited in document order, descending into every ring, part
+/// and member of a geometry collection.
+///
+/// # Arguments
+///
+/// * `value` - A GeoJSON geometry value
+/// * `visit` - The function called with each position
+pub(crate) fn for_each_position(value: &Value, visit: &mut impl FnMut(&[f64])) {
+    match value {
+        Value::Point(position) => visit(position),
+        Value::MultiPoint(positions) | Value::LineString(positions) => {
+            positions.iter().for_each(|p| visit(p))
+        }
+        Value::MultiLineString(lines) | Value::Polygon(lines) => {
+            lines.iter().flatten().for_each(|p| visit(p))
+        }
+        Value::MultiPolygon(polygons) => polygons.iter().flatten().flatten().for_each(|p| visit(p)),
+        Value::GeometryCollection(geometries) => geometries
+            .iter()
+            .for_each(|geometry| for_each_position(&geometry.value, visit)),
+    }
+}
+
+/// Call a function for every position of every feature of a GeoJSON object
+///
+/// The function receives the index of the feature the position belongs to. A
+/// single `Feature` or `Geometry` counts as feature 0, features without a
+/// geometry are skipped.
+///
+/// # Arguments
+///
+/// * `geojson` - A GeoJSON object
+/// * `visit` - The function called with each feature index and position
+pub(crate) fn for_each_feature_position(geojson: &GeoJson, mut visit: impl FnMut(usize, &[f64])) {
+    match geojson {
+        GeoJson::FeatureCollection(collection) => {
+            for (index, feature) in collection.features.iter().enumerate() {
+                if let Some(geometry) = &feature.geometry {
+                    for_each_position(&geometry.value, &mut |p| visit(index, p));
+                }
+            }
+        }
+        GeoJson::Feature(feature) => {
+            if let Some(geometry) = &feature.geometry {
+                for_each_position(&geometry.value, &mut |p| visit(0, p));
+            }
+        }
+        GeoJson::Geometry(geometry) => for_each_position(&geometry.value, &mut |p| visit(0, p)),
+    }
+}
+
+/// Call a function for every position of a GeoJSON geometry value, allowing changes
+///
+/// # Arguments
+///
+/// * `value` - A GeoJSON geometry value
+/// * `visit` - The function called with each position
+pub(crate) fn for_each_position_mut(value: &mut Value, visit: &mut impl FnMut(&mut Vec<f64>)) {
+    match value {
+        Value::Point(position) => visit(position),
+        Value::MultiPoint(positions) | Value::LineString(positions) => {
+            positions.iter_mut().for_each(&mut *visit)
+        }
+        Value::MultiLineString(lines) | Value::Polygon(lines) => {
+            lines.iter_mut().flatten().for_each(&mut *visit)
+        }
+        Value::MultiPolygon(polygons) => polygons
+            .iter_mut()
+            .flatten()
+            .flatten()
+            .for_each(&mut *visit),
+        Value::GeometryCollection(geometries) => geometries
+            .iter_mut()
+            .for_each(|geometry| for_each_position_mut(&mut geometry.value, visit)),
+    }
+}
+
+/// Collect the ordinates beyond x and y of every position, such as Z and M values
+///
+/// # Arguments
+///
+/// * `value` - A GeoJSON geometry value
+///
+/// # Returns
+///
+/// * `Option<Vec<Vec<f64>>>` - The extra ordinates per position in document order, `None` if every position is 2D
+pub(crate) fn extra_ordinates(value: &Value) -> Option<Vec<Vec<f64>>> {
+    let mut extras = Vec::new();
+    let mut any = false;
+    for_each_position(value, &mut |position| {
+        let extra = position.get(2..).unwrap_or_default();
+        any |= !extra.is_empty();
+        extras.push(extra.to_vec());
+    });
+    any.then_some(extras)
+}
+
+/// Append previously collected extra ordinates to the positions of output geometries
+///
+/// Positions are matched in document order, across all geometries, so this
+/// only works when processing kept every vertex.
+///
+/// # Arguments
+///
+/// * `values` - The output geometry values of one input geometry, in order
+/// * `extras` - The extra ordinates from `extra_ordinates`
+///
+/// # Returns
+///
+/// * `bool` - Whether the vertex counts matched and the ordinates were restored
+pub(crate) fn restore_extra_ordinates(values: &mut [&mut Value], extras: &[Vec<f64>]) -> bool {
+    let mut count = 0;
+    for value in values.iter() {
+        for_each_position(value, &mut |_| count += 1);
+    }
+    if count != extras.len() {
+        return false;
+    }
+    let mut extras = extras.iter();
+    for value in values.iter_mut() {
+        for_each_position_mut(value, &mut |position| {
+            if let Some(extra) = extras.next() {
+                position.extend_from_slice(extra);
+            }
+        });
+    }
+    true
+}
@@ -1,4 +1,5 @@
 use geo::{LineString, Point};
+use serde_json::json;
 
 pub struct GeometryFixtures;
 
@@ -21,6 +22,73 @@ impl GeometryFixtures {
             .collect();
         LineString::from(points)
     }
+
+    /// A small feature collection around the Reichstag in Berlin, in EPSG:4326
+    pub fn berlin_feature_collection() -> serde_json::Value {
+        json!({
+            "type": "FeatureCollection",
+            "features": [
+                {
+                    "type": "Feature",
+                    "id": "reichstag",
+                    "properties": { "name": "Reichstag" },
+                    "geometry": { "type": "Point", "coordinates": [13.377, 52.518] }
+                },
+                {
+                    "type": "Feature",
+                    "id": "spree",
+                    "properties": { "name": "Spree" },
+                    "geometry": {
+                        "type": "LineString",
+                        "coordinates": [[13.377, 52.518], [13.379, 52.517], [13.381, 52.516]]
+                    }
+                },
+                {
+                    "type": "Feature",
+                    "id": "tiergarten",
+                    "properties": { "name": "Tiergarten" },
+                    "geometry": {
+                        "type": "Polygon",
+                        "coordinates": [[
+                            [13.350, 52.515],
+                            [13.355, 52.515],
+                            [13.355, 52.510],
+                            [13.350, 52.510],
+                            [13.350, 52.515]
+                        ]]
+                    }
+                }
+            ]
+        })
+    }
+
+    pub fn mixed_property_collection() -> serde_json::Value {
+        json!({
+            "type": "FeatureCollection",
+            "features": [
+                {
+                    "type": "Feature",
+                    "properties": { "name": "Reichstag", "floors": 4, "listed": true },
+                    "geometry": { "type": "Point", "coordinates": [13.376, 52.518] }
+                },
+                {
+                    "type": "Feature",
+                    "properties": { "name": "Fernsehturm", "floors": 2, "listed": null },
+                    "geometry": { "type": "Point", "coordinates": [13.409, 52.520] }
+                },
+                {
+                    "type": "Feature",
+                    "properties": { "name": "Rotes Rathaus", "floors": "42", "listed": true },
+                    "geometry": { "type": "Point", "coordinates": [13.408, 52.518] }
+                },
+                {
+                    "type": "Feature",
+                    "properties": { "name": "Berliner Dom", "floors": "unknown" },
+                    "geometry": { "type": "Point", "coordinates": [13.401, 52.519] }
+                }
+            ]
+        })
+    }
 }
 
 #[cfg(test)]
@@ -1,6 +1,7 @@
 use geo::Point;
 use std::collections::VecDeque;
 
+#[derive(Default)]
 pub struct TestBufferPool {
     point_buffers: VecDeque<Vec<Point<f64>>>,
 }
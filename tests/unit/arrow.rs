@@ -0,0 +1,228 @@
+use arrow_array::{Array, BinaryArray, Float64Array, ListArray, RecordBatch, StringArray};
+use arrow_ipc::reader::StreamReader;
+use geo::{Coord, LineString, Polygon};
+use geojson::FeatureCollection;
+use proj_exercise_simple::arrow::{to_arrow, write_arrow_ipc, GeometryEncoding, GEOMETRY_COLUMN};
+use proj_exercise_simple::coerce::CoerceTo;
+use proj_exercise_simple::error::ProjectionError;
+use proj_exercise_simple::options::ProcessingOptions;
+use proj_exercise_simple::transformer::TransformerConfig;
+use serde_json::json;
+
+fn square(x: f64, y: f64) -> serde_json::Value {
+    json!([[
+        [x, y],
+        [x + 1.0, y],
+        [x + 1.0, y + 1.0],
+        [x, y + 1.0],
+        [x, y]
+    ]])
+}
+
+/// Two buildings, the second with its floor count as a string
+fn buildings() -> FeatureCollection {
+    serde_json::from_value(json!({
+        "type": "FeatureCollection",
+        "features": [
+            {
+                "type": "Feature",
+                "properties": { "name": "north", "floors": 3, "listed": true },
+                "geometry": { "type": "Polygon", "coordinates": square(13.0, 52.0) }
+            },
+            {
+                "type": "Feature",
+                "properties": { "name": "south", "floors": "4", "listed": false },
+                "geometry": { "type": "Polygon", "coordinates": square(13.0, 51.0) }
+            },
+            {
+                "type": "Feature",
+                "properties": { "name": "east", "floors": 2 },
+                "geometry": { "type": "Polygon", "coordinates": square(14.0, 52.0) }
+            }
+        ]
+    }))
+    .unwrap()
+}
+
+fn export(
+    collection: &FeatureCollection,
+    encoding: GeometryEncoding,
+    options: &ProcessingOptions,
+) -> RecordBatch {
+    to_arrow(
+        collection,
+        encoding,
+        &mut TransformerConfig::default(),
+        options,
+    )
+    .unwrap()
+}
+
+/// Write a batch as an IPC stream and read it back
+fn roundtrip(batch: &RecordBatch) -> RecordBatch {
+    let mut bytes = Vec::new();
+    write_arrow_ipc(&mut bytes, batch).unwrap();
+    let mut reader = StreamReader::try_new(bytes.as_slice(), None).unwrap();
+    let batch = reader.next().unwrap().unwrap();
+    assert!(reader.next().is_none());
+    batch
+}
+
+/// Decode a little endian WKB polygon
+fn decode_wkb_polygon(wkb: &[u8]) -> Polygon<f64> {
+    let u32_at = |offset: usize| u32::from_le_bytes(wkb[offset..offset + 4].try_into().unwrap());
+    let f64_at = |offset: usize| f64::from_le_bytes(wkb[offset..offset + 8].try_into().unwrap());
+    assert_eq!(wkb[0], 1, "expected little endian WKB");
+    assert_eq!(u32_at(1), 3, "expected a WKB polygon");
+
+    let mut offset = 9;
+    let mut rings = Vec::new();
+    for _ in 0..u32_at(5) {
+        let count = u32_at(offset) as usize;
+        offset += 4;
+        let ring: Vec<Coord<f64>> = (0..count)
+            .map(|i| Coord {
+                x: f64_at(offset + 16 * i),
+                y: f64_at(offset + 16 * i + 8),
+            })
+            .collect();
+        offset += 16 * count;
+        rings.push(LineString::new(ring));
+    }
+    assert_eq!(offset, wkb.len());
+    let exterior = rings.remove(0);
+    Polygon::new(exterior, rings)
+}
+
+/// The first feature projected by the regular pipeline
+fn projected_first_polygon() -> Polygon<f64> {
+    let output = proj_exercise_simple::helpers::process_feature_collection(
+        serde_json::to_value(buildings()).unwrap(),
+        &mut TransformerConfig::default(),
+    )
+    .unwrap();
+    let geojson::GeoJson::FeatureCollection(collection) = output else {
+        panic!("expected a feature collection");
+    };
+    let geometry = collection.features[0].geometry.clone().unwrap();
+    Polygon::try_from(geometry.value).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wkb_export_reads_back() {
+        let batch = roundtrip(&export(
+            &buildings(),
+            GeometryEncoding::Wkb,
+            &ProcessingOptions::new(),
+        ));
+        assert_eq!(batch.num_rows(), 3);
+        assert_eq!(batch.schema().field(0).name(), GEOMETRY_COLUMN);
+        let metadata = batch.schema().field(0).metadata().clone();
+        assert_eq!(metadata["ARROW:extension:name"], "geoarrow.wkb");
+        assert!(metadata["ARROW:extension:metadata"].contains("EPSG:3857"));
+
+        let names = batch.column_by_name("name").unwrap();
+        let names = names.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(names.value(1), "south");
+
+        // "4" was coerced to the majority type of the key
+        let floors = batch.column_by_name("floors").unwrap();
+        let floors = floors.as_any().downcast_ref::<Float64Array>().unwrap();
+        assert_eq!(floors.value(1), 4.0);
+
+        // The key is missing from the last feature
+        assert!(batch.column_by_name("listed").unwrap().is_null(2));
+
+        let geometry = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<BinaryArray>()
+            .unwrap();
+        assert_eq!(
+            decode_wkb_polygon(geometry.value(0)),
+            projected_first_polygon()
+        );
+    }
+
+    #[test]
+    fn test_native_export_nests_polygon_rings() {
+        let batch = roundtrip(&export(
+            &buildings(),
+            GeometryEncoding::GeoArrowNative,
+            &ProcessingOptions::new(),
+        ));
+        assert_eq!(
+            batch.schema().field(0).metadata()["ARROW:extension:name"],
+            "geoarrow.polygon"
+        );
+        let polygons = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<ListArray>()
+            .unwrap();
+        assert_eq!(polygons.len(), 3);
+
+        let rings = polygons.value(0);
+        let rings = rings.as_any().downcast_ref::<ListArray>().unwrap();
+        assert_eq!(rings.len(), 1);
+        assert_eq!(rings.value(0).len(), 5);
+    }
+
+    #[test]
+    fn test_native_export_rejects_mixed_types_unless_coerced() {
+        let collection: FeatureCollection = serde_json::from_value(json!({
+            "type": "FeatureCollection",
+            "features": [
+                {
+                    "type": "Feature",
+                    "properties": null,
+                    "geometry": { "type": "Point", "coordinates": [1.0, 2.0] }
+                },
+                {
+                    "type": "Feature",
+                    "properties": null,
+                    "geometry": { "type": "MultiPoint", "coordinates": [[3.0, 4.0], [5.0, 6.0]] }
+                }
+            ]
+        }))
+        .unwrap();
+
+        match to_arrow(
+            &collection,
+            GeometryEncoding::GeoArrowNative,
+            &mut TransformerConfig::default(),
+            &ProcessingOptions::new(),
+        ) {
+            Err(ProjectionError::UnexpectedGeometry { feature_index, .. }) => {
+                assert_eq!(feature_index, 1)
+            }
+            other => panic!("expected a mixed geometry error, got {other:?}"),
+        }
+
+        let options = ProcessingOptions::new().with_coerce(CoerceTo::Multi);
+        let batch = export(&collection, GeometryEncoding::GeoArrowNative, &options);
+        assert_eq!(
+            batch.schema().field(0).metadata()["ARROW:extension:name"],
+            "geoarrow.multipoint"
+        );
+        let points = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<ListArray>()
+            .unwrap();
+        assert_eq!(points.value_length(0), 1);
+        assert_eq!(points.value_length(1), 2);
+
+        // WKB holds any mix of types
+        let batch = export(
+            &collection,
+            GeometryEncoding::Wkb,
+            &ProcessingOptions::new(),
+        );
+        assert_eq!(batch.num_rows(), 2);
+    }
+}
@@ -0,0 +1,93 @@
+use geojson::{GeoJson, Value};
+use proj_exercise_simple::axis_order::AxisOrder;
+use proj_exercise_simple::helpers::{process_feature_collection_with_options, ProcessingOutput};
+use proj_exercise_simple::options::ProcessingOptions;
+use proj_exercise_simple::transformer::TransformerConfig;
+use serde_json::json;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process(json_value: serde_json::Value, order: AxisOrder) -> ProcessingOutput {
+        let mut config = TransformerConfig::default();
+        let options = ProcessingOptions::new().with_input_axis_order(order);
+        process_feature_collection_with_options(json_value, &mut config, &options).unwrap()
+    }
+
+    fn point_feature(x: f64, y: f64) -> serde_json::Value {
+        json!({
+            "type": "Feature",
+            "properties": null,
+            "geometry": { "type": "Point", "coordinates": [x, y] }
+        })
+    }
+
+    fn point_of(geojson: &GeoJson) -> Vec<f64> {
+        match geojson {
+            GeoJson::Feature(feature) => match &feature.geometry.as_ref().unwrap().value {
+                Value::Point(point) => point.clone(),
+                _ => panic!("Expected a point"),
+            },
+            _ => panic!("Expected a feature"),
+        }
+    }
+
+    #[test]
+    fn test_lat_lon_input_matches_lon_lat() {
+        let expected = process(point_feature(13.377, 52.518), AxisOrder::LonLat);
+        let swapped = process(point_feature(52.518, 13.377), AxisOrder::LatLon);
+        assert_eq!(point_of(&swapped.geojson), point_of(&expected.geojson));
+        assert!(swapped.report.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_auto_flags_ambiguous_point() {
+        let output = process(point_feature(52.518, 13.377), AxisOrder::Auto);
+        assert_eq!(output.report.warnings.len(), 1);
+        assert_eq!(output.report.warnings[0].feature_index, 0);
+        assert!(output.report.warnings[0].message.contains("ambiguous"));
+    }
+
+    #[test]
+    fn test_auto_swaps_latitude_first_features() {
+        let collection = json!({
+            "type": "FeatureCollection",
+            "bbox": [-33.9, 35.6, 139.7, 151.3],
+            "features": [
+                point_feature(139.69, 35.69),
+                {
+                    "type": "Feature",
+                    "properties": null,
+                    "geometry": {
+                        "type": "LineString",
+                        "coordinates": [[-33.86, 151.2], [-33.87, 151.21]]
+                    }
+                }
+            ]
+        });
+        let output = process(collection, AxisOrder::Auto);
+        assert_eq!(output.report.warnings.len(), 1);
+        assert_eq!(output.report.warnings[0].feature_index, 1);
+        assert!(output.report.warnings[0].message.contains("swapped"));
+
+        let expected = process(
+            json!({
+                "type": "Feature",
+                "properties": null,
+                "geometry": {
+                    "type": "LineString",
+                    "coordinates": [[151.2, -33.86], [151.21, -33.87]]
+                }
+            }),
+            AxisOrder::LonLat,
+        );
+        let GeoJson::FeatureCollection(processed) = output.geojson else {
+            panic!("Expected a feature collection");
+        };
+        let GeoJson::Feature(expected) = expected.geojson else {
+            panic!("Expected a feature");
+        };
+        assert_eq!(processed.features[1].geometry, expected.geometry);
+    }
+}
@@ -1,4 +1,14 @@
-use proj_exercise_simple::pool::CoordinateBufferPool;
+use proj_exercise_simple::coordinates::{Coordinate, Line};
+use proj_exercise_simple::error::ProjectionError;
+use proj_exercise_simple::helpers::process_feature_collection;
+use proj_exercise_simple::pool::{
+    default_pool_params, set_default_pool_params, BufferPoolError, BufferType,
+    CoordinateBufferPool, PoolEvent,
+};
+use proj_exercise_simple::transformer::TransformerConfig;
+use serde_json::json;
+use std::error::Error;
+use std::sync::{Arc, Mutex, PoisonError};
 #[cfg(test)]
 mod tests {
 
@@ -69,4 +79,277 @@ mod tests {
         println!("stats: {:?}", pool.stats().unwrap());
         Ok(())
     }
+
+    #[test]
+    fn test_prefill_populates_every_queue() {
+        let pool = CoordinateBufferPool::new(10, 100);
+        pool.prefill(5).unwrap();
+
+        assert_eq!(pool.point_buffers.lock().unwrap().len(), 5);
+        assert_eq!(pool.line_buffers.lock().unwrap().len(), 5);
+        assert_eq!(pool.polygon_buffers.lock().unwrap().len(), 5);
+        assert_eq!(pool.stats().unwrap().prefilled_buffers(), 15);
+
+        // The first get reuses a prefilled buffer instead of allocating
+        let buffer = pool.get_point_buffer().unwrap();
+        assert_eq!(buffer.capacity(), 10);
+        assert_eq!(pool.point_buffers.lock().unwrap().len(), 4);
+
+        let lines = pool.get_line_buffer().unwrap();
+        assert_eq!(lines.capacity(), 10);
+        assert_eq!(pool.line_buffers.lock().unwrap().len(), 4);
+    }
+
+    #[test]
+    fn test_prefill_respects_max_size() {
+        let pool = CoordinateBufferPool::new(10, 3);
+        pool.prefill(5).unwrap();
+        assert_eq!(pool.point_buffers.lock().unwrap().len(), 3);
+
+        // Prefilling again only tops up missing buffers
+        pool.prefill(3).unwrap();
+        assert_eq!(pool.point_buffers.lock().unwrap().len(), 3);
+        assert_eq!(pool.stats().unwrap().prefilled_buffers(), 9);
+    }
+
+    #[test]
+    fn test_reused_buffers_keep_their_capacity() {
+        let pool = CoordinateBufferPool::new(10, 100);
+
+        let mut points = pool.get_point_buffer().unwrap();
+        points.extend((0..50).map(|i| Coordinate::new(i as f64, 0.0)));
+        let grown = points.capacity();
+        pool.return_point_buffer(points).unwrap();
+
+        let mut lines = pool.get_line_buffer().unwrap();
+        lines.extend((0..50).map(|_| Line::new(Vec::new())));
+        let grown_lines = lines.capacity();
+        pool.return_line_buffer(lines).unwrap();
+
+        // Repeated retrievals neither grow nor shrink the buffers
+        for _ in 0..5 {
+            let points = pool.get_point_buffer().unwrap();
+            assert_eq!(points.capacity(), grown);
+            assert!(points.is_empty());
+            pool.return_point_buffer(points).unwrap();
+
+            let lines = pool.get_line_buffer().unwrap();
+            assert_eq!(lines.capacity(), grown_lines);
+            pool.return_line_buffer(lines).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_minimum_capacity_picks_a_large_enough_buffer() {
+        let pool = CoordinateBufferPool::new(10, 100);
+        pool.prefill(2).unwrap();
+        let mut large = pool.get_point_buffer().unwrap();
+        large.reserve(500);
+        large.push(Coordinate::new(1.0, 2.0));
+        let large_capacity = large.capacity();
+        pool.return_point_buffer(large).unwrap();
+
+        // The large buffer sits behind a small one and is still found
+        let buffer = pool.get_point_buffer_with_minimum_capacity(500).unwrap();
+        assert_eq!(buffer.capacity(), large_capacity);
+        assert!(buffer.is_empty());
+        assert_eq!(pool.point_buffers.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_minimum_capacity_allocates_when_no_buffer_fits() {
+        let pool = CoordinateBufferPool::new(10, 100);
+        pool.prefill(3).unwrap();
+
+        let buffer = pool.get_point_buffer_with_minimum_capacity(1000).unwrap();
+        assert!(buffer.capacity() >= 1000);
+        assert_eq!(pool.point_buffers.lock().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_return_point_buffers_returns_a_batch() {
+        let pool = CoordinateBufferPool::new(10, 100);
+        pool.prefill(2).unwrap();
+        let buffers: Vec<_> = (0..3)
+            .map(|_| {
+                let mut buffer = pool.get_or_create_point_buffer();
+                buffer.push(Coordinate::new(1.0, 2.0));
+                buffer
+            })
+            .collect();
+        pool.prefill(1).unwrap();
+        let before = pool.point_buffers.lock().unwrap().len();
+
+        pool.return_point_buffers(buffers).unwrap();
+
+        let pooled = pool.point_buffers.lock().unwrap();
+        assert_eq!(pooled.len(), before + 3);
+        assert!(pooled.iter().all(Vec::is_empty));
+    }
+
+    #[test]
+    fn test_full_pool_drops_single_and_batch_returns_alike() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&events);
+        let pool = CoordinateBufferPool::new(10, 2)
+            .with_event_hook(move |event| recorded.lock().unwrap().push(event));
+
+        // A batch that overflows the pool keeps what fits
+        pool.return_point_buffers(vec![Vec::new(), Vec::new(), Vec::new()])
+            .unwrap();
+        pool.return_line_buffers(vec![Vec::new(), Vec::new(), Vec::new()])
+            .unwrap();
+        pool.return_polygon_buffers(vec![Vec::new(), Vec::new(), Vec::new()])
+            .unwrap();
+        // A single buffer returned to the full pool is dropped the same way
+        pool.return_point_buffer(Vec::new()).unwrap();
+        pool.return_line_buffer(Vec::new()).unwrap();
+        pool.return_polygon_buffer(Vec::new()).unwrap();
+
+        assert_eq!(pool.point_buffers.lock().unwrap().len(), 2);
+        assert_eq!(pool.line_buffers.lock().unwrap().len(), 2);
+        assert_eq!(pool.polygon_buffers.lock().unwrap().len(), 2);
+        // Only the kept buffers are reported
+        let events = events.lock().unwrap();
+        for buffer_type in [BufferType::Point, BufferType::Line, BufferType::Polygon] {
+            let returns = events
+                .iter()
+                .filter(|event| {
+                    matches!(event, PoolEvent::Return { buffer_type: t, .. } if *t == buffer_type)
+                })
+                .count();
+            assert_eq!(returns, 2);
+        }
+    }
+
+    #[test]
+    fn test_event_hook_sees_gets_returns_and_misses() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&events);
+        let pool = CoordinateBufferPool::new(10, 100)
+            .with_event_hook(move |event| recorded.lock().unwrap().push(event));
+
+        let points = pool.get_point_buffer().unwrap();
+        pool.return_point_buffer(points).unwrap();
+        let points = pool.get_point_buffer().unwrap();
+        let lines = pool.get_line_buffer().unwrap();
+        pool.return_line_buffer(lines).unwrap();
+        let polygons = pool.get_polygon_buffer().unwrap();
+        drop((points, polygons));
+
+        let get = |buffer_type| PoolEvent::Get {
+            buffer_type,
+            capacity: 10,
+        };
+        let put = |buffer_type| PoolEvent::Return {
+            buffer_type,
+            capacity: 10,
+        };
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![
+                PoolEvent::Miss,
+                get(BufferType::Point),
+                put(BufferType::Point),
+                get(BufferType::Point),
+                PoolEvent::Miss,
+                get(BufferType::Line),
+                put(BufferType::Line),
+                PoolEvent::Miss,
+                get(BufferType::Polygon),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_event_hook_may_use_the_pool() {
+        let pool = Arc::new(Mutex::new(None::<Arc<CoordinateBufferPool>>));
+        let inner = Arc::clone(&pool);
+        let hooked = Arc::new(
+            CoordinateBufferPool::new(10, 100).with_event_hook(move |_| {
+                if let Some(pool) = inner.lock().unwrap().as_ref() {
+                    // Reading the pool from the hook must not deadlock
+                    let _ = pool.point_buffers.lock().unwrap().len();
+                }
+            }),
+        );
+        *pool.lock().unwrap() = Some(Arc::clone(&hooked));
+
+        let buffer = hooked.get_point_buffer().unwrap();
+        hooked.return_point_buffer(buffer).unwrap();
+        assert_eq!(hooked.point_buffers.lock().unwrap().len(), 1);
+        pool.lock().unwrap().take();
+    }
+
+    #[test]
+    fn test_poisoned_mutex_error_chain() {
+        let mutex = Arc::new(Mutex::new(()));
+        let poisoner = Arc::clone(&mutex);
+        let _ = std::thread::spawn(move || {
+            let _guard = poisoner.lock().unwrap();
+            panic!("poison the mutex");
+        })
+        .join();
+        let original = mutex.lock().unwrap_err();
+
+        let error = ProjectionError::from(BufferPoolError::from(original));
+        let pool_error = error.source().unwrap();
+        assert!(pool_error.to_string().starts_with("Mutex was poisoned"));
+        let poison = pool_error.source().unwrap();
+        assert!(poison.downcast_ref::<PoisonError<()>>().is_some());
+        assert_eq!(
+            poison.to_string(),
+            "poisoned lock: another task failed inside"
+        );
+        assert!(poison.source().is_none());
+    }
+
+    #[test]
+    fn test_get_or_create_grows_beyond_max_size() {
+        let pool = CoordinateBufferPool::new(10, 2);
+        let buffers: Vec<_> = (0..5).map(|_| pool.get_or_create_point_buffer()).collect();
+        assert!(buffers.iter().all(|b| b.is_empty() && b.capacity() == 10));
+        assert_eq!(pool.stats().unwrap().current_usage(), 5);
+
+        // Returning more buffers than max_size drops the extra ones instead of failing
+        for buffer in buffers {
+            pool.return_point_buffer(buffer).unwrap();
+        }
+        assert_eq!(pool.stats().unwrap().current_usage(), 0);
+
+        let mut reused = pool.get_or_create_point_buffer();
+        reused.push(Coordinate::new(1.0, 2.0));
+        pool.return_point_buffer(reused).unwrap();
+        assert!(pool.get_or_create_point_buffer().is_empty());
+    }
+
+    #[test]
+    fn test_default_pool_params_apply_to_convenience_functions() {
+        let original = default_pool_params();
+        assert_eq!(original, (10, 100));
+        set_default_pool_params(4096, 8);
+
+        let coordinates: Vec<[f64; 2]> = (0..10_000)
+            .map(|i| [i as f64 / 100.0, i as f64 / 200.0])
+            .collect();
+        let input = json!({
+            "type": "Feature",
+            "geometry": { "type": "LineString", "coordinates": coordinates },
+            "properties": {}
+        });
+        let output = process_feature_collection(input, &mut TransformerConfig::default());
+
+        let pool = CoordinateBufferPool::default();
+        let capacity = pool.get_point_buffer().unwrap().capacity();
+        set_default_pool_params(original.0, original.1);
+
+        assert!(output.is_ok());
+        assert_eq!(capacity, 4096);
+        assert_eq!(
+            CoordinateBufferPool::default()
+                .get_or_create_point_buffer()
+                .capacity(),
+            10
+        );
+    }
 }
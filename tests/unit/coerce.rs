@@ -0,0 +1,130 @@
+use geojson::GeoJson;
+use proj_exercise_simple::coerce::CoerceTo;
+use proj_exercise_simple::error::ProjectionError;
+use proj_exercise_simple::explode::ExplodePolicy;
+use proj_exercise_simple::helpers::process_feature_collection_with_options;
+use proj_exercise_simple::options::ProcessingOptions;
+use proj_exercise_simple::transformer::TransformerConfig;
+use serde_json::json;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn feature(geometry: serde_json::Value) -> serde_json::Value {
+        json!({ "type": "Feature", "properties": null, "geometry": geometry })
+    }
+
+    fn mixed_collection() -> serde_json::Value {
+        json!({
+            "type": "FeatureCollection",
+            "features": [
+                feature(json!({ "type": "Point", "coordinates": [1.0, 2.0] })),
+                feature(json!({ "type": "LineString", "coordinates": [[0.0, 0.0], [1.0, 1.0]] })),
+                feature(json!({
+                    "type": "Polygon",
+                    "coordinates": [[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 0.0]]]
+                })),
+                feature(json!({ "type": "MultiPoint", "coordinates": [[1.0, 2.0], [3.0, 4.0]] })),
+            ]
+        })
+    }
+
+    fn type_names(geojson: &GeoJson) -> Vec<&'static str> {
+        match geojson {
+            GeoJson::FeatureCollection(collection) => collection
+                .features
+                .iter()
+                .map(|f| f.geometry.as_ref().unwrap().value.type_name())
+                .collect(),
+            _ => panic!("Expected a feature collection"),
+        }
+    }
+
+    #[test]
+    fn test_coerce_to_multi() {
+        let mut config = TransformerConfig::default();
+        let options = ProcessingOptions::new().with_coerce(CoerceTo::Multi);
+        let output =
+            process_feature_collection_with_options(mixed_collection(), &mut config, &options)
+                .unwrap();
+        assert_eq!(
+            type_names(&output.geojson),
+            vec![
+                "MultiPoint",
+                "MultiLineString",
+                "MultiPolygon",
+                "MultiPoint"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_single_or_error_unwraps_single_members() {
+        let json_value = json!({
+            "type": "FeatureCollection",
+            "features": [feature(json!({ "type": "MultiPoint", "coordinates": [[1.0, 2.0]] }))]
+        });
+        let mut config = TransformerConfig::default();
+        let options = ProcessingOptions::new().with_coerce(CoerceTo::SingleOrError);
+        let output =
+            process_feature_collection_with_options(json_value, &mut config, &options).unwrap();
+        assert_eq!(type_names(&output.geojson), vec!["Point"]);
+    }
+
+    #[test]
+    fn test_single_or_error_rejects_multi_member_geometry() {
+        let square = |x: f64| vec![[x, 0.0], [x + 1.0, 0.0], [x + 1.0, 1.0], [x, 0.0]];
+        let json_value = json!({
+            "type": "FeatureCollection",
+            "features": [
+                feature(json!({ "type": "Point", "coordinates": [1.0, 2.0] })),
+                feature(json!({ "type": "MultiPolygon", "coordinates": [[square(0.0)], [square(2.0)]] })),
+            ]
+        });
+        let mut config = TransformerConfig::default();
+        let options = ProcessingOptions::new().with_coerce(CoerceTo::SingleOrError);
+        let result = process_feature_collection_with_options(json_value, &mut config, &options);
+        match result {
+            Err(ProjectionError::UnexpectedGeometry {
+                feature_index,
+                reason,
+            }) => {
+                assert_eq!(feature_index, 1);
+                assert!(reason.contains("MultiPolygon with 2 members"));
+            }
+            other => panic!("Expected an UnexpectedGeometry error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_geometry_collections_are_exploded_or_rejected() {
+        let json_value = feature(json!({
+            "type": "GeometryCollection",
+            "geometries": [
+                { "type": "Point", "coordinates": [1.0, 2.0] },
+                { "type": "LineString", "coordinates": [[0.0, 0.0], [1.0, 1.0]] }
+            ]
+        }));
+
+        let mut config = TransformerConfig::default();
+        let options = ProcessingOptions::new().with_coerce(CoerceTo::Multi);
+        let result =
+            process_feature_collection_with_options(json_value.clone(), &mut config, &options);
+        assert!(matches!(
+            result,
+            Err(ProjectionError::UnexpectedGeometry {
+                feature_index: 0,
+                ..
+            })
+        ));
+
+        let options = options.with_explode(ExplodePolicy::Collections);
+        let output =
+            process_feature_collection_with_options(json_value, &mut config, &options).unwrap();
+        assert_eq!(
+            type_names(&output.geojson),
+            vec!["MultiPoint", "MultiLineString"]
+        );
+    }
+}
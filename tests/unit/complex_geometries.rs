@@ -1,4 +1,7 @@
 use geojson::{Feature, FeatureCollection, GeoJson, Geometry, Value};
+use proj_exercise_simple::coordinates::BoundingBox;
+use proj_exercise_simple::kind::GeometryKind;
+use proj_exercise_simple::testing::{random_feature_collection, synthetic_line_string};
 use proj_exercise_simple::{helpers::process_feature_collection, transformer::TransformerConfig};
 
 #[test]
@@ -135,25 +138,11 @@ fn test_complex_feature_collection() {
 #[test]
 fn test_complex_feature_collection_with_large_geometries() {
     // Create a feature with a large number of points using valid coordinate ranges
-    let mut large_line_string = Vec::new();
-    for i in 0..1000 {
-        // Use valid longitude (-180 to 180) and latitude (-90 to 90) ranges
-        let lon = -180.0 + (i as f64 * 0.36); // 360 degrees / 1000 points
-        let lat = -90.0 + (i as f64 * 0.18); // 180 degrees / 1000 points
-        large_line_string.push(vec![lon, lat]);
-    }
-
-    let feature = Feature {
-        bbox: None,
-        geometry: Some(Geometry::new(Value::LineString(large_line_string))),
-        id: None,
-        properties: None,
-        foreign_members: None,
-    };
+    let line_string = synthetic_line_string(1000, BoundingBox::new(-180.0, 180.0, -85.0, 85.0));
 
     let feature_collection = FeatureCollection {
         bbox: None,
-        features: vec![feature],
+        features: vec![Feature::from(line_string)],
         foreign_members: None,
     };
 
@@ -190,25 +179,11 @@ fn test_complex_feature_collection_with_large_geometries() {
 #[test]
 fn test_complex_feature_collection_with_very_large_geometries() {
     // Create a feature with a very large number of points using valid coordinate ranges
-    let mut very_large_line_string = Vec::new();
-    for i in 0..10000 {
-        // Use valid longitude (-180 to 180) and latitude (-90 to 90) ranges
-        let lon = -180.0 + (i as f64 * 0.036); // 360 degrees / 10000 points
-        let lat = -90.0 + (i as f64 * 0.018); // 180 degrees / 10000 points
-        very_large_line_string.push(vec![lon, lat]);
-    }
-
-    let feature = Feature {
-        bbox: None,
-        geometry: Some(Geometry::new(Value::LineString(very_large_line_string))),
-        id: None,
-        properties: None,
-        foreign_members: None,
-    };
+    let line_string = synthetic_line_string(10000, BoundingBox::new(-180.0, 180.0, -85.0, 85.0));
 
     let feature_collection = FeatureCollection {
         bbox: None,
-        features: vec![feature],
+        features: vec![Feature::from(line_string)],
         foreign_members: None,
     };
 
@@ -241,3 +216,27 @@ fn test_complex_feature_collection_with_very_large_geometries() {
         _ => panic!("Expected FeatureCollection"),
     }
 }
+
+#[test]
+fn test_random_feature_collection_with_every_geometry_kind() {
+    let counts = [
+        (GeometryKind::Point, 20),
+        (GeometryKind::LineString, 20),
+        (GeometryKind::Polygon, 20),
+        (GeometryKind::MultiPoint, 10),
+        (GeometryKind::MultiLineString, 10),
+        (GeometryKind::MultiPolygon, 10),
+    ];
+    let feature_collection = random_feature_collection(7, &counts);
+    let json_value = serde_json::to_value(GeoJson::FeatureCollection(feature_collection)).unwrap();
+
+    let result = process_feature_collection(json_value, &mut TransformerConfig::default());
+    match result {
+        Ok(GeoJson::FeatureCollection(processed_collection)) => {
+            // Multi geometries are split into one feature per part
+            assert!(processed_collection.features.len() >= 90);
+        }
+        Err(e) => panic!("Failed to process feature collection: {:?}", e),
+        _ => panic!("Expected FeatureCollection"),
+    }
+}
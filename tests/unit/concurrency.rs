@@ -0,0 +1,120 @@
+//! Shares transformer configs and buffer pools across threads
+//!
+//! The crate does not depend on tokio, so these tests share a session across
+//! scoped std threads instead of tokio tasks. Both exercise the same
+//! `Send + Sync` contract, as tasks on a multi-threaded runtime run on such
+//! threads.
+
+use crate::fixtures::GeometryFixtures;
+use proj_exercise_simple::helpers::process_feature_collection;
+use proj_exercise_simple::pool::CoordinateBufferPool;
+use proj_exercise_simple::transformer::TransformerConfig;
+use std::sync::Arc;
+use std::thread;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shared_config_processes_on_many_threads() {
+        let config = Arc::new(TransformerConfig::default().with_precision(3));
+        let expected = process_feature_collection(
+            GeometryFixtures::berlin_feature_collection(),
+            &mut (*config).clone(),
+        )
+        .unwrap();
+
+        let outputs: Vec<_> = thread::scope(|scope| {
+            let handles: Vec<_> = (0..8)
+                .map(|_| {
+                    let config = Arc::clone(&config);
+                    scope.spawn(move || {
+                        let mut config = (*config).clone();
+                        process_feature_collection(
+                            GeometryFixtures::berlin_feature_collection(),
+                            &mut config,
+                        )
+                        .unwrap()
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+        assert!(outputs.iter().all(|output| *output == expected));
+    }
+
+    #[test]
+    fn test_transformers_are_cached_per_thread() {
+        let config = TransformerConfig::default();
+        config.get_transformer().unwrap();
+        assert!(config.is_transformer_available().unwrap());
+
+        thread::scope(|scope| {
+            scope.spawn(|| {
+                // Another thread creates its own transformer
+                assert!(!config.is_transformer_available().unwrap());
+                config.get_transformer().unwrap();
+                assert!(config.is_transformer_available().unwrap());
+            });
+        });
+
+        // Clearing invalidates the transformers of every thread
+        config.clear_cache().unwrap();
+        assert!(!config.is_transformer_available().unwrap());
+        thread::scope(|scope| {
+            scope.spawn(|| assert!(!config.is_transformer_available().unwrap()));
+        });
+    }
+
+    #[test]
+    fn test_updating_crs_does_not_affect_clones() {
+        let mut config = TransformerConfig::default();
+        let clone = config.clone();
+        config
+            .update_crs("EPSG:4326".to_string(), "EPSG:4326".to_string())
+            .unwrap();
+
+        let (x, _): (f64, f64) = clone
+            .get_transformer()
+            .unwrap()
+            .convert((1.0, 2.0))
+            .unwrap();
+        assert!((x - 111319.49079327357).abs() < 1e-6);
+        let (x, _): (f64, f64) = config
+            .get_transformer()
+            .unwrap()
+            .convert((1.0, 2.0))
+            .unwrap();
+        assert!((x - 1.0).abs() < 1e-9);
+        assert_eq!(config.to(), "EPSG:4326");
+        assert_eq!(clone.to(), "EPSG:3857");
+    }
+
+    #[test]
+    fn test_clones_share_crs_strings() {
+        let config = TransformerConfig::default();
+        let clone = config.clone();
+        assert!(std::ptr::eq(config.from(), clone.from()));
+        assert!(std::ptr::eq(config.to(), clone.to()));
+    }
+
+    #[test]
+    fn test_shared_buffer_pool_across_threads() {
+        let pool = Arc::new(CoordinateBufferPool::new(10, 100));
+        thread::scope(|scope| {
+            for _ in 0..4 {
+                let pool = Arc::clone(&pool);
+                scope.spawn(move || {
+                    for _ in 0..25 {
+                        let buffer = pool.get_point_buffer().unwrap();
+                        pool.return_point_buffer(buffer).unwrap();
+                    }
+                });
+            }
+        });
+        let stats = pool.stats().unwrap();
+        assert_eq!(stats.total_allocations(), 100);
+        assert_eq!(stats.current_usage(), 0);
+    }
+}
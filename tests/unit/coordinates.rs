@@ -0,0 +1,766 @@
+use geo::Area;
+use proj_exercise_simple::coordinates::{BoundingBox, Coordinate, Line, Polygon};
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::*;
+
+    #[test]
+    fn test_coordinate_ordering_is_lexicographic() {
+        let mut coords = vec![
+            Coordinate::new(1.0, 3.0),
+            Coordinate::new(0.0, 9.0),
+            Coordinate::new(1.0, -2.0),
+        ];
+        coords.sort();
+        assert_eq!(
+            coords,
+            vec![
+                Coordinate::new(0.0, 9.0),
+                Coordinate::new(1.0, -2.0),
+                Coordinate::new(1.0, 3.0),
+            ]
+        );
+        assert!(Coordinate::new(1.0, 2.0) < Coordinate::new(1.0, 2.5));
+    }
+
+    #[test]
+    fn test_coordinate_btree_set_deduplicates() {
+        let set: BTreeSet<Coordinate> = vec![
+            Coordinate::new(13.377, 52.518),
+            Coordinate::new(13.377, 52.518),
+            Coordinate::new(13.379, 52.517),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn test_coordinate_ordering_with_nan_is_total() {
+        let mut coords = [
+            Coordinate::new(f64::NAN, 0.0),
+            Coordinate::new(1.0, 0.0),
+            Coordinate::new(f64::NAN, 0.0),
+        ];
+        coords.sort();
+        assert_eq!(coords[0], Coordinate::new(1.0, 0.0));
+        assert!(coords[1].x.is_nan() && coords[2].x.is_nan());
+    }
+
+    #[test]
+    fn test_coordinate_equality_agrees_with_ordering() {
+        let pairs = [
+            (Coordinate::new(0.0, 1.0), Coordinate::new(-0.0, 1.0)),
+            (Coordinate::new(1.0, -0.0), Coordinate::new(1.0, 0.0)),
+            (
+                Coordinate::new(f64::NAN, 1.0),
+                Coordinate::new(f64::NAN, 1.0),
+            ),
+            (
+                Coordinate::new(f64::NAN, 1.0),
+                Coordinate::new(-f64::NAN, 1.0),
+            ),
+            (Coordinate::new(1.0, 2.0), Coordinate::new(f64::NAN, 2.0)),
+            (Coordinate::new(1.0, 2.0), Coordinate::new(1.0, 3.0)),
+        ];
+        for (a, b) in pairs {
+            assert_eq!(
+                a == b,
+                a.cmp(&b) == std::cmp::Ordering::Equal,
+                "{a:?} {b:?}"
+            );
+            assert_eq!(a == b, b == a);
+        }
+        assert_eq!(Coordinate::new(0.0, 0.0), Coordinate::new(-0.0, -0.0));
+        assert_eq!(
+            Coordinate::new(f64::NAN, 0.0),
+            Coordinate::new(f64::NAN, 0.0)
+        );
+
+        let set: BTreeSet<Coordinate> = pairs.iter().flat_map(|&(a, b)| [a, b]).collect();
+        assert_eq!(set.len(), 6);
+        let mut coords: Vec<Coordinate> = pairs.iter().flat_map(|&(a, b)| [a, b]).collect();
+        coords.sort();
+        coords.dedup();
+        assert_eq!(coords.len(), set.len());
+    }
+
+    #[test]
+    fn test_merge_collinear_removes_straight_vertices() {
+        let line = Line::new(vec![
+            Coordinate::new(0.0, 0.0),
+            Coordinate::new(0.1, 0.0),
+            Coordinate::new(0.2, 0.0001),
+            Coordinate::new(0.3, 0.0),
+            Coordinate::new(1.0, 0.0),
+            Coordinate::new(1.0, 1.0),
+        ]);
+        let merged = line.merge_collinear(1.0);
+        assert_eq!(
+            merged.coordinates,
+            vec![
+                Coordinate::new(0.0, 0.0),
+                Coordinate::new(1.0, 0.0),
+                Coordinate::new(1.0, 1.0),
+            ]
+        );
+
+        // A tolerance of zero keeps every distinct vertex
+        assert_eq!(line.merge_collinear(0.0).coordinates.len(), 6);
+    }
+
+    #[test]
+    fn test_merge_collinear_keeps_reversals_and_gentle_curves() {
+        // Going back along the same line is collinear but must not be merged
+        let reversal = Line::new(vec![
+            Coordinate::new(0.0, 0.0),
+            Coordinate::new(2.0, 0.0),
+            Coordinate::new(1.0, 0.0),
+        ]);
+        assert_eq!(reversal.merge_collinear(5.0).coordinates.len(), 3);
+
+        // A quarter circle of 0.5 degree steps keeps its shape with a 1 degree tolerance
+        let arc: Line = (0..=180)
+            .map(|i| {
+                let angle = (i as f64 * 0.5).to_radians();
+                Coordinate::new(angle.cos(), angle.sin())
+            })
+            .collect();
+        let merged = arc.merge_collinear(1.0);
+        assert!(merged.coordinates.len() > 45);
+        assert!(merged.coordinates.len() < arc.coordinates.len());
+    }
+
+    #[test]
+    fn test_merge_collinear_drops_repeated_vertices() {
+        let line = Line::new(vec![
+            Coordinate::new(0.0, 0.0),
+            Coordinate::new(0.0, 0.0),
+            Coordinate::new(1.0, 1.0),
+            Coordinate::new(2.0, 2.0),
+        ]);
+        assert_eq!(
+            line.merge_collinear(0.5).coordinates,
+            vec![Coordinate::new(0.0, 0.0), Coordinate::new(2.0, 2.0)]
+        );
+    }
+
+    #[test]
+    fn test_polygon_parts_accessors() {
+        let exterior = Line::new(vec![
+            Coordinate::new(0.0, 0.0),
+            Coordinate::new(4.0, 0.0),
+            Coordinate::new(4.0, 4.0),
+            Coordinate::new(0.0, 0.0),
+        ]);
+        let hole = Line::new(vec![
+            Coordinate::new(1.0, 1.0),
+            Coordinate::new(2.0, 1.0),
+            Coordinate::new(2.0, 2.0),
+            Coordinate::new(1.0, 1.0),
+        ]);
+        let polygon = Polygon::new(exterior.clone(), vec![hole.clone()]);
+
+        assert_eq!(polygon.holes().len(), 1);
+        assert_eq!(polygon.holes()[0].coordinates, hole.coordinates);
+
+        let outer = polygon.exterior_only();
+        assert_eq!(outer.exterior.coordinates, exterior.coordinates);
+        assert!(outer.holes().is_empty());
+
+        let (ring, interiors) = polygon.into_parts();
+        assert_eq!(ring.coordinates, exterior.coordinates);
+        assert_eq!(interiors.len(), 1);
+    }
+
+    #[test]
+    fn test_is_within_distance() {
+        let line = Line::new(vec![
+            Coordinate::new(0.0, 0.0),
+            Coordinate::new(10.0, 0.0),
+            Coordinate::new(10.0, 10.0),
+        ]);
+        // Perpendicular distance to the first and second segment
+        assert!(line.is_within_distance(Coordinate::new(5.0, 0.5), 0.5));
+        assert!(line.is_within_distance(Coordinate::new(11.0, 5.0), 1.0));
+        assert!(!line.is_within_distance(Coordinate::new(5.0, 2.0), 1.0));
+
+        // Beyond the end of the line only the endpoint distance counts
+        assert!(!line.is_within_distance(Coordinate::new(10.0, 12.0), 1.5));
+        assert!(line.is_within_distance(Coordinate::new(10.0, 11.0), 1.5));
+        assert!(!line.is_within_distance(Coordinate::new(-3.0, 0.0), 2.0));
+
+        // A single-point line behaves like a point
+        let point = Line::new(vec![Coordinate::new(1.0, 1.0)]);
+        assert!(point.is_within_distance(Coordinate::new(1.0, 2.0), 1.0));
+        assert!(!Line::new(vec![]).is_within_distance(Coordinate::new(0.0, 0.0), 1.0));
+    }
+
+    fn square_ring(min: f64, max: f64) -> Line {
+        Line::new(vec![
+            Coordinate::new(min, min),
+            Coordinate::new(max, min),
+            Coordinate::new(max, max),
+            Coordinate::new(min, max),
+            Coordinate::new(min, min),
+        ])
+    }
+
+    #[test]
+    fn test_buffer_square_approaches_minkowski_sum() {
+        let square = Polygon::new(square_ring(0.0, 1.0), vec![]);
+        let buffered = square.buffer(1.0, 64);
+        let expected = 1.0 + 4.0 + std::f64::consts::PI;
+        let area = buffered.to_geo().unsigned_area();
+        assert!(area < expected);
+        assert!(expected - area < 1e-2);
+
+        // Every buffered vertex is exactly `distance` away from the square
+        let ring = &buffered.exterior.coordinates;
+        assert_eq!(ring.first(), ring.last());
+        for c in ring {
+            let dx = (0.0 - c.x).max(c.x - 1.0).max(0.0);
+            let dy = (0.0 - c.y).max(c.y - 1.0).max(0.0);
+            assert!((dx.hypot(dy) - 1.0).abs() < 1e-9);
+        }
+
+        // Orientation of the input ring does not matter
+        let mut reversed = square_ring(0.0, 1.0);
+        reversed.coordinates.reverse();
+        let area_reversed = Polygon::new(reversed, vec![])
+            .buffer(1.0, 64)
+            .to_geo()
+            .unsigned_area();
+        assert!((area - area_reversed).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_buffer_shrinks_and_drops_holes() {
+        let polygon = Polygon::new(square_ring(0.0, 10.0), vec![square_ring(3.0, 7.0)]);
+        let buffered = polygon.buffer(1.0, 8);
+        assert_eq!(buffered.holes().len(), 1);
+        let hole = Polygon::new(buffered.holes()[0].clone(), vec![]);
+        assert!((hole.to_geo().unsigned_area() - 4.0).abs() < 1e-9);
+
+        let buffered = polygon.buffer(3.0, 8);
+        assert!(buffered.holes().is_empty());
+
+        // A zero distance keeps the polygon as it is
+        assert_eq!(polygon.buffer(0.0, 8).holes().len(), 1);
+    }
+
+    #[test]
+    fn test_negative_buffer_shrinks_polygon() {
+        let square = Polygon::new(square_ring(0.0, 10.0), vec![]);
+        let shrunk = square.buffer(-1.0, 8);
+        assert!((shrunk.to_geo().unsigned_area() - 64.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_tile_index_round_trips_through_tile_bounds() {
+        let reichstag = Coordinate::new(13.376, 52.518);
+        for zoom in [0, 5, 12, 18] {
+            let (x, y) = reichstag.to_tile_index(zoom);
+            let bounds = Coordinate::to_tile_bounds(x, y, zoom);
+            assert!(bounds.min.x <= reichstag.x && reichstag.x < bounds.max.x);
+            assert!(bounds.min.y < reichstag.y && reichstag.y <= bounds.max.y);
+        }
+        assert_eq!(reichstag.to_tile_index(0), (0, 0));
+    }
+
+    #[test]
+    fn test_tile_index_clamps_to_the_outermost_tiles() {
+        assert_eq!(Coordinate::new(180.0, -90.0).to_tile_index(2), (3, 3));
+        assert_eq!(Coordinate::new(-180.0, 90.0).to_tile_index(2), (0, 0));
+
+        let south_east = Coordinate::to_tile_bounds(3, 3, 2);
+        assert_eq!(south_east.max.x, 180.0);
+        assert!((south_east.min.y + 85.0511).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_bounding_box_contains_and_intersects() {
+        let bbox = BoundingBox::new(0.0, 10.0, 0.0, 10.0);
+        assert!(bbox.contains(Coordinate::new(5.0, 5.0)));
+        assert!(bbox.contains(Coordinate::new(10.0, 0.0)));
+        assert!(!bbox.contains(Coordinate::new(10.1, 5.0)));
+        // Projected coordinates are not limited to longitude/latitude ranges
+        let metres = BoundingBox::new(1.0e6, 2.0e6, 6.0e6, 7.0e6);
+        assert!(metres.contains(Coordinate::new(1.5e6, 6.5e6)));
+
+        assert!(bbox.intersects(&BoundingBox::new(5.0, 15.0, 5.0, 15.0)));
+        assert!(bbox.intersects(&BoundingBox::new(10.0, 20.0, 0.0, 10.0)));
+        assert!(!bbox.intersects(&BoundingBox::new(11.0, 20.0, 0.0, 10.0)));
+        assert!(!bbox.intersects(&metres));
+    }
+
+    #[test]
+    fn test_bounding_box_to_geojson_feature() {
+        let bbox = BoundingBox::new(13.0, 14.0, 52.0, 53.0);
+        let feature = bbox.to_geojson_feature();
+        match &feature.geometry.as_ref().unwrap().value {
+            geojson::Value::Polygon(rings) => {
+                assert_eq!(rings[0].len(), 5);
+                assert_eq!(rings[0][0], rings[0][4]);
+                assert_eq!(rings[0][2], vec![14.0, 53.0]);
+            }
+            _ => panic!("Expected Polygon geometry"),
+        }
+        assert_eq!(
+            feature.property("minX").and_then(|v| v.as_f64()),
+            Some(13.0)
+        );
+        assert_eq!(
+            feature.property("minY").and_then(|v| v.as_f64()),
+            Some(52.0)
+        );
+
+        let parsed: geojson::GeoJson = bbox.to_geojson_string().parse().unwrap();
+        assert_eq!(parsed, geojson::GeoJson::Feature(feature));
+    }
+
+    #[test]
+    fn test_coordinate_from_geojson_point() {
+        let point = geojson::Value::Point(vec![13.377, 52.518, 34.0]);
+        assert_eq!(
+            Coordinate::from_geojson_point(&point).unwrap(),
+            Coordinate::new(13.377, 52.518)
+        );
+
+        let line = geojson::Value::LineString(vec![vec![0.0, 0.0], vec![1.0, 1.0]]);
+        let error = Coordinate::from_geojson_point(&line).unwrap_err();
+        assert!(error
+            .to_string()
+            .ends_with("Expected Point geometry, got LineString"));
+
+        let short = geojson::Value::Point(vec![13.377]);
+        let error = Coordinate::from_geojson_point(&short).unwrap_err();
+        assert!(error
+            .to_string()
+            .ends_with("Point coordinate array has fewer than 2 elements"));
+    }
+
+    #[test]
+    fn test_resample_places_evenly_spaced_vertices() {
+        let line = Line::new(vec![Coordinate::new(0.0, 0.0), Coordinate::new(10.0, 0.0)]);
+        let resampled = line.resample(2.0);
+        let xs: Vec<f64> = resampled.coordinates.iter().map(|c| c.x).collect();
+        assert_eq!(xs, vec![0.0, 2.0, 4.0, 6.0, 8.0, 10.0]);
+        assert!(resampled.coordinates.iter().all(|c| c.y == 0.0));
+    }
+
+    #[test]
+    fn test_resample_follows_corners_and_keeps_end() {
+        let line = Line::new(vec![
+            Coordinate::new(0.0, 0.0),
+            Coordinate::new(3.0, 0.0),
+            Coordinate::new(3.0, 4.0),
+        ]);
+        let resampled = line.resample(2.0);
+        assert_eq!(
+            resampled.coordinates,
+            vec![
+                Coordinate::new(0.0, 0.0),
+                Coordinate::new(2.0, 0.0),
+                Coordinate::new(3.0, 1.0),
+                Coordinate::new(3.0, 3.0),
+                Coordinate::new(3.0, 4.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bbox_centroid_is_bbox_center_not_centroid() {
+        // Most vertices are bunched at the start, the bbox center ignores that
+        let line = Line::new(vec![
+            Coordinate::new(0.0, 0.0),
+            Coordinate::new(0.1, 0.0),
+            Coordinate::new(0.2, 0.0),
+            Coordinate::new(10.0, 6.0),
+        ]);
+        assert_eq!(line.bbox_centroid(), Some(Coordinate::new(5.0, 3.0)));
+        assert_eq!(Line::new(vec![]).bbox_centroid(), None);
+
+        let hole = Line::new(vec![Coordinate::new(1.0, 1.0), Coordinate::new(2.0, 1.0)]);
+        let polygon = Polygon::new(line, vec![hole]);
+        assert_eq!(polygon.bbox_centroid(), Some(Coordinate::new(5.0, 3.0)));
+    }
+
+    #[test]
+    fn test_array_and_tuple_conversions_round_trip() {
+        let coord = Coordinate::new(-0.5, 1e10);
+        let array: [f64; 2] = coord.into();
+        let tuple: (f64, f64) = coord.into();
+        assert_eq!(array, [-0.5, 1e10]);
+        assert_eq!(tuple, (-0.5, 1e10));
+        assert_eq!(Coordinate::from(array), coord);
+        assert_eq!(Coordinate::from(tuple), coord);
+
+        let line: Line = [[0.0, 0.0], [1.0, 2.0]]
+            .into_iter()
+            .map(Coordinate::from)
+            .collect();
+        assert_eq!(line.coordinates[1], Coordinate::new(1.0, 2.0));
+    }
+
+    #[test]
+    fn test_line_to_multipoint() {
+        let line = Line::new(vec![
+            Coordinate::new(0.0, 0.0),
+            Coordinate::new(1.0, 2.0),
+            Coordinate::new(3.0, 4.0),
+        ]);
+        let multi_point = line.to_multipoint();
+        assert_eq!(
+            multi_point,
+            geo::MultiPoint::from(vec![(0.0, 0.0), (1.0, 2.0), (3.0, 4.0)])
+        );
+    }
+
+    #[test]
+    fn test_coordinate_try_from_slice() {
+        assert_eq!(
+            Coordinate::try_from_slice(&[1.0, 2.0, 3.0]).unwrap(),
+            Coordinate::new(1.0, 2.0)
+        );
+        assert!(Coordinate::try_from_slice(&[1.0]).is_err());
+        assert!(Coordinate::try_from_slice(&[]).is_err());
+    }
+
+    #[test]
+    fn test_line_from_geojson_coords() {
+        let line = Line::from_geojson_coords(&[vec![0.0, 0.0], vec![1.0, 2.0, 5.0]]).unwrap();
+        assert_eq!(
+            line.coordinates,
+            vec![Coordinate::new(0.0, 0.0), Coordinate::new(1.0, 2.0)]
+        );
+        assert!(Line::from_geojson_coords(&[vec![0.0, 0.0], vec![1.0]]).is_err());
+    }
+
+    #[test]
+    fn test_line_from_geojson_ring() {
+        let ring = vec![
+            vec![0.0, 0.0],
+            vec![1.0, 0.0],
+            vec![1.0, 1.0],
+            vec![0.0, 0.0],
+        ];
+        assert_eq!(Line::from_geojson_ring(&ring).unwrap().coordinates.len(), 4);
+
+        let too_short = Line::from_geojson_ring(&[vec![0.0, 0.0], vec![1.0, 0.0], vec![0.0, 0.0]]);
+        assert!(too_short.is_err());
+
+        let open = Line::from_geojson_ring(&[
+            vec![0.0, 0.0],
+            vec![1.0, 0.0],
+            vec![1.0, 1.0],
+            vec![0.0, 1.0],
+        ]);
+        assert!(open.is_err());
+    }
+
+    #[test]
+    fn test_polygon_from_geojson_rings() {
+        let rings = vec![
+            vec![
+                vec![0.0, 0.0],
+                vec![4.0, 0.0],
+                vec![4.0, 4.0],
+                vec![0.0, 0.0],
+            ],
+            vec![
+                vec![1.0, 1.0],
+                vec![2.0, 1.0],
+                vec![2.0, 2.0],
+                vec![1.0, 1.0],
+            ],
+        ];
+        let polygon = Polygon::from_geojson_rings(&rings).unwrap();
+        assert_eq!(polygon.exterior.coordinates[1], Coordinate::new(4.0, 0.0));
+        assert_eq!(polygon.interiors.len(), 1);
+        assert_eq!(
+            polygon.interiors[0].coordinates[1],
+            Coordinate::new(2.0, 1.0)
+        );
+
+        assert!(Polygon::from_geojson_rings(&[]).is_err());
+        assert!(Polygon::from_geojson_rings(&[vec![vec![0.0, 0.0], vec![1.0]]]).is_err());
+    }
+
+    #[test]
+    fn test_geodesic_midpoint_across_the_pole() {
+        let midpoint = Coordinate::new(0.0, 45.0).geodesic_midpoint(&Coordinate::new(180.0, 45.0));
+        // The great circle runs over the north pole, the arithmetic mean would be (90, 45)
+        assert!((midpoint.y - 90.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_geodesic_midpoint_matches_mean_over_short_distances() {
+        let a = Coordinate::new(13.37, 52.51);
+        let b = Coordinate::new(13.40, 52.52);
+        let midpoint = a.geodesic_midpoint(&b);
+        assert!((midpoint.x - (a.x + b.x) / 2.0).abs() < 1e-4);
+        assert!((midpoint.y - (a.y + b.y) / 2.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_geodesic_midpoint_across_the_antimeridian() {
+        let midpoint = Coordinate::new(170.0, 0.0).geodesic_midpoint(&Coordinate::new(-170.0, 0.0));
+        assert!((midpoint.x.abs() - 180.0).abs() < 1e-9);
+        assert!(midpoint.y.abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_geojson_value_and_geometry_naming() {
+        let coordinate = Coordinate::new(1.0, 2.0);
+        assert_eq!(
+            coordinate.to_geojson_value(),
+            geojson::Value::Point(vec![1.0, 2.0])
+        );
+        assert_eq!(
+            coordinate.to_geojson_geometry().value,
+            coordinate.to_geojson_value()
+        );
+
+        let ring = Line::new(vec![
+            Coordinate::new(0.0, 0.0),
+            Coordinate::new(1.0, 0.0),
+            Coordinate::new(1.0, 1.0),
+            Coordinate::new(0.0, 0.0),
+        ]);
+        assert_eq!(
+            ring.to_geojson_geometry(),
+            geojson::Geometry::new(ring.to_geojson_value())
+        );
+        let polygon = Polygon::new(ring, vec![]);
+        assert!(matches!(
+            polygon.to_geojson_geometry().value,
+            geojson::Value::Polygon(ref rings) if rings[0].len() == 4
+        ));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_deprecated_to_geojson_matches_to_geojson_value() {
+        let line = Line::new(vec![Coordinate::new(0.0, 0.0), Coordinate::new(1.0, 1.0)]);
+        assert_eq!(line.to_geojson(), line.to_geojson_value());
+        let polygon = Polygon::new(line.clone(), vec![line]);
+        assert_eq!(polygon.to_geojson(), polygon.to_geojson_value());
+    }
+
+    #[test]
+    fn test_coordinate_from_str() {
+        assert_eq!(
+            " -73.5 ,40.25 ".parse::<Coordinate>().unwrap(),
+            Coordinate::new(-73.5, 40.25)
+        );
+        assert!("1.0".parse::<Coordinate>().is_err());
+        assert!("1.0,2.0,3.0".parse::<Coordinate>().is_err());
+        assert!("east,2.0".parse::<Coordinate>().is_err());
+    }
+
+    #[test]
+    fn test_line_from_csv_skips_blank_lines_and_comments() {
+        let csv = "# lon,lat\n13.377,52.518\n\n  # a comment\n13.376,52.516\r\n13.375,52.514";
+        let line = Line::from_csv_str(csv).unwrap();
+        assert_eq!(line.coordinates.len(), 3);
+        assert_eq!(line.coordinates[2], Coordinate::new(13.375, 52.514));
+
+        let streamed = Line::from_csv_reader(csv.as_bytes()).unwrap();
+        assert_eq!(streamed.coordinates, line.coordinates);
+    }
+
+    #[test]
+    fn test_line_from_csv_reports_the_line_number() {
+        let csv = "13.377,52.518\n\n13.376;52.516\n";
+        for result in [
+            Line::from_csv_str(csv),
+            Line::from_csv_reader(csv.as_bytes()),
+        ] {
+            let message = result.unwrap_err().to_string();
+            assert!(message.contains("line 3"), "{message}");
+        }
+    }
+
+    #[test]
+    fn test_polygon_from_exterior_coords_closes_the_ring() {
+        let polygon = Polygon::from_exterior_coords(vec![
+            Coordinate::new(0.0, 0.0),
+            Coordinate::new(1.0, 0.0),
+            Coordinate::new(1.0, 1.0),
+        ])
+        .unwrap();
+        assert_eq!(polygon.exterior.coordinates.len(), 4);
+        assert_eq!(polygon.exterior.coordinates[3], Coordinate::new(0.0, 0.0));
+        assert!(polygon.interiors.is_empty());
+
+        let closed = Polygon::from_exterior_vecs(vec![
+            vec![0.0, 0.0],
+            vec![1.0, 0.0],
+            vec![1.0, 1.0],
+            vec![0.0, 0.0],
+        ])
+        .unwrap();
+        assert_eq!(closed.exterior.coordinates, polygon.exterior.coordinates);
+    }
+
+    #[test]
+    fn test_polygon_from_exterior_coords_needs_three_distinct_points() {
+        let degenerate = vec![
+            Coordinate::new(0.0, 0.0),
+            Coordinate::new(1.0, 0.0),
+            Coordinate::new(1.0, 0.0),
+            Coordinate::new(0.0, 0.0),
+        ];
+        assert!(Polygon::from_exterior_coords(degenerate).is_err());
+        assert!(Polygon::from_exterior_coords(vec![]).is_err());
+        assert!(
+            Polygon::from_exterior_vecs(vec![vec![0.0, 0.0], vec![1.0], vec![1.0, 1.0]]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_split_by_bbox_reproduces_line() {
+        let bbox = BoundingBox::new(0.0, 10.0, 0.0, 10.0);
+        let line = Line::new(vec![
+            Coordinate::new(-5.0, 5.0),
+            Coordinate::new(5.0, 5.0),
+            Coordinate::new(5.0, 8.0),
+            Coordinate::new(15.0, 8.0),
+            Coordinate::new(20.0, 20.0),
+            Coordinate::new(5.0, 12.0),
+            Coordinate::new(5.0, 2.0),
+        ]);
+        let (inside, outside) = line.split_by_bbox(&bbox);
+
+        assert_eq!(inside.len(), 2);
+        assert_eq!(outside.len(), 2);
+        assert_eq!(
+            inside[0].coordinates,
+            vec![
+                Coordinate::new(0.0, 5.0),
+                Coordinate::new(5.0, 5.0),
+                Coordinate::new(5.0, 8.0),
+                Coordinate::new(10.0, 8.0),
+            ]
+        );
+        assert_eq!(
+            inside[1].coordinates,
+            vec![Coordinate::new(5.0, 10.0), Coordinate::new(5.0, 2.0)]
+        );
+
+        // Stitching the parts in order gives back the line, with the crossing points added
+        let mut parts: Vec<&Line> = inside.iter().chain(&outside).collect();
+        let mut stitched = vec![line.coordinates[0]];
+        while !parts.is_empty() {
+            let next = parts
+                .iter()
+                .position(|part| part.coordinates.first() == stitched.last())
+                .expect("every part starts where another ends");
+            stitched.extend_from_slice(&parts.remove(next).coordinates[1..]);
+        }
+        let crossings = [
+            Coordinate::new(0.0, 5.0),
+            Coordinate::new(10.0, 8.0),
+            Coordinate::new(5.0, 10.0),
+        ];
+        stitched.retain(|c| !crossings.contains(c));
+        assert_eq!(stitched, line.coordinates);
+    }
+
+    #[test]
+    fn test_split_by_bbox_edge_cases() {
+        let bbox = BoundingBox::new(0.0, 10.0, 0.0, 10.0);
+
+        let along_edge = Line::new(vec![Coordinate::new(0.0, 0.0), Coordinate::new(10.0, 0.0)]);
+        let (inside, outside) = along_edge.split_by_bbox(&bbox);
+        assert_eq!((inside.len(), outside.len()), (1, 0));
+
+        let corner = Line::new(vec![Coordinate::new(-1.0, 1.0), Coordinate::new(1.0, -1.0)]);
+        let (inside, outside) = corner.split_by_bbox(&bbox);
+        assert_eq!((inside.len(), outside.len()), (0, 1));
+        assert_eq!(outside[0].coordinates, corner.coordinates);
+
+        let point = Line::new(vec![Coordinate::new(5.0, 5.0)]);
+        let (inside, outside) = point.split_by_bbox(&bbox);
+        assert_eq!((inside.len(), outside.len()), (1, 0));
+        assert_eq!(inside[0].coordinates, point.coordinates);
+        let (inside, outside) = Line::new(vec![]).split_by_bbox(&bbox);
+        assert!(inside.is_empty() && outside.is_empty());
+    }
+
+    fn ring(coords: &[(f64, f64)]) -> Line {
+        Line::new(coords.iter().map(|&(x, y)| Coordinate::new(x, y)).collect())
+    }
+
+    const CW_SHELL: [(f64, f64); 5] = [
+        (0.0, 0.0),
+        (0.0, 10.0),
+        (10.0, 10.0),
+        (10.0, 0.0),
+        (0.0, 0.0),
+    ];
+    const CW_HOLE: [(f64, f64); 5] = [(2.0, 2.0), (2.0, 4.0), (4.0, 4.0), (4.0, 2.0), (2.0, 2.0)];
+
+    #[test]
+    fn test_enforce_hole_orientation_reverses_cw_hole_of_cw_shell() {
+        let mut polygon = Polygon::new(ring(&CW_SHELL), vec![ring(&CW_HOLE)]);
+        polygon.enforce_hole_orientation();
+
+        // The shell keeps its direction, the hole now winds counter-clockwise
+        assert_eq!(polygon.exterior.coordinates, ring(&CW_SHELL).coordinates);
+        let mut expected = CW_HOLE.to_vec();
+        expected.reverse();
+        assert_eq!(
+            polygon.interiors[0].coordinates,
+            ring(&expected).coordinates
+        );
+        let hole = geo::Polygon::new(polygon.to_geo().interiors()[0].clone(), vec![]);
+        assert!(hole.signed_area() > 0.0);
+    }
+
+    #[test]
+    fn test_enforce_hole_orientation_keeps_opposite_holes() {
+        let mut ccw_shell = CW_SHELL.to_vec();
+        ccw_shell.reverse();
+        let mut ccw_hole = CW_HOLE.to_vec();
+        ccw_hole.reverse();
+        let mut polygon = Polygon::new(ring(&ccw_shell), vec![ring(&CW_HOLE), ring(&ccw_hole)]);
+        polygon.enforce_hole_orientation();
+
+        assert_eq!(polygon.interiors[0].coordinates, ring(&CW_HOLE).coordinates);
+        assert_eq!(polygon.interiors[1].coordinates, ring(&CW_HOLE).coordinates);
+    }
+
+    #[test]
+    fn test_deduplicate_by_proximity_measures_from_last_kept_vertex() {
+        // Each step is 0.4, so a vertex is kept every third step
+        let trace = Line::new(
+            (0..8)
+                .map(|i| Coordinate::new(i as f64 * 0.4, 0.0))
+                .collect(),
+        );
+        let cleaned = trace.deduplicate_by_proximity(1.0);
+        let xs: Vec<f64> = cleaned.coordinates.iter().map(|c| c.x).collect();
+        assert_eq!(xs.len(), 3);
+        assert!((xs[1] - 1.2).abs() < 1e-12);
+        assert!((xs[2] - 2.4).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_deduplicate_by_proximity_edge_cases() {
+        let repeated = Line::new(vec![Coordinate::new(5.0, 5.0); 4]);
+        assert_eq!(
+            repeated.deduplicate_by_proximity(0.1).coordinates,
+            vec![Coordinate::new(5.0, 5.0)]
+        );
+        // Every distance is at least zero, so nothing is dropped
+        assert_eq!(repeated.deduplicate_by_proximity(0.0).coordinates.len(), 4);
+        assert!(Line::new(vec![])
+            .deduplicate_by_proximity(1.0)
+            .coordinates
+            .is_empty());
+    }
+}
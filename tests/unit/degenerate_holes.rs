@@ -0,0 +1,104 @@
+use proj_exercise_simple::helpers::process_feature_collection_with_options;
+use proj_exercise_simple::options::ProcessingOptions;
+use proj_exercise_simple::transformer::TransformerConfig;
+use serde_json::json;
+
+fn square(x: f64, y: f64, size: f64) -> serde_json::Value {
+    json!([
+        [x, y],
+        [x + size, y],
+        [x + size, y + size],
+        [x, y + size],
+        [x, y]
+    ])
+}
+
+fn polygon(holes: &[serde_json::Value]) -> serde_json::Value {
+    let mut rings = vec![square(0.0, 0.0, 1.0)];
+    rings.extend_from_slice(holes);
+    json!({
+        "type": "FeatureCollection",
+        "features": [{
+            "type": "Feature",
+            "geometry": { "type": "Polygon", "coordinates": rings },
+            "properties": {}
+        }]
+    })
+}
+
+fn output_rings(geojson: geojson::GeoJson) -> Vec<Vec<Vec<f64>>> {
+    let geojson::GeoJson::FeatureCollection(collection) = geojson else {
+        panic!("expected a feature collection");
+    };
+    match &collection.features[0].geometry.as_ref().unwrap().value {
+        geojson::Value::Polygon(rings) => rings.clone(),
+        other => panic!("expected a polygon, got {other:?}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collapsed_hole_is_removed_with_a_warning() {
+        // A hole of about 2 m, well below the 5 m tolerance
+        let input = polygon(&[square(0.5, 0.5, 0.00002)]);
+        let mut config = TransformerConfig::default().with_simplification(5.0);
+
+        let output =
+            process_feature_collection_with_options(input, &mut config, &ProcessingOptions::new())
+                .unwrap();
+
+        assert_eq!(output.report.warnings.len(), 1);
+        assert_eq!(output.report.warnings[0].feature_index, 0);
+        assert!(output.report.warnings[0].message.contains("hole 0"));
+        assert_eq!(output_rings(output.geojson).len(), 1);
+    }
+
+    #[test]
+    fn test_only_the_collapsed_hole_is_removed() {
+        let input = polygon(&[
+            square(0.2, 0.2, 0.1),
+            square(0.5, 0.5, 0.00002),
+            square(0.7, 0.7, 0.1),
+        ]);
+        let mut config = TransformerConfig::default().with_simplification(5.0);
+
+        let output =
+            process_feature_collection_with_options(input, &mut config, &ProcessingOptions::new())
+                .unwrap();
+
+        assert_eq!(output.report.warnings.len(), 1);
+        assert!(output.report.warnings[0].message.contains("hole 1"));
+        let rings = output_rings(output.geojson);
+        assert_eq!(rings.len(), 3);
+        assert!(rings[1][0][0] < rings[2][0][0]);
+    }
+
+    #[test]
+    fn test_holes_keep_their_input_order() {
+        // Neither sorted by position nor by size
+        let xs = [0.6, 0.1, 0.35];
+        let holes: Vec<_> = xs
+            .iter()
+            .enumerate()
+            .map(|(i, x)| square(*x, 0.4, 0.05 * (i + 1) as f64))
+            .collect();
+        let output = process_feature_collection_with_options(
+            polygon(&holes),
+            &mut TransformerConfig::default(),
+            &ProcessingOptions::new(),
+        )
+        .unwrap();
+
+        assert!(output.report.warnings.is_empty());
+        let rings = output_rings(output.geojson);
+        assert_eq!(rings.len(), 4);
+        let reference = TransformerConfig::default();
+        for (hole, x) in rings[1..].iter().zip(xs) {
+            let (expected, _) = reference.transform_point(x, 0.4).unwrap();
+            assert!((hole[0][0] - expected).abs() < 1e-6);
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use geojson::GeoJson;
+use proj_exercise_simple::coordinates::{Coordinate, Line};
+use proj_exercise_simple::densify::{Densify, DensifyMethod};
+use proj_exercise_simple::helpers::process_feature_collection_with_options;
+use proj_exercise_simple::options::ProcessingOptions;
+use proj_exercise_simple::transformer::TransformerConfig;
+use serde_json::json;
+
+const JFK: Coordinate = Coordinate {
+    x: -73.7781,
+    y: 40.6413,
+};
+const LHR: Coordinate = Coordinate {
+    x: -0.4543,
+    y: 51.47,
+};
+
+fn haversine_km(a: &Coordinate, b: &Coordinate) -> f64 {
+    let (lat1, lat2) = (a.y.to_radians(), b.y.to_radians());
+    let h = ((lat2 - lat1) / 2.0).sin().powi(2)
+        + lat1.cos() * lat2.cos() * ((b.x - a.x).to_radians() / 2.0).sin().powi(2);
+    2.0 * 6371.0088 * h.sqrt().asin()
+}
+
+fn output_vertex_count(densify: Option<Densify>) -> usize {
+    let input = json!({
+        "type": "Feature",
+        "geometry": { "type": "LineString", "coordinates": [[-73.7781, 40.6413], [-0.4543, 51.47]] },
+        "properties": {}
+    });
+    let mut options = ProcessingOptions::new();
+    if let Some(densify) = densify {
+        options = options.with_densify(densify);
+    }
+    let output =
+        process_feature_collection_with_options(input, &mut TransformerConfig::default(), &options)
+            .unwrap();
+    let GeoJson::Feature(feature) = output.geojson else {
+        panic!("Expected a feature");
+    };
+    match feature.geometry.unwrap().value {
+        geojson::Value::LineString(positions) => positions.len(),
+        other => panic!("Expected a line string, got {}", other.type_name()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_great_circle_from_jfk_to_lhr_arcs_north() {
+        let route = Line::new(vec![JFK, LHR]).densify_geodesic(500.0);
+        // 5540 km in segments of at most 500 km
+        assert_eq!(route.coordinates.len(), 13);
+        assert_eq!(route.coordinates[0], JFK);
+        assert_eq!(route.coordinates[12], LHR);
+
+        let max_latitude = route
+            .coordinates
+            .iter()
+            .map(|c| c.y)
+            .fold(f64::MIN, f64::max);
+        assert!(max_latitude > LHR.y + 0.5);
+
+        let lengths: Vec<f64> = route
+            .coordinates
+            .windows(2)
+            .map(|pair| haversine_km(&pair[0], &pair[1]))
+            .collect();
+        assert!(lengths.iter().all(|l| (l - 5540.019 / 12.0).abs() < 0.01));
+
+        // The middle vertex is the great-circle midpoint
+        let midpoint = JFK.geodesic_midpoint(&LHR);
+        assert!(haversine_km(&route.coordinates[6], &midpoint) < 1.0);
+    }
+
+    #[test]
+    fn test_great_circle_across_the_antimeridian() {
+        let line = Line::new(vec![
+            Coordinate::new(170.0, 0.0),
+            Coordinate::new(-170.0, 0.0),
+        ]);
+        let densified = line.densify_geodesic(500.0);
+        assert_eq!(densified.coordinates.len(), 6);
+        assert!(densified.coordinates.iter().all(|c| c.x.abs() >= 170.0));
+        assert!(densified.coordinates.iter().all(|c| c.y.abs() < 1e-9));
+    }
+
+    #[test]
+    fn test_great_circle_over_the_pole() {
+        let line = Line::new(vec![
+            Coordinate::new(0.0, 80.0),
+            Coordinate::new(180.0, 80.0),
+        ]);
+        let densified = line.densify_geodesic(100.0);
+        let top = densified
+            .coordinates
+            .iter()
+            .map(|c| c.y)
+            .fold(f64::MIN, f64::max);
+        assert!((top - 90.0).abs() < 0.5);
+    }
+
+    #[test]
+    fn test_short_segments_are_unchanged() {
+        let line = Line::new(vec![JFK, LHR]);
+        assert_eq!(line.densify_geodesic(10_000.0).coordinates.len(), 2);
+        assert_eq!(line.densify_geodesic(0.0).coordinates.len(), 2);
+    }
+
+    #[test]
+    fn test_pipeline_option_densifies_before_projecting() {
+        assert_eq!(output_vertex_count(None), 2);
+        assert_eq!(
+            output_vertex_count(Some(Densify::new(DensifyMethod::GreatCircle, 500.0))),
+            13
+        );
+        assert_eq!(
+            output_vertex_count(Some(Densify::new(DensifyMethod::Linear, 500.0))),
+            13
+        );
+    }
+}
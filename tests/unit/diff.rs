@@ -0,0 +1,43 @@
+use geo::LineString;
+use proj_exercise_simple::diff::geometry_diff;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inserted_vertex_is_reported_once() {
+        let old = LineString::from(vec![(0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (3.0, 0.0)]);
+        let new = LineString::from(vec![
+            (0.0, 0.0),
+            (1.0, 0.0),
+            (1.5, 0.5),
+            (2.0, 0.0),
+            (3.0, 0.0),
+        ]);
+        let diff = geometry_diff(&old, &new, 0.01);
+        assert_eq!(diff.inserted, vec![2]);
+        assert!(diff.deleted.is_empty());
+        assert!(diff.moved.is_empty());
+    }
+
+    #[test]
+    fn test_deleted_and_moved_vertices() {
+        let old = LineString::from(vec![(0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (3.0, 0.0)]);
+        let new = LineString::from(vec![(0.0, 0.0), (2.0, 0.05), (3.0, 0.0)]);
+        let diff = geometry_diff(&old, &new, 0.1);
+        assert_eq!(diff.deleted, vec![1]);
+        assert_eq!(diff.moved, vec![(2, 1)]);
+        assert!(diff.inserted.is_empty());
+    }
+
+    #[test]
+    fn test_moves_beyond_tolerance_are_replacements() {
+        let old = LineString::from(vec![(0.0, 0.0), (1.0, 0.0)]);
+        let new = LineString::from(vec![(0.0, 0.0), (1.0, 5.0)]);
+        let diff = geometry_diff(&old, &new, 0.1);
+        assert_eq!(diff.deleted, vec![1]);
+        assert_eq!(diff.inserted, vec![1]);
+        assert!(geometry_diff(&old, &old, 0.1).is_empty());
+    }
+}
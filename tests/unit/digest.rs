@@ -0,0 +1,95 @@
+use crate::fixtures::GeometryFixtures;
+use geojson::{GeoJson, Geometry, Value};
+use proj_exercise_simple::geometry_processor::GeometryProcessor;
+use proj_exercise_simple::helpers::{process_feature_collection_with_options, ProcessedGeometry};
+use proj_exercise_simple::options::ProcessingOptions;
+use proj_exercise_simple::pool::CoordinateBufferPool;
+use proj_exercise_simple::transformer::TransformerConfig;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process(value: Value) -> ProcessedGeometry {
+        let geometry = Geometry::new(value);
+        let mut config = TransformerConfig::default();
+        let mut pool = CoordinateBufferPool::new(10, 100);
+        GeometryProcessor::new(&geometry, &mut config)
+            .process(&mut pool)
+            .unwrap()
+    }
+
+    fn line(offset: f64) -> Value {
+        Value::LineString(vec![vec![13.377, 52.518], vec![13.379, 52.517 + offset]])
+    }
+
+    #[test]
+    fn test_same_geometry_gives_same_digest() {
+        assert_eq!(
+            process(line(0.0)).geohash_digest(2),
+            process(line(0.0)).geohash_digest(2)
+        );
+    }
+
+    #[test]
+    fn test_digest_sensitivity_follows_precision() {
+        // 1e-7 degrees is about a centimeter in Web Mercator at this latitude
+        let original = process(line(0.0));
+        let nudged = process(line(1e-7));
+        assert_eq!(original.geohash_digest(0), nudged.geohash_digest(0));
+        assert_ne!(original.geohash_digest(3), nudged.geohash_digest(3));
+
+        // Moving a vertex by more than the quantum changes the digest
+        let moved = process(line(1e-3));
+        assert_ne!(original.geohash_digest(0), moved.geohash_digest(0));
+    }
+
+    #[test]
+    fn test_hole_order_does_not_change_digest() {
+        let exterior = [
+            (0.0, 0.0),
+            (10.0, 0.0),
+            (10.0, 10.0),
+            (0.0, 10.0),
+            (0.0, 0.0),
+        ];
+        let a = [(1.0, 1.0), (2.0, 1.0), (2.0, 2.0), (1.0, 1.0)];
+        let b = [(5.0, 5.0), (6.0, 5.0), (6.0, 6.0), (5.0, 5.0)];
+        let ring = |coords: &[(f64, f64)]| geo::LineString::from(coords.to_vec());
+        let first = geo::Polygon::new(ring(&exterior), vec![ring(&a), ring(&b)]);
+        let second = geo::Polygon::new(ring(&exterior), vec![ring(&b), ring(&a)]);
+        assert_eq!(
+            ProcessedGeometry::Polygon(first.clone()).geohash_digest(6),
+            ProcessedGeometry::Polygon(second).geohash_digest(6)
+        );
+        // The structure is part of the digest
+        let multi = geo::MultiPolygon::new(vec![first.clone()]);
+        assert_ne!(
+            ProcessedGeometry::Polygon(first).geohash_digest(6),
+            ProcessedGeometry::MultiPolygon(multi).geohash_digest(6)
+        );
+    }
+
+    #[test]
+    fn test_pipeline_writes_geometry_hash() {
+        let mut config = TransformerConfig::default();
+        let options = ProcessingOptions::new().with_geometry_hash(3);
+        let output = process_feature_collection_with_options(
+            GeometryFixtures::berlin_feature_collection(),
+            &mut config,
+            &options,
+        )
+        .unwrap();
+        let GeoJson::FeatureCollection(collection) = output.geojson else {
+            panic!("Expected a FeatureCollection");
+        };
+        let hashes: Vec<&str> = collection
+            .features
+            .iter()
+            .map(|f| f.property("geometry_hash").unwrap().as_str().unwrap())
+            .collect();
+        assert!(hashes.iter().all(|hash| hash.len() == 16));
+        assert_ne!(hashes[0], hashes[1]);
+        assert_ne!(hashes[1], hashes[2]);
+    }
+}
@@ -0,0 +1,66 @@
+use proj_exercise_simple::coordinates::Coordinate;
+use proj_exercise_simple::distortion::{distortion_at, AREAL_SCALE_KEY, MAX_ANGULAR_KEY};
+use proj_exercise_simple::helpers::process_feature_collection_with_options;
+use proj_exercise_simple::options::ProcessingOptions;
+use proj_exercise_simple::transformer::TransformerConfig;
+use serde_json::json;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_web_mercator_areal_scale_is_inverse_cos_squared() {
+        let mut config = TransformerConfig::default();
+        for latitude in [0.0_f64, 45.0, 60.0] {
+            let distortion = distortion_at(&Coordinate::new(13.4, latitude), &mut config).unwrap();
+            let expected = 1.0 / latitude.to_radians().cos().powi(2);
+            assert!(
+                (distortion.areal_scale / expected - 1.0).abs() < 1e-3,
+                "areal scale {} at {latitude}, expected {expected}",
+                distortion.areal_scale
+            );
+            // Conformal: equal scale in every direction
+            assert!((distortion.max_scale / distortion.min_scale - 1.0).abs() < 1e-3);
+            assert!(distortion.max_angular_distortion < 0.1);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "proj")]
+    fn test_equal_area_projection_distorts_angles() {
+        let mut config =
+            TransformerConfig::new("EPSG:4326".to_string(), "EPSG:6933".to_string()).unwrap();
+        let distortion = distortion_at(&Coordinate::new(0.0, 60.0), &mut config).unwrap();
+        // Equal area on the ellipsoid, so close to 1 against the reference sphere
+        assert!((distortion.areal_scale - 1.0).abs() < 0.01);
+        assert!(distortion.max_angular_distortion > 10.0);
+    }
+
+    #[test]
+    fn test_pipeline_annotates_features_with_distortion() {
+        let input = json!({
+            "type": "FeatureCollection",
+            "features": [{
+                "type": "Feature",
+                "geometry": { "type": "Point", "coordinates": [10.0, 60.0] },
+                "properties": { "name": "north" }
+            }]
+        });
+        let options = ProcessingOptions::new().with_distortion(true);
+        let output = process_feature_collection_with_options(
+            input,
+            &mut TransformerConfig::default(),
+            &options,
+        )
+        .unwrap();
+        let geojson::GeoJson::FeatureCollection(collection) = output.geojson else {
+            panic!("Expected a feature collection");
+        };
+        let feature = &collection.features[0];
+        let areal = feature.property(AREAL_SCALE_KEY).unwrap().as_f64().unwrap();
+        assert!((areal - 4.0).abs() < 4e-3);
+        assert!(feature.property(MAX_ANGULAR_KEY).is_some());
+        assert_eq!(feature.property("name").unwrap(), "north");
+    }
+}
@@ -0,0 +1,104 @@
+use geojson::feature::Id;
+use geojson::GeoJson;
+use proj_exercise_simple::explode::ExplodePolicy;
+use proj_exercise_simple::helpers::{
+    process_feature_collection, process_feature_collection_with_options,
+};
+use proj_exercise_simple::options::ProcessingOptions;
+use proj_exercise_simple::transformer::TransformerConfig;
+use serde_json::json;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collection_feature(id: serde_json::Value) -> serde_json::Value {
+        json!({
+            "type": "FeatureCollection",
+            "features": [{
+                "type": "Feature",
+                "id": id,
+                "properties": { "name": "landmarks", "rank": 1 },
+                "geometry": {
+                    "type": "GeometryCollection",
+                    "geometries": [
+                        { "type": "Point", "coordinates": [13.377, 52.518] },
+                        { "type": "LineString", "coordinates": [[13.377, 52.518], [13.379, 52.517]] },
+                        { "type": "MultiPoint", "coordinates": [[13.35, 52.51], [13.36, 52.52]] }
+                    ]
+                }
+            }]
+        })
+    }
+
+    fn features(
+        json_value: serde_json::Value,
+        options: &ProcessingOptions,
+    ) -> Vec<geojson::Feature> {
+        let mut config = TransformerConfig::default();
+        let output =
+            process_feature_collection_with_options(json_value, &mut config, options).unwrap();
+        match output.geojson {
+            GeoJson::FeatureCollection(collection) => collection.features,
+            _ => panic!("Expected a feature collection"),
+        }
+    }
+
+    #[test]
+    fn test_explode_collection_members() {
+        let options = ProcessingOptions::new().with_explode(ExplodePolicy::Collections);
+        let features = features(collection_feature(json!("berlin")), &options);
+        assert_eq!(features.len(), 3);
+
+        for (part_index, feature) in features.iter().enumerate() {
+            assert_eq!(feature.property("part_index").unwrap(), part_index);
+            assert_eq!(feature.property("name").unwrap(), "landmarks");
+            assert_eq!(feature.property("rank").unwrap(), 1);
+            assert_eq!(
+                feature.id,
+                Some(Id::String(format!("berlin:{}", part_index)))
+            );
+        }
+        assert_eq!(
+            features[2].geometry.as_ref().unwrap().value.type_name(),
+            "MultiPoint"
+        );
+    }
+
+    #[test]
+    fn test_explode_all_splits_multi_geometries() {
+        let options = ProcessingOptions::new().with_explode(ExplodePolicy::All);
+        let features = features(collection_feature(json!("berlin")), &options);
+        let types: Vec<_> = features
+            .iter()
+            .map(|f| f.geometry.as_ref().unwrap().value.type_name())
+            .collect();
+        assert_eq!(types, vec!["Point", "LineString", "Point", "Point"]);
+        assert_eq!(features[3].property("part_index").unwrap(), 3);
+    }
+
+    #[test]
+    fn test_numeric_ids_are_dropped_unless_kept() {
+        let options = ProcessingOptions::new().with_explode(ExplodePolicy::Collections);
+        let dropped = features(collection_feature(json!(7)), &options);
+        assert!(dropped.iter().all(|f| f.id.is_none()));
+
+        let options = options.with_keep_numeric_part_ids(true);
+        let kept = features(collection_feature(json!(7)), &options);
+        assert!(kept.iter().all(|f| f.id == Some(Id::Number(7.into()))));
+    }
+
+    #[test]
+    fn test_unexploded_features_keep_id_and_properties() {
+        let mut config = TransformerConfig::default();
+        let output =
+            process_feature_collection(collection_feature(json!("berlin")), &mut config).unwrap();
+        let GeoJson::FeatureCollection(collection) = output else {
+            panic!("Expected a feature collection");
+        };
+        let feature = &collection.features[0];
+        assert_eq!(feature.id, Some(Id::String("berlin".to_string())));
+        assert_eq!(feature.property("name").unwrap(), "landmarks");
+        assert!(!feature.contains_property("part_index"));
+    }
+}
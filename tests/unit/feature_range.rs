@@ -0,0 +1,61 @@
+use geojson::feature::Id;
+use geojson::{Feature, FeatureCollection, GeoJson, Geometry, Value};
+use proj_exercise_simple::error::ProjectionError;
+use proj_exercise_simple::helpers::process_feature_range;
+use proj_exercise_simple::transformer::TransformerConfig;
+
+fn numbered_collection(count: usize) -> serde_json::Value {
+    let collection: FeatureCollection = (0..count)
+        .map(|i| Feature {
+            id: Some(Id::Number(i.into())),
+            ..Feature::from(Geometry::new(Value::Point(vec![i as f64, 0.0])))
+        })
+        .collect();
+    serde_json::to_value(collection).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_the_range_is_processed() {
+        let output = process_feature_range(
+            numbered_collection(6),
+            2,
+            4,
+            &mut TransformerConfig::default(),
+        )
+        .unwrap();
+        let GeoJson::FeatureCollection(collection) = output else {
+            panic!("Expected a feature collection");
+        };
+        let ids: Vec<_> = collection.features.iter().map(|f| f.id.clone()).collect();
+        assert_eq!(
+            ids,
+            vec![Some(Id::Number(2.into())), Some(Id::Number(3.into()))]
+        );
+        let Some(Value::Point(point)) = collection.features[0].geometry.as_ref().map(|g| &g.value)
+        else {
+            panic!("Expected a point");
+        };
+        assert!((point[0] - 222638.98158654713).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_out_of_bounds_range_is_rejected() {
+        let mut config = TransformerConfig::default();
+        for (start, end) in [(4, 7), (5, 3)] {
+            let result = process_feature_range(numbered_collection(6), start, end, &mut config);
+            assert!(matches!(
+                result,
+                Err(ProjectionError::InvalidFeatureRange {
+                    feature_count: 6,
+                    ..
+                })
+            ));
+        }
+        let empty = process_feature_range(numbered_collection(6), 6, 6, &mut config).unwrap();
+        assert!(matches!(empty, GeoJson::FeatureCollection(c) if c.features.is_empty()));
+    }
+}
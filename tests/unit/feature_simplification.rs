@@ -0,0 +1,84 @@
+use proj_exercise_simple::helpers::process_feature_collection_with_options;
+use proj_exercise_simple::options::ProcessingOptions;
+use proj_exercise_simple::transformer::TransformerConfig;
+use serde_json::json;
+
+/// A zigzag along the equator, with wiggles of about 11 km
+fn zigzag_feature(importance: Option<f64>) -> serde_json::Value {
+    let coordinates: Vec<[f64; 2]> = (0..=20)
+        .map(|i| [i as f64 * 0.5, if i % 2 == 0 { 0.0 } else { 0.1 }])
+        .collect();
+    json!({
+        "type": "Feature",
+        "geometry": { "type": "LineString", "coordinates": coordinates },
+        "properties": { "importance": importance }
+    })
+}
+
+fn vertex_counts(input: serde_json::Value, options: &ProcessingOptions) -> Vec<usize> {
+    let output =
+        process_feature_collection_with_options(input, &mut TransformerConfig::default(), options)
+            .unwrap();
+    match output.geojson {
+        geojson::GeoJson::FeatureCollection(collection) => collection
+            .features
+            .iter()
+            .map(|feature| match &feature.geometry.as_ref().unwrap().value {
+                geojson::Value::LineString(positions) => positions.len(),
+                other => panic!("expected a line string, got {other:?}"),
+            })
+            .collect(),
+        other => panic!("expected a feature collection, got {other:?}"),
+    }
+}
+
+fn by_importance() -> ProcessingOptions {
+    ProcessingOptions::new().with_feature_simplification(|feature| {
+        let importance = feature.property("importance")?.as_f64()?;
+        Some(100_000.0 / importance)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_epsilon_follows_importance() {
+        let input = json!({
+            "type": "FeatureCollection",
+            "features": [zigzag_feature(Some(1000.0)), zigzag_feature(Some(1.0))]
+        });
+        // 100 m keeps every wiggle, 100 km removes all of them
+        assert_eq!(vertex_counts(input, &by_importance()), vec![21, 2]);
+    }
+
+    #[test]
+    fn test_features_without_value_use_default() {
+        let input = json!({
+            "type": "FeatureCollection",
+            "features": [zigzag_feature(None), zigzag_feature(Some(1.0))]
+        });
+        assert_eq!(vertex_counts(input, &by_importance()), vec![21, 2]);
+
+        let output = process_feature_collection_with_options(
+            json!({ "type": "FeatureCollection", "features": [zigzag_feature(None)] }),
+            &mut TransformerConfig::default().with_simplification(100_000.0),
+            &by_importance(),
+        )
+        .unwrap();
+        assert!(output.geojson.to_string().contains("[0.0,0.0],[1113194."));
+    }
+
+    #[test]
+    fn test_config_epsilon_is_restored() {
+        let mut config = TransformerConfig::default();
+        process_feature_collection_with_options(
+            json!({ "type": "FeatureCollection", "features": [zigzag_feature(Some(1.0))] }),
+            &mut config,
+            &by_importance(),
+        )
+        .unwrap();
+        assert_eq!(config.simplification_epsilon, None);
+    }
+}
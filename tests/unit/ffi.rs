@@ -0,0 +1,104 @@
+use proj_exercise_simple::ffi::{
+    gp_free_string, gp_process_json, gp_session_create, gp_session_destroy, gp_session_process,
+    GP_ERROR_INVALID_ARGUMENT, GP_ERROR_PROCESSING, GP_OK,
+};
+use std::ffi::{c_char, CStr, CString};
+use std::ptr;
+
+/// Call `gp_process_json` and take ownership of the returned strings
+fn process_json(input: &CStr, from: &CStr, to: &CStr) -> (i32, Option<String>, Option<String>) {
+    let mut output: *mut c_char = ptr::null_mut();
+    let mut error: *mut c_char = ptr::null_mut();
+    let code = unsafe {
+        gp_process_json(
+            input.as_ptr(),
+            from.as_ptr(),
+            to.as_ptr(),
+            &mut output,
+            &mut error,
+        )
+    };
+    (code, take_string(output), take_string(error))
+}
+
+fn take_string(value: *mut c_char) -> Option<String> {
+    if value.is_null() {
+        return None;
+    }
+    let string = unsafe { CStr::from_ptr(value) }
+        .to_string_lossy()
+        .into_owned();
+    unsafe { gp_free_string(value) };
+    Some(string)
+}
+
+fn point() -> CString {
+    CString::new(r#"{"type": "Point", "coordinates": [1.0, 2.0]}"#).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process_json_success() {
+        let (code, output, error) = process_json(&point(), c"EPSG:4326", c"EPSG:3857");
+        assert_eq!(code, GP_OK);
+        assert!(error.is_none());
+        let output: serde_json::Value = serde_json::from_str(&output.unwrap()).unwrap();
+        let x = output["coordinates"][0].as_f64().unwrap();
+        assert!((x - 111319.49079327357).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_process_json_errors() {
+        let (code, output, error) = process_json(c"not json", c"EPSG:4326", c"EPSG:3857");
+        assert_eq!(code, GP_ERROR_PROCESSING);
+        assert!(output.is_none());
+        assert!(error.unwrap().contains("GeoJSON"));
+
+        let (code, _, error) = process_json(&point(), c"EPSG:4326", c"not a crs");
+        assert_eq!(code, GP_ERROR_PROCESSING);
+        assert!(error.is_some());
+
+        let mut error: *mut c_char = ptr::null_mut();
+        let code = unsafe {
+            gp_process_json(
+                ptr::null(),
+                c"EPSG:4326".as_ptr(),
+                c"EPSG:3857".as_ptr(),
+                &mut ptr::null_mut(),
+                &mut error,
+            )
+        };
+        assert_eq!(code, GP_ERROR_INVALID_ARGUMENT);
+        assert_eq!(take_string(error).unwrap(), "input is null");
+    }
+
+    #[test]
+    fn test_session_is_reused_across_calls() {
+        let session = unsafe { gp_session_create(c"EPSG:4326".as_ptr(), c"EPSG:3857".as_ptr()) };
+        assert!(!session.is_null());
+        for _ in 0..3 {
+            let mut output: *mut c_char = ptr::null_mut();
+            let code = unsafe {
+                gp_session_process(session, point().as_ptr(), &mut output, ptr::null_mut())
+            };
+            assert_eq!(code, GP_OK);
+            assert!(take_string(output).is_some());
+        }
+        unsafe { gp_session_destroy(session) };
+
+        let invalid = unsafe { gp_session_create(c"EPSG:4326".as_ptr(), c"nope".as_ptr()) };
+        assert!(invalid.is_null());
+        let code = unsafe {
+            gp_session_process(
+                ptr::null_mut(),
+                point().as_ptr(),
+                &mut ptr::null_mut(),
+                ptr::null_mut(),
+            )
+        };
+        assert_eq!(code, GP_ERROR_INVALID_ARGUMENT);
+    }
+}
@@ -0,0 +1,34 @@
+use proj_exercise_simple::testing::{arbitrary_geojson, assert_pipeline_invariants};
+use proj_exercise_simple::transformer::TransformerConfig;
+use std::fs;
+use std::path::Path;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seed_corpus_upholds_pipeline_invariants() {
+        let corpus =
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("fuzz/corpus/process_feature_collection");
+        let mut config = TransformerConfig::default();
+        let mut count = 0;
+        for entry in fs::read_dir(corpus).unwrap() {
+            let path = entry.unwrap().path();
+            let document = fs::read_to_string(&path).unwrap();
+            let json_value = serde_json::from_str(&document)
+                .unwrap_or_else(|e| panic!("{} is not JSON: {e}", path.display()));
+            assert_pipeline_invariants(json_value, &mut config);
+            count += 1;
+        }
+        assert!(count >= 20);
+    }
+
+    #[test]
+    fn test_generated_documents_uphold_pipeline_invariants() {
+        let mut config = TransformerConfig::default();
+        for seed in 0..500 {
+            assert_pipeline_invariants(arbitrary_geojson(seed), &mut config);
+        }
+    }
+}
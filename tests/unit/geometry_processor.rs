@@ -1,12 +1,21 @@
 use geo::Point;
 use geojson::{Geometry, Value};
-use proj_exercise_simple::geometry_processor::GeometryProcessor;
+use proj_exercise_simple::coordinates::Coordinate;
+use proj_exercise_simple::geometry_processor::{
+    process_geo_geometry, project_ring, project_ring_into, GeometryProcessor,
+};
 use proj_exercise_simple::transformer::TransformerConfig;
 #[cfg(test)]
 mod tests {
 
     use proj_exercise_simple::{
-        error::ProjectionError, helpers::ProcessedGeometry, pool::CoordinateBufferPool,
+        coordinates::CoordinateBounds,
+        error::{GeometryValidationError, ProjectionError, ValidationError},
+        helpers::{
+            process_feature_collection, process_feature_collection_with_options, ProcessedGeometry,
+        },
+        options::{NullGeometryPolicy, ProcessingOptions, RingClosure, ValidationStrategy},
+        pool::CoordinateBufferPool,
     };
 
     use super::*;
@@ -326,4 +335,682 @@ mod tests {
             _ => panic!("Expected MultiPolygon with interiors geometry"),
         }
     }
+
+    #[test]
+    fn test_project_coordinates_matches_full_path_for_polygon() {
+        let polygon = Geometry::new(Value::Polygon(vec![
+            vec![
+                vec![0.0, 0.0],
+                vec![4.0, 0.0],
+                vec![4.0, 4.0],
+                vec![0.0, 4.0],
+                vec![0.0, 0.0],
+            ],
+            vec![
+                vec![1.0, 1.0],
+                vec![2.0, 1.0],
+                vec![2.0, 2.0],
+                vec![1.0, 1.0],
+            ],
+        ]));
+        let mut buffer_pool = CoordinateBufferPool::new(10, 100);
+
+        let mut config = TransformerConfig::default();
+        let fast = GeometryProcessor::new(&polygon, &mut config)
+            .project_coordinates(&mut buffer_pool)
+            .unwrap();
+        let full = GeometryProcessor::new(&polygon, &mut config)
+            .process(&mut buffer_pool)
+            .unwrap()
+            .to_geojson_geometry();
+
+        match full.value {
+            Value::Polygon(rings) => assert_eq!(fast, rings),
+            _ => panic!("Expected Polygon geometry"),
+        }
+    }
+
+    #[test]
+    fn test_project_coordinates_validates_input() {
+        let mut config = TransformerConfig::default();
+        let line = Geometry::new(Value::LineString(vec![vec![0.0, 0.0], vec![200.0, 0.0]]));
+        let mut buffer_pool = CoordinateBufferPool::new(10, 100);
+        let result =
+            GeometryProcessor::new(&line, &mut config).project_coordinates(&mut buffer_pool);
+        assert!(matches!(
+            result,
+            Err(ProjectionError::InvalidCoordinates(_))
+        ));
+
+        // The source and projected buffers are returned to the pool even on error
+        assert_eq!(buffer_pool.point_buffers.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_custom_coordinate_bounds() {
+        let mut config = TransformerConfig::default();
+        let point = Geometry::new(Value::Point(vec![200.0, 10.0]));
+        let mut buffer_pool = CoordinateBufferPool::new(10, 100);
+
+        // Outside WGS84, rejected by default
+        let result = GeometryProcessor::new(&point, &mut config).process(&mut buffer_pool);
+        assert!(matches!(
+            result,
+            Err(ProjectionError::InvalidCoordinates(_))
+        ));
+
+        // Inside the custom range
+        let bounds = CoordinateBounds::new(0.0, 360.0, -90.0, 90.0);
+        let result = GeometryProcessor::new(&point, &mut config)
+            .with_coordinate_bounds(bounds)
+            .process(&mut buffer_pool);
+        assert!(result.is_ok());
+
+        // Still outside the custom range
+        let point = Geometry::new(Value::Point(vec![-10.0, 10.0]));
+        let result = GeometryProcessor::new(&point, &mut config)
+            .with_coordinate_bounds(bounds)
+            .process(&mut buffer_pool);
+        assert!(matches!(
+            result,
+            Err(ProjectionError::InvalidCoordinates(_))
+        ));
+    }
+
+    #[test]
+    fn test_pipeline_coordinate_bounds() {
+        let json_value = serde_json::json!({
+            "type": "Feature",
+            "properties": null,
+            "geometry": { "type": "Point", "coordinates": [200.0, 10.0] }
+        });
+        let mut config = TransformerConfig::default();
+        let result = process_feature_collection_with_options(
+            json_value.clone(),
+            &mut config,
+            &ProcessingOptions::new(),
+        );
+        assert!(result.is_err());
+
+        let options = ProcessingOptions::new()
+            .with_coordinate_bounds(CoordinateBounds::new(0.0, 360.0, -90.0, 90.0));
+        let result = process_feature_collection_with_options(json_value, &mut config, &options);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_projected_source_is_not_limited_to_wgs84() {
+        // Web Mercator meters are far outside the WGS84 range
+        let mut config =
+            TransformerConfig::new("EPSG:3857".to_string(), "EPSG:4326".to_string()).unwrap();
+        let json_value = serde_json::json!({
+            "type": "Feature",
+            "properties": null,
+            "geometry": { "type": "Point", "coordinates": [111319.49079327357, 222684.20850554455] }
+        });
+        let output = process_feature_collection_with_options(
+            json_value,
+            &mut config,
+            &ProcessingOptions::new(),
+        )
+        .unwrap();
+        match output.geojson {
+            geojson::GeoJson::Feature(feature) => match feature.geometry.unwrap().value {
+                Value::Point(point) => {
+                    assert!((point[0] - 1.0).abs() < 1e-9);
+                    assert!((point[1] - 2.0).abs() < 1e-9);
+                }
+                _ => panic!("Expected Point geometry"),
+            },
+            _ => panic!("Expected a feature"),
+        }
+    }
+
+    #[test]
+    fn test_empty_feature_collection_skips_transformer() {
+        let json_value = serde_json::json!({ "type": "FeatureCollection", "features": [] });
+        let mut config = TransformerConfig::default();
+        let output = process_feature_collection(json_value, &mut config).unwrap();
+        match output {
+            geojson::GeoJson::FeatureCollection(collection) => {
+                assert!(collection.features.is_empty())
+            }
+            _ => panic!("Expected a FeatureCollection"),
+        }
+        assert!(!config.is_transformer_available().unwrap());
+    }
+
+    #[test]
+    fn test_rect_and_triangle_project_to_polygons() {
+        let mut config = TransformerConfig::default();
+        let mut pool = CoordinateBufferPool::new(10, 100);
+        let bounds = CoordinateBounds::wgs84();
+
+        let rect = geo::Rect::new(
+            geo::coord! { x: 0.0, y: 0.0 },
+            geo::coord! { x: 1.0, y: 2.0 },
+        );
+        match process_geo_geometry(&rect.into(), &mut config, &mut pool, bounds).unwrap() {
+            ProcessedGeometry::Polygon(polygon) => {
+                let ring = &polygon.exterior().0;
+                assert_eq!(ring.len(), 5);
+                assert_eq!(ring.first(), ring.last());
+                assert!((ring[2].x - 111319.49079327357).abs() < 1e-6);
+                assert!((ring[2].y - 222684.20850554455).abs() < 1e-6);
+            }
+            _ => panic!("Expected Polygon geometry"),
+        }
+
+        let triangle = geo::Triangle::new(
+            geo::coord! { x: 0.0, y: 0.0 },
+            geo::coord! { x: 1.0, y: 0.0 },
+            geo::coord! { x: 1.0, y: 2.0 },
+        );
+        match process_geo_geometry(&triangle.into(), &mut config, &mut pool, bounds).unwrap() {
+            ProcessedGeometry::Polygon(polygon) => {
+                assert_eq!(polygon.exterior().0.len(), 4);
+                assert!(polygon.interiors().is_empty());
+            }
+            _ => panic!("Expected Polygon geometry"),
+        }
+    }
+
+    #[test]
+    fn test_rect_corners_are_validated() {
+        let mut config = TransformerConfig::default();
+        let mut pool = CoordinateBufferPool::new(10, 100);
+        let rect = geo::Rect::new(
+            geo::coord! { x: 0.0, y: 0.0 },
+            geo::coord! { x: 1.0, y: 95.0 },
+        );
+        let result = process_geo_geometry(
+            &rect.into(),
+            &mut config,
+            &mut pool,
+            CoordinateBounds::wgs84(),
+        );
+        assert!(matches!(
+            result,
+            Err(ProjectionError::InvalidCoordinates(_))
+        ));
+    }
+
+    #[test]
+    fn test_collection_with_rect_converts_to_geojson() {
+        let mut config = TransformerConfig::default();
+        let mut pool = CoordinateBufferPool::new(10, 100);
+        let collection = geo::Geometry::GeometryCollection(geo::GeometryCollection::from(vec![
+            geo::Geometry::Point(Point::new(1.0, 2.0)),
+            geo::Geometry::Rect(geo::Rect::new(
+                geo::coord! { x: 0.0, y: 0.0 },
+                geo::coord! { x: 1.0, y: 1.0 },
+            )),
+        ]));
+        let processed = process_geo_geometry(
+            &collection,
+            &mut config,
+            &mut pool,
+            CoordinateBounds::wgs84(),
+        )
+        .unwrap();
+        match processed.to_geojson_geometry().value {
+            Value::GeometryCollection(geometries) => {
+                assert!(matches!(geometries[0].value, Value::Point(_)));
+                assert!(
+                    matches!(&geometries[1].value, Value::Polygon(rings) if rings[0].len() == 5)
+                );
+            }
+            _ => panic!("Expected GeometryCollection geometry"),
+        }
+    }
+
+    fn collection_with_null_geometry() -> serde_json::Value {
+        serde_json::json!({
+            "type": "FeatureCollection",
+            "features": [
+                {
+                    "type": "Feature",
+                    "properties": { "name": "Reichstag" },
+                    "geometry": { "type": "Point", "coordinates": [13.377, 52.518] }
+                },
+                {
+                    "type": "Feature",
+                    "id": "census",
+                    "properties": { "population": 3_700_000 },
+                    "geometry": null
+                },
+                {
+                    "type": "Feature",
+                    "properties": { "name": "Fernsehturm" },
+                    "geometry": { "type": "Point", "coordinates": [13.409, 52.520] }
+                }
+            ]
+        })
+    }
+
+    #[test]
+    fn test_null_geometry_policies() {
+        let mut config = TransformerConfig::default();
+        let options = |policy| ProcessingOptions::new().with_null_geometry(policy);
+
+        let result = process_feature_collection_with_options(
+            collection_with_null_geometry(),
+            &mut config,
+            &options(NullGeometryPolicy::Error),
+        );
+        assert!(matches!(result, Err(ProjectionError::InvalidGeometryType)));
+
+        let output = process_feature_collection_with_options(
+            collection_with_null_geometry(),
+            &mut config,
+            &options(NullGeometryPolicy::PassThrough),
+        )
+        .unwrap();
+        let geojson::GeoJson::FeatureCollection(collection) = output.geojson else {
+            panic!("Expected a FeatureCollection");
+        };
+        assert_eq!(collection.features.len(), 3);
+        assert!(collection.features[1].geometry.is_none());
+        assert_eq!(
+            collection.features[1].property("population"),
+            Some(&serde_json::json!(3_700_000))
+        );
+        assert!(output.report.warnings.is_empty());
+
+        let output = process_feature_collection_with_options(
+            collection_with_null_geometry(),
+            &mut config,
+            &options(NullGeometryPolicy::Skip),
+        )
+        .unwrap();
+        let geojson::GeoJson::FeatureCollection(collection) = output.geojson else {
+            panic!("Expected a FeatureCollection");
+        };
+        assert_eq!(collection.features.len(), 2);
+        assert_eq!(output.report.warnings.len(), 1);
+        assert_eq!(output.report.warnings[0].feature_index, 1);
+    }
+
+    #[test]
+    fn test_eager_validation_checks_everything_first() {
+        let json_value = serde_json::json!({
+            "type": "FeatureCollection",
+            "features": [
+                {
+                    "type": "Feature",
+                    "properties": null,
+                    "geometry": { "type": "Point", "coordinates": [13.377, 52.518] }
+                },
+                {
+                    "type": "Feature",
+                    "properties": null,
+                    "geometry": {
+                        "type": "LineString",
+                        "coordinates": [[13.377, 52.518], [13.379, 95.0]]
+                    }
+                }
+            ]
+        });
+        let mut config = TransformerConfig::default();
+
+        let options = ProcessingOptions::new().with_validation(ValidationStrategy::EagerAll);
+        match process_feature_collection_with_options(json_value.clone(), &mut config, &options) {
+            Err(ProjectionError::InvalidCoordinates(message)) => {
+                assert!(message.ends_with("in feature 1"))
+            }
+            _ => panic!("Expected an InvalidCoordinates error"),
+        }
+
+        let options = ProcessingOptions::new().with_validation(ValidationStrategy::PerGeometry);
+        let result = process_feature_collection_with_options(json_value, &mut config, &options);
+        assert!(matches!(
+            result,
+            Err(ProjectionError::InvalidCoordinates(_))
+        ));
+
+        let options = ProcessingOptions::new().with_validation(ValidationStrategy::EagerAll);
+        let output = process_feature_collection_with_options(
+            crate::fixtures::GeometryFixtures::berlin_feature_collection(),
+            &mut config,
+            &options,
+        );
+        assert!(output.is_ok());
+    }
+
+    #[test]
+    fn test_m_values_survive_projection() {
+        let json_value = serde_json::json!({
+            "type": "Feature",
+            "properties": { "route": "U2" },
+            "geometry": {
+                "type": "LineString",
+                "coordinates": [
+                    [0.0, 0.0, 30.0, 0.0],
+                    [1.0, 2.0, 35.0, 125.5],
+                    [2.0, 3.0, 40.0, 310.25]
+                ]
+            }
+        });
+        let mut config = TransformerConfig::default();
+        let output = process_feature_collection_with_options(
+            json_value,
+            &mut config,
+            &ProcessingOptions::new(),
+        )
+        .unwrap();
+        assert!(output.report.warnings.is_empty());
+        let geojson::GeoJson::Feature(feature) = output.geojson else {
+            panic!("Expected a feature");
+        };
+        match feature.geometry.unwrap().value {
+            Value::LineString(positions) => {
+                let measures: Vec<f64> = positions.iter().map(|p| p[3]).collect();
+                assert_eq!(measures, vec![0.0, 125.5, 310.25]);
+                assert_eq!(positions[1][2], 35.0);
+                assert!((positions[1][0] - 111319.49079327357).abs() < 1e-6);
+                assert!((positions[1][1] - 222684.20850554455).abs() < 1e-6);
+            }
+            _ => panic!("Expected LineString geometry"),
+        }
+    }
+
+    #[test]
+    fn test_m_values_dropped_with_warning_when_simplified() {
+        let json_value = serde_json::json!({
+            "type": "Feature",
+            "properties": null,
+            "geometry": {
+                "type": "LineString",
+                "coordinates": [[0.0, 0.0, 0.0, 0.0], [1.0, 1.0, 0.0, 1.0], [2.0, 2.0, 0.0, 2.0]]
+            }
+        });
+        let mut config = TransformerConfig::default().with_simplification(10_000.0);
+        let output = process_feature_collection_with_options(
+            json_value,
+            &mut config,
+            &ProcessingOptions::new(),
+        )
+        .unwrap();
+        assert_eq!(output.report.warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_project_coordinates_keeps_m_values() {
+        let geometry = Geometry::new(Value::LineString(vec![
+            vec![1.0, 2.0, 0.0, 42.0],
+            vec![2.0, 3.0, 0.0, 43.0],
+        ]));
+        let mut config = TransformerConfig::default();
+        let mut pool = CoordinateBufferPool::new(10, 100);
+        let parts = GeometryProcessor::new(&geometry, &mut config)
+            .project_coordinates(&mut pool)
+            .unwrap();
+        assert_eq!(parts[0][0][3], 42.0);
+        assert_eq!(parts[0][1][3], 43.0);
+    }
+
+    #[test]
+    fn test_validate_feature_collection_reports_every_error_by_index() {
+        let fc = geojson::FeatureCollection {
+            bbox: None,
+            features: vec![
+                geojson::Feature::from(Geometry::new(Value::Point(vec![1.0, 2.0]))),
+                geojson::Feature::from(Geometry::new(Value::LineString(vec![
+                    vec![200.0, 0.0],
+                    vec![0.0, 0.0],
+                    vec![0.0, -100.0],
+                ]))),
+                geojson::Feature::default(),
+                geojson::Feature::from(Geometry::new(Value::Polygon(vec![vec![
+                    vec![0.0, 0.0],
+                    vec![1.0, 0.0],
+                    vec![1.0, 1.0],
+                    vec![0.0, 0.0],
+                ]]))),
+            ],
+            foreign_members: None,
+        };
+
+        let errors = GeometryProcessor::validate_feature_collection(&fc, CoordinateBounds::wgs84());
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].0, 1);
+        assert_eq!(
+            errors[0].1,
+            vec![
+                ValidationError::InvalidPosition {
+                    position: vec![200.0, 0.0],
+                    reason: "Coordinates out of valid range".to_string(),
+                },
+                ValidationError::InvalidPosition {
+                    position: vec![0.0, -100.0],
+                    reason: "Coordinates out of valid range".to_string(),
+                },
+            ]
+        );
+        assert_eq!(errors[1], (2, vec![ValidationError::MissingGeometry]));
+    }
+
+    #[test]
+    fn test_validate_feature_collection_uses_bounds_and_checks_rings() {
+        let fc = geojson::FeatureCollection {
+            bbox: None,
+            features: vec![
+                geojson::Feature::from(Geometry::new(Value::Point(vec![5.0e5, 6.0e6]))),
+                geojson::Feature::from(Geometry::new(Value::MultiPolygon(vec![
+                    vec![vec![
+                        vec![0.0, 0.0],
+                        vec![1.0, 0.0],
+                        vec![1.0, 1.0],
+                        vec![0.0, 0.0],
+                    ]],
+                    vec![
+                        vec![
+                            vec![0.0, 0.0],
+                            vec![1.0, 0.0],
+                            vec![1.0, 1.0],
+                            vec![0.0, 1.0],
+                        ],
+                        vec![vec![0.0, 0.0], vec![1.0, 0.0], vec![0.0, 0.0]],
+                    ],
+                ]))),
+            ],
+            foreign_members: None,
+        };
+
+        // Projected coordinates are valid against an unbounded range, not against WGS84
+        let wgs84 = GeometryProcessor::validate_feature_collection(&fc, CoordinateBounds::wgs84());
+        assert_eq!(wgs84[0].0, 0);
+        let unbounded =
+            GeometryProcessor::validate_feature_collection(&fc, CoordinateBounds::unbounded());
+        assert_eq!(unbounded.len(), 1);
+
+        let (index, errors) = &unbounded[0];
+        assert_eq!(*index, 1);
+        assert!(matches!(
+            errors[..],
+            [
+                ValidationError::InvalidRing {
+                    polygon: 1,
+                    ring: 0,
+                    source: GeometryValidationError::UnclosedRing,
+                },
+                ValidationError::InvalidRing {
+                    polygon: 1,
+                    ring: 1,
+                    source: GeometryValidationError::TooFewPoints {
+                        expected: 4,
+                        found: 3
+                    },
+                },
+            ]
+        ));
+    }
+
+    fn unclosed_polygon() -> serde_json::Value {
+        serde_json::json!({
+            "type": "Polygon",
+            "coordinates": [[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]]
+        })
+    }
+
+    fn exterior_of(geojson: geojson::GeoJson) -> Vec<Vec<f64>> {
+        let geojson::GeoJson::Geometry(geometry) = geojson else {
+            panic!("Expected a geometry");
+        };
+        let Value::Polygon(mut rings) = geometry.value else {
+            panic!("Expected a polygon");
+        };
+        rings.remove(0)
+    }
+
+    #[test]
+    fn test_unclosed_ring_is_rejected_by_default() {
+        let result = process_feature_collection_with_options(
+            unclosed_polygon(),
+            &mut TransformerConfig::default(),
+            &ProcessingOptions::new().with_ring_closure(RingClosure::Error),
+        );
+        assert!(matches!(
+            result,
+            Err(ProjectionError::InvalidCoordinates(reason)) if reason.contains("not closed")
+        ));
+        assert!(
+            process_feature_collection(unclosed_polygon(), &mut TransformerConfig::default())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_unclosed_ring_is_auto_closed() {
+        let output = process_feature_collection_with_options(
+            unclosed_polygon(),
+            &mut TransformerConfig::default(),
+            &ProcessingOptions::new().with_ring_closure(RingClosure::AutoClose),
+        )
+        .unwrap();
+        let exterior = exterior_of(output.geojson);
+        assert_eq!(exterior.len(), 5);
+        assert_eq!(exterior.first(), exterior.last());
+    }
+
+    #[test]
+    fn test_unclosed_ring_is_ignored() {
+        let output = process_feature_collection_with_options(
+            unclosed_polygon(),
+            &mut TransformerConfig::default(),
+            &ProcessingOptions::new().with_ring_closure(RingClosure::Ignore),
+        )
+        .unwrap();
+        // The ring reaches the projection unchanged, geo closes it on output
+        let exterior = exterior_of(output.geojson);
+        assert_eq!(exterior.len(), 5);
+        assert_eq!(exterior[3][0], 0.0);
+    }
+
+    fn coordinates(ring: &[[f64; 2]]) -> Vec<Coordinate> {
+        ring.iter().map(|&[x, y]| Coordinate::new(x, y)).collect()
+    }
+
+    const EXTERIOR: [[f64; 2]; 5] = [[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [1.0, 2.0], [0.0, 0.0]];
+    const HOLE: [[f64; 2]; 5] = [[0.5, 0.5], [0.5, 1.0], [1.0, 1.0], [1.0, 0.5], [0.5, 0.5]];
+
+    #[test]
+    fn test_project_ring_matches_web_mercator() {
+        let config = TransformerConfig::default();
+        let pool = CoordinateBufferPool::new(10, 100);
+
+        let ring = project_ring(&coordinates(&EXTERIOR), &config, &pool).unwrap();
+        assert_eq!(ring.0.len(), 5);
+        assert!((ring.0[3].x - 111319.49079327357).abs() < 1e-6);
+        assert!((ring.0[3].y - 222684.20850554455).abs() < 1e-6);
+        assert_eq!(ring.0[0], geo::coord! { x: 0.0, y: 0.0 });
+        // The intermediate buffer went back to the pool
+        assert_eq!(pool.point_buffers.lock().unwrap().len(), 1);
+        assert_eq!(config.transformation_count(), 5);
+
+        let mut out = vec![geo::coord! { x: -1.0, y: -1.0 }];
+        project_ring_into(&coordinates(&EXTERIOR), &config, &mut out).unwrap();
+        assert_eq!(out[0], geo::coord! { x: -1.0, y: -1.0 });
+        assert_eq!(&out[1..], &ring.0[..]);
+    }
+
+    #[test]
+    fn test_polygon_processors_match_project_ring() {
+        let rings = |rings: &[&[[f64; 2]; 5]]| -> Vec<Vec<Vec<f64>>> {
+            rings
+                .iter()
+                .map(|ring| ring.iter().map(|c| c.to_vec()).collect())
+                .collect()
+        };
+        let config = TransformerConfig::default();
+        let pool = CoordinateBufferPool::new(10, 100);
+        let expected_exterior = project_ring(&coordinates(&EXTERIOR), &config, &pool).unwrap();
+        let expected_hole = project_ring(&coordinates(&HOLE), &config, &pool).unwrap();
+
+        let polygon = Geometry::new(Value::Polygon(rings(&[&EXTERIOR, &HOLE])));
+        let multi_polygon = Geometry::new(Value::MultiPolygon(vec![
+            rings(&[&EXTERIOR, &HOLE]),
+            rings(&[&EXTERIOR]),
+        ]));
+        let mut config = TransformerConfig::default();
+        let mut buffer_pool = CoordinateBufferPool::new(10, 100);
+
+        match GeometryProcessor::new(&polygon, &mut config)
+            .process(&mut buffer_pool)
+            .unwrap()
+        {
+            ProcessedGeometry::Polygon(p) => {
+                assert_eq!(p.exterior(), &expected_exterior);
+                assert_eq!(p.interiors(), std::slice::from_ref(&expected_hole));
+            }
+            other => panic!("Expected Polygon geometry, got {other:?}"),
+        }
+        match GeometryProcessor::new(&multi_polygon, &mut config)
+            .process(&mut buffer_pool)
+            .unwrap()
+        {
+            ProcessedGeometry::MultiPolygon(mp) => {
+                assert_eq!(mp.0[0].exterior(), &expected_exterior);
+                assert_eq!(mp.0[0].interiors(), &[expected_hole]);
+                assert_eq!(mp.0[1].exterior(), &expected_exterior);
+                assert!(mp.0[1].interiors().is_empty());
+            }
+            other => panic!("Expected MultiPolygon geometry, got {other:?}"),
+        }
+        assert_eq!(buffer_pool.stats().unwrap().current_usage(), 0);
+    }
+
+    #[test]
+    fn test_line_processors_match_project_ring_across_batches() {
+        // Longer than one batch of 1000 coordinates
+        let coords: Vec<Coordinate> = (0..2500)
+            .map(|i| Coordinate::new(i as f64 * 0.01, (i % 7) as f64 * 0.1))
+            .collect();
+        let positions: Vec<Vec<f64>> = coords.iter().map(|c| vec![c.x, c.y]).collect();
+        let config = TransformerConfig::default();
+        let expected = project_ring(&coords, &config, &CoordinateBufferPool::new(10, 100)).unwrap();
+        assert_eq!(config.transformation_count(), 2500);
+
+        let mut config = TransformerConfig::default();
+        let mut buffer_pool = CoordinateBufferPool::new(10, 100);
+        let line = Geometry::new(Value::LineString(positions.clone()));
+        match GeometryProcessor::new(&line, &mut config)
+            .process(&mut buffer_pool)
+            .unwrap()
+        {
+            ProcessedGeometry::LineString(ls) => assert_eq!(ls, expected),
+            other => panic!("Expected LineString geometry, got {other:?}"),
+        }
+        let lines = Geometry::new(Value::MultiLineString(vec![positions.clone(), positions]));
+        match GeometryProcessor::new(&lines, &mut config)
+            .process(&mut buffer_pool)
+            .unwrap()
+        {
+            ProcessedGeometry::MultiLineString(mls) => {
+                assert_eq!(mls.0, vec![expected.clone(), expected]);
+            }
+            other => panic!("Expected MultiLineString geometry, got {other:?}"),
+        }
+        assert_eq!(config.transformation_count(), 7500);
+    }
 }
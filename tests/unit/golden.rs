@@ -0,0 +1,155 @@
+//! Golden-file regression tests on committed real-world fixtures
+//!
+//! Every fixture in `tests/golden/input` is processed with every configuration
+//! below and compared to `tests/golden/expected/{fixture}.{configuration}.geojson`.
+//! Numbers are compared with a tolerance, everything else must match exactly.
+//!
+//! After an intentional output change, regenerate the expected files with
+//! `cargo test --test lib regenerate_golden_files -- --ignored` and review the diff.
+
+use proj_exercise_simple::helpers::process_feature_collection;
+use proj_exercise_simple::transformer::TransformerConfig;
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const FIXTURES: [&str; 3] = ["country_with_holes", "gps_trace", "mixed_collection"];
+
+/// UTM needs PROJ, the other configurations also run on the built-in Web Mercator backend
+#[cfg(feature = "proj")]
+const CONFIGURATIONS: &[&str] = &["default", "precision_6", "simplify_10m", "epsg_25832"];
+#[cfg(not(feature = "proj"))]
+const CONFIGURATIONS: &[&str] = &["default", "precision_6", "simplify_10m"];
+
+/// Absolute tolerance, one micrometre in metric CRSs
+const ABSOLUTE_TOLERANCE: f64 = 1e-6;
+
+/// Relative tolerance for large projected values
+const RELATIVE_TOLERANCE: f64 = 1e-9;
+
+fn golden_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden")
+}
+
+fn configuration(name: &str) -> TransformerConfig {
+    match name {
+        "default" => TransformerConfig::default(),
+        "precision_6" => TransformerConfig::default().with_precision(6),
+        "simplify_10m" => TransformerConfig::default().with_simplification(10.0),
+        "epsg_25832" => {
+            TransformerConfig::new("EPSG:4326".to_string(), "EPSG:25832".to_string()).unwrap()
+        }
+        _ => panic!("Unknown configuration {name}"),
+    }
+}
+
+fn process(fixture: &str, configuration_name: &str) -> Value {
+    let path = golden_dir()
+        .join("input")
+        .join(format!("{fixture}.geojson"));
+    let input: Value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+    let output = process_feature_collection(input, &mut configuration(configuration_name))
+        .unwrap_or_else(|e| panic!("{fixture} with {configuration_name} failed: {e}"));
+    serde_json::to_value(output).unwrap()
+}
+
+fn expected_path(fixture: &str, configuration_name: &str) -> PathBuf {
+    golden_dir()
+        .join("expected")
+        .join(format!("{fixture}.{configuration_name}.geojson"))
+}
+
+/// Compare two JSON documents, allowing numbers to differ by float noise
+///
+/// Returns the JSON path of the first difference.
+fn compare(expected: &Value, actual: &Value, path: &str) -> Result<(), String> {
+    match (expected, actual) {
+        (Value::Number(e), Value::Number(a)) => {
+            let (e, a) = (e.as_f64().unwrap(), a.as_f64().unwrap());
+            let tolerance = ABSOLUTE_TOLERANCE.max(RELATIVE_TOLERANCE * e.abs().max(a.abs()));
+            if (e - a).abs() <= tolerance {
+                Ok(())
+            } else {
+                Err(format!("{path}: expected {e}, got {a}"))
+            }
+        }
+        (Value::Array(e), Value::Array(a)) => {
+            if e.len() != a.len() {
+                return Err(format!(
+                    "{path}: expected {} elements, got {}",
+                    e.len(),
+                    a.len()
+                ));
+            }
+            e.iter()
+                .zip(a)
+                .enumerate()
+                .try_for_each(|(i, (e, a))| compare(e, a, &format!("{path}[{i}]")))
+        }
+        (Value::Object(e), Value::Object(a)) => {
+            if !e.keys().eq(a.keys()) {
+                return Err(format!(
+                    "{path}: expected keys {:?}, got {:?}",
+                    e.keys().collect::<Vec<_>>(),
+                    a.keys().collect::<Vec<_>>()
+                ));
+            }
+            e.iter()
+                .try_for_each(|(key, e)| compare(e, &a[key], &format!("{path}.{key}")))
+        }
+        (e, a) if e == a => Ok(()),
+        (e, a) => Err(format!("{path}: expected {e}, got {a}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_outputs_match_golden_files() {
+        let mut failures = Vec::new();
+        for fixture in FIXTURES {
+            for configuration_name in CONFIGURATIONS {
+                let path = expected_path(fixture, configuration_name);
+                let expected: Value = serde_json::from_str(
+                    &fs::read_to_string(&path)
+                        .unwrap_or_else(|e| panic!("{}: {e}", path.display())),
+                )
+                .unwrap();
+                let actual = process(fixture, configuration_name);
+                if let Err(difference) = compare(&expected, &actual, "$") {
+                    failures.push(format!("{fixture}.{configuration_name}: {difference}"));
+                }
+            }
+        }
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+
+    #[test]
+    fn test_comparator_tolerates_float_noise_only() {
+        let expected = serde_json::json!({ "coordinates": [[1489199.0, 6894699.5]] });
+        let noisy = serde_json::json!({ "coordinates": [[1489199.0000001, 6894699.5]] });
+        assert!(compare(&expected, &noisy, "$").is_ok());
+
+        let moved = serde_json::json!({ "coordinates": [[1489199.1, 6894699.5]] });
+        assert!(compare(&expected, &moved, "$").is_err());
+
+        // A lost hole or vertex is a structural change
+        let shorter = serde_json::json!({ "coordinates": [] });
+        assert!(compare(&expected, &shorter, "$").is_err());
+    }
+
+    #[test]
+    #[ignore = "writes the expected files, run only to accept an intentional output change"]
+    fn regenerate_golden_files() {
+        for fixture in FIXTURES {
+            for configuration_name in CONFIGURATIONS {
+                let output = process(fixture, configuration_name);
+                let mut document = serde_json::to_string_pretty(&output).unwrap();
+                document.push('\n');
+                fs::write(expected_path(fixture, configuration_name), document).unwrap();
+            }
+        }
+    }
+}
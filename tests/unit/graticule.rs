@@ -0,0 +1,90 @@
+use geojson::{Feature, Value};
+use proj_exercise_simple::graticule::generate_graticule;
+use proj_exercise_simple::transformer::TransformerConfig;
+
+fn kind(feature: &Feature) -> &str {
+    feature.property("type").and_then(|v| v.as_str()).unwrap()
+}
+
+fn positions(feature: &Feature) -> &[Vec<f64>] {
+    match &feature.geometry.as_ref().unwrap().value {
+        Value::LineString(positions) => positions,
+        other => panic!("Expected a line string, got {}", other.type_name()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_world_graticule_in_web_mercator() {
+        let mut config = TransformerConfig::default();
+        let graticule = generate_graticule(30.0, None, 1.0, &mut config).unwrap();
+
+        // 13 meridians from -180 to 180, the poles are clamped so 5 parallels from -60 to 60
+        let (meridians, parallels): (Vec<&Feature>, Vec<&Feature>) = graticule
+            .features
+            .iter()
+            .partition(|feature| kind(feature) == "meridian");
+        assert_eq!(meridians.len(), 13);
+        assert_eq!(parallels.len(), 5);
+
+        for parallel in parallels {
+            let positions = positions(parallel);
+            // 360 degrees densified at 1 degree
+            assert_eq!(positions.len(), 361);
+            let y = positions[0][1];
+            assert!(positions.iter().all(|p| (p[1] - y).abs() < 1e-6));
+        }
+        for meridian in meridians {
+            let positions = positions(meridian);
+            assert!(positions.iter().all(|p| p[1].is_finite()));
+            let x = positions[0][0];
+            assert!(positions.iter().all(|p| (p[0] - x).abs() < 1e-6));
+        }
+    }
+
+    #[test]
+    fn test_graticule_properties_and_bbox() {
+        let mut config = TransformerConfig::default();
+        let graticule =
+            generate_graticule(15.0, Some([-30.0, 0.0, 0.0, 20.0]), 5.0, &mut config).unwrap();
+        let values: Vec<(String, f64)> = graticule
+            .features
+            .iter()
+            .map(|feature| {
+                let value = feature.property("value").and_then(|v| v.as_f64()).unwrap();
+                (kind(feature).to_string(), value)
+            })
+            .collect();
+        let expected = [
+            ("meridian", -30.0),
+            ("meridian", -15.0),
+            ("meridian", 0.0),
+            ("parallel", 0.0),
+            ("parallel", 15.0),
+        ];
+        assert_eq!(values.len(), expected.len());
+        for ((kind, value), (expected_kind, expected_value)) in values.iter().zip(expected) {
+            assert_eq!(kind, expected_kind);
+            assert_eq!(*value, expected_value);
+        }
+        assert!(generate_graticule(0.0, None, 1.0, &mut config).is_err());
+    }
+
+    #[test]
+    fn test_geographic_graticule_keeps_the_poles() {
+        let mut config =
+            TransformerConfig::new("EPSG:4326".to_string(), "EPSG:4326".to_string()).unwrap();
+        let graticule = generate_graticule(90.0, None, 10.0, &mut config).unwrap();
+        let meridian = graticule
+            .features
+            .iter()
+            .find(|feature| kind(feature) == "meridian")
+            .unwrap();
+        let positions = positions(meridian);
+        assert_eq!(positions.first().unwrap()[1], -90.0);
+        assert_eq!(positions.last().unwrap()[1], 90.0);
+    }
+}
@@ -0,0 +1,114 @@
+use proj_exercise_simple::coordinates::{Coordinate, CoordinateBounds};
+use proj_exercise_simple::error::ProjectionError;
+use proj_exercise_simple::grid::{
+    project_grid, project_regular_grid, project_regular_grid_edge, project_regular_grid_into,
+};
+use proj_exercise_simple::transformer::TransformerConfig;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn max_error(interpolated: &[Vec<(f64, f64)>], exact: &[Vec<(f64, f64)>]) -> f64 {
+        interpolated
+            .iter()
+            .flatten()
+            .zip(exact.iter().flatten())
+            .map(|(a, b)| (a.0 - b.0).hypot(a.1 - b.1))
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn test_interpolated_grid_stays_close_to_exact() {
+        let bounds = CoordinateBounds::new(13.0, 14.0, 52.0, 53.0);
+        let config = TransformerConfig::default();
+        let exact = project_grid(bounds, 41, 41, &config, 1).unwrap();
+
+        // Control points 0.1 degrees apart
+        let coarse = project_grid(bounds, 41, 41, &config, 4).unwrap();
+        let coarse_error = max_error(&coarse, &exact);
+        assert!(coarse_error < 10.0);
+        assert!(coarse_error > 0.0);
+
+        // Halving the control spacing reduces the error roughly fourfold
+        let fine = project_grid(bounds, 41, 41, &config, 2).unwrap();
+        assert!(max_error(&fine, &exact) < coarse_error / 3.0);
+
+        // Control points are projected exactly
+        assert_eq!(coarse[4][8], exact[4][8]);
+        assert_eq!(coarse[40][40], exact[40][40]);
+    }
+
+    #[test]
+    fn test_uneven_control_step_includes_last_point() {
+        let bounds = CoordinateBounds::new(0.0, 1.0, 0.0, 1.0);
+        let config = TransformerConfig::default();
+        let exact = project_grid(bounds, 10, 7, &config, 1).unwrap();
+        let grid = project_grid(bounds, 10, 7, &config, 4).unwrap();
+        assert_eq!(grid.len(), 7);
+        assert!(grid.iter().all(|row| row.len() == 10));
+        assert_eq!(grid[6][9], exact[6][9]);
+    }
+
+    #[test]
+    fn test_invalid_grid_size() {
+        let bounds = CoordinateBounds::new(0.0, 1.0, 0.0, 1.0);
+        let config = TransformerConfig::default();
+        assert!(matches!(
+            project_grid(bounds, 1, 5, &config, 1),
+            Err(ProjectionError::InvalidCoordinates(_))
+        ));
+        assert!(project_grid(bounds, 5, 5, &config, 0).is_err());
+    }
+
+    fn project_point(config: &TransformerConfig, x: f64, y: f64) -> Coordinate {
+        let (x, y): (f64, f64) = config.get_transformer().unwrap().convert((x, y)).unwrap();
+        Coordinate::new(x, y)
+    }
+
+    #[test]
+    fn test_regular_grid_matches_individual_points() {
+        let config = TransformerConfig::default();
+        let grid =
+            project_regular_grid(Coordinate::new(10.0, 40.0), 1.0, 2.0, 3, 3, &config).unwrap();
+        assert_eq!(grid.len(), 9);
+        for j in 0..3 {
+            for i in 0..3 {
+                let expected = project_point(&config, 10.0 + i as f64, 40.0 + 2.0 * j as f64);
+                assert_eq!(grid[j * 3 + i], expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_regular_grid_reuses_the_output_buffer() {
+        let config = TransformerConfig::default();
+        let mut output = Vec::with_capacity(64);
+        output.push(Coordinate::new(0.0, 0.0));
+        project_regular_grid_into(
+            Coordinate::new(0.0, 0.0),
+            1.0,
+            1.0,
+            4,
+            2,
+            &config,
+            &mut output,
+        )
+        .unwrap();
+        assert_eq!(output.len(), 8);
+        assert!(output.capacity() >= 64);
+    }
+
+    #[test]
+    fn test_regular_grid_edge_is_the_closed_outline() {
+        let config = TransformerConfig::default();
+        let origin = Coordinate::new(10.0, 40.0);
+        let edge = project_regular_grid_edge(origin, 1.0, 1.0, 3, 2, &config).unwrap();
+        let grid = project_regular_grid(origin, 1.0, 1.0, 3, 2, &config).unwrap();
+        // Row-major indices of the outline, counter-clockwise from the origin
+        let expected: Vec<Coordinate> = [0, 1, 2, 5, 4, 3, 0].iter().map(|&i| grid[i]).collect();
+        assert_eq!(edge, expected);
+
+        assert!(project_regular_grid_edge(origin, 1.0, 1.0, 1, 3, &config).is_err());
+    }
+}
@@ -0,0 +1,56 @@
+use crate::fixtures::GeometryFixtures;
+use geojson::{GeoJson, Geometry, Value};
+use proj_exercise_simple::error::ProjectionError;
+use proj_exercise_simple::helpers::{
+    process_feature_collection, process_feature_collection_in_place,
+};
+use proj_exercise_simple::transformer::TransformerConfig;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_in_place_matches_returned_output() {
+        let mut config = TransformerConfig::default();
+        let expected =
+            process_feature_collection(GeometryFixtures::berlin_feature_collection(), &mut config)
+                .unwrap();
+
+        let mut geojson =
+            GeoJson::from_json_value(GeometryFixtures::berlin_feature_collection()).unwrap();
+        if let GeoJson::FeatureCollection(collection) = &mut geojson {
+            collection.features[0].bbox = Some(vec![13.377, 52.518, 13.377, 52.518]);
+        }
+        process_feature_collection_in_place(&mut geojson, &mut config).unwrap();
+        assert_eq!(geojson, expected);
+    }
+
+    #[test]
+    fn test_in_place_geometry_keeps_z_values() {
+        let mut geojson = GeoJson::Geometry(Geometry::new(Value::LineString(vec![
+            vec![1.0, 2.0, 30.0],
+            vec![1.5, 2.5, 40.0],
+        ])));
+        process_feature_collection_in_place(&mut geojson, &mut TransformerConfig::default())
+            .unwrap();
+        let GeoJson::Geometry(Geometry {
+            value: Value::LineString(positions),
+            ..
+        }) = geojson
+        else {
+            panic!("Expected LineString geometry");
+        };
+        assert!((positions[0][0] - 111319.49079327357).abs() < 1e-6);
+        assert_eq!(positions[0][2], 30.0);
+        assert_eq!(positions[1][2], 40.0);
+    }
+
+    #[test]
+    fn test_in_place_rejects_missing_geometry() {
+        let mut geojson = GeoJson::Feature(geojson::Feature::default());
+        let result =
+            process_feature_collection_in_place(&mut geojson, &mut TransformerConfig::default());
+        assert!(matches!(result, Err(ProjectionError::InvalidGeometryType)));
+    }
+}
@@ -0,0 +1,192 @@
+//! Checks the `tracing` instrumentation with a subscriber that records everything
+//!
+//! Run with `cargo test --features tracing`.
+
+use proj_exercise_simple::helpers::process_feature_collection_with_options;
+use proj_exercise_simple::options::{NullGeometryPolicy, ProcessingOptions};
+use proj_exercise_simple::transformer::TransformerConfig;
+use serde_json::json;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+/// A recorded span or event with its fields and the names of its ancestors
+#[derive(Debug, Clone)]
+struct Captured {
+    name: String,
+    fields: BTreeMap<String, String>,
+    ancestors: Vec<String>,
+}
+
+#[derive(Default)]
+struct State {
+    spans: Vec<Captured>,
+    events: Vec<Captured>,
+    stack: Vec<u64>,
+}
+
+/// Records every span and event, nesting spans by the entered stack
+#[derive(Clone, Default)]
+struct CaptureSubscriber(Arc<Mutex<State>>);
+
+struct FieldVisitor<'a>(&'a mut BTreeMap<String, String>);
+
+impl Visit for FieldVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0
+            .insert(field.name().to_string(), format!("{value:?}"));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), value.to_string());
+    }
+}
+
+impl State {
+    fn ancestors(&self, parent: Option<u64>) -> Vec<String> {
+        let mut ancestors = Vec::new();
+        let mut current = parent;
+        while let Some(id) = current {
+            let index = id as usize - 1;
+            ancestors.push(self.spans[index].name.clone());
+            current = self.spans[index]
+                .fields
+                .get("__parent")
+                .map(|p| p.parse().unwrap());
+        }
+        ancestors
+    }
+}
+
+impl Subscriber for CaptureSubscriber {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, attributes: &Attributes<'_>) -> Id {
+        let mut state = self.0.lock().unwrap();
+        let parent = attributes
+            .parent()
+            .map(Id::into_u64)
+            .or_else(|| state.stack.last().copied());
+        let mut fields = BTreeMap::new();
+        attributes.record(&mut FieldVisitor(&mut fields));
+        let ancestors = state.ancestors(parent);
+        if let Some(parent) = parent {
+            fields.insert("__parent".to_string(), parent.to_string());
+        }
+        state.spans.push(Captured {
+            name: attributes.metadata().name().to_string(),
+            fields,
+            ancestors,
+        });
+        Id::from_u64(state.spans.len() as u64)
+    }
+
+    fn record(&self, span: &Id, values: &Record<'_>) {
+        let mut state = self.0.lock().unwrap();
+        let index = span.into_u64() as usize - 1;
+        values.record(&mut FieldVisitor(&mut state.spans[index].fields));
+    }
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut state = self.0.lock().unwrap();
+        let mut fields = BTreeMap::new();
+        event.record(&mut FieldVisitor(&mut fields));
+        let parent = state.stack.last().copied();
+        let ancestors = state.ancestors(parent);
+        state.events.push(Captured {
+            name: fields.get("message").cloned().unwrap_or_default(),
+            fields,
+            ancestors,
+        });
+    }
+
+    fn enter(&self, span: &Id) {
+        self.0.lock().unwrap().stack.push(span.into_u64());
+    }
+
+    fn exit(&self, _span: &Id) {
+        self.0.lock().unwrap().stack.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn capture(json_value: serde_json::Value, options: &ProcessingOptions) -> State {
+        let subscriber = CaptureSubscriber::default();
+        let state = Arc::clone(&subscriber.0);
+        tracing::subscriber::with_default(subscriber, || {
+            let mut config = TransformerConfig::default().with_simplification(1.0);
+            process_feature_collection_with_options(json_value, &mut config, options).unwrap();
+        });
+        Arc::try_unwrap(state).ok().unwrap().into_inner().unwrap()
+    }
+
+    #[test]
+    fn test_span_hierarchy_and_warning_event() {
+        let collection = json!({
+            "type": "FeatureCollection",
+            "features": [
+                {
+                    "type": "Feature",
+                    "id": "spree",
+                    "geometry": {
+                        "type": "LineString",
+                        "coordinates": [[13.377, 52.518], [13.379, 52.517], [13.381, 52.516]]
+                    },
+                    "properties": {}
+                },
+                { "type": "Feature", "geometry": null, "properties": {} }
+            ]
+        });
+        let options = ProcessingOptions::new().with_null_geometry(NullGeometryPolicy::Skip);
+        let state = capture(collection, &options);
+
+        let names: Vec<&str> = state.spans.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "process_feature_collection",
+                "feature",
+                "project_geometry",
+                "feature"
+            ]
+        );
+        assert_eq!(state.spans[0].fields["feature_count"], "2");
+        assert_eq!(state.spans[1].ancestors, vec!["process_feature_collection"]);
+        assert_eq!(state.spans[1].fields["feature_index"], "0");
+        assert!(state.spans[1].fields["feature_id"].contains("spree"));
+        assert_eq!(
+            state.spans[2].ancestors,
+            vec!["feature", "process_feature_collection"]
+        );
+        assert_eq!(state.spans[2].fields["coordinate_count"], "3");
+
+        let warning = state
+            .events
+            .iter()
+            .find(|e| e.name == "processing warning")
+            .expect("warning event");
+        assert_eq!(warning.fields["feature_index"], "1");
+        assert_eq!(
+            warning.ancestors,
+            vec!["feature", "process_feature_collection"]
+        );
+
+        let simplified = state
+            .events
+            .iter()
+            .find(|e| e.name == "simplified geometry")
+            .expect("simplification event");
+        assert_eq!(simplified.fields["vertices_before"], "3");
+        assert!(state.events.iter().any(|e| e.name == "transformer created"));
+    }
+}
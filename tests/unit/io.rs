@@ -0,0 +1,72 @@
+use crate::fixtures::GeometryFixtures;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use geojson::GeoJson;
+use proj_exercise_simple::error::ProjectionError;
+use proj_exercise_simple::io::{process_file, read_geojson_file};
+use proj_exercise_simple::transformer::TransformerConfig;
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::PathBuf;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("proj-exercise-{}-{}", std::process::id(), name))
+    }
+
+    fn write_gzipped(path: &PathBuf, value: &serde_json::Value) {
+        let mut encoder = GzEncoder::new(File::create(path).unwrap(), Compression::default());
+        encoder.write_all(value.to_string().as_bytes()).unwrap();
+        encoder.finish().unwrap();
+    }
+
+    #[test]
+    fn test_process_gzipped_file() {
+        let input = temp_path("input.json.gz");
+        let output = temp_path("output.json.gz");
+        write_gzipped(&input, &GeometryFixtures::berlin_feature_collection());
+
+        let mut config = TransformerConfig::default();
+        process_file(&input, &output, &mut config).unwrap();
+
+        let mut text = String::new();
+        GzDecoder::new(File::open(&output).unwrap())
+            .read_to_string(&mut text)
+            .unwrap();
+        let geojson: GeoJson = text.parse().unwrap();
+        match geojson {
+            GeoJson::FeatureCollection(collection) => assert_eq!(collection.features.len(), 3),
+            _ => panic!("Expected a feature collection"),
+        }
+
+        std::fs::remove_file(input).unwrap();
+        std::fs::remove_file(output).unwrap();
+    }
+
+    #[test]
+    fn test_plain_output_and_detection_by_magic_bytes() {
+        // A gzipped file without a .gz extension is still detected
+        let input = temp_path("mislabelled.json");
+        let output = temp_path("plain-output.json");
+        write_gzipped(&input, &GeometryFixtures::berlin_feature_collection());
+        assert!(read_geojson_file(&input).is_ok());
+
+        let mut config = TransformerConfig::default();
+        process_file(&input, &output, &mut config).unwrap();
+        let text = std::fs::read_to_string(&output).unwrap();
+        assert!(text.parse::<GeoJson>().is_ok());
+
+        std::fs::remove_file(input).unwrap();
+        std::fs::remove_file(output).unwrap();
+    }
+
+    #[test]
+    fn test_missing_file_is_io_error() {
+        let result = read_geojson_file(temp_path("does-not-exist.json"));
+        assert!(matches!(result, Err(ProjectionError::IoError(_))));
+    }
+}
@@ -0,0 +1,100 @@
+use geo::{
+    coord, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon, Point,
+    Polygon,
+};
+use geojson::{Geometry, Value};
+use proj_exercise_simple::helpers::ProcessedGeometry;
+use proj_exercise_simple::kind::{
+    is_supported, supported_geometry_types, GeometryKind, HasGeometryKind,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_processed_geometry_kinds() {
+        let line = LineString::from(vec![coord! { x: 0.0, y: 0.0 }, coord! { x: 1.0, y: 1.0 }]);
+        let polygon = Polygon::new(
+            LineString::from(vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 0.0)]),
+            vec![],
+        );
+        let cases = [
+            (
+                ProcessedGeometry::Point(Point::new(0.0, 0.0)),
+                GeometryKind::Point,
+            ),
+            (
+                ProcessedGeometry::LineString(line.clone()),
+                GeometryKind::LineString,
+            ),
+            (
+                ProcessedGeometry::Polygon(polygon.clone()),
+                GeometryKind::Polygon,
+            ),
+            (
+                ProcessedGeometry::MultiPoint(MultiPoint::from(vec![Point::new(0.0, 0.0)])),
+                GeometryKind::MultiPoint,
+            ),
+            (
+                ProcessedGeometry::MultiLineString(MultiLineString::new(vec![line])),
+                GeometryKind::MultiLineString,
+            ),
+            (
+                ProcessedGeometry::MultiPolygon(MultiPolygon::new(vec![polygon])),
+                GeometryKind::MultiPolygon,
+            ),
+            (
+                ProcessedGeometry::GeometryCollection(GeometryCollection::default()),
+                GeometryKind::GeometryCollection,
+            ),
+        ];
+        for (geometry, kind) in cases {
+            assert_eq!(geometry.geometry_kind(), kind);
+            // The name matches the GeoJSON type of the converted geometry
+            assert_eq!(
+                geometry.to_geojson_geometry().value.type_name(),
+                kind.as_str()
+            );
+        }
+    }
+
+    #[test]
+    fn test_geojson_geometry_kind() {
+        let geometry = Geometry::new(Value::MultiPoint(vec![vec![1.0, 2.0]]));
+        assert_eq!(geometry.geometry_kind(), GeometryKind::MultiPoint);
+        assert_eq!(geometry.value.geometry_kind().to_string(), "MultiPoint");
+    }
+
+    #[test]
+    fn test_all_geojson_geometry_types_are_supported() {
+        let names: Vec<&str> = supported_geometry_types()
+            .iter()
+            .map(GeometryKind::as_str)
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "Point",
+                "LineString",
+                "Polygon",
+                "MultiPoint",
+                "MultiLineString",
+                "MultiPolygon",
+                "GeometryCollection",
+            ]
+        );
+
+        let point = Geometry::new(Value::Point(vec![1.0, 2.0]));
+        let values = [
+            point.value.clone(),
+            Value::LineString(vec![vec![0.0, 0.0], vec![1.0, 1.0]]),
+            Value::Polygon(vec![]),
+            Value::MultiPoint(vec![]),
+            Value::MultiLineString(vec![]),
+            Value::MultiPolygon(vec![]),
+            Value::GeometryCollection(vec![point]),
+        ];
+        assert!(values.iter().all(is_supported));
+    }
+}
@@ -0,0 +1,75 @@
+use crate::fixtures::GeometryFixtures;
+use geojson::GeoJson;
+use proj_exercise_simple::error::ProjectionError;
+use proj_exercise_simple::helpers::process_feature_collection_with_options;
+use proj_exercise_simple::measure::MeasureProperties;
+use proj_exercise_simple::options::ProcessingOptions;
+use proj_exercise_simple::transformer::TransformerConfig;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measures_are_attached_to_projected_features() {
+        let mut config = TransformerConfig::default();
+        let options =
+            ProcessingOptions::new().with_measure_properties(MeasureProperties::default());
+        let output = process_feature_collection_with_options(
+            GeometryFixtures::berlin_feature_collection(),
+            &mut config,
+            &options,
+        )
+        .unwrap();
+        let GeoJson::FeatureCollection(collection) = output.geojson else {
+            panic!("Expected a FeatureCollection");
+        };
+
+        let point = &collection.features[0];
+        assert!(point.property("area_m2").is_none());
+        assert!(point.property("length_m").is_none());
+
+        let line_length = collection.features[1].property("length_m").unwrap();
+        assert!(line_length.as_f64().unwrap() > 0.0);
+
+        let polygon = &collection.features[2];
+        let area = polygon.property("area_m2").unwrap().as_f64().unwrap();
+        // About 340 m by 556 m, inflated by Web Mercator at this latitude
+        assert!(area > 0.0);
+        assert!((area / (340.0 * 556.0) - 1.0 / 52.5f64.to_radians().cos().powi(2)).abs() < 0.1);
+        assert_eq!(polygon.property("name").unwrap(), "Tiergarten");
+    }
+
+    #[test]
+    fn test_custom_measure_keys() {
+        let mut config = TransformerConfig::default();
+        let options =
+            ProcessingOptions::new().with_measure_properties(MeasureProperties::new("area", "len"));
+        let output = process_feature_collection_with_options(
+            GeometryFixtures::berlin_feature_collection(),
+            &mut config,
+            &options,
+        )
+        .unwrap();
+        let GeoJson::FeatureCollection(collection) = output.geojson else {
+            panic!("Expected a FeatureCollection");
+        };
+        assert!(collection.features[1].property("len").is_some());
+        assert!(collection.features[2].property("area").is_some());
+    }
+
+    #[test]
+    fn test_measures_need_a_projected_target() {
+        let mut config =
+            TransformerConfig::new("EPSG:3857".to_string(), "EPSG:4326".to_string()).unwrap();
+        let options =
+            ProcessingOptions::new().with_measure_properties(MeasureProperties::default());
+        let json_value = serde_json::json!({
+            "type": "Feature",
+            "properties": null,
+            "geometry": { "type": "Point", "coordinates": [0.0, 0.0] }
+        });
+        let result = process_feature_collection_with_options(json_value, &mut config, &options);
+        assert!(matches!(result, Err(ProjectionError::TransformerError(_))));
+    }
+}
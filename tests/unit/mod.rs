@@ -1,3 +1,53 @@
+pub mod arrow;
+pub mod axis_order;
 pub mod buffer_pool;
+pub mod coerce;
 pub mod complex_geometries;
+pub mod concurrency;
+pub mod coordinates;
+pub mod degenerate_holes;
+pub mod densify;
+pub mod diff;
+pub mod digest;
+pub mod distortion;
+pub mod explode;
+pub mod feature_range;
+pub mod feature_simplification;
+pub mod ffi;
+pub mod fuzz;
 pub mod geometry_processor;
+pub mod golden;
+pub mod graticule;
+pub mod grid;
+pub mod in_place;
+#[cfg(feature = "tracing")]
+pub mod instrumentation;
+pub mod io;
+pub mod kind;
+pub mod measure;
+pub mod multiple_targets;
+pub mod natural_earth;
+pub mod ndjson;
+pub mod nonblocking;
+pub mod normalize;
+pub mod numeric_robustness;
+pub mod order;
+pub mod plan;
+pub mod pointer;
+pub mod post_transform_hook;
+pub mod reexports;
+pub mod roundtrip;
+pub mod schema;
+pub mod self_intersection;
+pub mod spatial_index;
+pub mod stable;
+pub mod strict_rfc7946;
+pub mod testing;
+pub mod tiles;
+pub mod transformer_stats;
+pub mod typed;
+pub mod unique_ids;
+pub mod validation;
+pub mod vertex_budget;
+pub mod vertices;
+pub mod web_mercator;
@@ -0,0 +1,104 @@
+use proj_exercise_simple::error::ProjectionError;
+use proj_exercise_simple::helpers::process_to_multiple;
+use serde_json::json;
+
+const WEB_MERCATOR: &str = "EPSG:3857";
+/// UTM zone 33N, which covers Berlin
+#[cfg(feature = "proj")]
+const UTM_33N: &str = "EPSG:32633";
+
+fn berlin() -> serde_json::Value {
+    json!({
+        "type": "FeatureCollection",
+        "features": [
+            {
+                "type": "Feature",
+                "geometry": { "type": "Point", "coordinates": [13.4, 52.5] },
+                "properties": { "name": "Berlin" }
+            },
+            {
+                "type": "Feature",
+                "geometry": {
+                    "type": "Polygon",
+                    "coordinates": [[[13.0, 52.0], [14.0, 52.0], [14.0, 53.0], [13.0, 53.0], [13.0, 52.0]]]
+                },
+                "properties": { "name": "Around Berlin" }
+            }
+        ]
+    })
+}
+
+#[cfg(feature = "proj")]
+fn first_point(geojson: &geojson::GeoJson) -> (f64, f64) {
+    match geojson {
+        geojson::GeoJson::FeatureCollection(collection) => {
+            match &collection.features[0].geometry.as_ref().unwrap().value {
+                geojson::Value::Point(p) => (p[0], p[1]),
+                other => panic!("expected a point, got {other:?}"),
+            }
+        }
+        other => panic!("expected a feature collection, got {other:?}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "proj")]
+    fn test_two_targets_give_different_valid_outputs() {
+        let outputs = process_to_multiple(
+            berlin(),
+            vec![WEB_MERCATOR.to_string(), UTM_33N.to_string()],
+        )
+        .unwrap();
+        assert_eq!(outputs.len(), 2);
+
+        let mercator = &outputs[WEB_MERCATOR];
+        let utm = &outputs[UTM_33N];
+        assert_ne!(mercator, utm);
+
+        let (x, y) = first_point(mercator);
+        assert!((x - 1_491_681.0).abs() < 1.0 && (y - 6_891_041.0).abs() < 1.0);
+        // West of the zone's central meridian at 15°, so below the 500 km false easting
+        let (x, y) = first_point(utm);
+        assert!((x - 391_390.7).abs() < 1.0 && (y - 5_817_855.2).abs() < 1.0);
+
+        for output in outputs.values() {
+            let geojson::GeoJson::FeatureCollection(collection) = output else {
+                panic!("expected a feature collection");
+            };
+            assert_eq!(collection.features.len(), 2);
+            assert_eq!(
+                collection.features[1].property("name"),
+                Some(&json!("Around Berlin"))
+            );
+            // The output is valid GeoJSON
+            assert!(output.to_string().parse::<geojson::GeoJson>().is_ok());
+            match &collection.features[1].geometry.as_ref().unwrap().value {
+                geojson::Value::Polygon(rings) => {
+                    assert_eq!(rings[0].len(), 5);
+                    assert_eq!(rings[0].first(), rings[0].last());
+                }
+                other => panic!("expected a polygon, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_duplicate_targets_are_projected_once() {
+        let outputs = process_to_multiple(
+            berlin(),
+            vec![WEB_MERCATOR.to_string(), WEB_MERCATOR.to_string()],
+        )
+        .unwrap();
+        assert_eq!(outputs.len(), 1);
+    }
+
+    #[test]
+    fn test_invalid_target_is_an_error() {
+        let result = process_to_multiple(berlin(), vec!["EPSG:not-a-code".to_string()]);
+        assert!(matches!(result, Err(ProjectionError::TransformerError(_))));
+    }
+}
@@ -0,0 +1,52 @@
+use geojson::GeoJson;
+use proj_exercise_simple::helpers::process_feature_collection;
+use proj_exercise_simple::io::read_geojson_file;
+use proj_exercise_simple::transformer::TransformerConfig;
+use std::path::Path;
+
+fn countries() -> serde_json::Value {
+    let path =
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/data/ne_110m_admin_0_countries.geojson");
+    read_geojson_file(path).unwrap()
+}
+
+/// Collect every ordinate of a GeoJSON coordinates array, non-numbers become NaN
+fn ordinates(coordinates: &serde_json::Value, output: &mut Vec<f64>) {
+    match coordinates {
+        serde_json::Value::Array(values) => values.iter().for_each(|v| ordinates(v, output)),
+        other => output.push(other.as_f64().unwrap_or(f64::NAN)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_natural_earth_countries_project_to_web_mercator() {
+        let input = countries();
+        let input_count = input["features"].as_array().unwrap().len();
+        assert_eq!(input_count, 180);
+
+        let output = process_feature_collection(input, &mut TransformerConfig::default()).unwrap();
+
+        // The output survives a serialization round trip through the geojson crate
+        let reparsed: GeoJson = output.to_string().parse().unwrap();
+        let GeoJson::FeatureCollection(collection) = reparsed else {
+            panic!("Expected a feature collection");
+        };
+        assert_eq!(collection.features.len(), input_count);
+
+        for feature in &collection.features {
+            let geometry = serde_json::to_value(feature.geometry.as_ref().unwrap()).unwrap();
+            let mut values = Vec::new();
+            ordinates(&geometry["coordinates"], &mut values);
+            assert!(!values.is_empty());
+            assert!(
+                values.iter().all(|v| v.is_finite()),
+                "Non-finite coordinate in {:?}",
+                feature.property("name")
+            );
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use proj_exercise_simple::error::ProjectionError;
+use proj_exercise_simple::ndjson::NdjsonJob;
+use proj_exercise_simple::options::{CheckpointConfig, ProcessingOptions};
+use proj_exercise_simple::transformer::TransformerConfig;
+use serde_json::json;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A fresh directory for the files of one test
+fn test_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("ndjson_{}_{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Write a line-delimited input of point features, with a blank line in the middle
+fn write_input(dir: &Path, count: usize) -> PathBuf {
+    let mut text = String::new();
+    for i in 0..count {
+        let feature = json!({
+            "type": "Feature",
+            "geometry": { "type": "Point", "coordinates": [i as f64 * 0.5, i as f64 * 0.25] },
+            "properties": { "index": i }
+        });
+        text.push_str(&feature.to_string());
+        text.push('\n');
+        if i == count / 2 {
+            text.push('\n');
+        }
+    }
+    let path = dir.join("input.geojsonl");
+    fs::write(&path, text).unwrap();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_each_input_line_becomes_one_output_line() {
+        let dir = test_dir("lines");
+        let input = write_input(&dir, 7);
+        let output = dir.join("output.geojsonl");
+        let stats = NdjsonJob::new(&input, &output)
+            .run(&mut TransformerConfig::default(), &ProcessingOptions::new())
+            .unwrap();
+        assert_eq!(stats.features_read, 7);
+        assert_eq!(stats.features_written, 7);
+
+        let text = fs::read_to_string(&output).unwrap();
+        let first: serde_json::Value = serde_json::from_str(text.lines().next().unwrap()).unwrap();
+        assert_eq!(first["properties"]["index"], 0);
+        assert_eq!(text.lines().count(), 7);
+    }
+
+    #[test]
+    fn test_resumed_job_matches_uninterrupted_run() {
+        let dir = test_dir("resume");
+        let input = write_input(&dir, 25);
+        let expected_path = dir.join("expected.geojsonl");
+        let expected_stats = NdjsonJob::new(&input, &expected_path)
+            .run(&mut TransformerConfig::default(), &ProcessingOptions::new())
+            .unwrap();
+
+        let output = dir.join("output.geojsonl");
+        let checkpoint = dir.join("job.checkpoint");
+        let options =
+            ProcessingOptions::new().with_checkpoint(CheckpointConfig::new(&checkpoint, 5));
+        // Stops after 12 features, the last checkpoint was written after 10
+        NdjsonJob::new(&input, &output)
+            .with_interruption_after(12)
+            .run(&mut TransformerConfig::default(), &options)
+            .unwrap();
+        assert_eq!(fs::read_to_string(&output).unwrap().lines().count(), 12);
+
+        let mut job = NdjsonJob::resume_from_checkpoint(&checkpoint).unwrap();
+        assert_eq!(job.stats().features_read, 10);
+        let stats = job
+            .run(&mut TransformerConfig::default(), &options)
+            .unwrap();
+
+        assert_eq!(stats, expected_stats);
+        assert_eq!(
+            fs::read_to_string(&output).unwrap(),
+            fs::read_to_string(&expected_path).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_resume_rejects_modified_output() {
+        let dir = test_dir("modified");
+        let input = write_input(&dir, 10);
+        let output = dir.join("output.geojsonl");
+        let checkpoint = dir.join("job.checkpoint");
+        let options =
+            ProcessingOptions::new().with_checkpoint(CheckpointConfig::new(&checkpoint, 3));
+        NdjsonJob::new(&input, &output)
+            .with_interruption_after(4)
+            .run(&mut TransformerConfig::default(), &options)
+            .unwrap();
+
+        let text = fs::read_to_string(&output).unwrap();
+        fs::write(&output, text.replace("\"index\":2", "\"index\":9")).unwrap();
+
+        let result = NdjsonJob::resume_from_checkpoint(&checkpoint)
+            .unwrap()
+            .run(&mut TransformerConfig::default(), &options);
+        assert!(matches!(result, Err(ProjectionError::InvalidCheckpoint(_))));
+    }
+}
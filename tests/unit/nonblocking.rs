@@ -0,0 +1,142 @@
+use crate::fixtures::GeometryFixtures;
+use proj_exercise_simple::error::ProjectionError;
+use proj_exercise_simple::helpers::process_feature_collection;
+use proj_exercise_simple::kind::GeometryKind;
+use proj_exercise_simple::ndjson::NdjsonJob;
+use proj_exercise_simple::nonblocking::{process_feature_collection_async, process_ndjson_async};
+use proj_exercise_simple::options::{CancellationToken, ProcessingOptions};
+use proj_exercise_simple::testing::random_feature_collection;
+use proj_exercise_simple::transformer::TransformerConfig;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+fn large_collection() -> serde_json::Value {
+    let collection = random_feature_collection(7, &[(GeometryKind::LineString, 20_000)]);
+    serde_json::to_value(collection).unwrap()
+}
+
+fn ndjson_input() -> String {
+    let collection = random_feature_collection(3, &[(GeometryKind::Polygon, 20)]);
+    collection
+        .features
+        .iter()
+        .map(|feature| serde_json::to_string(feature).unwrap() + "\n\n")
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_async_matches_sync_output() {
+        let expected = process_feature_collection(
+            GeometryFixtures::berlin_feature_collection(),
+            &mut TransformerConfig::default(),
+        )
+        .unwrap();
+        let output = process_feature_collection_async(
+            GeometryFixtures::berlin_feature_collection(),
+            TransformerConfig::default(),
+            ProcessingOptions::new(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(output.geojson, expected);
+    }
+
+    #[tokio::test]
+    async fn test_dropping_task_cancels_job() {
+        let config = TransformerConfig::default();
+        let task = process_feature_collection_async(
+            large_collection(),
+            config.clone(),
+            ProcessingOptions::new(),
+        );
+        // Let the job start on the blocking pool
+        let started = Instant::now();
+        while config.stats().transformations == 0 {
+            assert!(started.elapsed() < Duration::from_secs(10));
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+        drop(task);
+
+        // The job stops within one batch of coordinates
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let after_drop = config.stats().transformations;
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert_eq!(config.stats().transformations, after_drop);
+
+        let total = TransformerConfig::default();
+        process_feature_collection(large_collection(), &mut total.clone()).unwrap();
+        assert!(after_drop < total.stats().transformations);
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_token_fails_job() {
+        let token = CancellationToken::new();
+        token.cancel();
+        let output = process_feature_collection_async(
+            GeometryFixtures::berlin_feature_collection(),
+            TransformerConfig::default(),
+            ProcessingOptions::new().with_cancellation(token),
+        )
+        .await;
+        assert!(matches!(output, Err(ProjectionError::Cancelled)));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_long_job_does_not_block_runtime_thread() {
+        let ticks = Arc::new(AtomicUsize::new(0));
+        let watchdog_ticks = Arc::clone(&ticks);
+        let watchdog = tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_millis(1));
+            loop {
+                interval.tick().await;
+                watchdog_ticks.fetch_add(1, Ordering::Relaxed);
+            }
+        });
+
+        let started = Instant::now();
+        let output = process_feature_collection_async(
+            large_collection(),
+            TransformerConfig::default(),
+            ProcessingOptions::new(),
+        )
+        .await;
+        let elapsed = started.elapsed();
+        watchdog.abort();
+
+        assert!(output.is_ok());
+        assert!(elapsed > Duration::from_millis(20), "job took {elapsed:?}");
+        // The only runtime thread kept running the watchdog during the job
+        assert!(ticks.load(Ordering::Relaxed) >= 10);
+    }
+
+    #[tokio::test]
+    async fn test_ndjson_stream_matches_sync_job() {
+        let input = ndjson_input();
+        let dir = std::env::temp_dir();
+        let input_path = dir.join("nonblocking_ndjson_input.geojsonl");
+        let output_path = dir.join("nonblocking_ndjson_output.geojsonl");
+        std::fs::write(&input_path, &input).unwrap();
+        let expected_stats = NdjsonJob::new(&input_path, &output_path)
+            .run(&mut TransformerConfig::default(), &ProcessingOptions::new())
+            .unwrap();
+        let expected = std::fs::read(&output_path).unwrap();
+
+        let mut output = Vec::new();
+        let stats = process_ndjson_async(
+            input.as_bytes(),
+            &mut output,
+            TransformerConfig::default(),
+            ProcessingOptions::new(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(stats, expected_stats);
+        assert_eq!(stats.features_read, 20);
+        assert_eq!(output, expected);
+    }
+}
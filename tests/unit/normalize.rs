@@ -0,0 +1,111 @@
+use geo::{coord, BoundingRect, Rect};
+use geojson::{Feature, GeoJson, Geometry, Value};
+use proj_exercise_simple::helpers::process_feature_collection_with_options;
+use proj_exercise_simple::normalize::{
+    normalize_geojson_to_extent, normalize_to_extent, FitToExtent,
+};
+use proj_exercise_simple::options::ProcessingOptions;
+use proj_exercise_simple::transformer::TransformerConfig;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target() -> Rect<f64> {
+        Rect::new(coord! { x: 0.0, y: 0.0 }, coord! { x: 100.0, y: 100.0 })
+    }
+
+    fn collection() -> geo::Geometry<f64> {
+        geo::Geometry::GeometryCollection(geo::GeometryCollection::new_from(vec![
+            geo::Point::new(0.0, 0.0).into(),
+            geo::LineString::from(vec![(2.0, 5.0), (10.0, 20.0)]).into(),
+        ]))
+    }
+
+    #[test]
+    fn test_fit_preserving_aspect_centers_horizontally() {
+        let (fitted, transform) = normalize_to_extent(&collection(), target(), true);
+        let bounds = fitted.bounding_rect().unwrap();
+        assert_eq!(bounds.min(), coord! { x: 25.0, y: 0.0 });
+        assert_eq!(bounds.max(), coord! { x: 75.0, y: 100.0 });
+
+        // The returned transform maps other data consistently
+        assert_eq!(
+            transform.apply(coord! { x: 5.0, y: 10.0 }),
+            coord! { x: 50.0, y: 50.0 }
+        );
+    }
+
+    #[test]
+    fn test_fit_without_aspect_fills_target() {
+        let (fitted, _) = normalize_to_extent(&collection(), target(), false);
+        let bounds = fitted.bounding_rect().unwrap();
+        assert_eq!(bounds.min(), coord! { x: 0.0, y: 0.0 });
+        assert_eq!(bounds.max(), coord! { x: 100.0, y: 100.0 });
+    }
+
+    #[test]
+    fn test_degenerate_bounds_do_not_divide_by_zero() {
+        let point: geo::Geometry<f64> = geo::Point::new(3.0, 4.0).into();
+        for preserve_aspect in [true, false] {
+            let (fitted, _) = normalize_to_extent(&point, target(), preserve_aspect);
+            assert_eq!(fitted, geo::Point::new(50.0, 50.0).into());
+        }
+
+        // A vertical line fills the height and is centered horizontally
+        let vertical: geo::Geometry<f64> =
+            geo::LineString::from(vec![(7.0, 0.0), (7.0, 2.0)]).into();
+        let (fitted, _) = normalize_to_extent(&vertical, target(), false);
+        let bounds = fitted.bounding_rect().unwrap();
+        assert_eq!(bounds.min(), coord! { x: 50.0, y: 0.0 });
+        assert_eq!(bounds.max(), coord! { x: 50.0, y: 100.0 });
+    }
+
+    #[test]
+    fn test_fit_geojson_uses_combined_bounds() {
+        let mut feature = Feature::from(Geometry::new(Value::Point(vec![10.0, 20.0])));
+        feature.set_property("name", "corner");
+        let mut geojson = GeoJson::from(geojson::FeatureCollection {
+            bbox: None,
+            features: vec![
+                Feature::from(Geometry::new(Value::Point(vec![0.0, 0.0]))),
+                feature,
+            ],
+            foreign_members: None,
+        });
+
+        normalize_geojson_to_extent(&mut geojson, &FitToExtent::new(target(), true)).unwrap();
+        let GeoJson::FeatureCollection(collection) = geojson else {
+            panic!("Expected a feature collection");
+        };
+        let corner = &collection.features[1];
+        assert_eq!(
+            corner.geometry.as_ref().unwrap().value,
+            Value::Point(vec![75.0, 100.0])
+        );
+        assert_eq!(corner.property("name").unwrap(), "corner");
+    }
+
+    #[test]
+    fn test_pipeline_fits_projected_output() {
+        let json_value =
+            serde_json::to_value(GeoJson::from(Geometry::new(Value::LineString(vec![
+                vec![1.0, 1.0],
+                vec![2.0, 3.0],
+            ]))))
+            .unwrap();
+        let mut config = TransformerConfig::default();
+        let options = ProcessingOptions::new().with_fit_to_extent(target(), false);
+        let output =
+            process_feature_collection_with_options(json_value, &mut config, &options).unwrap();
+        assert!(output.transform.is_some());
+
+        let GeoJson::Geometry(geometry) = output.geojson else {
+            panic!("Expected a geometry");
+        };
+        let line = geo::Geometry::<f64>::try_from(geometry.value).unwrap();
+        let bounds = line.bounding_rect().unwrap();
+        assert!((bounds.min().x - 0.0).abs() < 1e-9);
+        assert!((bounds.max().y - 100.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,98 @@
+use geojson::{GeoJson, Geometry, Value};
+use proj_exercise_simple::coordinates::{
+    sanitize_position, CoordinateBounds, DEFAULT_MAX_MAGNITUDE,
+};
+use proj_exercise_simple::error::ProjectionError;
+use proj_exercise_simple::helpers::{
+    process_feature_collection, process_feature_collection_in_place,
+    process_feature_collection_with_options,
+};
+use proj_exercise_simple::options::ProcessingOptions;
+use proj_exercise_simple::transformer::TransformerConfig;
+use serde_json::json;
+
+/// A config with a projected source, which is not limited to the WGS84 range
+fn mercator_to_wgs84() -> TransformerConfig {
+    TransformerConfig::new("EPSG:3857".to_string(), "EPSG:4326".to_string()).unwrap()
+}
+
+fn line(coordinates: serde_json::Value) -> serde_json::Value {
+    json!({
+        "type": "Feature",
+        "geometry": { "type": "LineString", "coordinates": coordinates },
+        "properties": {}
+    })
+}
+
+fn assert_invalid_coordinates<T: std::fmt::Debug>(result: Result<T, ProjectionError>) {
+    match result {
+        Err(ProjectionError::InvalidCoordinates(_)) => {}
+        other => panic!("expected invalid coordinates, got {other:?}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_position() {
+        assert_eq!(
+            sanitize_position(&[-1e15, 1e15], DEFAULT_MAX_MAGNITUDE).unwrap(),
+            (-1e15, 1e15)
+        );
+        assert_invalid_coordinates(sanitize_position(&[1e308, 0.0], DEFAULT_MAX_MAGNITUDE));
+        assert_invalid_coordinates(sanitize_position(&[0.0, -1e308], DEFAULT_MAX_MAGNITUDE));
+        assert_invalid_coordinates(sanitize_position(&[f64::NAN, 0.0], f64::INFINITY));
+        assert_invalid_coordinates(sanitize_position(&[0.0, f64::INFINITY], f64::INFINITY));
+        assert_invalid_coordinates(sanitize_position(&[0.0], DEFAULT_MAX_MAGNITUDE));
+        // Subnormal values are finite and small, so they pass
+        assert!(sanitize_position(&[f64::MIN_POSITIVE / 2.0, 0.0], DEFAULT_MAX_MAGNITUDE).is_ok());
+    }
+
+    #[test]
+    fn test_unbounded_range_rejects_huge_values() {
+        let bounds = CoordinateBounds::unbounded();
+        assert!(bounds.validate(1e14, -1e14).is_ok());
+        assert_invalid_coordinates(bounds.validate(1e308, 0.0));
+        assert_invalid_coordinates(bounds.validate(f64::NEG_INFINITY, 0.0));
+        assert!(bounds.with_max_magnitude(1e20).validate(1e18, 0.0).is_ok());
+    }
+
+    #[test]
+    fn test_huge_coordinates_error_instead_of_overflowing() {
+        let input = line(json!([[0.0, 0.0], [1e308, 1e308]]));
+        assert_invalid_coordinates(process_feature_collection(
+            input.clone(),
+            &mut mercator_to_wgs84(),
+        ));
+
+        // Subnormal values are harmless
+        let tiny = line(json!([[0.0, 0.0], [f64::MIN_POSITIVE / 2.0, 1.0]]));
+        assert!(process_feature_collection_with_options(
+            tiny,
+            &mut mercator_to_wgs84(),
+            &ProcessingOptions::new(),
+        )
+        .is_ok());
+        assert_invalid_coordinates(process_feature_collection_with_options(
+            input,
+            &mut mercator_to_wgs84(),
+            &ProcessingOptions::new(),
+        ));
+    }
+
+    #[test]
+    fn test_in_place_path_rejects_non_finite_coordinates() {
+        for value in [1e308, f64::INFINITY, f64::NAN] {
+            let mut geojson = GeoJson::Geometry(Geometry::new(Value::LineString(vec![
+                vec![0.0, 0.0],
+                vec![value, 0.0],
+            ])));
+            assert_invalid_coordinates(process_feature_collection_in_place(
+                &mut geojson,
+                &mut mercator_to_wgs84(),
+            ));
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use proj_exercise_simple::error::ProjectionError;
+use proj_exercise_simple::helpers::{process_feature_collection_with_options, ProcessingOutput};
+use proj_exercise_simple::ndjson::NdjsonJob;
+use proj_exercise_simple::options::ProcessingOptions;
+use proj_exercise_simple::order::SortKey;
+use proj_exercise_simple::transformer::TransformerConfig;
+use serde_json::json;
+
+fn point(id: serde_json::Value, x: f64, rank: i64) -> serde_json::Value {
+    let mut feature = json!({
+        "type": "Feature",
+        "geometry": { "type": "Point", "coordinates": [x, 0.0] },
+        "properties": { "rank": rank }
+    });
+    if !id.is_null() {
+        feature["id"] = id;
+    }
+    feature
+}
+
+/// Shuffled features, with "b" appearing twice with the same geometry and properties
+fn shuffled() -> serde_json::Value {
+    json!({
+        "type": "FeatureCollection",
+        "features": [
+            point(json!("c"), 3.0, 1),
+            point(json!("b"), 2.0, 2),
+            point(json!("a"), 1.0, 3),
+            point(json!("b"), 2.0, 2),
+        ]
+    })
+}
+
+fn run(input: serde_json::Value, options: &ProcessingOptions) -> ProcessingOutput {
+    process_feature_collection_with_options(input, &mut TransformerConfig::default(), options)
+        .unwrap()
+}
+
+fn ids(output: &ProcessingOutput) -> Vec<String> {
+    match &output.geojson {
+        geojson::GeoJson::FeatureCollection(collection) => collection
+            .features
+            .iter()
+            .map(|feature| match &feature.id {
+                Some(geojson::feature::Id::String(id)) => id.clone(),
+                Some(geojson::feature::Id::Number(id)) => id.to_string(),
+                None => "-".to_string(),
+            })
+            .collect(),
+        other => panic!("expected a feature collection, got {other:?}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dedupe_and_sort_by_id() {
+        let options = ProcessingOptions::new()
+            .with_dedupe(true)
+            .with_sort_by(SortKey::Id);
+        let output = run(shuffled(), &options);
+        assert_eq!(ids(&output), vec!["a", "b", "c"]);
+        assert_eq!(output.report.duplicates_removed, 1);
+    }
+
+    #[test]
+    fn test_dedupe_keeps_features_differing_in_properties() {
+        let input = json!({
+            "type": "FeatureCollection",
+            "features": [point(json!(1), 2.0, 1), point(json!(2), 2.0, 2), point(json!(3), 2.0, 1)]
+        });
+        let output = run(input, &ProcessingOptions::new().with_dedupe(true));
+        assert_eq!(ids(&output), vec!["1", "2"]);
+        assert_eq!(output.report.duplicates_removed, 1);
+
+        let output = run(shuffled(), &ProcessingOptions::new());
+        assert_eq!(ids(&output), vec!["c", "b", "a", "b"]);
+        assert_eq!(output.report.duplicates_removed, 0);
+    }
+
+    #[test]
+    fn test_sort_by_property_and_mixed_ids() {
+        let output = run(
+            shuffled(),
+            &ProcessingOptions::new().with_sort_by(SortKey::Property("rank".to_string())),
+        );
+        assert_eq!(ids(&output), vec!["c", "b", "b", "a"]);
+
+        let input = json!({
+            "type": "FeatureCollection",
+            "features": [
+                point(json!("x"), 0.0, 0),
+                point(serde_json::Value::Null, 0.0, 0),
+                point(json!(10), 0.0, 0),
+                point(json!(9), 0.0, 0),
+            ]
+        });
+        let output = run(input, &ProcessingOptions::new().with_sort_by(SortKey::Id));
+        assert_eq!(ids(&output), vec!["9", "10", "x", "-"]);
+    }
+
+    #[test]
+    fn test_sort_by_geometry_hash_is_independent_of_input_order() {
+        let options = ProcessingOptions::new().with_sort_by(SortKey::GeometryHash);
+        let forward = run(shuffled(), &options);
+        let mut reversed = shuffled();
+        reversed["features"].as_array_mut().unwrap().reverse();
+        let reversed = run(reversed, &options);
+        assert_eq!(ids(&forward), ids(&reversed));
+    }
+
+    #[test]
+    fn test_ndjson_rejects_sorting() {
+        let dir = std::env::temp_dir();
+        let input = dir.join("order_sort_input.geojsonl");
+        let output = dir.join("order_sort_output.geojsonl");
+        std::fs::write(&input, "{\"type\":\"Point\",\"coordinates\":[1.0,2.0]}\n").unwrap();
+
+        let result = NdjsonJob::new(&input, &output).run(
+            &mut TransformerConfig::default(),
+            &ProcessingOptions::new().with_sort_by(SortKey::Id),
+        );
+        match result {
+            Err(ProjectionError::IncompatibleOptions(message)) => {
+                assert!(message.contains("sort_by"))
+            }
+            other => panic!("expected incompatible options, got {other:?}"),
+        }
+    }
+}
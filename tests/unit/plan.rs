@@ -0,0 +1,82 @@
+use geojson::GeoJson;
+use proj_exercise_simple::coordinates::CoordinateBounds;
+use proj_exercise_simple::explode::ExplodePolicy;
+use proj_exercise_simple::helpers::process_feature_collection_with_options;
+use proj_exercise_simple::options::{NullGeometryPolicy, ProcessingOptions};
+use proj_exercise_simple::transformer::TransformerConfig;
+use serde_json::json;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collection(last_latitude: f64) -> serde_json::Value {
+        json!({
+            "type": "FeatureCollection",
+            "features": [
+                {
+                    "type": "Feature",
+                    "properties": { "name": "Museumsinsel" },
+                    "geometry": {
+                        "type": "MultiPoint",
+                        "coordinates": [[13.397, 52.520], [13.399, 52.521]]
+                    }
+                },
+                { "type": "Feature", "properties": { "name": "census" }, "geometry": null },
+                {
+                    "type": "Feature",
+                    "properties": { "name": "Fernsehturm" },
+                    "geometry": { "type": "Point", "coordinates": [13.409, last_latitude] }
+                }
+            ]
+        })
+    }
+
+    fn options() -> ProcessingOptions {
+        ProcessingOptions::new()
+            .with_explode(ExplodePolicy::All)
+            .with_null_geometry(NullGeometryPolicy::Skip)
+    }
+
+    #[test]
+    fn test_dry_run_reports_the_same_error_as_a_real_run() {
+        let mut config = TransformerConfig::default();
+        let real =
+            process_feature_collection_with_options(collection(95.0), &mut config, &options())
+                .unwrap_err();
+        let dry = process_feature_collection_with_options(
+            collection(95.0),
+            &mut config,
+            &options().with_dry_run(true),
+        )
+        .unwrap_err();
+        assert_eq!(dry.to_string(), real.to_string());
+    }
+
+    #[test]
+    fn test_dry_run_plans_without_projecting() {
+        let mut config = TransformerConfig::default();
+        let dry_options = options()
+            .with_coordinate_bounds(CoordinateBounds::wgs84())
+            .with_dry_run(true);
+        let dry =
+            process_feature_collection_with_options(collection(52.520), &mut config, &dry_options)
+                .unwrap();
+
+        // No transformer was needed, so no coordinate was transformed
+        assert!(!config.is_transformer_available().unwrap());
+        let GeoJson::FeatureCollection(output) = dry.geojson else {
+            panic!("Expected a FeatureCollection");
+        };
+        assert!(output.features.is_empty());
+        assert_eq!(dry.report.planned.len(), 1);
+        assert_eq!(dry.report.planned[0].feature_index, 0);
+        assert!(dry.report.planned[0].description.contains("split into 2"));
+
+        let real =
+            process_feature_collection_with_options(collection(52.520), &mut config, &options())
+                .unwrap();
+        assert_eq!(dry.report.warnings, real.report.warnings);
+        assert!(real.report.planned.is_empty());
+    }
+}
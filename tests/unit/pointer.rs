@@ -0,0 +1,67 @@
+use proj_exercise_simple::error::ProjectionError;
+use proj_exercise_simple::helpers::{process_at_pointer, process_feature_collection};
+use proj_exercise_simple::transformer::TransformerConfig;
+use serde_json::json;
+
+fn geo() -> serde_json::Value {
+    json!({
+        "type": "FeatureCollection",
+        "features": [{
+            "type": "Feature",
+            "geometry": { "type": "Point", "coordinates": [1.0, 2.0] },
+            "properties": { "name": "stop" }
+        }]
+    })
+}
+
+fn envelope() -> serde_json::Value {
+    json!({
+        "version": 2,
+        "meta": { "center": [1.0, 2.0] },
+        "data": { "geo": geo(), "count": 1 }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_the_pointed_subtree_is_projected() {
+        let doc = envelope();
+        let output =
+            process_at_pointer(&doc, "/data/geo", &mut TransformerConfig::default()).unwrap();
+
+        let expected =
+            process_feature_collection(geo(), &mut TransformerConfig::default()).unwrap();
+        assert_eq!(output["data"]["geo"], serde_json::Value::from(expected));
+
+        // Everything around the GeoJSON is unchanged, including coordinate-like arrays
+        let mut rest = output.clone();
+        rest["data"]["geo"] = geo();
+        assert_eq!(rest, doc);
+    }
+
+    #[test]
+    fn test_missing_pointer_is_an_error() {
+        let result = process_at_pointer(
+            &envelope(),
+            "/data/missing",
+            &mut TransformerConfig::default(),
+        );
+        assert!(matches!(
+            result,
+            Err(ProjectionError::PointerNotFound(pointer)) if pointer == "/data/missing"
+        ));
+    }
+
+    #[test]
+    fn test_pointer_to_non_geojson_is_an_error() {
+        let result = process_at_pointer(
+            &envelope(),
+            "/data/count",
+            &mut TransformerConfig::default(),
+        );
+        assert!(matches!(result, Err(ProjectionError::GeoJsonError(_))));
+    }
+}
@@ -0,0 +1,213 @@
+use proj_exercise_simple::error::ProjectionError;
+use proj_exercise_simple::helpers::process_feature_collection_with_options;
+use proj_exercise_simple::options::{ErrorPolicy, ProcessingOptions};
+use proj_exercise_simple::transformer::TransformerConfig;
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+
+const OFFSET: f64 = 1000.0;
+
+fn collection() -> serde_json::Value {
+    json!({
+        "type": "FeatureCollection",
+        "features": [
+            {
+                "type": "Feature",
+                "id": "a",
+                "geometry": { "type": "Point", "coordinates": [1.0, 2.0] },
+                "properties": { "name": "first" }
+            },
+            {
+                "type": "Feature",
+                "id": "b",
+                "geometry": { "type": "LineString", "coordinates": [[0.0, 0.0], [1.0, 2.0]] },
+                "properties": { "name": "second" }
+            }
+        ]
+    })
+}
+
+fn offset_options() -> ProcessingOptions {
+    ProcessingOptions::new().with_post_transform_hook(|geometry, _| {
+        geometry.map_coords_in_place(|c| geo::Coord {
+            x: c.x + OFFSET,
+            y: c.y - OFFSET,
+        });
+        Ok(())
+    })
+}
+
+fn features(geojson: geojson::GeoJson) -> Vec<geojson::Feature> {
+    match geojson {
+        geojson::GeoJson::FeatureCollection(collection) => collection.features,
+        other => panic!("expected a feature collection, got {other:?}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hook_translates_every_geometry() {
+        let mut config = TransformerConfig::default();
+        let plain = features(
+            process_feature_collection_with_options(
+                collection(),
+                &mut config,
+                &ProcessingOptions::new(),
+            )
+            .unwrap()
+            .geojson,
+        );
+        let shifted = features(
+            process_feature_collection_with_options(collection(), &mut config, &offset_options())
+                .unwrap()
+                .geojson,
+        );
+
+        let coords = |feature: &geojson::Feature| {
+            let geometry: geo::Geometry<f64> =
+                feature.geometry.clone().unwrap().value.try_into().unwrap();
+            geo::CoordsIter::coords_iter(&geometry).collect::<Vec<_>>()
+        };
+        for (plain, shifted) in plain.iter().zip(&shifted) {
+            for (a, b) in coords(plain).iter().zip(coords(shifted)) {
+                assert!((b.x - a.x - OFFSET).abs() < 1e-6);
+                assert!((a.y - b.y - OFFSET).abs() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hook_sees_feature_context() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&seen);
+        let options = ProcessingOptions::new().with_post_transform_hook(move |_, context| {
+            let name = context
+                .properties
+                .and_then(|p| p["name"].as_str())
+                .map(String::from);
+            let id = context.id.cloned();
+            recorded
+                .lock()
+                .unwrap()
+                .push((context.feature_index, id, name));
+            Ok(())
+        });
+
+        process_feature_collection_with_options(
+            collection(),
+            &mut TransformerConfig::default(),
+            &options,
+        )
+        .unwrap();
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 2);
+        assert_eq!(seen[0].0, 0);
+        assert_eq!(
+            seen[0].1,
+            Some(geojson::feature::Id::String("a".to_string()))
+        );
+        assert_eq!(seen[0].2.as_deref(), Some("first"));
+        assert_eq!(seen[1].0, 1);
+        assert_eq!(seen[1].2.as_deref(), Some("second"));
+    }
+
+    #[test]
+    fn test_hook_error_aborts_processing() {
+        let options = ProcessingOptions::new().with_post_transform_hook(|_, context| {
+            if context.feature_index == 1 {
+                return Err("rejected".into());
+            }
+            Ok(())
+        });
+
+        let result = process_feature_collection_with_options(
+            collection(),
+            &mut TransformerConfig::default(),
+            &options,
+        );
+
+        match result {
+            Err(ProjectionError::PostTransformHook {
+                feature_index,
+                source,
+            }) => {
+                assert_eq!(feature_index, 1);
+                assert_eq!(source.to_string(), "rejected");
+            }
+            other => panic!("expected a hook error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_lenient_policy_drops_features_whose_hook_fails() {
+        let options = ProcessingOptions::new()
+            .with_error_policy(ErrorPolicy::Lenient)
+            .with_post_transform_hook(|_, context| {
+                if context.feature_index == 1 {
+                    return Err("rejected".into());
+                }
+                Ok(())
+            });
+
+        let output = process_feature_collection_with_options(
+            collection(),
+            &mut TransformerConfig::default(),
+            &options,
+        )
+        .unwrap();
+
+        let kept = features(output.geojson);
+        assert_eq!(kept.len(), 1);
+        assert_eq!(
+            kept[0].id,
+            Some(geojson::feature::Id::String("a".to_string()))
+        );
+        assert_eq!(output.report.warnings.len(), 1);
+        assert_eq!(output.report.warnings[0].feature_index, 1);
+        assert_eq!(
+            output.report.warnings[0].message,
+            "feature was skipped: Post-transform hook failed for feature 1: rejected"
+        );
+    }
+
+    #[test]
+    fn test_hook_runs_before_simplification_and_rounding() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&seen);
+        let options = ProcessingOptions::new().with_post_transform_hook(move |geometry, _| {
+            geometry.map_coords_in_place(|c| {
+                recorded.lock().unwrap().push(c);
+                c
+            });
+            Ok(())
+        });
+        // The middle vertex lies within a few meters of the line between its neighbours
+        let input = json!({
+            "type": "LineString",
+            "coordinates": [[0.0, 0.0], [0.5, 0.5], [1.0, 1.0]]
+        });
+        let mut config = TransformerConfig::default()
+            .with_simplification(100.0)
+            .with_precision(0);
+
+        let output = process_feature_collection_with_options(input, &mut config, &options).unwrap();
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 3);
+        assert!(seen[1].x.fract() != 0.0);
+        let geojson::GeoJson::Geometry(geometry) = output.geojson else {
+            panic!("expected a geometry");
+        };
+        match geometry.value {
+            geojson::Value::LineString(positions) => {
+                assert_eq!(positions.len(), 2);
+                assert_eq!(positions[1][0].fract(), 0.0);
+            }
+            other => panic!("expected a line string, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use proj_exercise_simple::{
+    process_feature_collection, Coordinate, Line, Polygon, ProcessedGeometry, ProjectionError,
+    ProjectionResult, TransformerConfig,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flat_imports_match_module_paths() {
+        let coordinate: proj_exercise_simple::coordinates::Coordinate = Coordinate::new(1.0, 2.0);
+        let line = Line::new(vec![coordinate, Coordinate::new(2.0, 3.0)]);
+        let polygon = Polygon::new(line.clone(), vec![]);
+        assert_eq!(polygon.exterior.coordinates.len(), 2);
+
+        let point = ProcessedGeometry::Point(geo::Point::new(1.0, 2.0));
+        assert!(matches!(
+            point.to_geojson_value(),
+            geojson::Value::Point(ref p) if p == &vec![1.0, 2.0]
+        ));
+
+        let result: Result<geojson::GeoJson, ProjectionError> = process_feature_collection(
+            serde_json::json!({ "type": "Point", "coordinates": [1.0, 2.0] }),
+            &mut TransformerConfig::default(),
+        );
+        assert!(result.is_ok());
+
+        // The alias names the same type as the spelled out result
+        let aliased: ProjectionResult = result;
+        let _: proj_exercise_simple::helpers::ProjectionResult = aliased;
+    }
+}
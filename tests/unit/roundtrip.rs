@@ -0,0 +1,90 @@
+use crate::fixtures::GeometryFixtures;
+use geojson::GeoJson;
+use proj_exercise_simple::roundtrip::{
+    max_roundtrip_deviation, measure_roundtrip_error, verify_roundtrip,
+};
+use proj_exercise_simple::transformer::TransformerConfig;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn berlin() -> GeoJson {
+        GeoJson::from_json_value(GeometryFixtures::berlin_feature_collection()).unwrap()
+    }
+
+    #[test]
+    fn test_roundtrip_error_is_sub_millimeter() {
+        let config = TransformerConfig::default();
+        let report = measure_roundtrip_error(&berlin(), &config).unwrap();
+
+        assert_eq!(report.overall.vertex_count, 9);
+        assert_eq!(report.features.len(), 3);
+        assert_eq!(report.features[2].error.vertex_count, 5);
+        // One millimeter is roughly 1e-8 degrees
+        assert!(report.overall.max < 1e-8);
+        assert!(report.overall.mean <= report.overall.max);
+        assert!(report.overall.p50 <= report.overall.p95);
+        assert!(report.overall.p95 <= report.overall.p99);
+    }
+
+    #[test]
+    fn test_roundtrip_error_grows_with_truncated_precision() {
+        let full = measure_roundtrip_error(&berlin(), &TransformerConfig::default()).unwrap();
+        let truncated =
+            measure_roundtrip_error(&berlin(), &TransformerConfig::default().with_precision(3))
+                .unwrap();
+
+        assert!(truncated.overall.max > full.overall.max);
+        assert!(truncated.overall.max > 1e-10);
+        // Rounding to millimeters displaces a vertex by at most ~0.7 mm
+        assert!(truncated.overall.max < 1e-8);
+
+        let worst = truncated.worst_feature.unwrap();
+        assert_eq!(
+            truncated.worst_feature_id,
+            truncated.features[worst].id.clone()
+        );
+        assert_eq!(truncated.features[worst].error.max, truncated.overall.max);
+    }
+
+    #[test]
+    fn test_precision_rounds_processing_output() {
+        let mut config = TransformerConfig::default().with_precision(3);
+        let output = proj_exercise_simple::helpers::process_feature_collection(
+            GeometryFixtures::berlin_feature_collection(),
+            &mut config,
+        )
+        .unwrap();
+        let GeoJson::FeatureCollection(collection) = output else {
+            panic!("Expected a feature collection");
+        };
+        let geojson::Value::Point(point) = &collection.features[0].geometry.as_ref().unwrap().value
+        else {
+            panic!("Expected a point");
+        };
+        for value in point {
+            assert_eq!((value * 1000.0).round() / 1000.0, *value);
+        }
+    }
+
+    #[test]
+    fn test_verify_roundtrip_for_polygon() {
+        let polygon = geojson::Geometry::new(geojson::Value::Polygon(vec![vec![
+            vec![13.350, 52.515],
+            vec![13.355, 52.515],
+            vec![13.355, 52.510],
+            vec![13.350, 52.510],
+            vec![13.350, 52.515],
+        ]]));
+        let config = TransformerConfig::default();
+        assert!(verify_roundtrip(&polygon, &config, 1e-6).unwrap());
+        assert!(max_roundtrip_deviation(&polygon, &config).unwrap() < 1e-6);
+
+        // Rounding projected positions to whole meters moves them measurably
+        let coarse = TransformerConfig::default().with_precision(0);
+        let deviation = max_roundtrip_deviation(&polygon, &coarse).unwrap();
+        assert!(deviation > 0.0);
+        assert!(!verify_roundtrip(&polygon, &coarse, deviation / 2.0).unwrap());
+    }
+}
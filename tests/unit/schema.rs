@@ -0,0 +1,64 @@
+use crate::fixtures::GeometryFixtures;
+use geojson::GeoJson;
+use proj_exercise_simple::schema::{coerce_properties, infer_schema, PropertyType};
+use serde_json::json;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mixed_collection() -> GeoJson {
+        GeoJson::from_json_value(GeometryFixtures::mixed_property_collection()).unwrap()
+    }
+
+    #[test]
+    fn test_infer_schema_detects_conflict() {
+        let schema = infer_schema(&mixed_collection());
+        assert_eq!(schema.feature_count, 4);
+        assert_eq!(schema.conflicts(), vec!["floors"]);
+
+        let floors = &schema.properties["floors"];
+        assert_eq!(floors.types[&PropertyType::Number], 2);
+        assert_eq!(floors.types[&PropertyType::String], 2);
+        assert_eq!(floors.min, Some(2.0));
+        assert_eq!(floors.max, Some(4.0));
+
+        let listed = &schema.properties["listed"];
+        assert_eq!(listed.null_count, 1);
+        assert!(!listed.is_conflicting());
+        assert_eq!(listed.min, None);
+    }
+
+    #[test]
+    fn test_coerce_properties_to_majority_type() {
+        let mut geojson = mixed_collection();
+        let schema = infer_schema(&geojson);
+        let report = coerce_properties(&mut geojson, &schema);
+
+        assert_eq!(report.changes.len(), 1);
+        assert_eq!(report.changes[0].feature_index, 2);
+        assert_eq!(report.changes[0].from, json!("42"));
+        assert_eq!(report.changes[0].to, json!(42));
+        assert_eq!(report.unconverted, vec![(3, "floors".to_string())]);
+
+        let GeoJson::FeatureCollection(collection) = &geojson else {
+            panic!("Expected a FeatureCollection");
+        };
+        assert_eq!(collection.features[2].property("floors"), Some(&json!(42)));
+        assert_eq!(
+            collection.features[3].property("floors"),
+            Some(&json!("unknown"))
+        );
+    }
+
+    #[test]
+    fn test_coerce_without_conflicts_is_a_no_op() {
+        let mut geojson =
+            GeoJson::from_json_value(GeometryFixtures::berlin_feature_collection()).unwrap();
+        let before = geojson.clone();
+        let schema = infer_schema(&geojson);
+        let report = coerce_properties(&mut geojson, &schema);
+        assert!(report.changes.is_empty());
+        assert_eq!(geojson, before);
+    }
+}
@@ -0,0 +1,108 @@
+use geo::{Area, LineString, Polygon};
+use proj_exercise_simple::error::ProjectionError;
+use proj_exercise_simple::helpers::{process_feature_collection_with_options, ProcessingOutput};
+use proj_exercise_simple::options::ProcessingOptions;
+use proj_exercise_simple::self_intersection::{
+    repair_ring, ring_self_intersects, SelfIntersectionPolicy,
+};
+use proj_exercise_simple::transformer::TransformerConfig;
+use serde_json::json;
+
+/// A bow-tie whose right loop encloses 2.0 and left loop 0.5
+const BOW_TIE: [(f64, f64); 5] = [(0.0, 0.0), (3.0, 2.0), (3.0, 0.0), (0.0, 1.0), (0.0, 0.0)];
+
+fn bow_tie_collection() -> serde_json::Value {
+    let ring: Vec<[f64; 2]> = BOW_TIE.iter().map(|&(x, y)| [x, y]).collect();
+    json!({
+        "type": "FeatureCollection",
+        "features": [{
+            "type": "Feature",
+            "geometry": { "type": "Polygon", "coordinates": [ring] },
+            "properties": {}
+        }]
+    })
+}
+
+fn process(policy: SelfIntersectionPolicy) -> Result<ProcessingOutput, ProjectionError> {
+    process_feature_collection_with_options(
+        bow_tie_collection(),
+        &mut TransformerConfig::default(),
+        &ProcessingOptions::new().with_self_intersection(policy),
+    )
+}
+
+fn output_exterior(output: ProcessingOutput) -> LineString<f64> {
+    let geojson::GeoJson::FeatureCollection(collection) = output.geojson else {
+        panic!("expected a feature collection");
+    };
+    let geometry = collection.features[0].geometry.clone().unwrap();
+    match geo::Geometry::<f64>::try_from(geometry.value).unwrap() {
+        geo::Geometry::Polygon(polygon) => polygon.exterior().clone(),
+        other => panic!("expected a polygon, got {other:?}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ignore_leaves_the_ring_unchecked() {
+        let output = process(SelfIntersectionPolicy::Ignore).unwrap();
+        assert!(output.report.warnings.is_empty());
+        assert!(ring_self_intersects(&output_exterior(output)));
+    }
+
+    #[test]
+    fn test_warn_reports_the_bow_tie() {
+        let output = process(SelfIntersectionPolicy::Warn).unwrap();
+        assert_eq!(output.report.warnings.len(), 1);
+        assert_eq!(output.report.warnings[0].feature_index, 0);
+        assert_eq!(
+            output.report.warnings[0].message,
+            "Ring 0 of polygon 0 intersects itself"
+        );
+        assert!(ring_self_intersects(&output_exterior(output)));
+    }
+
+    #[test]
+    fn test_error_rejects_the_bow_tie() {
+        match process(SelfIntersectionPolicy::Error) {
+            Err(ProjectionError::SelfIntersectingRing {
+                feature_index,
+                polygon,
+                ring,
+            }) => assert_eq!((feature_index, polygon, ring), (0, 0, 0)),
+            other => panic!("expected a self-intersection error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_repair_keeps_a_simple_counter_clockwise_exterior() {
+        let exterior = process(SelfIntersectionPolicy::Repair)
+            .map(output_exterior)
+            .unwrap();
+        assert!(!ring_self_intersects(&exterior));
+        assert!(Polygon::new(exterior, Vec::new()).signed_area() > 0.0);
+    }
+
+    #[test]
+    fn test_repair_ring_keeps_the_largest_loop() {
+        let ring = LineString::from(BOW_TIE.to_vec());
+        let repaired = repair_ring(&ring);
+
+        assert!(!ring_self_intersects(&repaired));
+        assert!(repaired.is_closed());
+        let area = Polygon::new(repaired, Vec::new()).unsigned_area();
+        // Both loops together enclose 2.5
+        assert!(area > 0.0 && area < 2.5);
+        assert!((area - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_repair_ring_leaves_simple_rings_alone() {
+        let ring = LineString::from(vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 0.0)]);
+        assert!(!ring_self_intersects(&ring));
+        assert_eq!(repair_ring(&ring), ring);
+    }
+}
@@ -0,0 +1,157 @@
+use geojson::{Feature, GeoJson, Geometry, Value};
+use proj_exercise_simple::coordinates::Coordinate;
+use proj_exercise_simple::helpers::process_feature_collection_with_options;
+use proj_exercise_simple::options::ProcessingOptions;
+use proj_exercise_simple::spatial_index::SpatialIndex;
+use proj_exercise_simple::transformer::TransformerConfig;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Small deterministic generator so the test does not need a rand dependency
+    struct Lcg(u64);
+
+    impl Lcg {
+        fn next_f64(&mut self) -> f64 {
+            self.0 = self
+                .0
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (self.0 >> 11) as f64 / (1u64 << 53) as f64
+        }
+    }
+
+    fn square(min_x: f64, min_y: f64, size: f64) -> Feature {
+        Feature::from(Geometry::new(Value::Polygon(vec![vec![
+            vec![min_x, min_y],
+            vec![min_x + size, min_y],
+            vec![min_x + size, min_y + size],
+            vec![min_x, min_y + size],
+            vec![min_x, min_y],
+        ]])))
+    }
+
+    fn random_squares(count: usize) -> Vec<(f64, f64, f64)> {
+        let mut rng = Lcg(42);
+        (0..count)
+            .map(|_| {
+                let x = rng.next_f64() * 1000.0;
+                let y = rng.next_f64() * 1000.0;
+                let size = 1.0 + rng.next_f64() * 20.0;
+                (x, y, size)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_query_point_in_random_squares() {
+        let squares = random_squares(1000);
+        let features: Vec<Feature> = squares
+            .iter()
+            .map(|&(x, y, size)| square(x, y, size))
+            .collect();
+        let index = SpatialIndex::from_features(&features);
+        assert_eq!(index.len(), 1000);
+
+        let known = 537;
+        let (x, y, size) = squares[known];
+        let point = Coordinate::new(x + size / 2.0, y + size / 2.0);
+
+        // The candidate set must match a brute force bbox scan exactly
+        let expected: Vec<usize> = squares
+            .iter()
+            .enumerate()
+            .filter(|(_, &(sx, sy, ss))| {
+                sx <= point.x && point.x <= sx + ss && sy <= point.y && point.y <= sy + ss
+            })
+            .map(|(i, _)| i)
+            .collect();
+        let candidates = index.query_bbox(point, point);
+        assert_eq!(candidates, expected);
+
+        // For axis aligned squares the exact hits equal the bbox candidates
+        let hits = index.query_point(point);
+        assert!(hits.contains(&known));
+        assert_eq!(hits, expected);
+    }
+
+    #[test]
+    fn test_query_bbox_matches_brute_force() {
+        let squares = random_squares(1000);
+        let features: Vec<Feature> = squares
+            .iter()
+            .map(|&(x, y, size)| square(x, y, size))
+            .collect();
+        let index = SpatialIndex::from_features(&features);
+
+        let min = Coordinate::new(200.0, 300.0);
+        let max = Coordinate::new(400.0, 350.0);
+        let expected: Vec<usize> = squares
+            .iter()
+            .enumerate()
+            .filter(|(_, &(x, y, size))| {
+                x <= max.x && x + size >= min.x && y <= max.y && y + size >= min.y
+            })
+            .map(|(i, _)| i)
+            .collect();
+        assert!(!expected.is_empty());
+        assert_eq!(index.query_bbox(min, max), expected);
+    }
+
+    #[test]
+    fn test_query_point_exact_test_rejects_bbox_candidates() {
+        // A triangle whose bbox contains the point while the triangle itself does not
+        let triangle = Feature::from(Geometry::new(Value::Polygon(vec![vec![
+            vec![0.0, 0.0],
+            vec![10.0, 0.0],
+            vec![0.0, 10.0],
+            vec![0.0, 0.0],
+        ]])));
+        let index = SpatialIndex::from_features(&[triangle]);
+        let point = Coordinate::new(9.0, 9.0);
+        assert_eq!(index.query_bbox(point, point), vec![0]);
+        assert!(index.query_point(point).is_empty());
+        assert_eq!(index.query_point(Coordinate::new(1.0, 1.0)), vec![0]);
+    }
+
+    #[test]
+    fn test_empty_index() {
+        let index = SpatialIndex::from_features(&[]);
+        assert!(index.is_empty());
+        assert!(index.query_point(Coordinate::new(0.0, 0.0)).is_empty());
+    }
+
+    #[test]
+    fn test_pipeline_builds_index() {
+        let features = vec![square(0.0, 0.0, 1.0), square(2.0, 2.0, 1.0)];
+        let collection =
+            GeoJson::from(features.into_iter().collect::<geojson::FeatureCollection>());
+        let json_value = serde_json::to_value(collection).unwrap();
+
+        let mut config = TransformerConfig::default();
+        let options = ProcessingOptions::new().with_index(true);
+        let output =
+            process_feature_collection_with_options(json_value, &mut config, &options).unwrap();
+        let index = output.index.expect("index should be built");
+
+        // (2.5, 2.5) in Web Mercator
+        let point = Coordinate::new(278298.73, 278346.3);
+        assert_eq!(index.query_point(point), vec![1]);
+    }
+
+    #[test]
+    fn test_pipeline_without_index() {
+        let json_value =
+            serde_json::to_value(GeoJson::from(Geometry::new(Value::Point(vec![1.0, 2.0]))))
+                .unwrap();
+        let mut config = TransformerConfig::default();
+        let output = process_feature_collection_with_options(
+            json_value,
+            &mut config,
+            &ProcessingOptions::default(),
+        )
+        .unwrap();
+        assert!(output.index.is_none());
+    }
+}
@@ -0,0 +1,48 @@
+use crate::fixtures::GeometryFixtures;
+use geojson::GeoJson;
+use proj_exercise_simple::helpers::process_feature_collection_with_options;
+use proj_exercise_simple::options::ProcessingOptions;
+use proj_exercise_simple::stable::to_stable_string;
+use proj_exercise_simple::transformer::TransformerConfig;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process_stable(json_value: serde_json::Value) -> String {
+        let mut config = TransformerConfig::default().with_precision(3);
+        let options = ProcessingOptions::new().with_stable_output(true);
+        let output =
+            process_feature_collection_with_options(json_value, &mut config, &options).unwrap();
+        to_stable_string(&output.geojson)
+    }
+
+    #[test]
+    fn test_repeated_runs_are_byte_identical() {
+        let first = process_stable(GeometryFixtures::berlin_feature_collection());
+        let second = process_stable(GeometryFixtures::berlin_feature_collection());
+        assert_eq!(first, second);
+
+        // Feature order matches the input
+        let reichstag = first.find("reichstag").unwrap();
+        let spree = first.find("spree").unwrap();
+        let tiergarten = first.find("tiergarten").unwrap();
+        assert!(reichstag < spree && spree < tiergarten);
+    }
+
+    #[test]
+    fn test_stable_string_sorts_keys_and_normalizes_zero() {
+        let geojson: GeoJson = r#"{
+            "type": "Feature",
+            "zoo": true,
+            "properties": { "z": 1, "a": { "y": 2, "b": 3 } },
+            "geometry": { "type": "Point", "coordinates": [-0.0, 1.5] }
+        }"#
+        .parse()
+        .unwrap();
+        assert_eq!(
+            to_stable_string(&geojson),
+            r#"{"geometry":{"coordinates":[0.0,1.5],"type":"Point"},"properties":{"a":{"b":3,"y":2},"z":1},"type":"Feature","zoo":true}"#
+        );
+    }
+}
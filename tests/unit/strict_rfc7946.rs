@@ -0,0 +1,102 @@
+use proj_exercise_simple::error::{GeometryValidationError, ProjectionError};
+use proj_exercise_simple::helpers::{process_feature_collection_with_options, ProcessingOutput};
+use proj_exercise_simple::options::{NullGeometryPolicy, ProcessingOptions, RingClosure};
+use proj_exercise_simple::transformer::TransformerConfig;
+use serde_json::json;
+
+fn collection(geometries: Vec<serde_json::Value>) -> serde_json::Value {
+    let features: Vec<_> = geometries
+        .into_iter()
+        .map(|geometry| json!({ "type": "Feature", "geometry": geometry, "properties": {} }))
+        .collect();
+    json!({ "type": "FeatureCollection", "features": features })
+}
+
+fn process(
+    input: serde_json::Value,
+    options: &ProcessingOptions,
+) -> Result<ProcessingOutput, ProjectionError> {
+    process_feature_collection_with_options(input, &mut TransformerConfig::default(), options)
+}
+
+fn unclosed_ring() -> serde_json::Value {
+    json!({ "type": "Polygon", "coordinates": [[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]] })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strict_mode_rejects_unclosed_ring() {
+        let input = collection(vec![
+            json!({ "type": "Point", "coordinates": [1.0, 2.0] }),
+            unclosed_ring(),
+        ]);
+        // Auto-closing is not applied in strict mode
+        let options = ProcessingOptions::new()
+            .with_ring_closure(RingClosure::AutoClose)
+            .with_strict_rfc7946(true);
+        assert!(matches!(
+            process(input.clone(), &options),
+            Err(ProjectionError::NotRfc7946 {
+                feature_index: 1,
+                source: GeometryValidationError::UnclosedRing
+            })
+        ));
+
+        let lenient = ProcessingOptions::new().with_ring_closure(RingClosure::AutoClose);
+        assert!(process(input, &lenient).is_ok());
+    }
+
+    #[test]
+    fn test_strict_mode_rejects_out_of_range_coordinate() {
+        let input = collection(vec![
+            json!({ "type": "LineString", "coordinates": [[0.0, 0.0], [200.0, 10.0]] }),
+        ]);
+        let options = ProcessingOptions::new().with_strict_rfc7946(true);
+        let error = process(input, &options).unwrap_err();
+        assert!(matches!(
+            error,
+            ProjectionError::NotRfc7946 {
+                feature_index: 0,
+                source: GeometryValidationError::OutOfRange { x, y }
+            } if x == 200.0 && y == 10.0
+        ));
+        assert!(error.to_string().contains("RFC 7946"));
+    }
+
+    #[test]
+    fn test_strict_mode_accepts_conformant_input() {
+        let input = json!({
+            "type": "FeatureCollection",
+            "bbox": [0.0, 0.0, 1.0, 1.0],
+            "features": [
+                { "type": "Feature", "geometry": null, "properties": {} },
+                {
+                    "type": "Feature",
+                    "geometry": { "type": "Polygon", "coordinates": [[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 0.0]]] },
+                    "properties": {}
+                }
+            ]
+        });
+        let options = ProcessingOptions::new()
+            .with_null_geometry(NullGeometryPolicy::PassThrough)
+            .with_strict_rfc7946(true);
+        assert!(process(input, &options).is_ok());
+    }
+
+    #[test]
+    fn test_strict_mode_rejects_malformed_bbox() {
+        let mut input = collection(vec![json!({ "type": "Point", "coordinates": [1.0, 2.0] })]);
+        input["features"][0]["bbox"] = json!([1.0, 2.0, 1.0]);
+        let options = ProcessingOptions::new().with_strict_rfc7946(true);
+        assert!(matches!(
+            process(input, &options),
+            Err(ProjectionError::NotRfc7946 {
+                source: GeometryValidationError::InvalidBbox(3),
+                ..
+            })
+        ));
+    }
+}
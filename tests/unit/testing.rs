@@ -0,0 +1,101 @@
+use geojson::{Geometry, Value};
+use proj_exercise_simple::coordinates::{BoundingBox, Coordinate};
+use proj_exercise_simple::kind::GeometryKind;
+use proj_exercise_simple::testing::{
+    random_feature_collection, synthetic_line_string, synthetic_multi_polygon, synthetic_polygon,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn positions(value: &Value) -> Vec<Vec<f64>> {
+        match value {
+            Value::Point(p) => vec![p.clone()],
+            Value::MultiPoint(ps) | Value::LineString(ps) => ps.clone(),
+            Value::MultiLineString(ls) | Value::Polygon(ls) => ls.concat(),
+            Value::MultiPolygon(ps) => ps.iter().flat_map(|p| p.concat()).collect(),
+            Value::GeometryCollection(gs) => gs.iter().flat_map(|g| positions(&g.value)).collect(),
+        }
+    }
+
+    fn assert_in_range(geometry: &Geometry) {
+        for position in positions(&geometry.value) {
+            assert!((-180.0..=180.0).contains(&position[0]), "{position:?}");
+            assert!((-90.0..=90.0).contains(&position[1]), "{position:?}");
+        }
+    }
+
+    #[test]
+    fn test_synthetic_line_string_spans_bbox() {
+        let line = synthetic_line_string(11, BoundingBox::new(-10.0, 10.0, -5.0, 5.0));
+        let coords = positions(&line.value);
+        assert_eq!(coords.len(), 11);
+        assert_eq!(coords[0], vec![-10.0, -5.0]);
+        assert_eq!(coords[10], vec![10.0, 5.0]);
+        assert_eq!(coords[5], vec![0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_synthetic_line_string_is_clamped() {
+        let line = synthetic_line_string(100, BoundingBox::new(-200.0, 200.0, -95.0, 95.0));
+        assert_in_range(&line);
+    }
+
+    #[test]
+    fn test_synthetic_polygon_is_closed() {
+        let polygon = synthetic_polygon(16, Coordinate::new(13.4, 52.5), 0.1);
+        let Value::Polygon(rings) = &polygon.value else {
+            panic!("Expected Polygon geometry");
+        };
+        assert_eq!(rings.len(), 1);
+        assert_eq!(rings[0].len(), 17);
+        assert_eq!(rings[0].first(), rings[0].last());
+        assert_in_range(&polygon);
+    }
+
+    #[test]
+    fn test_synthetic_multi_polygon_stays_in_range() {
+        let multi_polygon = synthetic_multi_polygon(500, 8);
+        let Value::MultiPolygon(polygons) = &multi_polygon.value else {
+            panic!("Expected MultiPolygon geometry");
+        };
+        assert_eq!(polygons.len(), 500);
+        assert!(polygons.iter().all(|p| p[0].len() == 9));
+        assert_in_range(&multi_polygon);
+    }
+
+    #[test]
+    fn test_random_feature_collection_is_deterministic() {
+        let counts = [
+            (GeometryKind::Point, 5),
+            (GeometryKind::Polygon, 3),
+            (GeometryKind::GeometryCollection, 2),
+        ];
+        let first = random_feature_collection(42, &counts);
+        assert_eq!(first, random_feature_collection(42, &counts));
+        assert_ne!(first, random_feature_collection(43, &counts));
+    }
+
+    #[test]
+    fn test_random_feature_collection_counts_and_kinds() {
+        let counts = [
+            (GeometryKind::MultiLineString, 4),
+            (GeometryKind::Point, 0),
+            (GeometryKind::LineString, 2),
+        ];
+        let collection = random_feature_collection(1, &counts);
+        assert_eq!(collection.features.len(), 6);
+        assert_eq!(
+            collection.features[0].property("kind"),
+            Some(&serde_json::json!("MultiLineString"))
+        );
+        assert_eq!(
+            collection.features[5].property("kind"),
+            Some(&serde_json::json!("LineString"))
+        );
+        for feature in &collection.features {
+            assert_in_range(feature.geometry.as_ref().unwrap());
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use geo::Area;
+use geojson::{Geometry, Value};
+use proj_exercise_simple::tiles::to_tile_features;
+use proj_exercise_simple::transformer::TransformerConfig;
+
+fn square(min: f64, max: f64) -> Geometry {
+    Geometry::new(Value::Polygon(vec![vec![
+        vec![min, min],
+        vec![max, min],
+        vec![max, max],
+        vec![min, max],
+        vec![min, min],
+    ]]))
+}
+
+fn area(feature: &geojson::Feature) -> f64 {
+    let value = feature.geometry.as_ref().unwrap().value.clone();
+    geo::Geometry::<f64>::try_from(value)
+        .unwrap()
+        .unsigned_area()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_small_polygon_at_zoom_zero_lands_in_the_root_tile() {
+        let berlin = Geometry::new(Value::Polygon(vec![vec![
+            vec![13.3, 52.4],
+            vec![13.5, 52.4],
+            vec![13.5, 52.6],
+            vec![13.3, 52.4],
+        ]]));
+        let tiles = to_tile_features(&berlin, 0..=0, &mut TransformerConfig::default()).unwrap();
+        assert_eq!(tiles.len(), 1);
+        let feature = &tiles[&(0, 0, 0)];
+        assert!(matches!(
+            feature.geometry.as_ref().unwrap().value,
+            Value::Polygon(_)
+        ));
+    }
+
+    #[test]
+    fn test_polygon_across_tile_edges_is_split() {
+        let geometry = square(-1.0, 1.0);
+        let mut config = TransformerConfig::default();
+        let tiles = to_tile_features(&geometry, 0..=1, &mut config).unwrap();
+
+        // The root tile plus the four tiles meeting at (0, 0) on zoom 1
+        assert_eq!(tiles.len(), 5);
+        for key in [(1, 0, 0), (1, 1, 0), (1, 0, 1), (1, 1, 1)] {
+            assert!(tiles.contains_key(&key), "missing tile {key:?}");
+        }
+        let whole = area(&tiles[&(0, 0, 0)]);
+        let parts: f64 = tiles
+            .iter()
+            .filter(|(key, _)| key.0 == 1)
+            .map(|(_, feature)| area(feature))
+            .sum();
+        assert!((whole - parts).abs() / whole < 1e-9);
+        assert!((area(&tiles[&(1, 0, 0)]) - whole / 4.0).abs() / whole < 1e-6);
+    }
+
+    #[test]
+    fn test_line_is_cut_at_tile_edges() {
+        let line = Geometry::new(Value::LineString(vec![vec![-10.0, 10.0], vec![10.0, 10.0]]));
+        let tiles = to_tile_features(&line, 1..=1, &mut TransformerConfig::default()).unwrap();
+        assert_eq!(tiles.len(), 2);
+        let Value::LineString(west) = &tiles[&(1, 0, 0)].geometry.as_ref().unwrap().value else {
+            panic!("Expected a line string");
+        };
+        assert_eq!(west.len(), 2);
+        assert_eq!(west[1][0], 0.0);
+    }
+}
@@ -0,0 +1,139 @@
+use geojson::{Geometry, Value};
+use proj_exercise_simple::geometry_processor::GeometryProcessor;
+use proj_exercise_simple::helpers::process_feature_collection;
+use proj_exercise_simple::pool::CoordinateBufferPool;
+use proj_exercise_simple::transformer::{TransformerConfig, TransformerStats};
+use serde_json::json;
+use std::thread;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transform_point_is_counted() {
+        let config = TransformerConfig::default();
+        assert_eq!(config.stats(), TransformerStats::default());
+
+        let (x, y) = config.transform_point(1.0, 2.0).unwrap();
+        assert!((x - 111319.49079327357).abs() < 1e-6);
+        assert!((y - 222684.20850554455).abs() < 1e-6);
+        config.transform_point(3.0, 4.0).unwrap();
+
+        assert_eq!(config.transformation_count(), 2);
+        assert_eq!(config.stats().transform_errors, 0);
+    }
+
+    #[test]
+    fn test_failed_transformations_are_counted() {
+        let config = TransformerConfig::default();
+        assert!(config.transform_point(0.0, 91.0).is_err());
+        assert_eq!(
+            config.stats(),
+            TransformerStats {
+                transformations: 0,
+                transform_errors: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_pipeline_counts_projected_coordinates() {
+        let mut config = TransformerConfig::default();
+        let input = json!({
+            "type": "FeatureCollection",
+            "features": [
+                {
+                    "type": "Feature",
+                    "geometry": { "type": "Point", "coordinates": [1.0, 2.0] },
+                    "properties": {}
+                },
+                {
+                    "type": "Feature",
+                    "geometry": { "type": "LineString", "coordinates": [[0.0, 0.0], [1.0, 1.0], [2.0, 2.0]] },
+                    "properties": {}
+                }
+            ]
+        });
+        process_feature_collection(input, &mut config).unwrap();
+        assert_eq!(config.transformation_count(), 4);
+    }
+
+    #[test]
+    fn test_only_transformer_failures_are_counted_as_errors() {
+        let mut config = TransformerConfig::default();
+        let unclosed = json!({
+            "type": "FeatureCollection",
+            "features": [{
+                "type": "Feature",
+                "geometry": {
+                    "type": "Polygon",
+                    "coordinates": [[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]]
+                },
+                "properties": {}
+            }]
+        });
+        assert!(process_feature_collection(unclosed, &mut config).is_err());
+        let out_of_range = json!({
+            "type": "FeatureCollection",
+            "features": [{
+                "type": "Feature",
+                "geometry": { "type": "Point", "coordinates": [200.0, 0.0] },
+                "properties": {}
+            }]
+        });
+        assert!(process_feature_collection(out_of_range, &mut config).is_err());
+        assert_eq!(config.stats(), TransformerStats::default());
+    }
+
+    #[test]
+    fn test_project_coordinates_counts_projected_coordinates() {
+        let mut config = TransformerConfig::default();
+        let geometry = Geometry::new(Value::LineString(vec![vec![0.0, 0.0], vec![1.0, 1.0]]));
+        GeometryProcessor::new(&geometry, &mut config)
+            .project_coordinates(&mut CoordinateBufferPool::new(10, 100))
+            .unwrap();
+        assert_eq!(config.transformation_count(), 2);
+    }
+
+    #[test]
+    fn test_clones_share_counters_across_threads() {
+        let config = TransformerConfig::default();
+        thread::scope(|scope| {
+            for _ in 0..4 {
+                let clone = config.clone();
+                scope.spawn(move || clone.transform_point(1.0, 2.0).unwrap());
+            }
+        });
+        assert_eq!(config.transformation_count(), 4);
+    }
+
+    #[test]
+    fn test_clear_cache_resets_counters() {
+        let config = TransformerConfig::default();
+        config.transform_point(1.0, 2.0).unwrap();
+        let _ = config.transform_point(0.0, 91.0);
+        config.clear_cache().unwrap();
+        assert_eq!(config.stats(), TransformerStats::default());
+    }
+
+    #[test]
+    fn test_update_crs_leaves_clones_untouched() {
+        let mut config = TransformerConfig::default();
+        let clone = config.clone();
+        clone.transform_point(1.0, 2.0).unwrap();
+        assert!(clone.is_transformer_available().unwrap());
+
+        config
+            .update_crs("EPSG:3857".to_string(), "EPSG:4326".to_string())
+            .unwrap();
+
+        assert_eq!(config.stats(), TransformerStats::default());
+        assert!(!config.is_transformer_available().unwrap());
+        assert_eq!(clone.transformation_count(), 1);
+        assert!(clone.is_transformer_available().unwrap());
+        let (x, _) = clone.transform_point(1.0, 2.0).unwrap();
+        assert!((x - 111319.49079327357).abs() < 1e-6);
+        assert_eq!(clone.transformation_count(), 2);
+    }
+}
@@ -0,0 +1,122 @@
+use proj_exercise_simple::error::ProjectionError;
+use proj_exercise_simple::helpers::ProcessedGeometry;
+use proj_exercise_simple::transformer::TransformerConfig;
+use proj_exercise_simple::typed::{process_typed_collection, TypedFeature};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+struct Road {
+    name: String,
+    lanes: u8,
+}
+
+fn road(id: &str, properties: serde_json::Value) -> serde_json::Value {
+    json!({
+        "type": "Feature",
+        "id": id,
+        "geometry": { "type": "LineString", "coordinates": [[0.0, 0.0], [1.0, 2.0]] },
+        "properties": properties
+    })
+}
+
+fn collection(features: Vec<serde_json::Value>) -> serde_json::Value {
+    json!({ "type": "FeatureCollection", "features": features })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_properties_survive_projection() {
+        let input = collection(vec![
+            road("a", json!({ "name": "Main Street", "lanes": 2 })),
+            road(
+                "b",
+                json!({ "name": "Ring Road", "lanes": 4, "surface": "asphalt" }),
+            ),
+        ]);
+        let roads: Vec<TypedFeature<Road>> =
+            process_typed_collection(input, &mut TransformerConfig::default()).unwrap();
+
+        assert_eq!(roads.len(), 2);
+        assert_eq!(
+            roads[0].properties,
+            Road {
+                name: "Main Street".to_string(),
+                lanes: 2
+            }
+        );
+        assert_eq!(roads[1].properties.lanes, 4);
+        assert_eq!(
+            roads[1].id,
+            Some(geojson::feature::Id::String("b".to_string()))
+        );
+        match &roads[0].geometry {
+            ProcessedGeometry::LineString(line) => {
+                assert!((line.0[1].x - 111319.49079327357).abs() < 1e-6);
+            }
+            other => panic!("expected a line string, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_type_mismatch_names_field() {
+        let input = collection(vec![
+            road("a", json!({ "name": "Main Street", "lanes": 2 })),
+            road("b", json!({ "name": "Ring Road", "lanes": "four" })),
+        ]);
+        let error =
+            process_typed_collection::<Road>(input, &mut TransformerConfig::default()).unwrap_err();
+
+        match &error {
+            ProjectionError::InvalidProperties {
+                feature_index,
+                field,
+                ..
+            } => {
+                assert_eq!(*feature_index, 1);
+                assert_eq!(field.as_deref(), Some("lanes"));
+            }
+            other => panic!("expected invalid properties, got {other:?}"),
+        }
+        assert!(error.to_string().contains("`lanes`"));
+    }
+
+    #[test]
+    fn test_missing_field_is_reported() {
+        let input = collection(vec![road("a", json!({ "lanes": 2 }))]);
+        let error =
+            process_typed_collection::<Road>(input, &mut TransformerConfig::default()).unwrap_err();
+        assert!(error.to_string().contains("missing field `name`"));
+    }
+
+    #[test]
+    fn test_round_trip_through_geojson() {
+        let feature: geojson::Feature =
+            serde_json::from_value(road("a", json!({ "name": "Main Street", "lanes": 2 })))
+                .unwrap();
+        let typed = TypedFeature::<Road>::from_geojson(feature.clone()).unwrap();
+        let back = typed.into_geojson().unwrap();
+
+        assert_eq!(back.id, feature.id);
+        assert_eq!(back.properties, feature.properties);
+        assert_eq!(back.geometry, feature.geometry);
+    }
+
+    #[test]
+    fn test_non_object_properties_are_rejected() {
+        let feature: geojson::Feature =
+            serde_json::from_value(road("a", json!({ "name": "Main Street" }))).unwrap();
+        let typed = TypedFeature::<serde_json::Value>::from_geojson(feature).unwrap();
+        let typed = TypedFeature {
+            properties: json!("not an object"),
+            ..typed
+        };
+        assert!(matches!(
+            typed.into_geojson(),
+            Err(ProjectionError::InvalidProperties { .. })
+        ));
+    }
+}
@@ -0,0 +1,83 @@
+use proj_exercise_simple::error::ProjectionError;
+use proj_exercise_simple::helpers::process_feature_collection_with_options;
+use proj_exercise_simple::options::ProcessingOptions;
+use proj_exercise_simple::transformer::TransformerConfig;
+use serde_json::json;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point_feature(id: serde_json::Value, x: f64) -> serde_json::Value {
+        let mut feature = json!({
+            "type": "Feature",
+            "geometry": { "type": "Point", "coordinates": [x, 2.0] },
+            "properties": {}
+        });
+        if !id.is_null() {
+            feature["id"] = id;
+        }
+        feature
+    }
+
+    #[test]
+    fn test_duplicate_ids_are_listed_in_error() {
+        let collection = json!({
+            "type": "FeatureCollection",
+            "features": [
+                point_feature(json!("1"), 1.0),
+                point_feature(json!("2"), 2.0),
+                point_feature(json!("1"), 3.0),
+                point_feature(json!("1"), 4.0),
+            ]
+        });
+        let options = ProcessingOptions::new().with_require_unique_ids(true);
+        let result = process_feature_collection_with_options(
+            collection,
+            &mut TransformerConfig::default(),
+            &options,
+        );
+        match result {
+            Err(ProjectionError::DuplicateIds(ids)) => assert_eq!(ids, vec!["1".to_string()]),
+            other => panic!("Expected DuplicateIds error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_unique_and_mixed_type_ids_pass() {
+        let collection = json!({
+            "type": "FeatureCollection",
+            "features": [
+                point_feature(json!("1"), 1.0),
+                point_feature(json!(1), 2.0),
+                point_feature(json!(null), 3.0),
+                point_feature(json!(null), 4.0),
+            ]
+        });
+        let options = ProcessingOptions::new().with_require_unique_ids(true);
+        let output = process_feature_collection_with_options(
+            collection,
+            &mut TransformerConfig::default(),
+            &options,
+        )
+        .unwrap();
+        let geojson::GeoJson::FeatureCollection(fc) = output.geojson else {
+            panic!("Expected FeatureCollection");
+        };
+        assert_eq!(fc.features.len(), 4);
+    }
+
+    #[test]
+    fn test_duplicate_ids_allowed_by_default() {
+        let collection = json!({
+            "type": "FeatureCollection",
+            "features": [point_feature(json!("1"), 1.0), point_feature(json!("1"), 2.0)]
+        });
+        let result = process_feature_collection_with_options(
+            collection,
+            &mut TransformerConfig::default(),
+            &ProcessingOptions::new(),
+        );
+        assert!(result.is_ok());
+    }
+}
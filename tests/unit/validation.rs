@@ -0,0 +1,169 @@
+use geojson::{Geometry, Value};
+use proj_exercise_simple::error::GeometryValidationError;
+use proj_exercise_simple::validation::{validate_geometry, ValidationCache};
+
+fn square() -> Vec<Vec<f64>> {
+    vec![
+        vec![0.0, 0.0],
+        vec![1.0, 0.0],
+        vec![1.0, 1.0],
+        vec![0.0, 1.0],
+        vec![0.0, 0.0],
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_geometries() {
+        assert_eq!(validate_geometry(&Value::Polygon(vec![square()])), Ok(()));
+        assert_eq!(
+            validate_geometry(&Value::LineString(vec![
+                vec![0.0, 0.0],
+                vec![1.0, 1.0, 5.0]
+            ])),
+            Ok(())
+        );
+        let collection = Value::GeometryCollection(vec![
+            Geometry::new(Value::Point(vec![13.4, 52.5])),
+            Geometry::new(Value::MultiPolygon(vec![vec![square()]])),
+        ]);
+        assert_eq!(validate_geometry(&collection), Ok(()));
+    }
+
+    #[test]
+    fn test_unclosed_ring() {
+        let mut ring = square();
+        ring.pop();
+        assert_eq!(
+            validate_geometry(&Value::Polygon(vec![ring])),
+            Err(GeometryValidationError::UnclosedRing)
+        );
+    }
+
+    #[test]
+    fn test_too_few_points() {
+        assert_eq!(
+            validate_geometry(&Value::LineString(vec![vec![0.0, 0.0]])),
+            Err(GeometryValidationError::TooFewPoints {
+                expected: 2,
+                found: 1
+            })
+        );
+        let ring = vec![vec![0.0, 0.0], vec![1.0, 0.0], vec![0.0, 0.0]];
+        assert_eq!(
+            validate_geometry(&Value::Polygon(vec![ring])),
+            Err(GeometryValidationError::TooFewPoints {
+                expected: 4,
+                found: 3
+            })
+        );
+        assert_eq!(
+            validate_geometry(&Value::Point(vec![1.0])),
+            Err(GeometryValidationError::TooFewPoints {
+                expected: 2,
+                found: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_self_intersection() {
+        let bowtie = vec![
+            vec![0.0, 0.0],
+            vec![1.0, 1.0],
+            vec![1.0, 0.0],
+            vec![0.0, 1.0],
+            vec![0.0, 0.0],
+        ];
+        assert_eq!(
+            validate_geometry(&Value::Polygon(vec![bowtie])),
+            Err(GeometryValidationError::SelfIntersection)
+        );
+    }
+
+    #[test]
+    fn test_nan() {
+        assert_eq!(
+            validate_geometry(&Value::Point(vec![f64::NAN, 1.0])),
+            Err(GeometryValidationError::NaN)
+        );
+        assert_eq!(
+            validate_geometry(&Value::MultiPoint(vec![vec![0.0, f64::INFINITY]])),
+            Err(GeometryValidationError::NaN)
+        );
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        assert_eq!(
+            validate_geometry(&Value::Point(vec![181.0, 10.0])),
+            Err(GeometryValidationError::OutOfRange { x: 181.0, y: 10.0 })
+        );
+    }
+
+    #[test]
+    fn test_empty_geometry() {
+        for value in [
+            Value::Point(vec![]),
+            Value::MultiPoint(vec![]),
+            Value::Polygon(vec![]),
+            Value::MultiPolygon(vec![]),
+            Value::GeometryCollection(vec![]),
+            Value::MultiLineString(vec![vec![]]),
+        ] {
+            assert_eq!(
+                validate_geometry(&value),
+                Err(GeometryValidationError::EmptyGeometry)
+            );
+        }
+    }
+
+    #[test]
+    fn test_cache_serves_repeated_geometry() {
+        let mut cache = ValidationCache::new(10);
+        let polygon = Value::Polygon(vec![square()]);
+
+        assert_eq!(cache.validate(&polygon), Ok(()));
+        assert_eq!((cache.hits(), cache.misses()), (0, 1));
+        assert_eq!(cache.validate(&polygon.clone()), Ok(()));
+        assert_eq!((cache.hits(), cache.misses()), (1, 1));
+
+        // Errors are cached too
+        let empty = Value::LineString(vec![]);
+        for _ in 0..2 {
+            assert_eq!(
+                cache.validate(&empty),
+                Err(GeometryValidationError::EmptyGeometry)
+            );
+        }
+        assert_eq!((cache.hits(), cache.misses()), (2, 2));
+    }
+
+    #[test]
+    fn test_cache_distinguishes_close_coordinates() {
+        let mut cache = ValidationCache::new(10);
+        assert_eq!(cache.validate(&Value::Point(vec![180.0, 0.0])), Ok(()));
+        assert!(cache
+            .validate(&Value::Point(vec![180.0 + 1e-9, 0.0]))
+            .is_err());
+        assert_eq!(cache.misses(), 2);
+    }
+
+    #[test]
+    fn test_cache_evicts_oldest_result() {
+        let mut cache = ValidationCache::new(2);
+        let points: Vec<Value> = (0..3).map(|i| Value::Point(vec![i as f64, 0.0])).collect();
+        points.iter().for_each(|point| {
+            cache.validate(point).unwrap();
+        });
+        assert_eq!(cache.len(), 2);
+
+        cache.validate(&points[2]).unwrap();
+        assert_eq!(cache.hits(), 1);
+        cache.validate(&points[0]).unwrap();
+        assert_eq!(cache.misses(), 4);
+    }
+}
@@ -0,0 +1,161 @@
+use proj_exercise_simple::error::ProjectionError;
+use proj_exercise_simple::helpers::process_feature_collection_with_options;
+use proj_exercise_simple::options::{ErrorPolicy, ProcessingOptions};
+use proj_exercise_simple::transformer::TransformerConfig;
+use serde_json::json;
+use std::time::{Duration, Instant};
+
+fn line_feature(vertex_count: usize) -> serde_json::Value {
+    let coordinates: Vec<[f64; 2]> = (0..vertex_count)
+        .map(|i| [(i % 360) as f64 - 180.0, 0.0])
+        .collect();
+    json!({
+        "type": "Feature",
+        "geometry": { "type": "LineString", "coordinates": coordinates },
+        "properties": {}
+    })
+}
+
+fn collection(features: Vec<serde_json::Value>) -> serde_json::Value {
+    json!({ "type": "FeatureCollection", "features": features })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_oversized_feature_is_rejected_before_projection() {
+        let input = collection(vec![line_feature(2_000_000)]);
+        let mut config = TransformerConfig::default();
+        let options = ProcessingOptions::new().with_max_vertices_per_feature(100);
+
+        let started = Instant::now();
+        let result = process_feature_collection_with_options(input, &mut config, &options);
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert!(matches!(
+            result,
+            Err(ProjectionError::VertexBudgetExceeded {
+                got: 2_000_000,
+                limit: 100
+            })
+        ));
+        assert!(!config.is_transformer_available().unwrap());
+    }
+
+    #[test]
+    fn test_total_budget_counts_all_features() {
+        let input = collection(vec![line_feature(60), line_feature(60)]);
+        let options = ProcessingOptions::new()
+            .with_max_vertices_per_feature(100)
+            .with_max_vertices_total(100);
+        let result = process_feature_collection_with_options(
+            input,
+            &mut TransformerConfig::default(),
+            &options,
+        );
+        assert!(matches!(
+            result,
+            Err(ProjectionError::VertexBudgetExceeded {
+                got: 120,
+                limit: 100
+            })
+        ));
+    }
+
+    #[test]
+    fn test_input_within_budget_is_processed() {
+        let input = collection(vec![line_feature(60), line_feature(40)]);
+        let options = ProcessingOptions::new()
+            .with_max_vertices_per_feature(60)
+            .with_max_vertices_total(100);
+        let result = process_feature_collection_with_options(
+            input,
+            &mut TransformerConfig::default(),
+            &options,
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_slow_feature_times_out() {
+        let input = collection(vec![line_feature(10)]);
+        let options = ProcessingOptions::new().with_feature_timeout(Duration::ZERO);
+        let result = process_feature_collection_with_options(
+            input,
+            &mut TransformerConfig::default(),
+            &options,
+        );
+        assert!(matches!(
+            result,
+            Err(ProjectionError::Timeout { feature_index: 0 })
+        ));
+    }
+
+    #[test]
+    fn test_large_feature_times_out_between_batches() {
+        let input = collection(vec![line_feature(5000)]);
+        let mut config = TransformerConfig::default();
+        let options = ProcessingOptions::new().with_feature_timeout(Duration::ZERO);
+        let result = process_feature_collection_with_options(input, &mut config, &options);
+        assert!(matches!(
+            result,
+            Err(ProjectionError::Timeout { feature_index: 0 })
+        ));
+        // Projection stopped after the first batch of 1000 coordinates
+        assert_eq!(config.transformation_count(), 1000);
+    }
+
+    #[test]
+    fn test_lenient_policy_drops_timed_out_features() {
+        let input = collection(vec![line_feature(5000), line_feature(10)]);
+        let options = ProcessingOptions::new()
+            .with_feature_timeout(Duration::ZERO)
+            .with_error_policy(ErrorPolicy::Lenient);
+        let output = process_feature_collection_with_options(
+            input,
+            &mut TransformerConfig::default(),
+            &options,
+        )
+        .unwrap();
+
+        let geojson::GeoJson::FeatureCollection(collection) = output.geojson else {
+            panic!("expected a feature collection");
+        };
+        assert!(collection.features.is_empty());
+        let warnings: Vec<_> = output
+            .report
+            .warnings
+            .iter()
+            .map(|warning| (warning.feature_index, warning.message.as_str()))
+            .collect();
+        assert_eq!(
+            warnings,
+            vec![
+                (
+                    0,
+                    "feature was skipped: Processing feature 0 exceeded the timeout"
+                ),
+                (
+                    1,
+                    "feature was skipped: Processing feature 1 exceeded the timeout"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lenient_policy_keeps_features_within_the_timeout() {
+        let input = collection(vec![line_feature(10)]);
+        let options = ProcessingOptions::new()
+            .with_feature_timeout(Duration::from_secs(60))
+            .with_error_policy(ErrorPolicy::Lenient);
+        let output = process_feature_collection_with_options(
+            input,
+            &mut TransformerConfig::default(),
+            &options,
+        )
+        .unwrap();
+        assert!(output.report.warnings.is_empty());
+    }
+}
@@ -0,0 +1,162 @@
+use geojson::{GeoJson, Geometry, Value};
+use proj_exercise_simple::helpers::process_feature_collection_with_options;
+use proj_exercise_simple::options::{NullGeometryPolicy, ProcessingOptions};
+use proj_exercise_simple::transformer::TransformerConfig;
+use proj_exercise_simple::vertices::{
+    compute_bbox, compute_bbox_per_feature, iter_positions, ring_signed_area,
+};
+use serde_json::json;
+
+fn ring(min: f64, max: f64) -> Vec<Vec<f64>> {
+    vec![
+        vec![min, min],
+        vec![max, min],
+        vec![max, max],
+        vec![min, max],
+        vec![min, min],
+    ]
+}
+
+/// A feature of every geometry type, a feature without geometry and a 3D feature
+fn every_geometry_type() -> serde_json::Value {
+    let feature = |geometry: serde_json::Value| json!({ "type": "Feature", "geometry": geometry, "properties": {} });
+    json!({
+        "type": "FeatureCollection",
+        "features": [
+            feature(json!({ "type": "Point", "coordinates": [1.0, 2.0] })),
+            feature(json!({ "type": "MultiPoint", "coordinates": [[-3.0, 4.0], [5.0, -6.0]] })),
+            feature(json!({ "type": "LineString", "coordinates": [[0.0, 0.0], [7.0, 1.0]] })),
+            feature(json!({ "type": "MultiLineString", "coordinates": [[[0.0, 0.0], [1.0, 1.0]], [[8.0, 9.0], [9.0, 8.0]]] })),
+            feature(json!({ "type": "Polygon", "coordinates": [ring(-10.0, -5.0)] })),
+            feature(json!({ "type": "MultiPolygon", "coordinates": [[ring(0.0, 1.0)], [ring(20.0, 30.0)]] })),
+            feature(json!({
+                "type": "GeometryCollection",
+                "geometries": [
+                    { "type": "Point", "coordinates": [-40.0, 10.0] },
+                    { "type": "GeometryCollection", "geometries": [
+                        { "type": "LineString", "coordinates": [[0.0, -20.0], [1.0, 0.0]] }
+                    ] }
+                ]
+            })),
+            feature(serde_json::Value::Null),
+            feature(json!({ "type": "LineString", "coordinates": [[1.0, 1.0, 500.0], [2.0, 3.0, -500.0]] })),
+        ]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iter_positions_counts_polygon_with_hole() {
+        let polygon = Value::Polygon(vec![ring(0.0, 10.0), ring(2.0, 4.0)]);
+        assert_eq!(iter_positions(&polygon).count(), 10);
+        assert_eq!(iter_positions(&polygon).nth(6), Some(&vec![4.0, 2.0]));
+    }
+
+    #[test]
+    fn test_iter_positions_descends_into_collections() {
+        let collection = Value::GeometryCollection(vec![
+            Geometry::new(Value::Point(vec![1.0, 2.0])),
+            Geometry::new(Value::GeometryCollection(vec![Geometry::new(
+                Value::MultiPolygon(vec![vec![ring(0.0, 1.0)], vec![ring(5.0, 6.0)]]),
+            )])),
+            Geometry::new(Value::MultiLineString(vec![vec![
+                vec![0.0, 0.0],
+                vec![1.0, 1.0],
+            ]])),
+        ]);
+        assert_eq!(iter_positions(&collection).count(), 13);
+        assert_eq!(iter_positions(&collection).next(), Some(&vec![1.0, 2.0]));
+        assert!(iter_positions(&Value::GeometryCollection(vec![]))
+            .next()
+            .is_none());
+    }
+
+    #[test]
+    fn test_ring_signed_area_of_ccw_square() {
+        assert_eq!(ring_signed_area(&ring(0.0, 10.0)), 100.0);
+        // An unclosed ring has the same area
+        assert_eq!(ring_signed_area(&ring(0.0, 10.0)[..4]), 100.0);
+    }
+
+    #[test]
+    fn test_ring_signed_area_of_cw_square() {
+        let mut clockwise = ring(2.0, 4.0);
+        clockwise.reverse();
+        assert_eq!(ring_signed_area(&clockwise), -4.0);
+        assert_eq!(ring_signed_area(&[vec![0.0, 0.0], vec![1.0, 1.0]]), 0.0);
+    }
+
+    #[test]
+    fn test_compute_bbox_per_feature_covers_every_geometry_type() {
+        let geojson = GeoJson::from_json_value(every_geometry_type()).unwrap();
+        assert_eq!(
+            compute_bbox_per_feature(&geojson),
+            vec![
+                Some([1.0, 2.0, 1.0, 2.0]),
+                Some([-3.0, -6.0, 5.0, 4.0]),
+                Some([0.0, 0.0, 7.0, 1.0]),
+                Some([0.0, 0.0, 9.0, 9.0]),
+                Some([-10.0, -10.0, -5.0, -5.0]),
+                Some([0.0, 0.0, 30.0, 30.0]),
+                Some([-40.0, -20.0, 1.0, 10.0]),
+                None,
+                Some([1.0, 1.0, 2.0, 3.0]),
+            ]
+        );
+        assert_eq!(compute_bbox(&geojson), Some([-40.0, -20.0, 30.0, 30.0]));
+    }
+
+    #[test]
+    fn test_compute_bbox_of_empty_inputs() {
+        let empty =
+            GeoJson::from_json_value(json!({ "type": "FeatureCollection", "features": [] }));
+        assert_eq!(compute_bbox(&empty.unwrap()), None);
+        let null = GeoJson::from_json_value(json!({
+            "type": "Feature",
+            "geometry": null,
+            "properties": {}
+        }))
+        .unwrap();
+        assert_eq!(compute_bbox(&null), None);
+        assert_eq!(compute_bbox_per_feature(&null), vec![None]);
+    }
+
+    #[test]
+    fn test_compute_bbox_matches_projected_output() {
+        // Web Mercator is monotonic along each axis, so the corners of the
+        // input bbox project to the corners of the output bbox
+        let mut config = TransformerConfig::default();
+        let input = GeoJson::from_json_value(every_geometry_type()).unwrap();
+        let [min_x, min_y, max_x, max_y] = compute_bbox(&input).unwrap();
+        let options = ProcessingOptions::new().with_null_geometry(NullGeometryPolicy::PassThrough);
+        let output =
+            process_feature_collection_with_options(every_geometry_type(), &mut config, &options)
+                .unwrap();
+        let projected = compute_bbox(&output.geojson).unwrap();
+
+        let (px, py) = config.transform_point(min_x, min_y).unwrap();
+        let (qx, qy) = config.transform_point(max_x, max_y).unwrap();
+        for (actual, expected) in projected.iter().zip([px, py, qx, qy]) {
+            assert!((actual - expected).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_ring_signed_area_far_from_origin() {
+        // A 0.7 by 0.3 m rectangle near the edge of Web Mercator, where the
+        // cross products of raw coordinates are around 4e14 and the plain
+        // shoelace formula returns 0.25
+        let (x, y) = (20_037_508.34, 19_971_868.88);
+        let rectangle = vec![
+            vec![x, y],
+            vec![x + 0.7, y],
+            vec![x + 0.7, y + 0.3],
+            vec![x, y + 0.3],
+            vec![x, y],
+        ];
+        assert!((ring_signed_area(&rectangle) - 0.21).abs() < 1e-6);
+    }
+}
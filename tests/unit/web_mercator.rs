@@ -0,0 +1,67 @@
+use proj_exercise_simple::transformer::{
+    from_web_mercator, to_web_mercator, TransformerConfig, TransformerError,
+};
+
+/// Positions across the Web Mercator domain, including the antimeridian
+fn sample_positions() -> Vec<(f64, f64)> {
+    let mut positions = Vec::new();
+    for lon in (-180..=180).step_by(30) {
+        for lat in (-85..=85).step_by(17) {
+            positions.push((lon as f64, lat as f64));
+        }
+    }
+    positions.push((12.345678, 89.9));
+    positions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_built_in_forward_matches_proj() {
+        let transformer = TransformerConfig::default().get_transformer().unwrap();
+        for (lon, lat) in sample_positions() {
+            let expected: (f64, f64) = transformer.convert((lon, lat)).unwrap();
+            let (x, y) = to_web_mercator(lon, lat).unwrap();
+            assert!(
+                (x - expected.0).abs() < 1e-6 && (y - expected.1).abs() < 1e-6,
+                "({lon}, {lat}) projected to ({x}, {y}), PROJ gives {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_built_in_inverse_matches_proj() {
+        let inverse = TransformerConfig::default()
+            .get_inverse_transformer()
+            .unwrap();
+        for (lon, lat) in sample_positions() {
+            let (x, y) = to_web_mercator(lon, lat).unwrap();
+            let expected: (f64, f64) = inverse.convert((x, y)).unwrap();
+            let (rx, ry) = from_web_mercator(x, y).unwrap();
+            assert!(
+                (rx - expected.0).abs() < 1e-9 && (ry - expected.1).abs() < 1e-9,
+                "({x}, {y}) unprojected to ({rx}, {ry}), PROJ gives {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_built_in_wraps_longitude_and_rejects_the_poles() {
+        let (wrapped, _) = to_web_mercator(190.0, 0.0).unwrap();
+        let (expected, _) = to_web_mercator(-170.0, 0.0).unwrap();
+        assert!((wrapped - expected).abs() < 1e-6);
+
+        for (lon, lat) in [(0.0, 90.0), (0.0, -91.0), (f64::NAN, 0.0)] {
+            assert!(matches!(
+                to_web_mercator(lon, lat),
+                Err(TransformerError::OutsideDomain { .. })
+            ));
+        }
+        assert!(matches!(
+            from_web_mercator(f64::INFINITY, 0.0),
+            Err(TransformerError::OutsideDomain { .. })
+        ));
+    }
+}
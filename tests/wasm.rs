@@ -0,0 +1,50 @@
+//! Smoke tests for the pure-Rust backend on wasm32-unknown-unknown
+//!
+//! Run with `wasm-pack test --node --no-default-features -- --test wasm`.
+#![cfg(target_arch = "wasm32")]
+
+use geojson::{GeoJson, Value};
+use proj_exercise_simple::helpers::process_feature_collection;
+use proj_exercise_simple::transformer::{to_web_mercator, TransformerConfig};
+use serde_json::json;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn project(input: serde_json::Value) -> Value {
+    match process_feature_collection(input, &mut TransformerConfig::default()).unwrap() {
+        GeoJson::Geometry(geometry) => geometry.value,
+        other => panic!("expected a geometry, got {other}"),
+    }
+}
+
+fn assert_close(actual: &[f64], (x, y): (f64, f64)) {
+    assert!((actual[0] - x).abs() < 1e-6, "{} != {x}", actual[0]);
+    assert!((actual[1] - y).abs() < 1e-6, "{} != {y}", actual[1]);
+}
+
+#[wasm_bindgen_test]
+fn test_point_projection() {
+    let Value::Point(position) = project(json!({ "type": "Point", "coordinates": [13.4, 52.5] }))
+    else {
+        panic!("expected a point");
+    };
+    assert_close(&position, to_web_mercator(13.4, 52.5).unwrap());
+}
+
+#[wasm_bindgen_test]
+fn test_polygon_projection() {
+    let ring = [
+        [13.0, 52.0],
+        [14.0, 52.0],
+        [14.0, 53.0],
+        [13.0, 53.0],
+        [13.0, 52.0],
+    ];
+    let Value::Polygon(rings) = project(json!({ "type": "Polygon", "coordinates": [ring] })) else {
+        panic!("expected a polygon");
+    };
+    assert_eq!(rings.len(), 1);
+    assert_eq!(rings[0].len(), ring.len());
+    for (projected, [lon, lat]) in rings[0].iter().zip(ring) {
+        assert_close(projected, to_web_mercator(lon, lat).unwrap());
+    }
+}